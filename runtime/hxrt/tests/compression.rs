@@ -0,0 +1,96 @@
+use flate2::write::{DeflateEncoder, GzEncoder, ZlibEncoder};
+use flate2::Compression;
+use hxrt::bytes::Bytes;
+use hxrt::cell::HxRef;
+use hxrt::compression::{decode_content, decode_content_limited, is_supported_encoding};
+use std::io::Write;
+
+const BODY: &[u8] = b"hello hello hello compressed world";
+
+fn bytes(data: Vec<u8>) -> HxRef<Bytes> {
+    HxRef::new(Bytes::from_vec(data))
+}
+
+fn decoded(encoding: &str, data: Vec<u8>) -> Vec<u8> {
+    decode_content(encoding, &bytes(data))
+        .borrow()
+        .as_slice()
+        .to_vec()
+}
+
+fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+fn brotli(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut encoder = brotli::CompressorWriter::new(&mut out, 4096, 5, 22);
+    encoder.write_all(data).unwrap();
+    drop(encoder);
+    out
+}
+
+#[test]
+fn every_advertised_coding_round_trips() {
+    assert_eq!(decoded("gzip", gzip(BODY)), BODY);
+    assert_eq!(decoded("X-GZIP", gzip(BODY)), BODY);
+    assert_eq!(decoded("br", brotli(BODY)), BODY);
+    assert_eq!(decoded("identity", BODY.to_vec()), BODY);
+
+    let mut zlib = ZlibEncoder::new(Vec::new(), Compression::default());
+    zlib.write_all(BODY).unwrap();
+    assert_eq!(decoded("deflate", zlib.finish().unwrap()), BODY);
+    let mut raw = DeflateEncoder::new(Vec::new(), Compression::default());
+    raw.write_all(BODY).unwrap();
+    assert_eq!(decoded("deflate", raw.finish().unwrap()), BODY);
+
+    // Listed in order of application: gzip first, then br.
+    assert_eq!(decoded("gzip, br", brotli(&gzip(BODY))), BODY);
+}
+
+#[test]
+fn unknown_codings_and_corrupt_bodies_throw() {
+    assert!(is_supported_encoding("gzip, br"));
+    assert!(!is_supported_encoding("gzip, zstd"));
+    assert!(
+        hxrt::exception::catch_unwind(|| decode_content("zstd", &bytes(BODY.to_vec()))).is_err()
+    );
+    assert!(
+        hxrt::exception::catch_unwind(|| decode_content("gzip", &bytes(BODY.to_vec()))).is_err()
+    );
+    let mut truncated = gzip(BODY);
+    truncated.truncate(truncated.len() / 2);
+    assert!(hxrt::exception::catch_unwind(|| decode_content("gzip", &bytes(truncated))).is_err());
+}
+
+#[test]
+fn decoded_bodies_past_the_cap_throw() {
+    let bomb = gzip(&vec![0u8; 1 << 20]);
+    assert!(bomb.len() < 4096);
+    let decoded = decode_content_limited("gzip", &bytes(bomb.clone()), 1 << 20);
+    assert_eq!(decoded.borrow().as_slice().len(), 1 << 20);
+
+    for encoding in ["gzip", "gzip, identity"] {
+        let err = hxrt::exception::catch_unwind(|| {
+            decode_content_limited(encoding, &bytes(bomb.clone()), (1 << 20) - 1)
+        })
+        .unwrap_err();
+        let message = err.to_haxe_string();
+        assert!(message.starts_with("HXRT-HTTP-DECODE"), "{message}");
+    }
+    // Each stage is capped, including an intermediate one that a later stage would shrink.
+    assert!(hxrt::exception::catch_unwind(|| decode_content_limited(
+        "gzip, gzip",
+        &bytes(gzip(&gzip(&vec![0u8; 1 << 16]))),
+        1 << 12
+    ))
+    .is_err());
+    assert!(hxrt::exception::catch_unwind(|| decode_content_limited(
+        "identity",
+        &bytes(BODY.to_vec()),
+        -1
+    ))
+    .is_err());
+}
@@ -0,0 +1,124 @@
+use hxrt::cell::HxRef;
+use hxrt::http_pool::{
+    http_pool_acquire, http_pool_idle_count, http_pool_release, http_pool_set_idle_timeout,
+    http_pool_set_max_connections,
+};
+use hxrt::net::SocketHandle;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{IpAddr, Ipv4Addr, Shutdown, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+const LOOPBACK: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+/// Keep-alive stand-in server: answers every request with `ok`; `close_after` closes the
+/// connection after that many responses.
+fn spawn_server(close_after: usize) -> (i32, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind((LOOPBACK, 0)).unwrap();
+    let port = listener.local_addr().unwrap().port() as i32;
+    let accepted = Arc::new(AtomicUsize::new(0));
+    let counter = accepted.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            counter.fetch_add(1, Ordering::SeqCst);
+            std::thread::spawn(move || serve(stream.unwrap(), close_after));
+        }
+    });
+    (port, accepted)
+}
+
+fn serve(stream: TcpStream, close_after: usize) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut writer = stream;
+    for _ in 0..close_after {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                return;
+            }
+            if line == "\r\n" {
+                break;
+            }
+        }
+        writer
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok")
+            .unwrap();
+    }
+    let _ = writer.shutdown(Shutdown::Both);
+}
+
+fn round_trip(socket: &HxRef<SocketHandle>) {
+    let request = b"GET / HTTP/1.1\r\nHost: pool\r\n\r\n";
+    assert_eq!(
+        socket.borrow_mut().write_stream(request),
+        request.len() as i32
+    );
+    let expected = b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok";
+    let mut response = Vec::new();
+    let mut buf = [0u8; 64];
+    while response.len() < expected.len() {
+        let read = socket.borrow_mut().read_stream(&mut buf);
+        assert!(read > 0, "connection closed mid-response");
+        response.extend_from_slice(&buf[..read as usize]);
+    }
+    assert_eq!(response, expected);
+}
+
+#[test]
+fn released_connections_are_reused_until_the_server_closes_them() {
+    let (port, accepted) = spawn_server(2);
+    let key = format!("http://127.0.0.1:{port}");
+
+    let first = hxrt::net::socket_new_tcp();
+    assert!(!http_pool_acquire(&key, &first, 5.0));
+    first.borrow_mut().connect(LOOPBACK, port);
+    round_trip(&first);
+    http_pool_release(&key, &first, true);
+    assert_eq!(http_pool_idle_count(&key), 1);
+
+    let second = hxrt::net::socket_new_tcp();
+    assert!(http_pool_acquire(&key, &second, 5.0));
+    assert_eq!(http_pool_idle_count(&key), 0);
+    round_trip(&second);
+    assert_eq!(accepted.load(Ordering::SeqCst), 1);
+
+    // The server closes after its second response: the pooled connection is found dead.
+    http_pool_release(&key, &second, true);
+    std::thread::sleep(Duration::from_millis(50));
+    let third = hxrt::net::socket_new_tcp();
+    assert!(!http_pool_acquire(&key, &third, 5.0));
+    http_pool_release(&key, &third, false);
+    assert_eq!(http_pool_idle_count(&key), 0);
+}
+
+#[test]
+fn acquire_waits_for_a_slot_at_the_connection_cap() {
+    let key = "http://pool-cap.invalid:80";
+    http_pool_set_max_connections(1);
+
+    let held = hxrt::net::socket_new_tcp();
+    assert!(!http_pool_acquire(key, &held, 5.0));
+    let timed_out = hxrt::exception::catch_unwind(|| {
+        http_pool_acquire(key, &hxrt::net::socket_new_tcp(), 0.05)
+    });
+    assert!(timed_out.is_err());
+
+    let (sender, receiver) = mpsc::channel();
+    let waiter = std::thread::spawn(move || {
+        let socket = hxrt::net::socket_new_tcp();
+        let reused = http_pool_acquire(key, &socket, 5.0);
+        sender.send(()).unwrap();
+        http_pool_release(key, &socket, false);
+        reused
+    });
+    assert!(receiver.recv_timeout(Duration::from_millis(100)).is_err());
+    http_pool_release(key, &held, false);
+    assert!(receiver.recv_timeout(Duration::from_secs(5)).is_ok());
+    assert!(!waiter.join().unwrap());
+
+    http_pool_set_max_connections(8);
+    assert!(hxrt::exception::catch_unwind(|| http_pool_set_max_connections(0)).is_err());
+    assert!(hxrt::exception::catch_unwind(|| http_pool_set_idle_timeout(-1.0)).is_err());
+}
@@ -0,0 +1,169 @@
+use hxrt::http_proxy::{http_proxy_bypassed, http_proxy_connect, http_proxy_from_env};
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer};
+use rustls::{ServerConfig, ServerConnection};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, TcpListener, TcpStream};
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// One-shot TLS server answering `ping` with `pong`.
+fn spawn_tls_server() -> u16 {
+    let rcgen::CertifiedKey { cert, key_pair } =
+        rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+    let config = Arc::new(
+        ServerConfig::builder()
+            .with_no_client_auth()
+            .with_single_cert(
+                vec![CertificateDer::from(cert.der().as_ref().to_vec())],
+                PrivateKeyDer::from(PrivatePkcs8KeyDer::from(key_pair.serialize_der())),
+            )
+            .unwrap(),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut tls = rustls::StreamOwned::new(ServerConnection::new(config).unwrap(), stream);
+        let mut buf = [0u8; 4];
+        tls.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"ping");
+        tls.write_all(b"pong").unwrap();
+        tls.flush().unwrap();
+    });
+    port
+}
+
+/// One-shot proxy stand-in: reports the request head, answers `reply`, and on `200` relays bytes
+/// to `127.0.0.1:target`.
+fn spawn_proxy(reply: &'static str, target: u16) -> (u16, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let (client, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(client.try_clone().unwrap());
+        let mut head = String::new();
+        while !head.ends_with("\r\n\r\n") {
+            assert!(reader.read_line(&mut head).unwrap() > 0);
+        }
+        sender.send(head).unwrap();
+        let mut client = client;
+        client.write_all(reply.as_bytes()).unwrap();
+        if !reply.starts_with("HTTP/1.1 200") {
+            return;
+        }
+        let upstream = TcpStream::connect(("127.0.0.1", target)).unwrap();
+        let (mut up_read, mut up_write) = (upstream.try_clone().unwrap(), upstream);
+        let mut client_write = client.try_clone().unwrap();
+        let relay = std::thread::spawn(move || {
+            std::io::copy(&mut up_read, &mut client_write).ok();
+            client_write.shutdown(Shutdown::Write).ok();
+        });
+        std::io::copy(&mut reader, &mut up_write).ok();
+        up_write.shutdown(Shutdown::Write).ok();
+        relay.join().unwrap();
+    });
+    (port, receiver)
+}
+
+fn connect_to(port: u16) -> hxrt::cell::HxRef<hxrt::net::SocketHandle> {
+    let socket = hxrt::net::socket_new_tcp();
+    socket
+        .borrow_mut()
+        .connect(hxrt::net::host_resolve("127.0.0.1"), port as i32);
+    socket
+}
+
+#[test]
+fn connect_tunnel_carries_a_tls_session() {
+    let target = spawn_tls_server();
+    let (proxy, heads) = spawn_proxy("HTTP/1.1 200 Connection established\r\n\r\n", target);
+    let socket = connect_to(proxy);
+
+    http_proxy_connect(
+        &socket,
+        "localhost",
+        target as i32,
+        Some("Basic dXNlcjpwYXNz".to_string()),
+    );
+    let head = heads.recv().unwrap();
+    assert!(head.starts_with(&format!("CONNECT localhost:{target} HTTP/1.1\r\n")));
+    assert!(head.contains("\r\nProxy-Authorization: Basic dXNlcjpwYXNz\r\n"));
+
+    socket
+        .borrow_mut()
+        .tls_handshake_client(Some("localhost".to_string()), Some(false), None);
+    assert_eq!(socket.borrow_mut().write_stream(b"ping"), 4);
+    let mut out = [0u8; 4];
+    assert_eq!(socket.borrow_mut().read_stream(&mut out), 4);
+    assert_eq!(&out, b"pong");
+}
+
+#[test]
+fn refused_connect_throws() {
+    let (proxy, heads) = spawn_proxy(
+        "HTTP/1.1 407 Proxy Authentication Required\r\nContent-Length: 0\r\n\r\n",
+        0,
+    );
+    let socket = connect_to(proxy);
+    let refused =
+        hxrt::exception::catch_unwind(|| http_proxy_connect(&socket, "example.test", 443, None));
+    assert!(refused.is_err());
+    assert!(!heads.recv().unwrap().contains("Proxy-Authorization"));
+}
+
+#[test]
+fn no_proxy_matches_hosts_domains_and_ports() {
+    assert!(http_proxy_bypassed("*", "example.com", 80));
+    assert!(http_proxy_bypassed("localhost, .internal", "LOCALHOST", 80));
+    assert!(http_proxy_bypassed(
+        "localhost, .internal",
+        "api.internal",
+        443
+    ));
+    assert!(http_proxy_bypassed("internal", "internal", 443));
+    assert!(http_proxy_bypassed("internal", "deep.api.internal", 443));
+    assert!(!http_proxy_bypassed("internal", "notinternal", 443));
+    assert!(http_proxy_bypassed("example.com:8080", "example.com", 8080));
+    assert!(!http_proxy_bypassed("example.com:8080", "example.com", 80));
+    assert!(http_proxy_bypassed("10.0.0.1,::1", "::1", 80));
+    assert!(http_proxy_bypassed("[::1]:8443", "[::1]", 8443));
+    assert!(!http_proxy_bypassed("", "example.com", 80));
+}
+
+// The only test in this binary that touches the environment.
+#[test]
+fn cgi_requests_ignore_upper_case_http_proxy() {
+    for name in [
+        "http_proxy",
+        "https_proxy",
+        "HTTPS_PROXY",
+        "no_proxy",
+        "NO_PROXY",
+    ] {
+        std::env::remove_var(name);
+    }
+    std::env::set_var("HTTP_PROXY", "http://injected:3128");
+    std::env::remove_var("REQUEST_METHOD");
+    assert_eq!(
+        http_proxy_from_env(false, "example.com", 80).as_deref(),
+        Some("http://injected:3128")
+    );
+
+    std::env::set_var("REQUEST_METHOD", "GET");
+    assert_eq!(http_proxy_from_env(false, "example.com", 80), None);
+    std::env::set_var("http_proxy", "http://configured:8080");
+    assert_eq!(
+        http_proxy_from_env(false, "example.com", 80).as_deref(),
+        Some("http://configured:8080")
+    );
+    std::env::set_var("HTTPS_PROXY", "http://secure:8443");
+    assert_eq!(
+        http_proxy_from_env(true, "example.com", 443).as_deref(),
+        Some("http://secure:8443")
+    );
+
+    for name in ["HTTP_PROXY", "http_proxy", "HTTPS_PROXY", "REQUEST_METHOD"] {
+        std::env::remove_var(name);
+    }
+}
@@ -0,0 +1,299 @@
+use hxrt::cell::{HxDynRef, HxRc};
+use hxrt::http_server::{http_response, http_server_bind, HttpRequestHandle, HttpResponseHandle};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+type Handler = HxRc<dyn Fn(HttpRequestHandle) -> HttpResponseHandle + Send + Sync>;
+
+fn connect(port: i32) -> TcpStream {
+    let stream = TcpStream::connect(("127.0.0.1", port as u16)).unwrap();
+    stream
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    stream
+}
+
+/// Read one response: status, lower-cased headers and a `Content-Length` body.
+fn read_response(stream: &mut TcpStream, head_only: bool) -> (u16, Vec<(String, String)>, String) {
+    let mut head = Vec::new();
+    let mut byte = [0u8; 1];
+    while !head.ends_with(b"\r\n\r\n") {
+        assert_eq!(
+            stream.read(&mut byte).unwrap(),
+            1,
+            "connection closed early"
+        );
+        head.push(byte[0]);
+    }
+    let head = String::from_utf8(head).unwrap();
+    let mut lines = head.trim_end().split("\r\n");
+    let status = lines.next().unwrap()[9..12].parse().unwrap();
+    let headers: Vec<(String, String)> = lines
+        .map(|line| {
+            let (name, value) = line.split_once(": ").unwrap();
+            (name.to_ascii_lowercase(), value.to_owned())
+        })
+        .collect();
+    let length = headers
+        .iter()
+        .find(|(name, _)| name == "content-length")
+        .map_or(0, |(_, value)| value.parse().unwrap());
+    let mut body = vec![0u8; if head_only { 0 } else { length }];
+    stream.read_exact(&mut body).unwrap();
+    (status, headers, String::from_utf8(body).unwrap())
+}
+
+fn assert_closed(stream: &mut TcpStream) {
+    let mut rest = Vec::new();
+    stream.read_to_end(&mut rest).unwrap();
+    assert!(rest.is_empty(), "unexpected bytes: {rest:?}");
+}
+
+#[test]
+fn bodies_and_keep_alive_requests_reach_the_handler() {
+    let server = http_server_bind("127.0.0.1", 0);
+    let handler: Handler = HxRc::new(|request: HttpRequestHandle| {
+        let response = http_response(200);
+        response.set_header("X-Path", request.path());
+        response.set_header("X-Query", request.query().unwrap_or_default());
+        response.add_header("X-Trace", request.header("x-trace").unwrap_or_default());
+        response.set_body_string(format!("{} {}", request.method(), request.body_string()));
+        response
+    });
+    server.start(HxDynRef::new(handler));
+    let mut client = connect(server.port());
+
+    client
+        .write_all(b"POST /echo?a=1 HTTP/1.1\r\nHost: t\r\nX-Trace: one\r\nX-Trace: two\r\nContent-Length: 5\r\n\r\nhello")
+        .unwrap();
+    let (status, headers, body) = read_response(&mut client, false);
+    assert_eq!(status, 200);
+    assert_eq!(body, "POST hello");
+    assert!(headers.contains(&("x-path".into(), "/echo".into())));
+    assert!(headers.contains(&("x-query".into(), "a=1".into())));
+    assert!(headers.contains(&("x-trace".into(), "one, two".into())));
+    assert!(!headers.iter().any(|(name, _)| name == "connection"));
+
+    client
+        .write_all(b"PUT /chunks HTTP/1.1\r\nHost: t\r\nTransfer-Encoding: chunked\r\n\r\n4;ext=1\r\nWiki\r\n6\r\npedia \r\n0\r\nX-Checksum: 1\r\n\r\n")
+        .unwrap();
+    let (status, _, body) = read_response(&mut client, false);
+    assert_eq!(status, 200);
+    assert_eq!(body, "PUT Wikipedia ");
+
+    client
+        .write_all(b"GET / HTTP/1.1\r\nHost: t\r\nConnection: close\r\n\r\n")
+        .unwrap();
+    let (_, headers, body) = read_response(&mut client, false);
+    assert_eq!(body, "GET ");
+    assert!(headers.contains(&("connection".into(), "close".into())));
+    assert_closed(&mut client);
+
+    // HTTP/1.0 closes unless the client asks for keep-alive.
+    let mut client = connect(server.port());
+    client
+        .write_all(b"GET /old HTTP/1.0\r\nConnection: keep-alive\r\n\r\nGET /old HTTP/1.0\r\n\r\n")
+        .unwrap();
+    let (_, headers, _) = read_response(&mut client, false);
+    assert!(headers.contains(&("connection".into(), "keep-alive".into())));
+    let (_, headers, _) = read_response(&mut client, false);
+    assert!(headers.contains(&("connection".into(), "close".into())));
+    assert_closed(&mut client);
+
+    server.stop();
+    assert!(!server.is_running());
+}
+
+#[test]
+fn malformed_and_oversized_requests_skip_the_handler() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let server = http_server_bind("127.0.0.1", 0);
+    server.set_max_body_size(4);
+    let seen = calls.clone();
+    let handler: Handler = HxRc::new(move |_| {
+        seen.fetch_add(1, Ordering::SeqCst);
+        http_response(204)
+    });
+    server.start(HxDynRef::new(handler));
+
+    for (request, expected) in [
+        (&b"NOT A REQUEST LINE\r\n\r\n"[..], 400),
+        (b"GET / HTTP/2.0\r\nHost: t\r\n\r\n", 505),
+        (b"GET / HTTP/1.1\r\n\r\n", 400),
+        (b"GET / HTTP/1.1\r\nHost: t\r\n folded\r\n\r\n", 400),
+        (b"POST / HTTP/1.1\r\nHost: t\r\nContent-Length: 10\r\n\r\n0123456789", 413),
+        (b"POST / HTTP/1.1\r\nHost: t\r\nContent-Length: 1\r\nContent-Length: 2\r\n\r\nab", 400),
+        (b"POST / HTTP/1.1\r\nHost: t\r\nContent-Length: 1\r\nTransfer-Encoding: chunked\r\n\r\n", 400),
+        (b"POST / HTTP/1.1\r\nHost: t\r\nTransfer-Encoding: gzip\r\n\r\n", 501),
+        (b"POST / HTTP/1.1\r\nHost: t\r\nTransfer-Encoding: chunked\r\n\r\n9\r\n", 413),
+    ] {
+        let mut client = connect(server.port());
+        client.write_all(request).unwrap();
+        let (status, headers, _) = read_response(&mut client, false);
+        assert_eq!(status, expected, "{}", String::from_utf8_lossy(request));
+        assert!(headers.contains(&("connection".into(), "close".into())));
+        assert_closed(&mut client);
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    let mut client = connect(server.port());
+    client
+        .write_all(
+            b"POST / HTTP/1.1\r\nHost: t\r\nContent-Length: 2\r\nExpect: 100-continue\r\n\r\n",
+        )
+        .unwrap();
+    let (status, _, _) = read_response(&mut client, false);
+    assert_eq!(status, 100);
+    client.write_all(b"ok").unwrap();
+    let (status, headers, _) = read_response(&mut client, false);
+    assert_eq!(status, 204);
+    assert!(!headers.iter().any(|(name, _)| name == "content-length"));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    server.stop();
+}
+
+#[test]
+fn handler_exceptions_answer_500_and_head_omits_the_body() {
+    let server = http_server_bind("127.0.0.1", 0);
+    let handler: Handler = HxRc::new(|request: HttpRequestHandle| {
+        if request.path() == "/boom" {
+            hxrt::exception::throw(hxrt::dynamic::from(String::from("boom")));
+        }
+        let response = http_response(200);
+        response.set_header("Content-Length", "999");
+        response.set_body_string("twelve bytes");
+        response
+    });
+    server.start(HxDynRef::new(handler));
+    let mut client = connect(server.port());
+
+    client
+        .write_all(b"GET /boom HTTP/1.1\r\nHost: t\r\n\r\n")
+        .unwrap();
+    let (status, _, body) = read_response(&mut client, false);
+    assert_eq!(status, 500);
+    assert_eq!(body, "Internal Server Error");
+
+    client
+        .write_all(b"HEAD / HTTP/1.1\r\nHost: t\r\n\r\nGET / HTTP/1.1\r\nHost: t\r\n\r\n")
+        .unwrap();
+    let (status, headers, _) = read_response(&mut client, true);
+    assert_eq!(status, 200);
+    assert!(headers.contains(&("content-length".into(), "12".into())));
+    let (_, _, body) = read_response(&mut client, false);
+    assert_eq!(body, "twelve bytes");
+
+    server.stop();
+    server.stop();
+    let handler: Handler = HxRc::new(|_| http_response(200));
+    let restart = hxrt::exception::catch_unwind(|| server.serve(HxDynRef::new(handler)));
+    assert!(restart.is_err());
+    assert!(hxrt::exception::catch_unwind(|| http_response(99)).is_err());
+    assert!(
+        hxrt::exception::catch_unwind(|| http_response(200).set_header("X", "a\r\nb")).is_err()
+    );
+}
+
+#[test]
+fn connections_past_the_cap_get_503_without_stalling_accept() {
+    let server = http_server_bind("127.0.0.1", 0);
+    server.set_max_connections(1);
+    let handler: Handler = HxRc::new(|_| {
+        let response = http_response(200);
+        response.set_body_string("ok");
+        response
+    });
+    server.start(HxDynRef::new(handler));
+
+    // Holds the only slot on an idle keep-alive connection.
+    let mut holder = connect(server.port());
+    holder
+        .write_all(b"GET / HTTP/1.1\r\nHost: t\r\n\r\n")
+        .unwrap();
+    assert_eq!(read_response(&mut holder, false).0, 200);
+
+    // Trickles bytes so its rejected connection keeps draining for as long as it is allowed to.
+    let mut trickler = connect(server.port());
+    let trickle = std::thread::spawn(move || {
+        for _ in 0..40 {
+            if trickler.write_all(b"x").is_err() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+    });
+    std::thread::sleep(Duration::from_millis(100));
+
+    let started = Instant::now();
+    let mut rejected = connect(server.port());
+    rejected
+        .write_all(b"GET / HTTP/1.1\r\nHost: t\r\n\r\n")
+        .unwrap();
+    let (status, headers, _) = read_response(&mut rejected, false);
+    assert_eq!(status, 503);
+    assert!(headers.contains(&("connection".into(), "close".into())));
+    assert!(
+        started.elapsed() < Duration::from_secs(2),
+        "503 took {:?}",
+        started.elapsed()
+    );
+    assert_closed(&mut rejected);
+
+    // Closing the holder frees the slot.
+    drop(holder);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        let mut client = connect(server.port());
+        client
+            .write_all(b"GET / HTTP/1.1\r\nHost: t\r\nConnection: close\r\n\r\n")
+            .unwrap();
+        let (status, _, body) = read_response(&mut client, false);
+        if status == 200 {
+            assert_eq!(body, "ok");
+            break;
+        }
+        assert!(Instant::now() < deadline, "slot never freed");
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
+    trickle.join().unwrap();
+    server.stop();
+}
+
+#[test]
+fn interim_handler_statuses_answer_500_and_stop_wakes_accept() {
+    let server = http_server_bind("127.0.0.1", 0);
+    let handler: Handler = HxRc::new(|request: HttpRequestHandle| {
+        if request.path() == "/switch" {
+            return http_response(101);
+        }
+        http_response(204)
+    });
+    server.start(HxDynRef::new(handler));
+    let mut client = connect(server.port());
+
+    client
+        .write_all(b"GET /switch HTTP/1.1\r\nHost: t\r\n\r\n")
+        .unwrap();
+    let (status, _, body) = read_response(&mut client, false);
+    assert_eq!(status, 500);
+    assert_eq!(body, "Internal Server Error");
+
+    client
+        .write_all(b"GET / HTTP/1.1\r\nHost: t\r\nConnection: close\r\n\r\n")
+        .unwrap();
+    assert_eq!(read_response(&mut client, false).0, 204);
+
+    // Nothing connects after stop(): the waker alone must end the accept loop.
+    let started = Instant::now();
+    server.stop();
+    assert!(
+        started.elapsed() < Duration::from_millis(200),
+        "stop took {:?}",
+        started.elapsed()
+    );
+}
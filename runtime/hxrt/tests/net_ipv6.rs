@@ -0,0 +1,74 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+#[test]
+fn host_resolve_parses_ipv6_literals_and_keeps_ipv4_ints() {
+    let v6 = hxrt::net::host_resolve("::1");
+    assert_eq!(v6, IpAddr::V6(Ipv6Addr::LOCALHOST));
+    assert_eq!(hxrt::net::host_resolve("[::1]"), v6);
+    assert_eq!(hxrt::net::host_to_string(v6), "::1");
+    assert_eq!(hxrt::net::ip_to_ipv4_int(v6), 0);
+
+    let v4 = hxrt::net::host_resolve("127.0.0.1");
+    assert_eq!(v4, IpAddr::V4(Ipv4Addr::LOCALHOST));
+    assert_eq!(hxrt::net::ip_to_ipv4_int(v4), 0x7f00_0001);
+    assert_eq!(hxrt::net::ip_from_ipv4_int(0x7f00_0001), v4);
+
+    let mapped = hxrt::net::ip_parse("::ffff:127.0.0.1");
+    assert_eq!(hxrt::net::ip_to_ipv4_int(mapped), 0x7f00_0001);
+    assert_eq!(
+        hxrt::net::ip_parse(&hxrt::net::host_to_string(mapped)),
+        mapped
+    );
+}
+
+#[test]
+fn dual_stack_listener_accepts_ipv6_and_ipv4_peers() {
+    let listener = hxrt::net::socket_new_tcp();
+    listener.borrow_mut().bind(hxrt::net::host_resolve("::"), 0);
+    listener.borrow_mut().listen(4);
+    let (bound, port) = listener.borrow().host();
+    assert_eq!(bound, IpAddr::V6(Ipv6Addr::UNSPECIFIED));
+
+    for (client_ip, expected_peer) in [
+        ("::1", IpAddr::V6(Ipv6Addr::LOCALHOST)),
+        ("127.0.0.1", IpAddr::V4(Ipv4Addr::LOCALHOST)),
+    ] {
+        let client = hxrt::net::socket_new_tcp();
+        client
+            .borrow_mut()
+            .connect(hxrt::net::host_resolve(client_ip), port);
+        let conn = listener.borrow_mut().accept();
+        assert_eq!(conn.borrow().peer().0, expected_peer);
+        assert_eq!(client.borrow().peer(), (expected_peer, port));
+
+        assert_eq!(client.borrow_mut().write_stream(b"ping"), 4);
+        let (readable, _, _) =
+            hxrt::net::socket_select(vec![conn.clone()], vec![], vec![], Some(5.0));
+        assert_eq!(readable, vec![0]);
+        let mut buf = [0u8; 4];
+        assert_eq!(conn.borrow_mut().read_stream(&mut buf), 4);
+        assert_eq!(&buf, b"ping");
+    }
+}
+
+#[test]
+fn udp_round_trip_over_ipv6() {
+    let server = hxrt::net::socket_new_udp();
+    server.borrow_mut().bind(hxrt::net::host_resolve("::1"), 0);
+    let (server_ip, server_port) = server.borrow().host();
+
+    let client = hxrt::net::socket_new_udp();
+    client.borrow_mut().bind(hxrt::net::host_resolve("::1"), 0);
+    assert_eq!(
+        client
+            .borrow_mut()
+            .udp_send_to(b"hello", server_ip, server_port),
+        5
+    );
+    let mut buf = [0u8; 16];
+    server.borrow_mut().set_timeout(5.0);
+    let (n, from, _) = server.borrow_mut().udp_read_from(&mut buf);
+    assert_eq!(n, 5);
+    assert_eq!(&buf[..5], b"hello");
+    assert_eq!(from, IpAddr::V6(Ipv6Addr::LOCALHOST));
+}
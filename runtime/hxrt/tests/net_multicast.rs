@@ -0,0 +1,89 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+const GROUP: IpAddr = IpAddr::V4(Ipv4Addr::new(239, 255, 77, 77));
+const LOOPBACK: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+const ANY: IpAddr = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
+
+#[test]
+fn loopback_multicast_round_trip_reports_sender() {
+    let receiver = hxrt::net::socket_new_udp();
+    receiver.borrow_mut().set_reuse_address(true);
+    receiver.borrow_mut().bind(ANY, 0);
+    receiver.borrow_mut().set_timeout(5.0);
+    let (_, port) = receiver.borrow().host();
+    receiver.borrow_mut().udp_join_multicast(GROUP, LOOPBACK, 0);
+
+    // Options recorded before the socket exists are replayed when `bind` creates it.
+    let sender = hxrt::net::socket_new_udp();
+    sender.borrow_mut().udp_set_multicast_ttl(2);
+    sender.borrow_mut().udp_set_multicast_loop(true);
+    // A sender bound to 127.0.0.1 routes group traffic over the loopback interface.
+    sender.borrow_mut().bind(LOOPBACK, 0);
+    let (_, sender_port) = sender.borrow().host();
+    assert_eq!(sender.borrow().udp_multicast_ttl(), 2);
+    assert!(sender.borrow().udp_multicast_loop());
+
+    assert_eq!(sender.borrow_mut().udp_send_to(b"beacon", GROUP, port), 6);
+    let mut buf = [0u8; 16];
+    let (n, ip, from_port) = receiver.borrow_mut().udp_read_from(&mut buf);
+    assert_eq!(&buf[..n as usize], b"beacon");
+    assert_eq!((ip, from_port), (LOOPBACK, sender_port));
+    assert_eq!(receiver.borrow().udp_last_sender(), (LOOPBACK, sender_port));
+
+    receiver
+        .borrow_mut()
+        .udp_leave_multicast(GROUP, LOOPBACK, 0);
+}
+
+#[test]
+fn last_sender_keeps_ipv6_peers() {
+    let receiver = hxrt::net::socket_new_udp();
+    receiver
+        .borrow_mut()
+        .bind(IpAddr::V6(Ipv6Addr::LOCALHOST), 0);
+    receiver.borrow_mut().set_timeout(5.0);
+    let (_, port) = receiver.borrow().host();
+
+    let sender = hxrt::net::socket_new_udp();
+    sender.borrow_mut().bind(IpAddr::V6(Ipv6Addr::LOCALHOST), 0);
+    let (_, sender_port) = sender.borrow().host();
+    sender
+        .borrow_mut()
+        .udp_send_to(b"v6", IpAddr::V6(Ipv6Addr::LOCALHOST), port);
+
+    let mut buf = [0u8; 4];
+    let (n, ip, _) = receiver.borrow_mut().udp_read_from(&mut buf);
+    assert_eq!(n, 2);
+    assert_eq!(hxrt::net::ip_to_ipv4_int(ip), 0);
+    assert_eq!(
+        receiver.borrow().udp_last_sender(),
+        (IpAddr::V6(Ipv6Addr::LOCALHOST), sender_port)
+    );
+}
+
+#[test]
+fn invalid_multicast_requests_throw() {
+    let socket = hxrt::net::socket_new_udp();
+    let unbound =
+        hxrt::exception::catch_unwind(|| socket.borrow_mut().udp_join_multicast(GROUP, ANY, 0));
+    assert!(unbound.is_err(), "joining needs a bound socket");
+
+    socket.borrow_mut().bind(ANY, 0);
+    for join in [
+        (|s: &mut hxrt::net::SocketHandle| s.udp_join_multicast(LOOPBACK, ANY, 0))
+            as fn(&mut hxrt::net::SocketHandle),
+        |s| s.udp_join_multicast(GROUP, IpAddr::V6(Ipv6Addr::UNSPECIFIED), 0),
+        |s| s.udp_join_multicast(GROUP, ANY, -1),
+        |s| s.udp_set_multicast_ttl(256),
+        |s| {
+            s.udp_last_sender();
+        },
+    ] {
+        let result = hxrt::exception::catch_unwind(|| join(&mut socket.borrow_mut()));
+        assert!(result.is_err());
+    }
+
+    let tcp = hxrt::net::socket_new_tcp();
+    let result = hxrt::exception::catch_unwind(|| tcp.borrow_mut().udp_set_multicast_loop(false));
+    assert!(result.is_err(), "multicast options are UDP-only");
+}
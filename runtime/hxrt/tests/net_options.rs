@@ -0,0 +1,85 @@
+use std::net::{IpAddr, Ipv4Addr};
+
+const LOOPBACK: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+#[test]
+fn options_set_before_bind_are_replayed_and_inherited_by_accept() {
+    let listener = hxrt::net::socket_new_tcp();
+    {
+        let mut l = listener.borrow_mut();
+        l.set_keep_alive(true, 30.0, 5.0);
+        l.set_linger(2.0);
+        l.set_send_buffer_size(64 * 1024);
+        l.set_ttl(32);
+        // No OS socket yet: getters report the recorded values.
+        assert!(l.keep_alive());
+        assert_eq!(l.linger(), 2.0);
+        assert_eq!(l.ttl(), 32);
+        assert!(!l.reuse_address());
+    }
+    listener.borrow_mut().bind(LOOPBACK, 0);
+    listener.borrow_mut().listen(4);
+    let (_, port) = listener.borrow().host();
+
+    {
+        let l = listener.borrow();
+        assert!(
+            l.reuse_address(),
+            "listeners enable SO_REUSEADDR by default"
+        );
+        assert!(l.keep_alive());
+        assert_eq!(l.linger(), 2.0);
+        assert_eq!(l.ttl(), 32);
+        assert!(l.send_buffer_size() >= 64 * 1024);
+    }
+
+    let client = hxrt::net::socket_new_tcp();
+    client.borrow_mut().connect(LOOPBACK, port);
+    assert!(!client.borrow().keep_alive());
+    assert_eq!(client.borrow().linger(), -1.0);
+    client.borrow_mut().set_keep_alive(true, 0.0, 0.0);
+    client.borrow_mut().set_recv_buffer_size(32 * 1024);
+    assert!(client.borrow().keep_alive());
+    assert!(client.borrow().recv_buffer_size() >= 32 * 1024);
+
+    let conn = listener.borrow_mut().accept();
+    assert!(conn.borrow().keep_alive());
+    assert_eq!(conn.borrow().ttl(), 32);
+    conn.borrow_mut().set_linger(-1.0);
+    assert_eq!(conn.borrow().linger(), -1.0);
+}
+
+#[test]
+fn invalid_option_values_throw_option_errors() {
+    let socket = hxrt::net::socket_new_tcp();
+    for set in [
+        (|s: &mut hxrt::net::SocketHandle| s.set_ttl(0)) as fn(&mut hxrt::net::SocketHandle),
+        |s| s.set_ttl(256),
+        |s| s.set_send_buffer_size(0),
+        |s| s.set_recv_buffer_size(-1),
+        |s| s.set_linger(f64::NAN),
+    ] {
+        let result = hxrt::exception::catch_unwind(|| set(&mut socket.borrow_mut()));
+        assert!(result.is_err());
+    }
+    assert_eq!(socket.borrow().ttl(), 0);
+    assert_eq!(socket.borrow().send_buffer_size(), 0);
+}
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+))]
+#[test]
+fn reuse_port_lets_two_udp_sockets_share_a_port() {
+    let first = hxrt::net::socket_new_udp();
+    first.borrow_mut().set_reuse_port(true);
+    first.borrow_mut().bind(LOOPBACK, 0);
+    let (_, port) = first.borrow().host();
+    assert!(first.borrow().reuse_port());
+
+    let second = hxrt::net::socket_new_udp();
+    second.borrow_mut().set_reuse_port(true);
+    second.borrow_mut().bind(LOOPBACK, port);
+    assert_eq!(second.borrow().host().1, port);
+}
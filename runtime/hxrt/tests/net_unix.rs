@@ -0,0 +1,85 @@
+#![cfg(unix)]
+
+use std::path::PathBuf;
+
+fn socket_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("hxrt-{}-{}.sock", name, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn unix_stream_connects_accepts_and_selects() {
+    let path = socket_path("stream");
+    let path_text = path.to_str().unwrap();
+
+    let listener = hxrt::net::socket_new_tcp();
+    listener.borrow_mut().bind_unix(path_text);
+    listener.borrow_mut().listen(4);
+    assert_eq!(listener.borrow().unix_local_path(), path_text);
+
+    let client = hxrt::net::socket_new_tcp();
+    client.borrow_mut().connect_unix(path_text);
+    let conn = listener.borrow_mut().accept();
+    assert_eq!(client.borrow().unix_peer_path(), path_text);
+    assert_eq!(conn.borrow().unix_peer_path(), "");
+
+    assert_eq!(client.borrow_mut().write_stream(b"ping"), 4);
+    let (readable, _, _) = hxrt::net::socket_select(vec![conn.clone()], vec![], vec![], Some(5.0));
+    assert_eq!(readable, vec![0]);
+    let mut buf = [0u8; 4];
+    assert_eq!(conn.borrow_mut().read_stream(&mut buf), 4);
+    assert_eq!(&buf, b"ping");
+
+    client.borrow_mut().shutdown(false, true);
+    assert_eq!(conn.borrow_mut().read_stream(&mut buf), -1);
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn unix_stream_read_times_out() {
+    let path = socket_path("timeout");
+    let path_text = path.to_str().unwrap();
+
+    let listener = hxrt::net::socket_new_tcp();
+    listener.borrow_mut().bind_unix(path_text);
+    listener.borrow_mut().listen(1);
+    let client = hxrt::net::socket_new_tcp();
+    client.borrow_mut().set_timeout(0.05);
+    client.borrow_mut().connect_unix(path_text);
+    let _conn = listener.borrow_mut().accept();
+
+    let result = hxrt::exception::catch_unwind(|| {
+        let mut buf = [0u8; 1];
+        client.borrow_mut().read_stream(&mut buf)
+    });
+    assert!(result.is_err());
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn unix_datagram_round_trip() {
+    let path = socket_path("dgram");
+    let path_text = path.to_str().unwrap();
+
+    let server = hxrt::net::socket_new_udp();
+    server.borrow_mut().bind_unix(path_text);
+    server.borrow_mut().set_timeout(5.0);
+
+    let client = hxrt::net::socket_new_udp();
+    assert_eq!(client.borrow_mut().unix_send_to(b"hello", path_text), 5);
+    let mut buf = [0u8; 16];
+    assert_eq!(server.borrow_mut().read_stream(&mut buf), 5);
+    assert_eq!(&buf[..5], b"hello");
+
+    let connected = hxrt::net::socket_new_udp();
+    connected.borrow_mut().connect_unix(path_text);
+    assert_eq!(connected.borrow().unix_peer_path(), path_text);
+    assert_eq!(connected.borrow_mut().write_stream(b"again"), 5);
+    assert_eq!(server.borrow_mut().read_stream(&mut buf), 5);
+    assert_eq!(&buf[..5], b"again");
+
+    let _ = std::fs::remove_file(&path);
+}
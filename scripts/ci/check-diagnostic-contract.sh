@@ -68,7 +68,7 @@ run_warning_case test/negative/metal_dynamic_access compile.fallback.hxml HXRS-P
 run_error_case test/negative/metal_no_hxrt_requires_metal compile.hxml HXRS-NO-HXRT-REQUIRES-METAL 'requires `-D reflaxe_rust_profile=metal`' no-hxrt-profile
 run_error_case test/negative/metal_no_hxrt_dynamic_boundary compile.hxml HXRS-NO-HXRT-ELIGIBILITY 'reasonKind `dynamic`' no-hxrt-eligibility
 run_error_case test/negative/metal_no_hxrt_runtime_boundary compile.hxml HXRS-NO-HXRT-EMITTED-RUNTIME 'generated Rust still references `hxrt`' no-hxrt-emitted
-run_error_case test/negative/async_main_boundary compile.hxml HXRS-ASYNC-MAIN-SYNC '`main` must stay synchronous' async-main
+run_error_case test/negative/async_main_boundary compile.hxml HXRS-ASYNC-MAIN-SYNC 'async `main` must return `Void`' async-main
 run_error_case test/negative/metal_ref_alias_return_escape compile.hxml HXRS-BORROW-REGION 'returned borrow-only alias' borrow-region
 run_warning_case test/negative/portable_native_import_strict compile.warn.hxml HXRS-NATIVE-IMPORT-WARNING 'portable contract imported native target modules' native-import-warning
 run_error_case test/negative/portable_native_import_strict compile.hxml HXRS-NATIVE-IMPORT-ERROR 'portable contract imported native target modules' native-import-error
@@ -121,6 +121,63 @@ run_positive_compile_case() {
 	finish_policy_case "$success_label" "$case_start"
 }
 
+# Compiles a positive fixture, requires each `<generated file> <regex>` pair to match the emitted
+# code (comments stripped), then cargo-runs the crate and compares stdout with
+# `expected_stdout.txt` next to the fixture.
+run_output_shape_run_case() {
+	local fixture_rel="$1"
+	local hxml_file="$2"
+	local failure_label="$3"
+	shift 3
+	local case_start="$SECONDS"
+	local fixture_dir="$root_dir/$fixture_rel"
+	local out_dir="$fixture_dir/out_run_shape"
+	local log_file="$fixture_dir/.compile_run_shape.log"
+	local run_log="$fixture_dir/.run_shape.log"
+	echo "[metal-policy] case: ${failure_label}"
+
+	rm -rf "$out_dir"
+	rm -f "$log_file" "$run_log"
+
+	set +e
+	(cd "$fixture_dir" && haxe "$hxml_file" -D rust_no_build -D rust_output=out_run_shape) >"$log_file" 2>&1
+	local status=$?
+	set -e
+
+	if [[ "$status" -ne 0 ]]; then
+		echo "[metal-policy] error: expected compile success for ${failure_label}."
+		sed "s|$root_dir|.|g" "$log_file"
+		exit 1
+	fi
+
+	while [[ "$#" -ge 2 ]]; do
+		local generated="$out_dir/$1"
+		local pattern="$2"
+		shift 2
+		if [[ ! -f "$generated" ]] || ! file_code_match_regex "$pattern" "$generated"; then
+			echo "[metal-policy] error: expected /${pattern}/ in generated ${generated#"$out_dir/"} for ${failure_label}."
+			if [[ -f "$generated" ]]; then
+				sed "s|$root_dir|.|g" "$generated"
+			fi
+			exit 1
+		fi
+	done
+
+	if ! (cd "$out_dir" && cargo run -q) >"$run_log" 2>/dev/null; then
+		echo "[metal-policy] error: fixture did not cargo-run for ${failure_label}."
+		sed "s|$root_dir|.|g" "$run_log"
+		exit 1
+	fi
+	if ! diff -u "$fixture_dir/expected_stdout.txt" "$run_log"; then
+		echo "[metal-policy] error: unexpected stdout for ${failure_label}."
+		exit 1
+	fi
+
+	rm -f "$log_file" "$run_log"
+	rm -rf "$out_dir"
+	finish_policy_case "$failure_label" "$case_start"
+}
+
 run_internal_helper_boundary_cases() {
 	run_negative_case "test/negative/internal_rust_helper_import" '\[HXRS-INTERNAL-HELPER-IMPORT\] application code cannot import internal framework helper `rust\._internal\.MapStorageTools`' \
 		'internal Rust helper import rejection' '^Main\.hx:'
@@ -1685,6 +1742,81 @@ run_native_tcp_output_shape_case() {
 	finish_policy_case "$failure_label" "$case_start"
 }
 
+run_native_unix_output_shape_case() {
+	local fixture_rel="$1"
+	local hxml_file="$2"
+	local failure_label="$3"
+	local case_start="$SECONDS"
+	local fixture_dir="$root_dir/$fixture_rel"
+	local out_dir="$fixture_dir/out_native_unix_shape"
+	local log_file="$fixture_dir/.compile_native_unix_shape.log"
+	local run_log="$fixture_dir/.run_native_unix_shape.log"
+	local native_unix_rs="$out_dir/src/native_unix_tools.rs"
+	echo "[metal-policy] case: ${failure_label}"
+
+	rm -rf "$out_dir"
+	rm -f "$log_file" "$run_log"
+
+	set +e
+	(cd "$fixture_dir" && haxe "$hxml_file" -D rust_no_build -D rust_output=out_native_unix_shape) >"$log_file" 2>&1
+	local status=$?
+	set -e
+
+	if [[ "$status" -ne 0 ]]; then
+		echo "[metal-policy] error: expected compile success for ${failure_label}."
+		sed "s|$root_dir|.|g" "$log_file"
+		exit 1
+	fi
+
+	if [[ ! -f "$native_unix_rs" ]]; then
+		echo "[metal-policy] error: missing native_unix_tools.rs for ${failure_label}."
+		exit 1
+	fi
+	if match_regex 'hxrt[[:space:]]*=' "$out_dir/Cargo.toml" || [[ -d "$out_dir/hxrt" ]]; then
+		echo "[metal-policy] error: native Unix socket no-hxrt fixture emitted hxrt runtime for ${failure_label}."
+		sed "s|$root_dir|.|g" "$out_dir/Cargo.toml"
+		exit 1
+	fi
+	if tree_match_regex 'hxrt::|hxrt\.|Dynamic|__rust__|ERaw|SocketHandle|socket_native|sys_net' "$out_dir/src"; then
+		echo "[metal-policy] error: native Unix socket fixture used runtime, Dynamic, raw, or portable sys/socket paths for ${failure_label}."
+		sed "s|$root_dir|.|g" "$out_dir/src/main.rs"
+		exit 1
+	fi
+	if ! match_regex 'use std::os::unix::net::\{UnixListener as StdUnixListener, UnixStream as StdUnixStream\}' "$native_unix_rs"; then
+		echo "[metal-policy] error: native Unix socket fixture missing direct std::os::unix::net imports for ${failure_label}."
+		sed "s|$root_dir|.|g" "$native_unix_rs"
+		exit 1
+	fi
+	if ! match_regex 'listener: StdUnixListener' "$native_unix_rs" || ! match_regex 'stream: StdUnixStream' "$native_unix_rs"; then
+		echo "[metal-policy] error: native Unix socket fixture should wrap owned std listener/stream handles for ${failure_label}."
+		sed "s|$root_dir|.|g" "$native_unix_rs"
+		exit 1
+	fi
+	if ! match_regex 'set_read_timeout\(timeout\)' "$native_unix_rs" || ! match_regex 'shutdown\(Shutdown::Write\)' "$native_unix_rs"; then
+		echo "[metal-policy] error: native Unix socket fixture missing direct timeout/shutdown wiring for ${failure_label}."
+		sed "s|$root_dir|.|g" "$native_unix_rs"
+		exit 1
+	fi
+	if ! (cd "$out_dir" && cargo build -q); then
+		echo "[metal-policy] error: native Unix socket no-hxrt fixture did not cargo-build for ${failure_label}."
+		exit 1
+	fi
+	if ! (cd "$out_dir" && cargo run -q) >"$run_log" 2>&1; then
+		echo "[metal-policy] error: native Unix socket no-hxrt fixture did not cargo-run for ${failure_label}."
+		sed "s|$root_dir|.|g" "$run_log"
+		exit 1
+	fi
+	if [[ -s "$run_log" ]]; then
+		echo "[metal-policy] error: native Unix socket fixture produced unexpected stdout/stderr for ${failure_label}."
+		sed "s|$root_dir|.|g" "$run_log"
+		exit 1
+	fi
+
+	rm -f "$log_file" "$run_log"
+	rm -rf "$out_dir"
+	finish_policy_case "$failure_label" "$case_start"
+}
+
 run_native_udp_output_shape_case() {
 	local fixture_rel="$1"
 	local hxml_file="$2"
@@ -2323,6 +2455,173 @@ run_socket_addr_output_shape_case() {
 	finish_policy_case "$failure_label" "$case_start"
 }
 
+run_socket_addr_parse_output_shape_case() {
+	local fixture_rel="$1"
+	local hxml_file="$2"
+	local failure_label="$3"
+	local case_start="$SECONDS"
+	local fixture_dir="$root_dir/$fixture_rel"
+	local out_dir="$fixture_dir/out_socket_addr_parse_shape"
+	local log_file="$fixture_dir/.compile_socket_addr_parse_shape.log"
+	local run_log="$fixture_dir/.run_socket_addr_parse_shape.log"
+	local socket_addr_rs="$out_dir/src/native_socket_addr_tools.rs"
+	local dns_rs="$out_dir/src/native_dns_tools.rs"
+	local main_rs="$out_dir/src/main.rs"
+	echo "[metal-policy] case: ${failure_label}"
+
+	rm -rf "$out_dir"
+	rm -f "$log_file" "$run_log"
+
+	set +e
+	(cd "$fixture_dir" && haxe "$hxml_file" -D rust_no_build -D rust_output=out_socket_addr_parse_shape) >"$log_file" 2>&1
+	local status=$?
+	set -e
+
+	if [[ "$status" -ne 0 ]]; then
+		echo "[metal-policy] error: expected compile success for ${failure_label}."
+		sed "s|$root_dir|.|g" "$log_file"
+		exit 1
+	fi
+
+	if [[ ! -f "$socket_addr_rs" || ! -f "$dns_rs" ]]; then
+		echo "[metal-policy] error: missing socket address or DNS helper modules for ${failure_label}."
+		find "$out_dir/src" -maxdepth 1 -type f -name '*.rs' -print | sed "s|$root_dir|.|g"
+		exit 1
+	fi
+	if match_regex 'hxrt[[:space:]]*=' "$out_dir/Cargo.toml" || [[ -d "$out_dir/hxrt" ]]; then
+		echo "[metal-policy] error: socket-address parse no-hxrt fixture emitted hxrt runtime for ${failure_label}."
+		sed "s|$root_dir|.|g" "$out_dir/Cargo.toml"
+		exit 1
+	fi
+	if tree_code_match_regex 'hxrt::|hxrt\.|Dynamic|__rust__|ERaw|SocketHandle|socket_native|sys_net|host_native' "$out_dir/src"; then
+		echo "[metal-policy] error: socket-address parse fixture used runtime, Dynamic, raw, or portable sys.net paths for ${failure_label}."
+		sed "s|$root_dir|.|g" "$main_rs"
+		exit 1
+	fi
+	if match_regex 'crate::native_socket_addr_tools::SocketAddr::(parse|ipv4|ipv6)\(' "$main_rs" || match_regex '\.(isIpv4|isIpv6)\(\)' "$main_rs"; then
+		echo "[metal-policy] error: SocketAddr parse constructors and accessors should be compiler-lowered, not helper calls, for ${failure_label}."
+		sed "s|$root_dir|.|g" "$main_rs"
+		exit 1
+	fi
+	if ! match_regex 'parse::<std::net::SocketAddr>\(\)' "$main_rs" || ! match_regex 'parse::<std::net::Ipv4Addr>\(\)' "$main_rs" || ! match_regex 'parse::<std::net::Ipv6Addr>\(\)' "$main_rs"; then
+		echo "[metal-policy] error: generated call site should parse literal addresses directly through std::net for ${failure_label}."
+		sed "s|$root_dir|.|g" "$main_rs"
+		exit 1
+	fi
+	if ! match_regex 'std::net::SocketAddrV6::new\(__hx_socket_ip, __hx_socket_port, 0, 0\)' "$main_rs" || ! match_regex '\.as_std\(\)\.ip\(\)\.to_string\(\)' "$main_rs" || ! match_regex '\.as_std\(\)\.is_ipv6\(\)' "$main_rs"; then
+		echo "[metal-policy] error: generated call site should construct and inspect std::net addresses directly for ${failure_label}."
+		sed "s|$root_dir|.|g" "$main_rs"
+		exit 1
+	fi
+	if ! match_regex 'crate::native_dns_tools::Dns::resolve\(' "$main_rs" || ! match_regex 'to_socket_addrs\(\)' "$dns_rs" || ! match_regex 'SocketError::resolve\(' "$dns_rs"; then
+		echo "[metal-policy] error: host-name lookup should go through the typed DNS helper over std::net::ToSocketAddrs for ${failure_label}."
+		sed "s|$root_dir|.|g" "$dns_rs"
+		exit 1
+	fi
+	if match_regex 'to_socket_addrs|ToSocketAddrs|\.parse::<' "$socket_addr_rs"; then
+		echo "[metal-policy] error: socket-address helper should stay conversion-only, without parsing or DNS, for ${failure_label}."
+		sed "s|$root_dir|.|g" "$socket_addr_rs"
+		exit 1
+	fi
+	if ! (cd "$out_dir" && cargo build -q); then
+		echo "[metal-policy] error: socket-address parse no-hxrt fixture did not cargo-build for ${failure_label}."
+		exit 1
+	fi
+	if ! (cd "$out_dir" && cargo run -q) >"$run_log" 2>&1; then
+		echo "[metal-policy] error: socket-address parse no-hxrt fixture did not cargo-run for ${failure_label}."
+		sed "s|$root_dir|.|g" "$run_log"
+		exit 1
+	fi
+	if [[ -s "$run_log" ]]; then
+		echo "[metal-policy] error: socket-address parse fixture produced unexpected stdout/stderr for ${failure_label}."
+		sed "s|$root_dir|.|g" "$run_log"
+		exit 1
+	fi
+
+	rm -f "$log_file" "$run_log"
+	rm -rf "$out_dir"
+	finish_policy_case "$failure_label" "$case_start"
+}
+
+run_socket_options_output_shape_case() {
+	local fixture_rel="$1"
+	local hxml_file="$2"
+	local failure_label="$3"
+	local case_start="$SECONDS"
+	local fixture_dir="$root_dir/$fixture_rel"
+	local out_dir="$fixture_dir/out_socket_options_shape"
+	local log_file="$fixture_dir/.compile_socket_options_shape.log"
+	local run_log="$fixture_dir/.run_socket_options_shape.log"
+	local options_rs="$out_dir/src/native_socket_options_tools.rs"
+	local tcp_rs="$out_dir/src/native_tcp_tools.rs"
+	local udp_rs="$out_dir/src/native_udp_tools.rs"
+	local main_rs="$out_dir/src/main.rs"
+	echo "[metal-policy] case: ${failure_label}"
+
+	rm -rf "$out_dir"
+	rm -f "$log_file" "$run_log"
+
+	set +e
+	(cd "$fixture_dir" && haxe "$hxml_file" -D rust_no_build -D rust_output=out_socket_options_shape) >"$log_file" 2>&1
+	local status=$?
+	set -e
+
+	if [[ "$status" -ne 0 ]]; then
+		echo "[metal-policy] error: expected compile success for ${failure_label}."
+		sed "s|$root_dir|.|g" "$log_file"
+		exit 1
+	fi
+
+	if [[ ! -f "$options_rs" || ! -f "$tcp_rs" || ! -f "$udp_rs" ]]; then
+		echo "[metal-policy] error: missing socket options, TCP, or UDP helper modules for ${failure_label}."
+		find "$out_dir/src" -maxdepth 1 -type f -name '*.rs' -print | sed "s|$root_dir|.|g"
+		exit 1
+	fi
+	if match_regex 'hxrt[[:space:]]*=' "$out_dir/Cargo.toml" || [[ -d "$out_dir/hxrt" ]]; then
+		echo "[metal-policy] error: socket options no-hxrt fixture emitted hxrt runtime for ${failure_label}."
+		sed "s|$root_dir|.|g" "$out_dir/Cargo.toml"
+		exit 1
+	fi
+	if ! match_regex '^socket2[[:space:]]*=' "$out_dir/Cargo.toml"; then
+		echo "[metal-policy] error: socket options fixture should declare the socket2 dependency for ${failure_label}."
+		sed "s|$root_dir|.|g" "$out_dir/Cargo.toml"
+		exit 1
+	fi
+	if tree_code_match_regex 'hxrt::|hxrt\.|Dynamic|__rust__|ERaw|SocketHandle|socket_native|sys_net|host_native' "$out_dir/src"; then
+		echo "[metal-policy] error: socket options fixture used runtime, Dynamic, raw, or portable sys.net paths for ${failure_label}."
+		sed "s|$root_dir|.|g" "$main_rs"
+		exit 1
+	fi
+	if ! match_regex 'crate::native_socket_options_tools::SocketOptions::new\(\)' "$main_rs" || ! match_regex '\.bindTcp\(' "$main_rs" || ! match_regex 'SocketOptions::readFromStream\(&' "$main_rs"; then
+		echo "[metal-policy] error: generated call site should build, bind, and read options through the typed SocketOptions helper for ${failure_label}."
+		sed "s|$root_dir|.|g" "$main_rs"
+		exit 1
+	fi
+	if ! match_regex 'use socket2::' "$options_rs" || match_regex 'socket2' "$tcp_rs" || match_regex 'socket2' "$udp_rs"; then
+		echo "[metal-policy] error: socket2 should stay confined to the socket options helper for ${failure_label}."
+		sed "s|$root_dir|.|g" "$options_rs"
+		exit 1
+	fi
+	if ! (cd "$out_dir" && cargo build -q); then
+		echo "[metal-policy] error: socket options no-hxrt fixture did not cargo-build for ${failure_label}."
+		exit 1
+	fi
+	if ! (cd "$out_dir" && cargo run -q) >"$run_log" 2>&1; then
+		echo "[metal-policy] error: socket options no-hxrt fixture did not cargo-run for ${failure_label}."
+		sed "s|$root_dir|.|g" "$run_log"
+		exit 1
+	fi
+	if [[ -s "$run_log" ]]; then
+		echo "[metal-policy] error: socket options fixture produced unexpected stdout/stderr for ${failure_label}."
+		sed "s|$root_dir|.|g" "$run_log"
+		exit 1
+	fi
+
+	rm -f "$log_file" "$run_log"
+	rm -rf "$out_dir"
+	finish_policy_case "$failure_label" "$case_start"
+}
+
 run_current_process_binary_io_case() {
 	local fixture_rel="$1"
 	local hxml_file="$2"
@@ -3088,8 +3387,8 @@ run_negative_case "test/negative/metal_no_hxrt_runtime_boundary" '`-D rust_no_hx
 	'rust_no_hxrt rejects runtime-dependent output'
 run_negative_case "test/negative/async_preview_removed" '`-D rust_async_preview` was removed\. Use `-D rust_async`\.' \
 	'rust_async_preview define removed'
-run_negative_case "test/negative/async_main_boundary" '`main` must stay synchronous for the Rust async contract\.' \
-	'async boundary keeps main synchronous'
+run_negative_case "test/negative/async_main_boundary" 'async `main` must return `Void`' \
+	'async main rejects non-Void return types'
 run_negative_case "test/negative/async_constructor_contract" 'Constructors cannot be marked `@:async` / `@:rustAsync` under the Rust async contract\.' \
 	'async contract rejects constructors'
 run_negative_case "test/negative/profile_removed_idiomatic" 'Unknown `-D reflaxe_rust_profile=idiomatic`\. Expected portable\|metal\.' \
@@ -3358,6 +3657,87 @@ run_optional_fallback_case "test/snapshot/async_select" "compile.hxml" 'Metal fa
 run_optional_fallback_case "test/snapshot/async_select" "compile.tokio.hxml" 'Metal fallback active: generated output contains [0-9]+ raw Rust expression node\(s\) \(`ERaw`\) across [0-9]+ module\(s\)\.' \
 	'Main:' \
 	'metal fallback top-modules excludes Main in async_select tokio snapshot after typed async lowering migration'
+run_output_shape_run_case "test/positive/async_void_main" "compile.hxml" \
+	'Void @:async main runs through hxrt::async_::run_main' \
+	'src/main.rs' 'hxrt::async_::run_main\(' \
+	'src/main.rs' 'Box::pin\(async move' \
+	'src/main.rs' '\.await'
+run_output_shape_run_case "test/positive/async_stream_for_loop" "compile.hxml" \
+	'for over rust.async.Stream awaits hxrt::async_::stream_next per item' \
+	'src/main.rs' 'hxrt::async_::stream_next\(&mut [A-Za-z_0-9]+\)\.await' \
+	'src/main.rs' 'hxrt::async_::stream_buffered\('
+run_output_shape_run_case "test/positive/atomic_object_threads" "compile.hxml" \
+	'haxe.atomic.AtomicObject compareExchange loops stay linearizable across threads' \
+	'src/main.rs' 'hxrt::atomic::AtomicObjectHandle' \
+	'src/main.rs' '\.compare_exchange\('
+run_output_shape_run_case "test/positive/thread_create_with_options_join" "compile.hxml" \
+	'Thread.createWithOptions threads are joinable unless joinable: false' \
+	'src/sys_thread_thread.rs' 'hxrt::thread::thread_spawn_with_options\('
+run_output_shape_run_case "test/positive/cycle_collector_trace" "compile.hxml" \
+	'rust.CycleCollector emits HxTrace impls and collector_track registration for cyclic classes' \
+	'src/parent.rs' 'impl hxrt::cell::HxTrace for ([A-Za-z_]+::)*Parent' \
+	'src/parent.rs' 'hxrt::cell::HxTraceEdges::trace_edges\(&self\.children, tracer\)' \
+	'src/parent.rs' 'hxrt::cell::HxTraceEdges::clear_edges\(&mut self\.children\)' \
+	'src/parent.rs' 'hxrt::cell::collector_track\(&self_\)' \
+	'src/child.rs' 'impl hxrt::cell::HxTrace for ([A-Za-z_]+::)*Child' \
+	'src/child.rs' 'hxrt::cell::HxTraceEdges::trace_edges\(&self\.parent, tracer\)' \
+	'Cargo.toml' 'cycle_collector'
+run_output_shape_run_case "test/positive/weak_map_and_weak" "compile.hxml" \
+	'haxe.ds.WeakMap and rust.Weak drop entries and handles once the key object is gone' \
+	'src/haxe_ds_weak_map.rs' 'hxrt::weak::WeakMapHandle' \
+	'src/main.rs' 'hxrt::weak::WeakHandle'
+run_output_shape_run_case "test/positive/http_router_server" "compile.hxml" \
+	'rust.http.Router serves routes, 404/405 with Allow, HEAD via GET and prefix patterns over loopback' \
+	'src/rust_http_server.rs' 'hxrt::http_server::http_server_bind\(' \
+	'Cargo.toml' 'http_server'
+run_output_shape_run_case "test/positive/http_pool_retry" "compile.hxml" \
+	'pooled sys.Http resends only idempotent requests dropped before any response byte, never timeouts' \
+	'src/sys_http.rs' 'hxrt::http_pool::http_pool_acquire\('
+run_output_shape_run_case "test/positive/channels_bounded_select" "compile.hxml" \
+	'bounded channels block when full, drain then throw after close, and select across two channels' \
+	'src/main.rs' 'hxrt::concurrent::channel_bounded\(' \
+	'src/main.rs' 'hxrt::concurrent::channel_select\('
+run_output_shape_run_case "test/positive/cell_debug_reentrancy" "compile.hxml" \
+	'rust_hxrt_cell_debug reports a same-thread re-entrant borrow as HXRT-BORROW-REENTRANCY' \
+	'Cargo.toml' 'cell_debug' \
+	'src/main.rs' 'crate::guard_probe::with_write_guard\('
+run_output_shape_run_case "test/positive/thread_pools_work_stealing" "compile.hxml" \
+	'thread pools lower to the native work-stealing pool' \
+	'src/sys_thread_fixed_thread_pool.rs' 'hxrt::thread::pool_new_fixed\(' \
+	'src/sys_thread_elastic_thread_pool.rs' 'hxrt::thread::pool_set_max_workers\(' \
+	'src/main.rs' 'hxrt::thread::pool_queue_depth\('
+run_output_shape_run_case "test/positive/parallel_array_ops" "compile.hxml" \
+	'Parallel array operations lower to hxrt::concurrent' \
+	'src/main.rs' 'hxrt::concurrent::parallel_map\(' \
+	'src/main.rs' 'hxrt::concurrent::parallel_sort\('
+run_output_shape_run_case "test/positive/event_loop_io_watch" "compile.hxml" \
+	'socket and process pipe watches drive the EventLoop' \
+	'src/sys_net_socket.rs' 'crate::socket_native::watch\(' \
+	'src/sys_io_process.rs' 'hxrt::process::process_watch_output\('
+run_output_shape_run_case "test/positive/signals_hangup_self" "compile.hxml" \
+	'SIGHUP reaches Channel and EventLoop subscriptions' \
+	'src/rust_process_signals.rs' 'hxrt::signal::signal_notify\(' \
+	'src/rust_process_signals.rs' 'hxrt::signal::signal_listen\('
+run_output_shape_run_case "test/positive/exit_handlers_return" "compile.hxml" \
+	'exit handlers run LIFO when main returns' \
+	'src/main.rs' 'let _hx_exit_handlers = hxrt::sys::main_exit_guard\(\);'
+run_output_shape_run_case "test/positive/exit_handlers_sys_exit" "compile.hxml" \
+	'exit handlers run LIFO on Sys.exit' \
+	'src/main.rs' 'hxrt::sys::main_exit_guard\(\)'
+run_output_shape_run_case "test/positive/runtime_stats_counters" "compile.hxml" \
+	'rust_hxrt_stats counters surface through Runtime.stats()' \
+	'Cargo.toml' '"stats"' \
+	'src/main.rs' 'hxrt::sys::main_exit_guard\(\)'
+run_output_shape_run_case "test/positive/tracing_json_lines" "compile.hxml" \
+	'rust_hxrt_tracing writes application spans and events as JSON lines' \
+	'Cargo.toml' '"tracing"' \
+	'src/rust_tracing_tracing.rs' 'hxrt::trace::trace_use_json_lines\('
+run_output_shape_run_case "test/positive/net_ipv6_dual_stack" "compile.hxml" \
+	'sys.net.Host resolves IPv6 and binds dual-stack listeners' \
+	'src/sys_net_host.rs' 'crate::socket_native::host_ipv4\('
+run_output_shape_run_case "test/positive/http_redirect_request_state" "compile.hxml" \
+	'sys.Http resolves redirects against scheme-less URLs, drops Content-Type on the body-less follow-up and keeps postBytes for the next request' \
+	'src/sys_http.rs' 'fn resolve_url\('
 run_optional_fallback_case "test/snapshot/rust_async_tasks" "compile.hxml" 'Metal fallback active: generated output contains [0-9]+ raw Rust expression node\(s\) \(`ERaw`\) across [0-9]+ module\(s\)\.' \
 	'Main:' \
 	'metal fallback top-modules excludes Main in rust_async_tasks snapshot after typed async lowering migration'
@@ -3393,6 +3773,14 @@ run_native_tcp_output_shape_case "test/positive/metal_no_hxrt_native_tcp" "compi
 	'rust.net.NativeTcp emits direct std::net no-hxrt output'
 run_native_udp_output_shape_case "test/positive/metal_no_hxrt_native_udp" "compile.hxml" \
 	'rust.net.NativeUdp emits direct std::net UDP no-hxrt output'
+run_native_unix_output_shape_case "test/positive/metal_no_hxrt_native_unix" "compile.hxml" \
+	'rust.net.NativeUnix emits direct std::os::unix::net no-hxrt output'
+run_no_hxrt_success_case "test/positive/metal_no_hxrt_tcp_stream" "compile.hxml" \
+	'rust.net.TcpStream streaming methods emit Vec<u8> no-hxrt output'
+run_no_hxrt_success_case "test/positive/metal_no_hxrt_udp_multicast" "compile.hxml" \
+	'rust.net.UdpSocket multicast and recvFrom emit direct std::net no-hxrt output'
+run_positive_compile_case "test/positive/metal_tcp_stream_bytes" \
+	'rust.net.TcpStreamBytes reads and writes haxe.io.Bytes ranges through the native TCP owner'
 run_socket_error_output_shape_case "test/positive/metal_no_hxrt_socket_error" "compile.hxml" \
 	'rust.net.SocketError emits typed no-hxrt socket errors'
 run_udp_bytes_output_shape_case "test/positive/metal_no_hxrt_udp_bytes" "compile.hxml" \
@@ -3401,6 +3789,10 @@ run_tcp_bytes_output_shape_case "test/positive/metal_no_hxrt_tcp_bytes" "compile
 	'rust.net.TcpStream byte streams emit direct std::net no-hxrt output'
 run_socket_addr_output_shape_case "test/positive/metal_no_hxrt_socket_addr" "compile.hxml" \
 	'rust.net.SocketAddr emits direct std::net no-hxrt output'
+run_socket_addr_parse_output_shape_case "test/positive/metal_no_hxrt_socket_addr_parse" "compile.hxml" \
+	'rust.net.SocketAddr parse/ipv4/ipv6 and Dns.resolve emit direct std::net no-hxrt output'
+run_socket_options_output_shape_case "test/positive/metal_no_hxrt_socket_options" "compile.hxml" \
+	'rust.net.SocketOptions emits a socket2-backed no-hxrt options helper'
 run_current_process_binary_io_case "test/positive/metal_no_hxrt_current_process" "compile.hxml" \
 	'rust.process.CurrentProcess emits safe direct binary standard IO'
 run_current_process_error_only_case "test/positive/metal_no_hxrt_current_process_error_only" "compile.hxml" \
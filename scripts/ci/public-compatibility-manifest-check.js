@@ -86,7 +86,11 @@ const definePolicyOverrides = {
   rust_crate: ['valid Cargo crate name', 'derived from compilation context'],
   rust_extra_src: ['filesystem directory path', 'unset'],
   rust_forbid_unsafe: ['presence flag', 'disabled'],
+  rust_hxrt_cell_debug: ['presence flag', 'disabled'],
+  rust_hxrt_cycle_collector: ['presence flag', 'disabled'],
   rust_hxrt_features: ['comma-separated hxrt feature names', 'inferred feature set'],
+  rust_hxrt_stats: ['presence flag', 'disabled'],
+  rust_hxrt_tracing: ['presence flag', 'disabled'],
   rust_output: ['filesystem directory path', 'unset; Rust generation requires a value'],
   rust_target: ['Cargo target triple', 'host target'],
   rust_string_non_nullable: ['presence flag', 'enabled by default only in metal when no string-mode define is present'],
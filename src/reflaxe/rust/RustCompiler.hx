@@ -4100,6 +4100,11 @@ class RustCompiler extends GenericCompiler<RustFile, RustFile, RustExpr, RustFil
 
 			items.push(RImpl(RustImpl.inherent(classGenericDecls, rustClassTypeInstType(classType), implFunctions)));
 
+			// Child traversal for the opt-in cycle collector (generic classes stay untraced).
+			if (cycleCollectorEnabled() && classType.params.length == 0) {
+				items.push(RImpl(emitCycleTraceImpl(classType)));
+			}
+
 			// Extra Rust trait impls declared via `@:rustImpl(...)` metadata.
 			var rustImpls = rustImplsFromMeta(classType.meta);
 			for (spec in rustImpls) {
@@ -4162,16 +4167,14 @@ class RustCompiler extends GenericCompiler<RustFile, RustFile, RustExpr, RustFil
 			}
 
 			var mainFunc = findStaticMain(funcFields);
-			if (mainFunc != null && hasAsyncFunctionMeta(mainFunc.field.meta)) {
-				ensureAsyncAllowed(mainFunc.field.pos);
-				#if eval
-				RustDiagnostic.error(RustDiagnosticId.AsyncMainSync,
-					"`main` must stay synchronous for the Rust async contract. Move async work into a helper returning `rust.async.Future<T>` and call `rust.async.Async.blockOn(...)` from sync `main`.",
-					mainFunc.field.pos);
-				#end
-			}
 			// Rust `fn main()` is always unit-returning; compile as void to avoid accidental tail expressions.
-			var body:RustBlock = (mainFunc != null && mainFunc.expr != null) ? compileVoidBodyWithContext(mainFunc.expr, []) : defaultMainBody();
+			var body:RustBlock = if (mainFunc == null || mainFunc.expr == null) {
+				defaultMainBody();
+			} else if (hasAsyncFunctionMeta(mainFunc.field.meta)) {
+				compileAsyncMainBody(mainFunc);
+			} else {
+				withMainExitGuard(compileVoidBodyWithContext(mainFunc.expr, []));
+			};
 
 			items.push(RFn({
 				name: "main",
@@ -4369,6 +4372,192 @@ class RustCompiler extends GenericCompiler<RustFile, RustFile, RustExpr, RustFil
 		return null;
 	}
 
+	/**
+		Lowers `@:async static function main()` into a sync Rust `fn main()` that drives the body.
+
+		Why
+		- Async applications otherwise repeat the same `Async.blockOn(run())` wrapper in every entry
+		  point, and cannot start inside the tokio adapter before the first `await`.
+
+		What
+		- Compiles the Haxe `main` body as a unit-returning `async move` block and hands it to
+		  `hxrt::async_::run_main` (or `run_main_tokio` under `-D async_tokio_adapter`).
+		- The runtime helper flushes stdout and maps an uncaught Haxe throw to exit code `1`.
+
+		How
+		- `AsyncSyntaxMacro` leaves Void `main` bodies unwrapped, so the body is compiled exactly like a
+		  sync `main`, only inside an async function context that permits `await`.
+		- A non-`Void` async `main` is rejected with `HXRS-ASYNC-MAIN-SYNC`.
+	**/
+	function compileAsyncMainBody(mainFunc:ClassFuncData):RustBlock {
+		ensureAsyncAllowed(mainFunc.field.pos);
+		if (!TypeHelper.isVoid(mainFunc.ret)) {
+			#if eval
+			RustDiagnostic.error(RustDiagnosticId.AsyncMainSync,
+				"async `main` must return `Void` (got `" + TypeTools.toString(mainFunc.ret)
+				+ "`). Remove the return type or move value-producing work into a helper returning `rust.async.Future<T>`.",
+				mainFunc.field.pos);
+			#end
+		}
+		var inner = withFunctionContext(mainFunc.expr, [], Context.getType("Void"), () -> compileVoidBody(mainFunc.expr), true);
+		var runner = Context.defined("async_tokio_adapter") ? "run_main_tokio" : "run_main";
+		return {
+			stmts: [RSemi(ECall(rustRelativeExpr(["hxrt", "async_", runner]), [EPinAsyncMove(inner)]))],
+			tail: null
+		};
+	}
+
+	/**
+		Prepends the `hxrt::sys` exit-handler guard to a synchronous `main` body.
+
+		Why
+		- Handlers registered through `rust.process.ExitHandlers` must also run when `main` returns
+		  normally or an uncaught exception unwinds out of it, not only on `Sys.exit`.
+
+		What
+		- Binds `hxrt::sys::main_exit_guard()` as the first statement; its destructor runs the
+		  handlers and flushes stdout/stderr.
+
+		How
+		- Only programs that reference `rust.process.ExitHandlers` or enable the cycle collector or
+		  runtime stats (whose reports run at exit) get the guard, so other generated crates keep their
+		  existing `main` shape. Async `main` runs the handlers from `hxrt::async_::run_main` instead.
+	**/
+	function withMainExitGuard(body:RustBlock):RustBlock {
+		if (noHxrtEnabled() || !(usesExitHandlers() || cycleCollectorEnabled() || runtimeStatsEnabled()))
+			return body;
+		var guard = RLet("_hx_exit_handlers", false, null, ECall(rustRelativeExpr(["hxrt", "sys", "main_exit_guard"]), []));
+		return {
+			stmts: [guard].concat(body.stmts),
+			tail: body.tail
+		};
+	}
+
+	function usesExitHandlers():Bool {
+		for (moduleType in Context.getAllModuleTypes()) {
+			if (moduleType.getPath() == "rust.process.ExitHandlers")
+				return true;
+		}
+		return false;
+	}
+
+	/**
+		Whether the program opts into hxrt runtime stats (`-D rust_hxrt_stats` or `rust.Runtime`).
+
+		Counting itself lives in the runtime; the compiler only needs this to run the exit-time dump.
+	**/
+	function runtimeStatsEnabled():Bool {
+		if (noHxrtEnabled())
+			return false;
+		if (Context.defined("rust_hxrt_stats"))
+			return true;
+		for (moduleType in Context.getAllModuleTypes()) {
+			if (moduleType.getPath() == "rust.Runtime")
+				return true;
+		}
+		return false;
+	}
+
+	var cachedCycleCollectorEnabled:Null<Bool> = null;
+
+	/**
+		Whether generated classes take part in the hxrt cycle collector.
+
+		Why
+		- Tracing impls and constructor registration cost code size and an allocation-time lock, so
+		  they are emitted only for programs that ask for collection.
+
+		How
+		- Enabled by `-D rust_hxrt_cycle_collector` or by referencing `rust.CycleCollector`; never
+		  under `rust_no_hxrt`. The matching hxrt feature is inferred by `HxrtFeatureAnalyzer`.
+	**/
+	function cycleCollectorEnabled():Bool {
+		if (cachedCycleCollectorEnabled == null) {
+			var enabled = !noHxrtEnabled() && Context.defined("rust_hxrt_cycle_collector");
+			if (!enabled && !noHxrtEnabled()) {
+				for (moduleType in Context.getAllModuleTypes()) {
+					if (moduleType.getPath() == "rust.CycleCollector") {
+						enabled = true;
+						break;
+					}
+				}
+			}
+			cachedCycleCollectorEnabled = enabled;
+		}
+		return cachedCycleCollectorEnabled;
+	}
+
+	/**
+		Whether a class field of type `t` is reported to the cycle collector.
+
+		Why
+		- The collector may only follow edges whose target implements `hxrt::cell::HxTrace`, which
+		  the compiler emits for non-generic generated classes.
+
+		What
+		- Concrete generated class instances (`HxRef<crate::...>`) and arrays of them, nested to any
+		  depth. Interfaces, polymorphic bases, generic classes, `Dynamic`, closures, and native values
+		  stay untraced; the collector treats what they hold as externally referenced.
+	**/
+	function isCycleTracedFieldType(t:Type, pos:haxe.macro.Expr.Position):Bool {
+		if (isArrayType(t))
+			return isCycleTracedFieldType(arrayElementType(t), pos);
+		return switch (followType(t)) {
+			case TInst(clsRef, params): {
+					var cls = clsRef.get();
+					cls != null
+					&& isHxRefValueType(t)
+					&& !isBytesType(t)
+					&& (params == null || params.length == 0)
+					&& cls.params.length == 0
+					&& !isMainClass(cls)
+					&& shouldEmitClass(cls, false)
+					&& rustTypeIsHxRef(toRustType(t, pos));
+				}
+			case _: false;
+		}
+	}
+
+	/**
+		Builds the `hxrt::cell::HxTrace` impl for a generated class under the cycle collector.
+
+		Why
+		- Trial deletion needs every tracked class to report the strong edges it owns, and to drop
+		  them once the collector has proven the object is garbage.
+
+		What
+		- `trace` reports, and `clear_refs` nulls, each field accepted by `isCycleTracedFieldType`.
+
+		How
+		- Each field forwards to `hxrt::cell::HxTraceEdges`, so the runtime owns the `HxRef` and
+		  `Array` traversal rules.
+	**/
+	function emitCycleTraceImpl(classType:ClassType):RustImpl {
+		var traceStmts:Array<RustStmt> = [];
+		var clearStmts:Array<RustStmt> = [];
+		for (spec in getAllInstanceVarFieldSpecsForStruct(classType)) {
+			var field = spec.field;
+			var fieldType = specializeAncestorType(classType, spec.owner, field.type);
+			if (shouldOptionWrapStructFieldType(fieldType) || !isCycleTracedFieldType(fieldType, field.pos))
+				continue;
+			var access = rustField(ESelf, rustFieldName(classType, field));
+			traceStmts.push(RSemi(ECall(rustRelativeExpr(["hxrt", "cell", "HxTraceEdges", "trace_edges"]),
+				[EUnary("&", access), rustSingleExpr("tracer")])));
+			clearStmts.push(RSemi(ECall(rustRelativeExpr(["hxrt", "cell", "HxTraceEdges", "clear_edges"]), [EUnary("&mut ", access)])));
+		}
+		var tracerType = RBorrow(rustRelativeType(["hxrt", "cell", "HxTracer"]), true, null);
+		var tracerName = traceStmts.length > 0 ? "tracer" : "_tracer";
+		var associated:Array<RustAssociatedItem> = [
+			AssocFunction(RustAssociatedFunction.declaration(VPrivate, "trace", false, RustGenericParameters.empty(),
+				ReceiverBorrowed(false, null), [RustFunctionParameter.named(tracerName, tracerType)], null, RustWhereClause.empty(),
+				{stmts: traceStmts, tail: null})),
+			AssocFunction(RustAssociatedFunction.declaration(VPrivate, "clear_refs", false, RustGenericParameters.empty(),
+				ReceiverBorrowed(true, null), [], null, RustWhereClause.empty(), {stmts: clearStmts, tail: null}))
+		];
+		return RustImpl.traitImplementation(RustGenericParameters.empty(), rustRelativePath(["hxrt", "cell", "HxTrace"]),
+			rustClassTypeInstType(classType), RustWhereClause.empty(), associated);
+	}
+
 	function defaultMainBody():RustBlock {
 		return {
 			stmts: [RSemi(EMacroCall("println", [ELitString("hi")]))],
@@ -5771,6 +5960,76 @@ class RustCompiler extends GenericCompiler<RustFile, RustFile, RustExpr, RustFil
 		return RustPathAnalysis.matchesPlainRelative(rustExternNativePath(cls), ["hxrt", "async_"]);
 	}
 
+	function isRustAsyncStreamClass(cls:ClassType):Bool {
+		if (cls == null)
+			return false;
+		var packPath = cls.pack != null ? cls.pack.join(".") : "";
+		if (cls.name == "Stream" && packPath == "rust.async")
+			return true;
+		return RustPathAnalysis.matchesPlainRelative(rustExternNativePath(cls), ["hxrt", "async_", "HxStream"]);
+	}
+
+	/**
+		Returns the stream operand of `for (item in stream)` when `stream` is a `rust.async.Stream<T>`.
+
+		Haxe types the loop as `TFor(v, stream.iterator(), body)`; the extern `iterator()` exists only
+		so the loop typechecks and is never emitted as a Rust call.
+	**/
+	function rustAsyncStreamIterationSource(iterable:TypedExpr):Null<TypedExpr> {
+		return switch (unwrapMetaParen(iterable).expr) {
+			case TCall(call, []): switch (unwrapMetaParen(call).expr) {
+					case TField(obj, FInstance(clsRef, _, cfRef)):
+						var cf = cfRef.get();
+						cf != null && cf.getHaxeName() == "iterator" && isRustAsyncStreamClass(clsRef.get()) ? obj : null;
+					case _:
+						null;
+				}
+			case _:
+				null;
+		}
+	}
+
+	/**
+		Lowers `for (item in stream)` over a `rust.async.Stream<T>` inside an async function.
+
+		Why
+		- Async sequences cannot implement Rust `Iterator`; each step must be awaited.
+
+		What
+		- Emits `{ let mut __hx_stream = <stream>; loop { let item = match hxrt::async_::stream_next(&mut __hx_stream).await { Some(v) => v, None => { break; } }; <body> } }`.
+
+		How
+		- The stream is moved into the loop (streams are single-consumer), so generated crates need no
+		  direct `futures` dependency. Outside async functions this reports `HXRS-ASYNC-AWAIT-CONTEXT`.
+	**/
+	function compileAsyncStreamFor(v:TVar, stream:TypedExpr, body:TypedExpr, pos:haxe.macro.Expr.Position):RustStmt {
+		ensureAsyncAllowed(pos);
+		if (!currentFunctionIsAsync) {
+			#if eval
+			RustDiagnostic.error(RustDiagnosticId.AsyncAwaitContext,
+				"`for` iteration over `rust.async.Stream<T>` is only allowed inside `@:async` / `@:rustAsync` functions.", pos);
+			#end
+		}
+		var streamName = "__hx_stream";
+		var itemName = "__hx_item";
+		var next = EAwait(ECall(rustRelativeExpr(["hxrt", "async_", "stream_next"]), [EUnary("&mut ", rustSingleExpr(streamName))]));
+		var mutable = currentMutatedLocals != null && currentMutatedLocals.exists(v.id);
+		var bindItem = RLet(rustLocalDeclIdent(v), mutable, null, EMatch(next, [
+			{pat: PTupleStruct(RustPath.single("Some"), [PBind(itemName)]), expr: rustSingleExpr(itemName)},
+			{pat: PPath(RustPath.single("None")), expr: EBlock({stmts: [RBreak], tail: null})}
+		]));
+		var compiledStream = compileExpr(stream);
+		var loopBody = compileRepeatedVoidBody(body);
+		consumeLocalReadsLikeReadCounter(body);
+		return RExpr(EBlock({
+			stmts: [
+				RLet(streamName, true, null, compiledStream),
+				RLoop({stmts: [bindItem].concat(loopBody.stmts), tail: loopBody.tail})
+			],
+			tail: null
+		}), false);
+	}
+
 	function isRustAsyncFutureClass(cls:ClassType):Bool {
 		if (cls == null)
 			return false;
@@ -5847,6 +6106,115 @@ class RustCompiler extends GenericCompiler<RustFile, RustFile, RustExpr, RustFil
 		});
 	}
 
+	/**
+		Compile `SocketAddr.parse(...)`, `ipv4(...)`, and `ipv6(...)` directly into Rust AST.
+
+		Why
+		- Metal code needs to reach non-loopback hosts without portable `sys.net`. Parsing a literal
+		  address is still a pure `std::net` operation, so it belongs beside the localhost lowering
+		  instead of growing `native_socket_addr_tools.rs` into a parser.
+
+		What
+		- `parse(text)` emits `text.parse::<std::net::SocketAddr>()` and accepts both
+		  `"1.2.3.4:80"` and `"[::1]:80"` forms.
+		- `ipv4(ip, port)` / `ipv6(ip, port)` parse the typed IP family and validate the port with
+		  `u16::try_from(...)`, building `SocketAddrV4` / `SocketAddrV6` directly.
+		- Failures map to `SocketError::invalid_input(...)`. Host names are rejected here; DNS lives
+		  in `rust.net.Dns.resolve`.
+
+		How
+		- Arguments are bound once in Haxe evaluation order (text, then port) before matching.
+		- Results are handed to the wrapper through the crate-private `SocketAddr::from_std(...)`.
+	**/
+	function compileRustSocketAddrParseCall(textExpr:TypedExpr, portExpr:Null<TypedExpr>, family:Null<String>):RustExpr {
+		var textName = "__hx_socket_addr_text";
+		var portValue = "__hx_socket_port_value";
+		var port = "__hx_socket_port";
+		var ip = "__hx_socket_ip";
+		var textRead = rustSingleExpr(textName);
+		function okAddr(stdAddr:RustExpr):RustExpr {
+			return ECall(rustRelativeExpr(["Result", "Ok"]), [ECall(rustCrateExpr(["native_socket_addr_tools", "SocketAddr", "from_std"]), [stdAddr])]);
+		}
+		function invalid(template:String, value:RustExpr):RustExpr {
+			var message = EMacroCall("format", [ELitString(template), value]);
+			return ECall(rustRelativeExpr(["Result", "Err"]), [
+				ECall(rustCrateExpr(["native_socket_error_tools", "SocketError", "invalid_input"]), [message])
+			]);
+		}
+		function parseAs(typeName:String):RustExpr {
+			return ECall(rustGenericField(textRead, "parse", [GenericType(rustRelativeType(["std", "net", typeName]))]), []);
+		}
+
+		var stmts:Array<RustStmt> = [RLet(textName, false, null, compileExpr(textExpr))];
+		if (family == null) {
+			return EBlock({
+				stmts: stmts,
+				tail: EMatch(parseAs("SocketAddr"), [
+					{pat: PTupleStruct(rustRelativePath(["Result", "Ok"]), [PBind(ip)]), expr: okAddr(rustSingleExpr(ip))},
+					{
+						pat: PTupleStruct(rustRelativePath(["Result", "Err"]), [PWildcard]),
+						expr: invalid("invalid socket address: {}", textRead)
+					}
+				])
+			});
+		}
+
+		var v4 = family == "v4";
+		var ctorArgs = [rustSingleExpr(ip), rustSingleExpr(port)];
+		if (!v4) {
+			ctorArgs.push(ELitInt(0));
+			ctorArgs.push(ELitInt(0));
+		}
+		var stdAddr = ECall(rustField(ECall(rustRelativeExpr(["std", "net", v4 ? "SocketAddrV4" : "SocketAddrV6", "new"]), ctorArgs), "into"), []);
+		var parseIp = EMatch(parseAs(v4 ? "Ipv4Addr" : "Ipv6Addr"), [
+			{pat: PTupleStruct(rustRelativePath(["Result", "Ok"]), [PBind(ip)]), expr: okAddr(stdAddr)},
+			{
+				pat: PTupleStruct(rustRelativePath(["Result", "Err"]), [PWildcard]),
+				expr: invalid(v4 ? "invalid IPv4 address: {}" : "invalid IPv6 address: {}", textRead)
+			}
+		]);
+		stmts.push(RLet(portValue, false, RI32, compileExpr(portExpr)));
+		return EBlock({
+			stmts: stmts,
+			tail: EMatch(ECall(rustRelativeExpr(["u16", "try_from"]), [rustSingleExpr(portValue)]), [
+				{pat: PTupleStruct(rustRelativePath(["Result", "Ok"]), [PBind(port)]), expr: parseIp},
+				{
+					pat: PTupleStruct(rustRelativePath(["Result", "Err"]), [PWildcard]),
+					expr: invalid("socket port out of range: {}", rustSingleExpr(portValue))
+				}
+			])
+		});
+	}
+
+	/**
+		Compile `SocketAddr.ip()`, `isIpv4()`, and `isIpv6()` as direct Rust stdlib accessors.
+
+		Why
+		- Once addresses can come from parsing or DNS, callers need to inspect the family and host
+		  part without a helper method per accessor.
+
+		What
+		- `ip()` emits `addr.as_std().ip().to_string()` wrapped into the active Haxe String
+		  representation; the family predicates emit `is_ipv4()` / `is_ipv6()`.
+
+		How
+		- Mirrors `compileRustSocketAddrPortCall`: bind the receiver once and borrow through
+		  `as_std()`.
+	**/
+	function compileRustSocketAddrAccessorCall(receiver:TypedExpr, name:String):RustExpr {
+		var recvName = "__hx_socket_addr";
+		var stdAddr = ECall(rustField(rustSingleExpr(recvName), "as_std"), []);
+		var tail = switch (name) {
+			case "ip": wrapRustStringExpr(ECall(rustField(ECall(rustField(stdAddr, "ip"), []), "to_string"), []));
+			case "isIpv4": ECall(rustField(stdAddr, "is_ipv4"), []);
+			case _: ECall(rustField(stdAddr, "is_ipv6"), []);
+		}
+		return EBlock({
+			stmts: [RLet(recvName, false, null, compileExpr(receiver))],
+			tail: tail
+		});
+	}
+
 	/**
 		Compile `SocketAddr.port()` as a direct Rust stdlib accessor.
 
@@ -7412,6 +7780,9 @@ class RustCompiler extends GenericCompiler<RustFile, RustFile, RustExpr, RustFil
 				}
 				var structInitExpr = EStructLit(RustPath.single(rustSelfType), fieldInits);
 				stmts.push(RLet("self_", false, selfRefTy, ECall(rustCrateExpr(["HxRef", "new"]), [structInitExpr])));
+				if (cycleCollectorEnabled() && classType.params.length == 0) {
+					stmts.push(RSemi(ECall(rustRelativeExpr(["hxrt", "cell", "collector_track"]), [EUnary("&", rustSingleExpr("self_"))])));
+				}
 				for (spec in getAllInstanceDynamicMethodFieldSpecsForStorage(classType)) {
 					var cf = spec.field;
 					var fieldType = specializeAncestorType(classType, spec.owner, cf.type);
@@ -9350,6 +9721,8 @@ class RustCompiler extends GenericCompiler<RustFile, RustFile, RustExpr, RustFil
 					consumeLocalReadsLikeReadCounter(body);
 					out;
 				}
+			case TFor(v, iterable, body) if (rustAsyncStreamIterationSource(iterable) != null):
+				compileAsyncStreamFor(v, rustAsyncStreamIterationSource(iterable), body, iterable.pos);
 			case TFor(v, iterable, body): {
 					function iterCloned(x:TypedExpr):RustExpr {
 						// For `Array<T>`, prefer borrowed iteration only when the loop body does not
@@ -14457,6 +14830,18 @@ class RustCompiler extends GenericCompiler<RustFile, RustFile, RustExpr, RustFil
 							if (args.length != 1)
 								return unsupported(fullExpr, "SocketAddr.localhostDetailed args");
 							return compileRustSocketAddrLocalhostCall(args[0], true);
+						case "parse":
+							if (args.length != 1)
+								return unsupported(fullExpr, "SocketAddr.parse args");
+							return compileRustSocketAddrParseCall(args[0], null, null);
+						case "ipv4":
+							if (args.length != 2)
+								return unsupported(fullExpr, "SocketAddr.ipv4 args");
+							return compileRustSocketAddrParseCall(args[0], args[1], "v4");
+						case "ipv6":
+							if (args.length != 2)
+								return unsupported(fullExpr, "SocketAddr.ipv6 args");
+							return compileRustSocketAddrParseCall(args[0], args[1], "v6");
 						case _:
 					}
 				}
@@ -15516,6 +15901,11 @@ class RustCompiler extends GenericCompiler<RustFile, RustFile, RustExpr, RustFil
 							return unsupported(fullExpr, "SocketAddr.port args");
 						return compileRustSocketAddrPortCall(obj);
 					}
+					if (isRustNetSocketAddrClass(owner) && ["ip", "isIpv4", "isIpv6"].indexOf(cf.getHaxeName()) != -1) {
+						if (args.length != 0)
+							return unsupported(fullExpr, "SocketAddr." + cf.getHaxeName() + " args");
+						return compileRustSocketAddrAccessorCall(obj, cf.getHaxeName());
+					}
 					switch (cf.kind) {
 						case FMethod(_): {
 								// Extern instances compile as direct Rust method calls: `recv.method(args...)`.
@@ -87,6 +87,9 @@ class HxrtFeatureAnalyzer {
 			if (path == "sys.io.Process" || StringTools.startsWith(path, "hxrt.process."))
 				add("process", "module", path);
 
+			if (path == "rust.process.Signals" || StringTools.startsWith(path, "hxrt.signal."))
+				add("signal", "module", path);
+
 			if (path == "haxe.io.Error" || StringTools.startsWith(path, "haxe.io.Input") || StringTools.startsWith(path, "haxe.io.Output"))
 				add("io", "module", path);
 
@@ -95,12 +98,43 @@ class HxrtFeatureAnalyzer {
 
 			if (path == "rust.async.TokioRuntime")
 				add("async_tokio", "module", path);
+
+			if (path == "rust.CycleCollector" || path == "hxrt.cell.NativeCycleCollector")
+				add("cycle_collector", "module", path);
+
+			if (path == "rust.Runtime" || StringTools.startsWith(path, "hxrt.stats."))
+				add("stats", "module", path);
+
+			if (StringTools.startsWith(path, "rust.tracing.") || StringTools.startsWith(path, "hxrt.trace."))
+				add("tracing", "module", path);
+
+			if (StringTools.startsWith(path, "rust.http.") || StringTools.startsWith(path, "hxrt.http."))
+				add("http_server", "module", path);
+
+			if (StringTools.startsWith(path, "hxrt.compression."))
+				add("compression", "module", path);
 		}
 
 		if (hasDefine("async_tokio_adapter"))
 			add("async_tokio", "define", "async_tokio_adapter");
 
+		if (hasDefine("rust_hxrt_cell_debug"))
+			add("cell_debug", "define", "rust_hxrt_cell_debug");
+
+		if (hasDefine("rust_hxrt_cycle_collector"))
+			add("cycle_collector", "define", "rust_hxrt_cycle_collector");
+
+		if (hasDefine("rust_hxrt_stats"))
+			add("stats", "define", "rust_hxrt_stats");
+
+		if (hasDefine("rust_hxrt_tracing"))
+			add("tracing", "define", "rust_hxrt_tracing");
+
 		// Internal dependency edges so selective runtime slices remain compileable.
+		if (out.contains("http_server")) {
+			add("net", "dependency_edge", "http_server->net");
+			add("thread", "dependency_edge", "http_server->thread");
+		}
 		if (out.contains("net")) {
 			add("io", "dependency_edge", "net->io");
 			add("ssl", "dependency_edge", "net->ssl");
@@ -109,6 +143,10 @@ class HxrtFeatureAnalyzer {
 			add("io", "dependency_edge", "ssl->io");
 		if (out.contains("process"))
 			add("fs", "dependency_edge", "process->fs");
+		if (out.contains("stats"))
+			add("signal", "dependency_edge", "stats->signal");
+		if (out.contains("signal"))
+			add("thread", "dependency_edge", "signal->thread");
 		if (out.contains("async_tokio"))
 			add("async", "dependency_edge", "async_tokio->async");
 
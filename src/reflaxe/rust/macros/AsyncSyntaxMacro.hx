@@ -22,6 +22,8 @@ import haxe.macro.ExprTools;
  * How:
  * - Registered as a global `@:build(...)` macro while `-D rust_async` is enabled.
  * - Applies to function bodies and field initializers in user/framework code.
+ * - Void `@:async static function main()` bodies keep their statements as-is: the compiler drives
+ *   them through `hxrt::async_::run_main` instead of returning a `Future<T>`.
  */
 class AsyncSyntaxMacro {
 	static var installed:Bool = false;
@@ -43,7 +45,7 @@ class AsyncSyntaxMacro {
 				case FFun(fn):
 					if (fn.expr != null) {
 						var rewritten = rewriteExpr(fn.expr);
-						if (hasAsyncFunctionMeta(field)) {
+						if (hasAsyncFunctionMeta(field) && !isVoidEntryMain(field, fn)) {
 							rewritten = rewriteAsyncFunctionBody(rewritten);
 						}
 						fn.expr = rewritten;
@@ -84,6 +86,16 @@ class AsyncSyntaxMacro {
 		return false;
 	}
 
+	static function isVoidEntryMain(field:Field, fn:Function):Bool {
+		if (field.name != "main" || field.access == null || !field.access.contains(AStatic))
+			return false;
+		return switch (fn.ret) {
+			case null: true;
+			case TPath(path): path.pack.length == 0 && ((path.name == "Void" && path.sub == null) || (path.name == "StdTypes" && path.sub == "Void"));
+			case _: false;
+		}
+	}
+
 	static function rewriteAsyncFunctionBody(expr:Expr):Expr {
 		var rewritten = rewriteAsyncReturns(expr);
 		return ensureTopLevelReturn(rewritten);
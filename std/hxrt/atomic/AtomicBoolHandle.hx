@@ -0,0 +1,20 @@
+package hxrt.atomic;
+
+/**
+	Runtime cell for `haxe.atomic.AtomicBool` (`hxrt::atomic::AtomicBoolHandle`).
+
+	Shares the handle semantics of `AtomicIntHandle`, backed by `std::sync::atomic::AtomicBool`.
+**/
+@:native("hxrt::atomic::AtomicBoolHandle")
+extern class AtomicBoolHandle {
+	public function new(value:Bool);
+
+	@:native("compare_exchange")
+	public function compareExchange(expected:Bool, replacement:Bool):Bool;
+
+	public function exchange(value:Bool):Bool;
+
+	public function load():Bool;
+
+	public function store(value:Bool):Bool;
+}
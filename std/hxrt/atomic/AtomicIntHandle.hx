@@ -0,0 +1,39 @@
+package hxrt.atomic;
+
+/**
+	Runtime cell for `haxe.atomic.AtomicInt` (`hxrt::atomic::AtomicIntHandle`).
+
+	Why
+	- The portable `haxe.atomic.AtomicInt` abstract needs a typed boundary to a real `AtomicI32`.
+
+	What
+	- A cheap-clone shared handle; copies alias the same cell.
+	- Read-modify-write methods return the previous value, `store` returns the stored value.
+
+	How
+	- Instance methods map one-to-one to `&self` methods on the Rust handle, so no `HxCell` lock
+	  wraps the atomic operation.
+**/
+@:native("hxrt::atomic::AtomicIntHandle")
+extern class AtomicIntHandle {
+	public function new(value:Int);
+
+	public function add(b:Int):Int;
+
+	public function sub(b:Int):Int;
+
+	public function and(b:Int):Int;
+
+	public function or(b:Int):Int;
+
+	public function xor(b:Int):Int;
+
+	@:native("compare_exchange")
+	public function compareExchange(expected:Int, replacement:Int):Int;
+
+	public function exchange(value:Int):Int;
+
+	public function load():Int;
+
+	public function store(value:Int):Int;
+}
@@ -0,0 +1,27 @@
+package hxrt.atomic;
+
+/**
+	Runtime slot for `haxe.atomic.AtomicObject<T>` (`hxrt::atomic::AtomicObjectHandle<T>`).
+
+	Why
+	- Object handles are not word-sized, so the slot swaps whole handles under a short runtime-owned
+	  critical section instead of exposing a lock to Haxe code.
+
+	What
+	- `compareExchange` compares by reference identity (Haxe object `==`).
+	- `T` must be a Haxe object handle (class instance, interface value, anonymous object); the Rust
+	  side requires `hxrt::hxref::HxRefLike`.
+**/
+@:native("hxrt::atomic::AtomicObjectHandle")
+extern class AtomicObjectHandle<T> {
+	public function new(value:T);
+
+	@:native("compare_exchange")
+	public function compareExchange(expected:T, replacement:T):T;
+
+	public function exchange(value:T):T;
+
+	public function load():T;
+
+	public function store(value:T):T;
+}
@@ -0,0 +1,33 @@
+package hxrt.cell;
+
+/**
+	`hxrt.cell.NativeCycleCollector` (Rust runtime binding)
+
+	Why
+	- `rust.CycleCollector` needs typed access to the `hxrt::cell` collector entry points without
+	  inline `untyped __rust__` in std code.
+
+	What
+	- Extern binding to the `collector_*` functions of the hxrt `cycle_collector` feature.
+
+	How
+	- `@:native("hxrt::cell")` maps this class to the runtime module; referencing it enables the
+	  `cycle_collector` feature through `HxrtFeatureAnalyzer`.
+**/
+@:native("hxrt::cell")
+extern class NativeCycleCollector {
+	@:native("collector_collect")
+	public static function collect():Int;
+
+	@:native("collector_report")
+	public static function report():String;
+
+	@:native("collector_set_threshold")
+	public static function setThreshold(allocations:Int):Void;
+
+	@:native("collector_set_report_at_exit")
+	public static function setReportAtExit(enabled:Bool):Void;
+
+	@:native("collector_tracked_count")
+	public static function trackedCount():Int;
+}
@@ -0,0 +1,38 @@
+package hxrt.compression;
+
+import haxe.io.Bytes;
+import rust.Ref;
+
+/**
+	`hxrt.compression.NativeCompression` (Rust runtime binding)
+
+	Why
+	- `sys.Http` decodes compressed responses transparently; the codecs live in the runtime
+	  (`hxrt::compression`) instead of a pure-Haxe inflate implementation.
+
+	What
+	- `decode` undoes a `Content-Encoding` value (`gzip`, `deflate`, `br`, `identity`, or a list);
+	  `decodeLimited` caps the decoded size (`decode` uses the runtime default of 64 MiB).
+	- `isSupported` tells whether every listed coding can be decoded; `acceptEncoding` is the
+	  matching `Accept-Encoding` request value.
+	- Unknown codings, corrupt bodies and bodies past the cap throw `HXRT-HTTP-DECODE` (catchable
+	  String).
+
+	How
+	- `@:native("hxrt::compression")` maps this class to the runtime module; referencing it enables
+	  the `compression` feature through `HxrtFeatureAnalyzer`.
+**/
+@:native("hxrt::compression")
+extern class NativeCompression {
+	@:native("decode_content")
+	public static function decode(encoding:String, body:Ref<Bytes>):Bytes;
+
+	@:native("decode_content_limited")
+	public static function decodeLimited(encoding:String, body:Ref<Bytes>, maxBytes:Int):Bytes;
+
+	@:native("is_supported_encoding")
+	public static function isSupported(encoding:String):Bool;
+
+	@:native("accept_encoding")
+	public static function acceptEncoding():String;
+}
@@ -1,5 +1,6 @@
 package hxrt.concurrent;
 
+import rust.Duration;
 import rust.HxRef;
 import rust.MutRef;
 import rust.Option;
@@ -40,12 +41,54 @@ extern class NativeConcurrent {
 	@:native("channel_try_recv")
 	public static function channelTryRecv<T>(channel:Ref<HxRef<ChannelHandle<T>>>):Option<T>;
 
+	@:native("channel_bounded")
+	public static function channelBounded<T>(capacity:Int):HxRef<ChannelHandle<T>>;
+
+	@:native("channel_try_send")
+	public static function channelTrySend<T>(channel:Ref<HxRef<ChannelHandle<T>>>, value:T):Bool;
+
+	@:native("channel_send_timeout")
+	public static function channelSendTimeout<T>(channel:Ref<HxRef<ChannelHandle<T>>>, value:T, timeout:Duration):Bool;
+
+	@:native("channel_recv_timeout")
+	public static function channelRecvTimeout<T>(channel:Ref<HxRef<ChannelHandle<T>>>, timeout:Duration):Option<T>;
+
+	@:native("channel_close")
+	public static function channelClose<T>(channel:Ref<HxRef<ChannelHandle<T>>>):Void;
+
+	@:native("channel_is_closed")
+	public static function channelIsClosed<T>(channel:Ref<HxRef<ChannelHandle<T>>>):Bool;
+
+	@:native("channel_len")
+	public static function channelLen<T>(channel:Ref<HxRef<ChannelHandle<T>>>):Int;
+
+	@:native("channel_select")
+	public static function channelSelect<T>(channels:Ref<Array<HxRef<ChannelHandle<T>>>>):Int;
+
+	@:native("channel_select_timeout")
+	public static function channelSelectTimeout<T>(channels:Ref<Array<HxRef<ChannelHandle<T>>>>, timeout:Duration):Int;
+
 	@:native("task_spawn")
 	public static function taskSpawn<T>(job:() -> T):HxRef<TaskHandle<T>>;
 
 	@:native("task_join")
 	public static function taskJoin<T>(task:Ref<HxRef<TaskHandle<T>>>):T;
 
+	@:native("parallel_map")
+	public static function parallelMap<T, U>(array:Ref<Array<T>>, f:(T) -> U):Array<U>;
+
+	@:native("parallel_for_each")
+	public static function parallelForEach<T>(array:Ref<Array<T>>, f:(T) -> Void):Void;
+
+	@:native("parallel_filter")
+	public static function parallelFilter<T>(array:Ref<Array<T>>, f:(T) -> Bool):Array<T>;
+
+	@:native("parallel_reduce")
+	public static function parallelReduce<T>(array:Ref<Array<T>>, f:(T, T) -> T, initial:T):T;
+
+	@:native("parallel_sort")
+	public static function parallelSort<T>(array:Ref<Array<T>>, compare:(T, T) -> Int):Void;
+
 	@:native("mutex_new")
 	public static function mutexNew<T>(value:T):HxRef<MutexHandle<T>>;
 
@@ -0,0 +1,45 @@
+package hxrt.http;
+
+/**
+	Parsed request (`hxrt::http_server::HttpRequestHandle`) behind `rust.http.Request`.
+
+	Why
+	- The runtime parses and frames the request before the handler runs; Haxe code reads the result
+	  without copying it into a Haxe object first.
+
+	What
+	- Read-only accessors. Header names are case-insensitive; `header` joins repeated values with
+	  `", "` and `headerValues` keeps them apart. `query` is `null` without a `?`.
+	- Copies refer to the same request.
+**/
+@:native("hxrt::http_server::HttpRequestHandle")
+extern class HttpRequestHandle {
+	public function method():String;
+
+	public function target():String;
+
+	public function path():String;
+
+	public function query():Null<String>;
+
+	public function version():String;
+
+	public function header(name:String):Null<String>;
+
+	@:native("header_values")
+	public function headerValues(name:String):Array<String>;
+
+	@:native("header_names")
+	public function headerNames():Array<String>;
+
+	public function body():haxe.io.Bytes;
+
+	@:native("body_string")
+	public function bodyString():String;
+
+	@:native("remote_host")
+	public function remoteHost():String;
+
+	@:native("remote_port")
+	public function remotePort():Int;
+}
@@ -0,0 +1,38 @@
+package hxrt.http;
+
+import rust.Ref;
+
+/**
+	Response under construction (`hxrt::http_server::HttpResponseHandle`) behind
+	`rust.http.Response`.
+
+	Why
+	- The server frames the response (`Content-Length`, `Connection`) itself, so the handler only
+	  fills in status, headers and body on a runtime value.
+
+	What
+	- Setters validate eagerly: statuses outside 100..599 and header names or values that could
+	  inject lines throw `HXRT-HTTP-RESPONSE`.
+	- Copies refer to the same response.
+**/
+@:native("hxrt::http_server::HttpResponseHandle")
+extern class HttpResponseHandle {
+	public function status():Int;
+
+	@:native("set_status")
+	public function setStatus(status:Int):Void;
+
+	@:native("set_header")
+	public function setHeader(name:String, value:String):Void;
+
+	@:native("add_header")
+	public function addHeader(name:String, value:String):Void;
+
+	public function header(name:String):Null<String>;
+
+	@:native("set_body")
+	public function setBody(body:Ref<haxe.io.Bytes>):Void;
+
+	@:native("set_body_string")
+	public function setBodyString(text:String):Void;
+}
@@ -0,0 +1,40 @@
+package hxrt.http;
+
+/**
+	Runtime HTTP server (`hxrt::http_server::HttpServerHandle`) behind `rust.http.Server`.
+
+	Why
+	- The listening socket, accept loop and per-connection worker threads live in the runtime; Haxe
+	  code only configures the server and supplies the request handler.
+
+	What
+	- `serve` blocks the calling thread until `stop`; `start` accepts on a background thread.
+	- Config setters apply to requests read after the call. Copies refer to the same server.
+**/
+@:native("hxrt::http_server::HttpServerHandle")
+extern class HttpServerHandle {
+	public function port():Int;
+
+	public function host():String;
+
+	@:native("set_max_body_size")
+	public function setMaxBodySize(bytes:Int):Void;
+
+	@:native("set_keep_alive_timeout")
+	public function setKeepAliveTimeout(seconds:Float):Void;
+
+	@:native("set_read_timeout")
+	public function setReadTimeout(seconds:Float):Void;
+
+	@:native("set_max_connections")
+	public function setMaxConnections(connections:Int):Void;
+
+	@:native("is_running")
+	public function isRunning():Bool;
+
+	public function serve(handler:(HttpRequestHandle) -> HttpResponseHandle):Void;
+
+	public function start(handler:(HttpRequestHandle) -> HttpResponseHandle):Void;
+
+	public function stop():Void;
+}
@@ -0,0 +1,25 @@
+package hxrt.http;
+
+/**
+	`hxrt.http.NativeHttpServer` (Rust runtime binding)
+
+	Why
+	- `rust.http.Server` and `rust.http.Response` need typed access to the `hxrt::http_server`
+	  entry points without inline `untyped __rust__` in std code.
+
+	What
+	- `bind` opens a listening server; `response` creates an empty response with a status.
+	- Bad ports throw `HXRT-HTTP-CONFIG`, bad statuses `HXRT-HTTP-RESPONSE` (catchable Strings).
+
+	How
+	- `@:native("hxrt::http_server")` maps this class to the runtime module; referencing it enables
+	  the `http_server` feature through `HxrtFeatureAnalyzer`.
+**/
+@:native("hxrt::http_server")
+extern class NativeHttpServer {
+	@:native("http_server_bind")
+	public static function bind(host:String, port:Int):HttpServerHandle;
+
+	@:native("http_response")
+	public static function response(status:Int):HttpResponseHandle;
+}
@@ -0,0 +1,49 @@
+package hxrt.net;
+
+import rust.HxRef;
+import rust.Ref;
+
+/**
+	`hxrt.net.NativeHttpPool` (Rust runtime binding)
+
+	Why
+	- `sys.Http` reuses keep-alive connections across requests; the pool has to be shared by every
+	  thread, so it lives in the runtime (`hxrt::http_pool`) rather than in a Haxe static.
+
+	What
+	- `acquire` reserves a slot for a `"scheme://host:port"` key and moves a pooled connection into
+	  the given socket handle when one is live (returns `true`); otherwise the caller connects.
+	- `release` returns the slot; with `reusable` the connection moves back into the pool.
+	- Cap and idle-timeout settings, `clear` and `idleCount` for inspection.
+	- A wait for a free slot that times out throws `HXRT-HTTP-POOL` (catchable String).
+
+	How
+	- `@:native("hxrt::http_pool")` maps this class to the runtime module; the `hxrt.net` package
+	  enables the `net` feature through `HxrtFeatureAnalyzer`.
+**/
+@:native("hxrt::http_pool")
+extern class NativeHttpPool {
+	@:native("http_pool_acquire")
+	public static function acquire(key:String, handle:Ref<HxRef<SocketHandle>>, timeoutSeconds:Float):Bool;
+
+	@:native("http_pool_release")
+	public static function release(key:String, handle:Ref<HxRef<SocketHandle>>, reusable:Bool):Void;
+
+	@:native("http_pool_set_max_connections")
+	public static function setMaxConnections(connections:Int):Void;
+
+	@:native("http_pool_max_connections")
+	public static function maxConnections():Int;
+
+	@:native("http_pool_set_idle_timeout")
+	public static function setIdleTimeout(seconds:Float):Void;
+
+	@:native("http_pool_idle_timeout")
+	public static function idleTimeout():Float;
+
+	@:native("http_pool_clear")
+	public static function clear():Void;
+
+	@:native("http_pool_idle_count")
+	public static function idleCount(key:String):Int;
+}
@@ -0,0 +1,35 @@
+package hxrt.net;
+
+import rust.HxRef;
+import rust.Ref;
+
+/**
+	`hxrt.net.NativeHttpProxy` (Rust runtime binding)
+
+	Why
+	- `sys.Http` honours the proxy environment variables and tunnels HTTPS through `CONNECT`; the
+	  runtime (`hxrt::http_proxy`) owns the environment lookup and the tunnel exchange.
+
+	What
+	- `fromEnv` returns the proxy URL for a request (`https_proxy`/`http_proxy`, upper-case too), or
+	  `null` when none is set or `no_proxy`/`NO_PROXY` exempts the target. `HTTP_PROXY` is ignored
+	  when `REQUEST_METHOD` is set (CGI, "httpoxy").
+	- `bypassed` is the `NO_PROXY` match on its own.
+	- `connect` sends `CONNECT host:port` over a socket connected to the proxy; a refusal throws
+	  `HXRT-HTTP-PROXY` (catchable String).
+
+	How
+	- `@:native("hxrt::http_proxy")` maps this class to the runtime module; the `hxrt.net` package
+	  enables the `net` feature through `HxrtFeatureAnalyzer`.
+**/
+@:native("hxrt::http_proxy")
+extern class NativeHttpProxy {
+	@:native("http_proxy_from_env")
+	public static function fromEnv(secure:Bool, host:String, port:Int):Null<String>;
+
+	@:native("http_proxy_bypassed")
+	public static function bypassed(noProxy:String, host:String, port:Int):Bool;
+
+	@:native("http_proxy_connect")
+	public static function connect(handle:Ref<HxRef<SocketHandle>>, host:String, port:Int, authorization:Null<String>):Void;
+}
@@ -16,8 +16,10 @@ import rust.Ref;
 
 	What
 	- Exposes TCP socket lifecycle and polling helpers used by `std/rust/_std/sys/net/Socket.hx`.
-	- Returns plain typed values (`Int`, `Bool`, `Array<Array<Int>>`) so callers immediately return
-	  to normal typed Haxe code.
+	- Returns plain typed values (`Int`, `Bool`, `String`, `Array<Array<Int>>`) so callers
+	  immediately return to normal typed Haxe code.
+	- IP addresses cross the boundary as canonical address text (`"127.0.0.1"`, `"::1"`); the
+	  runtime parses them back into a full `IpAddr`, so IPv6 needs no extra handle type.
 
 	How
 	- Binds to crate module `socket_native` provided via `@:rustExtraSrc`.
@@ -32,14 +34,23 @@ extern class NativeSocket {
 	@:native("new_udp")
 	public static function newUdp():HxRef<SocketHandle>;
 
+	/**
+		Resolves `name` (an IP literal or host name, IPv4 preferred) to its canonical address text.
+	**/
 	@:native("host_resolve")
-	public static function hostResolve(name:Ref<String>):Int;
+	public static function hostResolve(name:Ref<String>):String;
+
+	/**
+		The `sys.net.Host.ip` integer for address text: IPv4 in network order, `0` for IPv6.
+	**/
+	@:native("host_ipv4")
+	public static function hostIpv4(address:Ref<String>):Int;
 
 	@:native("host_to_string")
 	public static function hostToString(ip:Int):String;
 
 	@:native("host_reverse")
-	public static function hostReverse(ip:Int):String;
+	public static function hostReverse(address:Ref<String>):String;
 
 	@:native("host_local")
 	public static function hostLocal():String;
@@ -47,20 +58,41 @@ extern class NativeSocket {
 	@:native("close")
 	public static function closeHandle(handle:Ref<HxRef<SocketHandle>>):Void;
 
-	public static function connect(handle:Ref<HxRef<SocketHandle>>, host:Int, port:Int):Void;
+	public static function connect(handle:Ref<HxRef<SocketHandle>>, host:Ref<String>, port:Int):Void;
+	/**
+		Connects a fresh TCP (stream) or UDP (datagram) handle to the Unix domain socket at `path`.
+
+		Throws the catchable String prefix `HXRT-NET-UNIX-UNSUPPORTED` on platforms without Unix
+		domain sockets.
+	**/
+	@:native("connect_unix")
+	public static function connectUnix(handle:Ref<HxRef<SocketHandle>>, path:Ref<String>):Void;
+
+	/**
+		Binds a fresh TCP (stream) or UDP (datagram) handle to a new Unix socket file at `path`.
+	**/
+	@:native("bind_unix")
+	public static function bindUnix(handle:Ref<HxRef<SocketHandle>>, path:Ref<String>):Void;
+
+	@:native("unix_path")
+	public static function unixPath(handle:Ref<HxRef<SocketHandle>>):String;
+
+	@:native("unix_peer_path")
+	public static function unixPeerPath(handle:Ref<HxRef<SocketHandle>>):String;
+
 	public static function listen(handle:Ref<HxRef<SocketHandle>>, connections:Int):Void;
 	public static function shutdown(handle:Ref<HxRef<SocketHandle>>, read:Bool, write:Bool):Void;
-	public static function bind(handle:Ref<HxRef<SocketHandle>>, host:Int, port:Int):Void;
+	public static function bind(handle:Ref<HxRef<SocketHandle>>, host:Ref<String>, port:Int):Void;
 	public static function accept(handle:Ref<HxRef<SocketHandle>>):HxRef<SocketHandle>;
 
 	@:native("peer_ip")
-	public static function peerIp(handle:Ref<HxRef<SocketHandle>>):Int;
+	public static function peerIp(handle:Ref<HxRef<SocketHandle>>):String;
 
 	@:native("peer_port")
 	public static function peerPort(handle:Ref<HxRef<SocketHandle>>):Int;
 
 	@:native("host_ip")
-	public static function hostIp(handle:Ref<HxRef<SocketHandle>>):Int;
+	public static function hostIp(handle:Ref<HxRef<SocketHandle>>):String;
 
 	@:native("host_port")
 	public static function hostPort(handle:Ref<HxRef<SocketHandle>>):Int;
@@ -71,12 +103,69 @@ extern class NativeSocket {
 	@:native("wait_for_read")
 	public static function waitForRead(handle:Ref<HxRef<SocketHandle>>):Void;
 
+	/**
+		Registers the socket with a thread's EventLoop; returns an EventLoop event id.
+
+		Throws the catchable String prefix `HXRT-EVENTLOOP-IO` when the socket is not open or the
+		interest is empty.
+	**/
+	@:native("watch")
+	public static function watch(threadId:Int, handle:Ref<HxRef<SocketHandle>>, readable:Bool, writable:Bool, callback:() -> Void):Int;
+
 	@:native("set_blocking")
 	public static function setBlocking(handle:Ref<HxRef<SocketHandle>>, blocking:Bool):Void;
 
 	@:native("set_fast_send")
 	public static function setFastSend(handle:Ref<HxRef<SocketHandle>>, fastSend:Bool):Void;
 
+	/**
+		Socket option accessors backing the Rust-target `sys.net.Socket` option extension.
+
+		Setters on a handle without an OS socket are recorded and applied when `bind`/`connect`
+		creates it; invalid values throw the String prefix `HXRT-NET-OPTION`.
+	**/
+	@:native("set_reuse_address")
+	public static function setReuseAddress(handle:Ref<HxRef<SocketHandle>>, enabled:Bool):Void;
+
+	@:native("reuse_address")
+	public static function reuseAddress(handle:Ref<HxRef<SocketHandle>>):Bool;
+
+	@:native("set_reuse_port")
+	public static function setReusePort(handle:Ref<HxRef<SocketHandle>>, enabled:Bool):Void;
+
+	@:native("reuse_port")
+	public static function reusePort(handle:Ref<HxRef<SocketHandle>>):Bool;
+
+	@:native("set_keep_alive")
+	public static function setKeepAlive(handle:Ref<HxRef<SocketHandle>>, enabled:Bool, idleSeconds:Float, intervalSeconds:Float):Void;
+
+	@:native("keep_alive")
+	public static function keepAlive(handle:Ref<HxRef<SocketHandle>>):Bool;
+
+	@:native("set_linger")
+	public static function setLinger(handle:Ref<HxRef<SocketHandle>>, seconds:Float):Void;
+
+	@:native("linger")
+	public static function linger(handle:Ref<HxRef<SocketHandle>>):Float;
+
+	@:native("set_send_buffer_size")
+	public static function setSendBufferSize(handle:Ref<HxRef<SocketHandle>>, bytes:Int):Void;
+
+	@:native("send_buffer_size")
+	public static function sendBufferSize(handle:Ref<HxRef<SocketHandle>>):Int;
+
+	@:native("set_recv_buffer_size")
+	public static function setRecvBufferSize(handle:Ref<HxRef<SocketHandle>>, bytes:Int):Void;
+
+	@:native("recv_buffer_size")
+	public static function recvBufferSize(handle:Ref<HxRef<SocketHandle>>):Int;
+
+	@:native("set_ttl")
+	public static function setTtl(handle:Ref<HxRef<SocketHandle>>, ttl:Int):Void;
+
+	@:native("ttl")
+	public static function ttl(handle:Ref<HxRef<SocketHandle>>):Int;
+
 	/**
 		Upgrade a connected TCP socket to a TLS client session.
 
@@ -138,9 +227,43 @@ extern class NativeSocket {
 	@:native("udp_send_to")
 	public static function udpSendTo(handle:Ref<HxRef<SocketHandle>>, bytes:Ref<haxe.io.Bytes>, pos:Int, len:Int, host:Int, port:Int):Int;
 
+	@:native("unix_send_to")
+	public static function unixSendTo(handle:Ref<HxRef<SocketHandle>>, bytes:Ref<haxe.io.Bytes>, pos:Int, len:Int, path:Ref<String>):Int;
+
 	@:native("udp_read_from")
 	public static function udpReadFrom(handle:Ref<HxRef<SocketHandle>>, bytes:Ref<haxe.io.Bytes>, pos:Int, len:Int):Array<Int>;
 
+	/**
+		UDP multicast and sender accessors backing the Rust-target `sys.net.UdpSocket` extension.
+
+		Groups and interfaces are canonical address text; IPv6 groups use `interfaceIndex` instead
+		of the interface address. `udpLastSenderIp` is the full (IPv4 or IPv6) sender of the last
+		`udpReadFrom` datagram. Invalid values throw the String prefix `HXRT-NET-OPTION`.
+	**/
+	@:native("udp_join_multicast")
+	public static function udpJoinMulticast(handle:Ref<HxRef<SocketHandle>>, group:Ref<String>, iface:Ref<String>, interfaceIndex:Int):Void;
+
+	@:native("udp_leave_multicast")
+	public static function udpLeaveMulticast(handle:Ref<HxRef<SocketHandle>>, group:Ref<String>, iface:Ref<String>, interfaceIndex:Int):Void;
+
+	@:native("udp_set_multicast_ttl")
+	public static function udpSetMulticastTtl(handle:Ref<HxRef<SocketHandle>>, ttl:Int):Void;
+
+	@:native("udp_multicast_ttl")
+	public static function udpMulticastTtl(handle:Ref<HxRef<SocketHandle>>):Int;
+
+	@:native("udp_set_multicast_loop")
+	public static function udpSetMulticastLoop(handle:Ref<HxRef<SocketHandle>>, enabled:Bool):Void;
+
+	@:native("udp_multicast_loop")
+	public static function udpMulticastLoop(handle:Ref<HxRef<SocketHandle>>):Bool;
+
+	@:native("udp_send_to_address")
+	public static function udpSendToAddress(handle:Ref<HxRef<SocketHandle>>, bytes:Ref<haxe.io.Bytes>, pos:Int, len:Int, address:Ref<String>, port:Int):Int;
+
+	@:native("udp_last_sender_ip")
+	public static function udpLastSenderIp(handle:Ref<HxRef<SocketHandle>>):String;
+
 	@:native("select_groups")
 	public static function selectGroups(read:Ref<Array<HxRef<SocketHandle>>>, write:Ref<Array<HxRef<SocketHandle>>>, others:Ref<Array<HxRef<SocketHandle>>>,
 		timeout:Null<Float>):Array<Array<Int>>;
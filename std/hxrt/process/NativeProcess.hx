@@ -33,6 +33,13 @@ extern class NativeProcess {
 
 	public static function pid(handle:Ref<HxRef<ProcessHandle>>):Int;
 
+	/**
+		Registers the child's stdout (`stderr == false`) or stderr pipe with a thread's EventLoop;
+		returns an EventLoop event id. Unix only; elsewhere throws `HXRT-EVENTLOOP-IO`.
+	**/
+	@:native("process_watch_output")
+	public static function watchOutput(threadId:Int, handle:Ref<HxRef<ProcessHandle>>, stderr:Bool, callback:() -> Void):Int;
+
 	@:native("wait_exit_code")
 	public static function waitExitCode(handle:Ref<HxRef<ProcessHandle>>):Int;
 
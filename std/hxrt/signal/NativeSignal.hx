@@ -0,0 +1,36 @@
+package hxrt.signal;
+
+import hxrt.concurrent.ChannelHandle;
+import rust.HxRef;
+import rust.Ref;
+import rust.process.Signal;
+
+/**
+	`hxrt.signal.NativeSignal` (typed runtime boundary)
+
+	Why
+	- `rust.process.Signals` needs process-wide OS signal handlers, which only the runtime can
+	  install safely.
+
+	What
+	- Typed extern bindings for `hxrt::signal`: subscribe a set of signals to an event loop (by
+	  thread id) or to a channel, cancel a subscription, and query platform support.
+
+	How
+	- Signals cross the boundary as their `Int` values; the runtime rejects anything outside
+	  `rust.process.Signal` with the catchable `HXRT-SIGNAL-UNSUPPORTED` String prefix.
+**/
+@:native("hxrt::signal")
+extern class NativeSignal {
+	@:native("signal_listen")
+	public static function listen(signals:Ref<Array<Signal>>, threadId:Int, callback:(Signal) -> Void):Int;
+
+	@:native("signal_notify")
+	public static function notify(signals:Ref<Array<Signal>>, channel:Ref<HxRef<ChannelHandle<Signal>>>):Int;
+
+	@:native("signal_cancel")
+	public static function cancel(subscriptionId:Int):Void;
+
+	@:native("signal_is_supported")
+	public static function isSupported(signal:Signal):Bool;
+}
@@ -0,0 +1,36 @@
+package hxrt.stats;
+
+import rust.process.Signal;
+
+/**
+	`hxrt.stats.NativeStats` (Rust runtime binding)
+
+	Why
+	- `rust.Runtime` needs typed access to the `hxrt::stats` entry points without inline
+	  `untyped __rust__` in std code.
+
+	What
+	- Extern binding to the `stats_*` functions of the hxrt `stats` feature. An empty `path` means
+	  stderr.
+
+	How
+	- `@:native("hxrt::stats")` maps this class to the runtime module; referencing it enables the
+	  `stats` feature through `HxrtFeatureAnalyzer`.
+**/
+@:native("hxrt::stats")
+extern class NativeStats {
+	@:native("stats_snapshot")
+	public static function snapshot():StatsSnapshot;
+
+	@:native("stats_json")
+	public static function json():String;
+
+	@:native("stats_dump")
+	public static function dump(path:String):Void;
+
+	@:native("stats_set_dump_at_exit")
+	public static function setDumpAtExit(path:String):Void;
+
+	@:native("stats_dump_on_signal")
+	public static function dumpOnSignal(signal:Signal, path:String):Int;
+}
@@ -0,0 +1,69 @@
+package hxrt.stats;
+
+/**
+	Point-in-time runtime counters (`hxrt::stats::StatsSnapshot`) behind `rust.Runtime.stats()`.
+
+	Why
+	- `rust.Runtime` builds its typed `rust.RuntimeStats` structure from one consistent copy of the
+	  counters instead of reading live atomics field by field.
+
+	What
+	- Process-wide totals plus indexed per-type and per-thread rows; counters are `Float` so they
+	  cannot overflow a Haxe `Int`.
+	- Types are ordered by live object count (highest first), threads by id. An index outside the
+	  row count throws "Outside bounds".
+
+	How
+	- Instance methods map one-to-one to `&self` methods on the Rust snapshot.
+**/
+@:native("hxrt::stats::StatsSnapshot")
+extern class StatsSnapshot {
+	public function allocations():Float;
+
+	@:native("live_objects")
+	public function liveObjects():Float;
+
+	@:native("lock_waits")
+	public function lockWaits():Float;
+
+	@:native("lock_wait_seconds")
+	public function lockWaitSeconds():Float;
+
+	public function throws():Float;
+
+	@:native("task_queue_depth")
+	public function taskQueueDepth():Int;
+
+	@:native("type_count")
+	public function typeCount():Int;
+
+	@:native("type_name")
+	public function typeName(index:Int):String;
+
+	@:native("type_allocations")
+	public function typeAllocations(index:Int):Float;
+
+	@:native("type_live")
+	public function typeLive(index:Int):Float;
+
+	@:native("type_lock_waits")
+	public function typeLockWaits(index:Int):Float;
+
+	@:native("thread_count")
+	public function threadCount():Int;
+
+	@:native("thread_id")
+	public function threadId(index:Int):Int;
+
+	@:native("thread_messages")
+	public function threadMessages(index:Int):Int;
+
+	@:native("thread_events")
+	public function threadEvents(index:Int):Int;
+
+	@:native("thread_regular_events")
+	public function threadRegularEvents(index:Int):Int;
+
+	@:native("to_json")
+	public function toJson():String;
+}
@@ -75,9 +75,16 @@ extern class NativeSys {
 	@:native("command")
 	public static function command(cmd:String, args:Null<Array<String>>):Int;
 
+	/** Runs registered exit handlers and flushes stdout/stderr before ending the process. */
 	@:native("exit")
 	public static function exit(code:Int):Void;
 
+	@:native("add_exit_handler")
+	public static function addExitHandler(handler:() -> Void):Int;
+
+	@:native("remove_exit_handler")
+	public static function removeExitHandler(id:Int):Bool;
+
 	@:native("time")
 	public static function time():Float;
 
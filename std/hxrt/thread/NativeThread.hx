@@ -1,5 +1,7 @@
 package hxrt.thread;
 
+import rust.HxRef;
+import rust.Ref;
 import sys.thread.Types.ThreadMessage;
 
 /**
@@ -60,6 +62,32 @@ extern class NativeThread {
 	@:native("thread_spawn_with_event_loop")
 	public static function spawnWithEventLoop(job:() -> Void):Int;
 
+	/**
+		Spawns a thread with an OS name, stack size, and optional join support.
+
+		What
+		- `name == null` leaves the thread unnamed; `stackSize == 0` keeps the platform default.
+		- Invalid options throw the catchable String prefix `HXRT-THREAD-OPTIONS`.
+
+		How
+		- Joinable threads keep their Rust `JoinHandle` in the runtime thread registry until
+		  `join` collects it.
+	**/
+	@:native("thread_spawn_with_options")
+	public static function spawnWithOptions(job:() -> Void, name:Null<String>, stackSize:Int, joinable:Bool, withEventLoop:Bool):Int;
+
+	/**
+		Waits for a joinable thread to finish.
+
+		Throws `HXRT-THREAD-NOT-JOINABLE` (not joinable or already joined), `HXRT-THREAD-SELF-JOIN`,
+		or `HXRT-THREAD-PANICKED` (the thread ended with a Rust panic).
+	**/
+	@:native("thread_join")
+	public static function join(threadId:Int):Void;
+
+	@:native("thread_is_alive")
+	public static function isAlive(threadId:Int):Bool;
+
 	/**
 		Sends a message payload to another thread queue.
 
@@ -111,4 +139,48 @@ extern class NativeThread {
 
 	@:native("event_loop_loop")
 	public static function eventLoopLoop(threadId:Int):Void;
+
+	/**
+		Work-stealing pool bindings used by `FixedThreadPool` and `ElasticThreadPool`.
+
+		Why
+		- Per-worker deques and worker lifecycle are runtime-owned; Haxe keeps only the handle.
+
+		What
+		- `poolRun` returns `false` (without running `job`) once the pool is shut down.
+		- `poolJoin` waits for every worker to exit; it throws `HXRT-THREAD-POOL-SELF-JOIN` when
+		  called from a worker of the same pool.
+		- A Haxe exception escaping a job is reported as `HXRT-THREAD-UNCAUGHT`; the worker survives.
+
+		How
+		- Fixed pools start all workers up front; elastic pools start workers on demand and retire
+		  them after `idleTimeout` seconds without work. `poolSetMaxWorkers` changes an elastic
+		  pool's cap for later `poolRun` calls.
+	**/
+	@:native("pool_new_fixed")
+	public static function poolNewFixed(workers:Int):HxRef<ThreadPoolHandle>;
+
+	@:native("pool_new_elastic")
+	public static function poolNewElastic(maxWorkers:Int, idleTimeout:Float):HxRef<ThreadPoolHandle>;
+
+	@:native("pool_set_max_workers")
+	public static function poolSetMaxWorkers(pool:Ref<HxRef<ThreadPoolHandle>>, maxWorkers:Int):Void;
+
+	@:native("pool_run")
+	public static function poolRun(pool:Ref<HxRef<ThreadPoolHandle>>, job:() -> Void):Bool;
+
+	@:native("pool_shutdown")
+	public static function poolShutdown(pool:Ref<HxRef<ThreadPoolHandle>>):Void;
+
+	@:native("pool_is_shutdown")
+	public static function poolIsShutdown(pool:Ref<HxRef<ThreadPoolHandle>>):Bool;
+
+	@:native("pool_join")
+	public static function poolJoin(pool:Ref<HxRef<ThreadPoolHandle>>):Void;
+
+	@:native("pool_threads_count")
+	public static function poolThreadsCount(pool:Ref<HxRef<ThreadPoolHandle>>):Int;
+
+	@:native("pool_queue_depth")
+	public static function poolQueueDepth(pool:Ref<HxRef<ThreadPoolHandle>>):Int;
 }
@@ -0,0 +1,19 @@
+package hxrt.thread;
+
+/**
+	Opaque runtime handle for `sys.thread.FixedThreadPool` / `sys.thread.ElasticThreadPool`
+	(`hxrt::thread::ThreadPoolHandle`).
+
+	Why
+	- Task dispatch, stealing, and worker lifecycle live in the Rust runtime so pools do not contend
+	  on one Haxe-level queue lock.
+
+	What
+	- An extern marker type mapped to the Rust struct `hxrt::thread::ThreadPoolHandle`.
+
+	How
+	- The Haxe pool classes store this as `rust.HxRef<ThreadPoolHandle>` and operate on it through
+	  the typed `NativeThread.pool*` bindings.
+**/
+@:native("hxrt::thread::ThreadPoolHandle")
+extern class ThreadPoolHandle {}
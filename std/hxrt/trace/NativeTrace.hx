@@ -0,0 +1,32 @@
+package hxrt.trace;
+
+/**
+	`hxrt.trace.NativeTrace` (Rust runtime binding)
+
+	Why
+	- `rust.tracing.Tracing` needs typed access to the `hxrt::trace` entry points without inline
+	  `untyped __rust__` in std code.
+
+	What
+	- Extern binding to the subscriber setup and span/event functions of the hxrt `tracing`
+	  feature. Levels are `rust.tracing.TraceLevel` values; fields travel as parallel key/value
+	  arrays, and an empty `path` means stderr.
+
+	How
+	- `@:native("hxrt::trace")` maps this class to the runtime module; referencing it enables the
+	  `tracing` feature through `HxrtFeatureAnalyzer`.
+**/
+@:native("hxrt::trace")
+extern class NativeTrace {
+	@:native("trace_use_stderr")
+	public static function useStderr(level:Int):Void;
+
+	@:native("trace_use_json_lines")
+	public static function useJsonLines(level:Int, path:String):Void;
+
+	@:native("trace_span")
+	public static function span(level:Int, name:String, keys:Array<String>, values:Array<String>):SpanHandle;
+
+	@:native("trace_event")
+	public static function event(level:Int, message:String, keys:Array<String>, values:Array<String>):Void;
+}
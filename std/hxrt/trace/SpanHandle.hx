@@ -0,0 +1,23 @@
+package hxrt.trace;
+
+/**
+	Runtime span (`hxrt::trace::SpanHandle`) behind `rust.tracing.Span`.
+
+	Why
+	- Application spans must be scoped on the thread that entered them, which `tracing` guards
+	  enforce; the runtime keeps those guards so Haxe code only sees `enter` / `exit`.
+
+	What
+	- `enter()` makes the span current on this thread until the matching `exit()`.
+	- `isEnabled()` reports whether an installed subscriber records the span.
+	- Copies refer to the same span.
+**/
+@:native("hxrt::trace::SpanHandle")
+extern class SpanHandle {
+	public function enter():Void;
+
+	public function exit():Void;
+
+	@:native("is_enabled")
+	public function isEnabled():Bool;
+}
@@ -0,0 +1,23 @@
+package hxrt.weak;
+
+/**
+	Runtime weak reference (`hxrt::weak::WeakHandle<T>`) behind `rust.Weak<T>`.
+
+	Why
+	- Haxe objects are reference counted on this target; a weak handle lets code point at an object
+	  without keeping it alive.
+
+	What
+	- `get()` returns the object, or `null` once the last strong reference is gone.
+	- `T` must be a runtime object handle (class instance, interface value); the Rust side requires
+	  `hxrt::weak::HxWeakable`, and `get()` additionally needs a null representation.
+**/
+@:native("hxrt::weak::WeakHandle")
+extern class WeakHandle<T> {
+	public function new(value:T);
+
+	public function get():T;
+
+	@:native("is_alive")
+	public function isAlive():Bool;
+}
@@ -0,0 +1,42 @@
+package hxrt.weak;
+
+/**
+	Runtime storage for `haxe.ds.WeakMap<K, V>` (`hxrt::weak::WeakMapHandle<K, V>`).
+
+	Why
+	- Entries must disappear once their key object is dropped, which needs runtime weak handles and
+	  identity keys rather than a Haxe-level map.
+
+	What
+	- Identity-keyed storage: keys are compared by reference, and dead keys are never reported.
+	- `K` must be a runtime object handle (`hxrt::weak::HxWeakable` on the Rust side).
+**/
+@:native("hxrt::weak::WeakMapHandle")
+extern class WeakMapHandle<K, V> {
+	public function new();
+
+	public function set(key:K, value:V):Void;
+
+	@:rustReturn("Option<V>")
+	public function get(key:K):Null<V>;
+
+	public function exists(key:K):Bool;
+
+	public function remove(key:K):Bool;
+
+	public function keys():Iterator<K>;
+
+	public function values():Iterator<V>;
+
+	@:native("key_values")
+	public function keyValues():KeyValueIterator<K, V>;
+
+	public function count():Int;
+
+	public function copy():WeakMapHandle<K, V>;
+
+	public function clear():Void;
+
+	@:native("debug_string")
+	public function debugString():String;
+}
@@ -0,0 +1,53 @@
+package rust;
+
+import hxrt.cell.NativeCycleCollector;
+
+/**
+	rust.CycleCollector
+
+	Why
+	- Class instances are reference counted on this target, so object graphs with back-references
+	  (parent/child trees, observers, doubly-linked lists) stay alive after the program drops them.
+	  Other Haxe targets reclaim those graphs with a garbage collector.
+
+	What
+	- `collect()` frees every tracked object that is only kept alive by reference cycles and returns
+	  how many objects were released.
+	- `setThreshold(n)` collects automatically after every `n` tracked allocations (`0` disables).
+	- `report()` describes the objects currently kept alive only by cycles, grouped per cycle with a
+	  count per type; it is empty when there are none.
+	- `setReportAtExit(true)` (or running with `HXRT_CYCLE_REPORT=1`) prints that report to stderr
+	  when the program exits.
+
+	How
+	- Referencing this class, or compiling with `-D rust_hxrt_cycle_collector`, makes generated
+	  constructors register their objects and generated classes report their class-typed and
+	  `Array` fields to the collector.
+	- Only those fields are traced. References held through `Dynamic`, interfaces, polymorphic base
+	  classes, closures, generic classes, or statics count as live roots, so a cycle through them is
+	  kept rather than freed.
+	- Collect at quiet points: while a collection runs, other threads should not be mutating the
+	  object graphs it inspects.
+**/
+class CycleCollector {
+	public static function collect():Int {
+		return NativeCycleCollector.collect();
+	}
+
+	public static function report():String {
+		return NativeCycleCollector.report();
+	}
+
+	public static function setThreshold(allocations:Int):Void {
+		NativeCycleCollector.setThreshold(allocations);
+	}
+
+	public static function setReportAtExit(enabled:Bool):Void {
+		NativeCycleCollector.setReportAtExit(enabled);
+	}
+
+	/** Number of tracked objects that are still alive. **/
+	public static function trackedCount():Int {
+		return NativeCycleCollector.trackedCount();
+	}
+}
@@ -15,7 +15,7 @@ package rust;
  * - Assignment or cloning preserves identity; mutation through one alias is visible through the
  *   others. The last owner of an acyclic graph releases its payload.
  * - Strong handle cycles are not tracing-collected. Long-lived cyclic graphs need an explicit edge
- *   break or a deliberately Rust-shaped ownership model.
+ *   break, a non-owning `rust.Weak<T>` back-edge, or a deliberately Rust-shaped ownership model.
  * - It is not a promise that the generated Rust representation is `Arc`, `HxCell`, `RwLock`, or
  *   any other particular type or layout.
  *
@@ -0,0 +1,79 @@
+package rust;
+
+import hxrt.stats.NativeStats;
+import rust.RuntimeStats;
+import rust.process.Signal;
+
+/**
+	rust.Runtime
+
+	Why
+	- Long-running generated services need to see where memory goes and where threads wait: which
+	  object types accumulate, which shared objects are contended, how often exceptions fly, and
+	  whether thread or event-loop queues back up.
+
+	What
+	- `stats()` returns a `rust.RuntimeStats` snapshot; `statsJson()` the same data as JSON.
+	- `dumpStats(?path)` writes that JSON to `path` (replacing the file) or to stderr.
+	- `dumpStatsAtExit(?path)` dumps once when the program exits; running with
+	  `HXRT_STATS_DUMP=<path>` (or `HXRT_STATS_DUMP=-` for stderr) does the same without code.
+	- `dumpStatsOnSignal(signal, ?path)` dumps each time `signal` arrives, e.g. `kill -HUP <pid>`
+	  on a running service. It returns a subscription id for `rust.process.Signals.cancel`; while
+	  subscribed, that signal no longer terminates the process.
+
+	How
+	- Referencing this class, or compiling with `-D rust_hxrt_stats`, enables the hxrt `stats`
+	  feature. Counting then adds atomic increments to object allocation and exceptions and a
+	  non-blocking try before each object lock; programs that do not opt in keep the plain runtime.
+	- Signal dumps are written from the runtime's signal thread, so they work even while every
+	  event loop is busy.
+**/
+class Runtime {
+	public static function stats():RuntimeStats {
+		var snapshot = NativeStats.snapshot();
+		var types:Array<RuntimeTypeStats> = [];
+		for (i in 0...snapshot.typeCount()) {
+			types.push({
+				name: snapshot.typeName(i),
+				allocations: snapshot.typeAllocations(i),
+				live: snapshot.typeLive(i),
+				lockWaits: snapshot.typeLockWaits(i)
+			});
+		}
+		var threads:Array<RuntimeThreadStats> = [];
+		for (i in 0...snapshot.threadCount()) {
+			threads.push({
+				id: snapshot.threadId(i),
+				messages: snapshot.threadMessages(i),
+				events: snapshot.threadEvents(i),
+				regularEvents: snapshot.threadRegularEvents(i)
+			});
+		}
+		return {
+			allocations: snapshot.allocations(),
+			liveObjects: snapshot.liveObjects(),
+			lockWaits: snapshot.lockWaits(),
+			lockWaitSeconds: snapshot.lockWaitSeconds(),
+			throws: snapshot.throws(),
+			taskQueue: snapshot.taskQueueDepth(),
+			types: types,
+			threads: threads
+		};
+	}
+
+	public static function statsJson():String {
+		return NativeStats.json();
+	}
+
+	public static function dumpStats(?path:String):Void {
+		NativeStats.dump(path == null ? "" : path);
+	}
+
+	public static function dumpStatsAtExit(?path:String):Void {
+		NativeStats.setDumpAtExit(path == null ? "" : path);
+	}
+
+	public static function dumpStatsOnSignal(signal:Signal, ?path:String):Int {
+		return NativeStats.dumpOnSignal(signal, path == null ? "" : path);
+	}
+}
@@ -0,0 +1,46 @@
+package rust;
+
+/**
+	rust.RuntimeStats
+
+	Why
+	- `rust.Runtime.stats()` should hand services a typed structure they can log, export, or assert
+	  on, rather than a JSON string to re-parse.
+
+	What
+	- Totals since program start: `allocations` (objects created through class constructors and
+	  other runtime object handles), `liveObjects` (those not yet dropped), `lockWaits` and
+	  `lockWaitSeconds` (object accesses that had to wait for another thread), and `throws`.
+	- `types`: the same counters per Rust type name, highest `live` first.
+	- `threads`: current queue depths of each live Haxe thread; `taskQueue` is the number of
+	  `rust.concurrent.Tasks` jobs waiting for a worker.
+**/
+typedef RuntimeStats = {
+	var allocations:Float;
+	var liveObjects:Float;
+	var lockWaits:Float;
+	var lockWaitSeconds:Float;
+	var throws:Float;
+	var taskQueue:Int;
+	var types:Array<RuntimeTypeStats>;
+	var threads:Array<RuntimeThreadStats>;
+}
+
+/** Counters of one Rust type (`name` is the generated Rust type path). **/
+typedef RuntimeTypeStats = {
+	var name:String;
+	var allocations:Float;
+	var live:Float;
+	var lockWaits:Float;
+}
+
+/**
+	Queue depths of one live thread: unread `Thread.sendMessage` values, pending one-time
+	`EventLoop` events, and repeating `EventLoop` events.
+**/
+typedef RuntimeThreadStats = {
+	var id:Int;
+	var messages:Int;
+	var events:Int;
+	var regularEvents:Int;
+}
@@ -0,0 +1,15 @@
+package rust;
+
+/**
+ * rust.U8
+ *
+ * Rust `u8` byte type intended for the `metal` profile, used as the element type of native byte
+ * buffers (`rust.Vec<rust.U8>` is Rust `Vec<u8>`).
+ *
+ * Notes:
+ * - This is an extern binding to the Rust primitive; it has no Haxe-side arithmetic. Typed facades
+ *   (for example `rust.net.TcpStream.read`) produce and consume these buffers so byte payloads
+ *   cross the boundary without widening every byte to `Int`.
+ */
+@:native("u8")
+extern class U8 {}
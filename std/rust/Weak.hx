@@ -0,0 +1,19 @@
+package rust;
+
+/**
+	rust.Weak<T>
+
+	Why
+	- Class instances are reference counted on this target, so a strong back-reference (child to
+	  parent, observer to subject) keeps a whole object graph alive after the program drops it.
+
+	What
+	- A non-owning reference: `new rust.Weak(obj)`, then `get()` returns `obj` while something else
+	  still owns it and `null` afterwards; `isAlive()` checks without producing a reference.
+	- `T` must be a class instance or interface type.
+
+	How
+	- Typedef alias to `hxrt.weak.WeakHandle<T>`, so the handle is the runtime value itself with no
+	  extra wrapper object. See `docs/hxref-lifecycle.md`.
+**/
+typedef Weak<T> = hxrt.weak.WeakHandle<T>;
@@ -147,6 +147,12 @@ class Sys {
 		return NativeSys.command(cmd, args);
 	}
 
+	/**
+	 * Exit the process with `code`.
+	 *
+	 * Handlers registered through `rust.process.ExitHandlers` run first (newest first), then
+	 * stdout/stderr are flushed, so buffered output is not lost.
+	 */
 	public static function exit(code:Int):Void {
 		NativeSys.exit(code);
 	}
@@ -0,0 +1,39 @@
+package haxe.atomic;
+
+import hxrt.atomic.AtomicBoolHandle;
+
+/**
+	`haxe.atomic.AtomicBool` (Rust target override)
+
+	Why
+	- Upstream wraps `AtomicInt`, which has no implementation for this target.
+
+	What
+	- Atomic boolean with the upstream API: `exchange` and `compareExchange` return the previous
+	  value; `load` returns the current value; `store` returns the stored value.
+
+	How
+	- Abstract over `hxrt::atomic::AtomicBoolHandle` (a shared `AtomicBool`) rather than an
+	  integer cell, so no `Int`/`Bool` conversion sits on the hot path.
+**/
+abstract AtomicBool(AtomicBoolHandle) {
+	public inline function new(value:Bool):Void {
+		this = new AtomicBoolHandle(value);
+	}
+
+	public inline function compareExchange(expected:Bool, replacement:Bool):Bool {
+		return this.compareExchange(expected, replacement);
+	}
+
+	public inline function exchange(value:Bool):Bool {
+		return this.exchange(value);
+	}
+
+	public inline function load():Bool {
+		return this.load();
+	}
+
+	public inline function store(value:Bool):Bool {
+		return this.store(value);
+	}
+}
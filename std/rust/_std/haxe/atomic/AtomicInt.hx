@@ -0,0 +1,62 @@
+package haxe.atomic;
+
+import hxrt.atomic.AtomicIntHandle;
+
+/**
+	`haxe.atomic.AtomicInt` (Rust target override)
+
+	Why
+	- Upstream declares a core-type abstract with no implementation for this target, so without an
+	  override `AtomicInt` only typechecks and never provides atomic updates across `sys.thread`
+	  workers.
+
+	What
+	- Atomic 32-bit integer with the upstream API and return values: `add`/`sub`/`and`/`or`/`xor`,
+	  `exchange`, and `compareExchange` return the previous value; `load` returns the current value;
+	  `store` returns the stored value. Arithmetic wraps on overflow.
+
+	How
+	- Abstract over `hxrt::atomic::AtomicIntHandle` (a shared `AtomicI32`); copies of an
+	  `AtomicInt` alias the same cell, matching reference semantics on other targets.
+**/
+abstract AtomicInt(AtomicIntHandle) {
+	public inline function new(value:Int):Void {
+		this = new AtomicIntHandle(value);
+	}
+
+	public inline function add(b:Int):Int {
+		return this.add(b);
+	}
+
+	public inline function sub(b:Int):Int {
+		return this.sub(b);
+	}
+
+	public inline function and(b:Int):Int {
+		return this.and(b);
+	}
+
+	public inline function or(b:Int):Int {
+		return this.or(b);
+	}
+
+	public inline function xor(b:Int):Int {
+		return this.xor(b);
+	}
+
+	public inline function compareExchange(expected:Int, replacement:Int):Int {
+		return this.compareExchange(expected, replacement);
+	}
+
+	public inline function exchange(value:Int):Int {
+		return this.exchange(value);
+	}
+
+	public inline function load():Int {
+		return this.load();
+	}
+
+	public inline function store(value:Int):Int {
+		return this.store(value);
+	}
+}
@@ -0,0 +1,43 @@
+package haxe.atomic;
+
+import hxrt.atomic.AtomicObjectHandle;
+
+/**
+	`haxe.atomic.AtomicObject` (Rust target override)
+
+	Why
+	- Upstream declares a core-type abstract with no implementation for this target.
+
+	What
+	- Atomic object reference with the upstream API: `exchange` and `compareExchange` return the
+	  previous object; `load` returns the current object; `store` returns the stored object.
+	- `compareExchange` compares by reference identity, as Haxe `==` does for objects.
+	- Limitation: `T` must be represented as a runtime object handle (class instance, interface,
+	  anonymous object); the same constraint `haxe.ds.ObjectMap` keys have.
+
+	How
+	- Abstract over `hxrt::atomic::AtomicObjectHandle<T>`, an `arc_swap` pointer slot: `load`,
+	  `store` and `exchange` are lock-free pointer swaps, and `compareExchange` checks identity and
+	  then publishes with a pointer compare-and-swap, retrying only when another thread won.
+**/
+abstract AtomicObject<T:{}>(AtomicObjectHandle<T>) {
+	public inline function new(value:T):Void {
+		this = new AtomicObjectHandle(value);
+	}
+
+	public inline function compareExchange(expected:T, replacement:T):T {
+		return this.compareExchange(expected, replacement);
+	}
+
+	public inline function exchange(value:T):T {
+		return this.exchange(value);
+	}
+
+	public inline function load():T {
+		return this.load();
+	}
+
+	public inline function store(value:T):T {
+		return this.store(value);
+	}
+}
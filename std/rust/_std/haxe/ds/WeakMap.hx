@@ -0,0 +1,76 @@
+package haxe.ds;
+
+import haxe.Constraints.IMap;
+import hxrt.weak.WeakMapHandle;
+
+/**
+	WeakMap (Rust target)
+
+	Why
+	- Upstream `haxe.ds.WeakMap` has no implementation for this target, and a plain `ObjectMap` would
+	  keep every key object alive for as long as the map exists.
+
+	What
+	- Identity-keyed map with the upstream `IMap` API. An entry disappears once its key object is no
+	  longer referenced anywhere else: `get`/`exists` stop finding it and iteration skips it.
+	- Keys must be class instances or interface values. Values are held strongly until their entry
+	  is pruned, and a value that references its own key keeps that key alive.
+
+	How
+	- Storage is `hxrt::weak::WeakMapHandle`, which keeps a weak handle per key and prunes dead
+	  entries during iteration and as the map grows.
+**/
+@:rustGeneric([
+	"K: hxrt::weak::HxWeakable + Clone + Send + Sync + 'static + std::fmt::Debug",
+	"V: Clone + Send + Sync + 'static + std::fmt::Debug"
+])
+class WeakMap<K:{}, V> implements IMap<K, V> {
+	var store:WeakMapHandle<K, V>;
+
+	public function new():Void {
+		store = new WeakMapHandle();
+	}
+
+	public function set(key:K, value:V):Void {
+		store.set(key, value);
+	}
+
+	@:rustReturn("Option<V>")
+	public function get(key:K):Null<V> {
+		return store.get(key);
+	}
+
+	public function exists(key:K):Bool {
+		return store.exists(key);
+	}
+
+	public function remove(key:K):Bool {
+		return store.remove(key);
+	}
+
+	public function keys():Iterator<K> {
+		return store.keys();
+	}
+
+	public function iterator():Iterator<V> {
+		return store.values();
+	}
+
+	public function keyValueIterator():KeyValueIterator<K, V> {
+		return store.keyValues();
+	}
+
+	public function copy():WeakMap<K, V> {
+		var out = new WeakMap<K, V>();
+		out.store = store.copy();
+		return out;
+	}
+
+	public function toString():String {
+		return store.debugString();
+	}
+
+	public function clear():Void {
+		store.clear();
+	}
+}
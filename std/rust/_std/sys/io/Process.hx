@@ -4,6 +4,8 @@ import haxe.io.Bytes;
 import hxrt.process.NativeProcess;
 import hxrt.process.ProcessHandle;
 import rust.HxRef;
+import sys.thread.EventLoop;
+import sys.thread.EventLoop.EventHandler;
 
 /**
 	`sys.io.Process` (Rust target implementation)
@@ -15,6 +17,7 @@ import rust.HxRef;
 	What
 	- Spawns a process immediately and exposes `stdin`, `stdout`, `stderr` streams.
 	- Supports `getPid()`, `exitCode(block)`, `kill()`, and `close()`.
+	- Rust-target extension: `watchStdout` / `watchStderr` register the pipes with an `EventLoop`.
 
 	How
 	- Uses a runtime handle `HxRef<hxrt.process.ProcessHandle>` backed by `hxrt::process::Process`
@@ -59,6 +62,28 @@ class Process {
 		return new ProcessStdin(handle);
 	}
 
+	/**
+		Call `callback` on `events`' thread whenever the child's stdout has data or has reached end
+		of file (Rust-target extension, Unix only).
+
+		Why: a loop that also serves timers or sockets should not block in `stdout.readBytes`.
+		What: a pipe at end of file stays ready, so cancel the watch with `events.cancel(handler)`
+		once a read reports EOF. Other platforms throw `HXRT-EVENTLOOP-IO`.
+		How: HXRT registers a duplicate of the pipe descriptor with the loop's `mio::Poll`.
+	**/
+	@:access(sys.thread.EventLoop)
+	public function watchStdout(events:EventLoop, callback:() -> Void):EventHandler {
+		var id:Int = NativeProcess.watchOutput(events.__threadId, handle, false, callback);
+		return id;
+	}
+
+	/** Like `watchStdout`, for the child's stderr pipe. **/
+	@:access(sys.thread.EventLoop)
+	public function watchStderr(events:EventLoop, callback:() -> Void):EventHandler {
+		var id:Int = NativeProcess.watchOutput(events.__threadId, handle, true, callback);
+		return id;
+	}
+
 	public function getPid():Int {
 		return NativeProcess.pid(handle);
 	}
@@ -1,5 +1,7 @@
 package sys.net;
 
+import hxrt.net.NativeSocket;
+
 /**
 	An address represents a port on a given host ip.
 
@@ -13,21 +15,31 @@ package sys.net;
 	- Supports comparison and cloning utilities used by some std/adaptor code.
 
 	How
-	- `getHost()` constructs a `sys.net.Host` without DNS by directly initializing `Host.ip`.
+	- `getHost()` constructs a `sys.net.Host` from the address text of `host`, without DNS.
+	- Like upstream, `host` is IPv4 only; `UdpSocket.readFrom` reports IPv6 senders as `0` and
+	  keeps their full address text in the private `address`, so `getHost()` and
+	  `UdpSocket.sendTo` still reach them. `address` is only consulted while `host` is `0`.
 **/
 class Address {
 	public var host:Int;
 	public var port:Int;
 
+	/**
+		Canonical text of an IPv6 sender recorded by `UdpSocket.readFrom`, or `null`.
+	**/
+	@:allow(sys.net)
+	var address:Null<String>;
+
 	public function new() {
 		host = 0;
 		port = 0;
+		address = null;
 	}
 
 	public function getHost():Host {
-		var h = new Host("127.0.0.1");
-		untyped h.ip = host;
-		return h;
+		if (host == 0 && address != null)
+			return new Host(address);
+		return new Host(NativeSocket.hostToString(host));
 	}
 
 	public function compare(a:Address):Int {
@@ -37,6 +49,13 @@ class Address {
 		var dp = a.port - port;
 		if (dp != 0)
 			return dp;
+		if (host == 0 && a.address != address) {
+			if (address == null)
+				return 1;
+			if (a.address == null)
+				return -1;
+			return a.address < address ? -1 : 1;
+		}
 		return 0;
 	}
 
@@ -44,6 +63,7 @@ class Address {
 		var c = new Address();
 		c.host = host;
 		c.port = port;
+		c.address = address;
 		return c;
 	}
 }
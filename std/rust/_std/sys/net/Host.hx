@@ -8,15 +8,19 @@ import hxrt.net.NativeSocket;
 	Why
 	- The upstream stdlib declares `sys.net.Host` as `extern`, so sys targets must provide a real
 	  implementation.
-	- Most `sys.net.*` APIs use `Host.ip:Int` as the canonical way to represent an IPv4 address.
+	- Most `sys.net.*` APIs use `Host.ip:Int` as the canonical way to represent an IPv4 address,
+	  which cannot hold an IPv6 address.
 
 	What
-	- Resolves a hostname to an IPv4 address (`ip:Int`).
-	- Converts the stored IP to a dotted-quad string.
+	- Resolves a hostname or IP literal to an IPv4 or IPv6 address. Names with both prefer IPv4.
+	- `ip:Int` holds the IPv4 address; it is `0` for IPv6 hosts.
+	- Converts the stored IP to a dotted-quad or IPv6 string.
 	- Supports reverse lookup and retrieving the local hostname.
 
 	How
 	- Resolution is performed by the Rust runtime (`hxrt::net`) using the platform DNS resolver.
+	- The full address is kept as its canonical text (`address`), which `sys.net.Socket` hands back
+	  to the runtime; IP literals are parsed without a DNS query.
 	- The `ip` integer is stored in network byte order (big-endian), like C `inet_addr`.
 	- Calls cross a typed native boundary (`hxrt.net.NativeSocket`) instead of inline
 	  `untyped __rust__` snippets.
@@ -28,20 +32,27 @@ class Host {
 	public var host(default, null):String;
 
 	/**
-		The resolved IPv4 address (network order / big-endian).
+		The resolved IPv4 address (network order / big-endian), or `0` for an IPv6 host.
 	**/
 	public var ip(default, null):Int;
 
+	/**
+		Canonical text of the resolved address (IPv4 or IPv6).
+	**/
+	@:allow(sys.net)
+	var address(default, null):String;
+
 	public function new(name:String):Void {
 		host = name;
-		ip = NativeSocket.hostResolve(name);
+		address = NativeSocket.hostResolve(name);
+		ip = NativeSocket.hostIpv4(address);
 	}
 
 	/**
-		Returns the dotted-quad IP representation of this host.
+		Returns the IP representation of this host: dotted quad for IPv4, RFC 5952 text for IPv6.
 	**/
 	public function toString():String {
-		return NativeSocket.hostToString(ip);
+		return address;
 	}
 
 	/**
@@ -50,7 +61,7 @@ class Host {
 		Note: this is best-effort; on failure it falls back to `toString()`.
 	**/
 	public function reverse():String {
-		return NativeSocket.hostReverse(ip);
+		return NativeSocket.hostReverse(address);
 	}
 
 	/**
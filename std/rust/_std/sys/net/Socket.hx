@@ -7,6 +7,8 @@ import rust.HxRef;
 import sys.net._SocketIO.SocketInput;
 import sys.net._SocketIO.SocketOutput;
 import sys.net.Types.SocketCustomValue;
+import sys.thread.EventLoop;
+import sys.thread.EventLoop.EventHandler;
 
 /**
 	`sys.net.Socket` (Rust target implementation)
@@ -16,15 +18,23 @@ import sys.net.Types.SocketCustomValue;
 	  TCP socket implementation.
 
 	What
-	- Implements basic TCP networking:
+	- Implements basic TCP networking over IPv4 and IPv6:
 	  - `connect`, `bind`, `listen`, `accept`
 	  - `peer`, `host`
 	  - `setTimeout`, `setBlocking`, `setFastSend`, `shutdown`
 	  - `select` (best-effort readiness polling)
+	  - `watch` (Rust-target extension): EventLoop readiness callbacks
+	  - `connectUnix`, `bindUnix`, `unixPath`, `unixPeerPath` (Rust-target extensions): Unix
+	    domain sockets on Unix platforms
+	  - `setReuseAddress`, `setReusePort`, `setKeepAlive`, `setLinger`, `setSendBufferSize`,
+	    `setReceiveBufferSize`, `setTtl` and matching getters (Rust-target extensions): socket
+	    options
 
 	How
 	- Socket state lives in the Rust runtime (`hxrt::net::SocketHandle`) and is referenced from Haxe
 	  via `HxRef<SocketHandle>`.
+	- Addresses are passed to the runtime as `Host` address text. Binding an IPv6 host is dual-stack:
+	  `new Host("::")` accepts IPv4 and IPv6 clients, and IPv4 peers report plain IPv4 hosts.
 	- `input`/`output` are exposed as properties to avoid storing Rust trait objects inside the
 	  generated struct (this keeps generated Rust simpler and warning-free).
 	- Rust-native operations are called through the typed `hxrt.net.NativeSocket` boundary so this
@@ -42,6 +52,7 @@ class Socket {
 	**/
 	public var custom:Null<SocketCustomValue> = null;
 
+	@:allow(sys.Http)
 	@:allow(sys.net.UdpSocket)
 	@:allow(sys.ssl.Socket)
 	private var handle:HxRef<SocketHandle>;
@@ -66,7 +77,44 @@ class Socket {
 	}
 
 	public function connect(host:Host, port:Int):Void {
-		NativeSocket.connect(handle, host.ip, port);
+		NativeSocket.connect(handle, host.address, port);
+	}
+
+	/**
+		Connect to the Unix domain socket at `path` (Rust-target extension).
+
+		Why: local IPC servers (Docker, databases, system daemons) listen on socket files, not ports.
+		What: the fresh socket becomes a Unix stream socket; `input`/`output`, `shutdown`, `select`
+		and `watch` work as for TCP, while `peer()`/`host()` throw because there is no IP address.
+		On `UdpSocket` it becomes a connected Unix datagram socket.
+		How: throws the String prefix `HXRT-NET-UNIX-UNSUPPORTED` on platforms without Unix sockets.
+	**/
+	public function connectUnix(path:String):Void {
+		NativeSocket.connectUnix(handle, path);
+	}
+
+	/**
+		Bind to a new Unix socket file at `path` (Rust-target extension), then `listen`/`accept`
+		as usual.
+
+		The file is not removed by `close`; delete a stale file before binding the same path again.
+	**/
+	public function bindUnix(path:String):Void {
+		NativeSocket.bindUnix(handle, path);
+	}
+
+	/**
+		Path this Unix socket is bound to, or `""` for an unnamed client end (Rust-target extension).
+	**/
+	public function unixPath():String {
+		return NativeSocket.unixPath(handle);
+	}
+
+	/**
+		Path of the connected peer, or `""` when the peer is unnamed (Rust-target extension).
+	**/
+	public function unixPeerPath():String {
+		return NativeSocket.unixPeerPath(handle);
 	}
 
 	public function listen(connections:Int):Void {
@@ -78,7 +126,7 @@ class Socket {
 	}
 
 	public function bind(host:Host, port:Int):Void {
-		NativeSocket.bind(handle, host.ip, port);
+		NativeSocket.bind(handle, host.address, port);
 	}
 
 	public function accept():Socket {
@@ -91,15 +139,11 @@ class Socket {
 	}
 
 	public function peer():{host:Host, port:Int} {
-		var host:Host = new Host("127.0.0.1");
-		untyped host.ip = NativeSocket.peerIp(handle);
-		return {host: host, port: NativeSocket.peerPort(handle)};
+		return {host: new Host(NativeSocket.peerIp(handle)), port: NativeSocket.peerPort(handle)};
 	}
 
 	public function host():{host:Host, port:Int} {
-		var host:Host = new Host("127.0.0.1");
-		untyped host.ip = NativeSocket.hostIp(handle);
-		return {host: host, port: NativeSocket.hostPort(handle)};
+		return {host: new Host(NativeSocket.hostIp(handle)), port: NativeSocket.hostPort(handle)};
 	}
 
 	public function setTimeout(timeout:Float):Void {
@@ -110,6 +154,25 @@ class Socket {
 		NativeSocket.waitForRead(handle);
 	}
 
+	/**
+		Call `callback` on `events`' thread whenever this socket is readable and/or writable
+		(Rust-target extension).
+
+		Why: reacting to socket data and timers on one `EventLoop` otherwise needs a busy loop around
+		`select` with a timeout.
+		What: `events.loop()` then blocks in a single OS readiness poll that also wakes for timers and
+		cross-thread `run` calls. The watch is level-style: while data stays unread, `callback` runs
+		again on the next pass. Cancel with `events.cancel(handler)`; closing the socket alone does not
+		remove the watch. Readiness is for the OS socket, so TLS data already buffered in the session
+		does not trigger it.
+		How: HXRT registers a clone of the OS handle with the loop's `mio::Poll`.
+	**/
+	@:access(sys.thread.EventLoop)
+	public function watch(events:EventLoop, readable:Bool, writable:Bool, callback:() -> Void):EventHandler {
+		var id:Int = NativeSocket.watch(events.__threadId, handle, readable, writable, callback);
+		return id;
+	}
+
 	public function setBlocking(b:Bool):Void {
 		NativeSocket.setBlocking(handle, b);
 	}
@@ -118,6 +181,88 @@ class Socket {
 		NativeSocket.setFastSend(handle, b);
 	}
 
+	/**
+		Socket options (Rust-target extensions).
+
+		Why: restarting a server on the same port, detecting dead peers and tuning throughput need
+		`SO_REUSEADDR`/`SO_REUSEPORT`, keepalive, `SO_LINGER`, buffer sizes and TTL, which the
+		portable Haxe API does not expose.
+		What: setters may be called before `bind`/`connect`; the values are applied as soon as the
+		OS socket exists (reuse flags before it binds). Getters report the OS value once the socket
+		is open, otherwise the value last set (or `false`/`0`). Listening TCP sockets already enable
+		`SO_REUSEADDR`. Accepted sockets inherit the listener's options.
+		How: invalid values throw the String prefix `HXRT-NET-OPTION`, as does enabling
+		`SO_REUSEPORT` on platforms without it; OS failures throw like other socket calls.
+	**/
+	public function setReuseAddress(b:Bool):Void {
+		NativeSocket.setReuseAddress(handle, b);
+	}
+
+	public function getReuseAddress():Bool {
+		return NativeSocket.reuseAddress(handle);
+	}
+
+	/** Let several sockets bind the same port (Unix only). **/
+	public function setReusePort(b:Bool):Void {
+		NativeSocket.setReusePort(handle, b);
+	}
+
+	public function getReusePort():Bool {
+		return NativeSocket.reusePort(handle);
+	}
+
+	/**
+		Enable or disable TCP keepalive probes. `idleSeconds` is the idle time before the first
+		probe and `intervalSeconds` the time between probes; values `<= 0` keep the OS defaults.
+	**/
+	public function setKeepAlive(enabled:Bool, idleSeconds:Float, intervalSeconds:Float):Void {
+		NativeSocket.setKeepAlive(handle, enabled, idleSeconds, intervalSeconds);
+	}
+
+	public function getKeepAlive():Bool {
+		return NativeSocket.keepAlive(handle);
+	}
+
+	/**
+		How long `close` waits to flush unsent data: `seconds < 0` disables lingering (the
+		default), `0` resets the connection on close.
+	**/
+	public function setLinger(seconds:Float):Void {
+		NativeSocket.setLinger(handle, seconds);
+	}
+
+	/** Linger time in seconds, or `-1` when lingering is off. **/
+	public function getLinger():Float {
+		return NativeSocket.linger(handle);
+	}
+
+	/** Request a send buffer of `bytes` (> 0); the OS may round or double it. **/
+	public function setSendBufferSize(bytes:Int):Void {
+		NativeSocket.setSendBufferSize(handle, bytes);
+	}
+
+	public function getSendBufferSize():Int {
+		return NativeSocket.sendBufferSize(handle);
+	}
+
+	/** Request a receive buffer of `bytes` (> 0); the OS may round or double it. **/
+	public function setReceiveBufferSize(bytes:Int):Void {
+		NativeSocket.setRecvBufferSize(handle, bytes);
+	}
+
+	public function getReceiveBufferSize():Int {
+		return NativeSocket.recvBufferSize(handle);
+	}
+
+	/** IP time-to-live (IPv6 hop limit) for outgoing packets, `1..255`. **/
+	public function setTtl(ttl:Int):Void {
+		NativeSocket.setTtl(handle, ttl);
+	}
+
+	public function getTtl():Int {
+		return NativeSocket.ttl(handle);
+	}
+
 	public static function select(read:Array<Socket>, write:Array<Socket>, others:Array<Socket>,
 			?timeout:Float):{read:Array<Socket>, write:Array<Socket>, others:Array<Socket>} {
 		var rh:Array<HxRef<SocketHandle>> = [for (s in read) s.handle];
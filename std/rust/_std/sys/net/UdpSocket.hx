@@ -19,6 +19,10 @@ import sys.net._SocketIO.SocketOutput;
 	  - `setBroadcast`
 	  - `sendTo`
 	  - `readFrom`
+	  - `sendToUnix` (Rust-target extension): datagrams to a Unix socket path
+	  - `joinMulticast`, `leaveMulticast`, `setMulticastTtl`, `setMulticastLoop` (Rust-target
+	    extensions): IPv4/IPv6 multicast group membership and options
+	- `readFrom` also records IPv6 senders in the `Address`, so `getHost()` and `sendTo` reach them.
 
 	How
 	- Reuses the base `sys.net.Socket` handle, but initializes it as UDP in `init()`.
@@ -33,10 +37,6 @@ class UdpSocket extends Socket {
 		output = new SocketOutput(h);
 	}
 
-	override public function bind(host:Host, port:Int):Void {
-		untyped __rust__("{0}.borrow_mut().bind({1} as i32, {2} as i32)", handle, host.ip, port);
-	}
-
 	public function setBroadcast(b:Bool):Void {
 		NativeSocket.udpSetBroadcast(handle, b);
 	}
@@ -47,9 +47,73 @@ class UdpSocket extends Socket {
 		if (len == 0)
 			return 0;
 
+		if (addr.host == 0 && addr.address != null)
+			return NativeSocket.udpSendToAddress(handle, buf, pos, len, addr.address, addr.port);
 		return NativeSocket.udpSendTo(handle, buf, pos, len, addr.host, addr.port);
 	}
 
+	/**
+		Join the multicast `group` (Rust-target extension). The socket must be bound, usually to
+		`new Host("0.0.0.0")` (or `"::"`) and the group's port.
+
+		IPv4 groups join on the local interface address `iface` (default: chosen by the OS).
+		IPv6 groups join on the interface numbered `interfaceIndex` (`0`: chosen by the OS).
+	**/
+	public function joinMulticast(group:Host, ?iface:Host, interfaceIndex:Int = 0):Void {
+		NativeSocket.udpJoinMulticast(handle, group.address, multicastInterface(iface), interfaceIndex);
+	}
+
+	/**
+		Leave a group joined with `joinMulticast`, using the same interface (Rust-target extension).
+	**/
+	public function leaveMulticast(group:Host, ?iface:Host, interfaceIndex:Int = 0):Void {
+		NativeSocket.udpLeaveMulticast(handle, group.address, multicastInterface(iface), interfaceIndex);
+	}
+
+	/**
+		Set how many router hops multicast datagrams may cross, `0..255` (Rust-target extension).
+
+		The default of `1` keeps them on the local subnet. IPv6 sockets set the multicast hop limit.
+	**/
+	public function setMulticastTtl(ttl:Int):Void {
+		NativeSocket.udpSetMulticastTtl(handle, ttl);
+	}
+
+	public function getMulticastTtl():Int {
+		return NativeSocket.udpMulticastTtl(handle);
+	}
+
+	/**
+		Whether group members on this host, including this socket, receive what it sends to a group
+		(Rust-target extension). On by default.
+	**/
+	public function setMulticastLoop(enabled:Bool):Void {
+		NativeSocket.udpSetMulticastLoop(handle, enabled);
+	}
+
+	public function getMulticastLoop():Bool {
+		return NativeSocket.udpMulticastLoop(handle);
+	}
+
+	static function multicastInterface(iface:Null<Host>):String {
+		return iface == null ? "0.0.0.0" : iface.address;
+	}
+
+	/**
+		Send one datagram to the Unix datagram socket bound at `path` (Rust-target extension).
+
+		A socket that was never bound sends from an unnamed address, so the receiver cannot reply;
+		call `bindUnix` first to receive answers. A bound receiver reads datagrams through `input`.
+	**/
+	public function sendToUnix(buf:Bytes, pos:Int, len:Int, path:String):Int {
+		if (pos < 0 || len < 0 || pos + len > buf.length)
+			throw Error.OutsideBounds;
+		if (len == 0)
+			return 0;
+
+		return NativeSocket.unixSendTo(handle, buf, pos, len, path);
+	}
+
 	public function readFrom(buf:Bytes, pos:Int, len:Int, addr:Address):Int {
 		if (pos < 0 || len < 0 || pos + len > buf.length)
 			throw Error.OutsideBounds;
@@ -61,6 +125,7 @@ class UdpSocket extends Socket {
 		if (out > 0) {
 			addr.host = readInfo[1];
 			addr.port = readInfo[2];
+			addr.address = addr.host == 0 ? NativeSocket.udpLastSenderIp(handle) : null;
 		}
 
 		if (out == 0)
@@ -33,6 +33,9 @@ enum NextEventTime {
 	- One-shot events (`run` / `runPromised`) executed on the owning thread.
 	- Promises (`promise`) to keep loops alive until events are delivered.
 	- Minimal repeating timers (`repeat` / `cancel`) based on seconds since program start.
+	- I/O readiness watches registered through `sys.net.Socket.watch` and
+	  `sys.io.Process.watchStdout` / `watchStderr` (Rust-target extensions). They share the
+	  `EventHandler` id space, so `cancel` removes them too, and keep `loop()` alive until cancelled.
 
 	How
 	- This is backed by the Rust runtime (`hxrt::thread`) per-thread state.
@@ -81,7 +84,7 @@ class EventLoop {
 	}
 
 	/**
-		Cancel future invocations of a repeating callback.
+		Cancel future invocations of a repeating callback or an I/O watch.
 
 		Why: cancellation must remain valid from inside the callback, including immediately before a
 		Haxe throw.
@@ -158,6 +161,9 @@ class EventLoop {
 		Why: a thread should block without polling when work is expected later.
 		What: returns false only when no work is expected; otherwise waits indefinitely or up to `timeout`.
 		How: HXRT uses the owning loop's condition variable and rechecks state in later progress passes.
+		Once the loop has I/O watches it blocks in one `mio::Poll` instead, which wakes for readiness,
+		timer deadlines, and cross-thread `run` / `repeat` / `cancel` calls, and queues ready watch
+		callbacks for the next `progress()`.
 	**/
 	public function wait(?timeout:Float):Bool {
 		return NativeThread.eventLoopWait(__threadId, timeout);
@@ -1,6 +1,8 @@
 package sys.thread;
 
-import haxe.Exception;
+import hxrt.thread.NativeThread;
+import hxrt.thread.ThreadPoolHandle;
+import rust.HxRef;
 
 /**
 	Thread pool with a constant amount of threads.
@@ -13,12 +15,13 @@ import haxe.Exception;
 	- `new(threadsCount)` creates a pool with `threadsCount` OS threads.
 	- `run(task)` enqueues `task` to be executed by an available worker.
 	- `shutdown()` prevents new tasks and causes workers to terminate after draining the queue.
+	- `rust.concurrent.ThreadPools.join(pool)` / `queueDepth(pool)` add a join and a backlog probe
+	  on the Rust target without widening this `@:coreApi` surface.
 
 	How
-	- Uses a shared `Deque<()->Void>` to distribute tasks.
-	- Each worker thread blocks on `queue.pop(true)` to avoid busy loops.
-	- Shutdown is implemented by enqueueing a special task that throws a private exception caught by
-	  the worker loop.
+	- Backed by `hxrt::thread::ThreadPoolHandle`: one deque per worker plus a global injector queue,
+	  with idle workers stealing from busy ones. Tasks submitted from a worker stay on its own deque.
+	- An exception escaping a task is reported as `HXRT-THREAD-UNCAUGHT`; the worker keeps running.
 **/
 @:coreApi
 class FixedThreadPool implements IThreadPool {
@@ -31,13 +34,11 @@ class FixedThreadPool implements IThreadPool {
 	/** Indicates if `shutdown` method of this pool has been called. */
 	public var isShutdown(get, never):Bool;
 
-	var _isShutdown = false;
-
 	function get_isShutdown():Bool
-		return _isShutdown;
+		return NativeThread.poolIsShutdown(__h);
 
-	final pool:Array<Worker>;
-	final queue = new Deque<() -> Void>();
+	@:allow(rust.concurrent)
+	final __h:HxRef<ThreadPoolHandle>;
 
 	/**
 		Create a new thread pool with `threadsCount` threads.
@@ -46,11 +47,7 @@ class FixedThreadPool implements IThreadPool {
 		if (threadsCount < 1)
 			throw new ThreadPoolException("FixedThreadPool needs threadsCount to be at least 1.");
 		this.threadsCount = threadsCount;
-		final p = new Array<Worker>();
-		for (_i in 0...threadsCount) {
-			p.push(new Worker(queue));
-		}
-		pool = p;
+		__h = NativeThread.poolNewFixed(threadsCount);
 	}
 
 	/**
@@ -59,9 +56,8 @@ class FixedThreadPool implements IThreadPool {
 		Throws an exception if the pool is shut down.
 	**/
 	public function run(task:() -> Void):Void {
-		if (_isShutdown)
+		if (!NativeThread.poolRun(__h, task))
 			throw new ThreadPoolException("Task is rejected. Thread pool is shut down.");
-		queue.add(task);
 	}
 
 	/**
@@ -71,36 +67,6 @@ class FixedThreadPool implements IThreadPool {
 		Multiple calls to this method have no effect.
 	**/
 	public function shutdown():Void {
-		if (_isShutdown)
-			return;
-		_isShutdown = true;
-		for (_ in pool) {
-			queue.add(() -> shutdownTask());
-		}
-	}
-
-	static function shutdownTask():Void {
-		throw new ShutdownException("");
-	}
-}
-
-private class ShutdownException extends Exception {}
-
-private class Worker {
-	final queue:Deque<() -> Void>;
-
-	public function new(queue:Deque<() -> Void>) {
-		this.queue = queue;
-		Thread.create(() -> Worker.loop(queue));
-	}
-
-	static function loop(queue:Deque<() -> Void>) {
-		try {
-			while (true) {
-				var task = queue.pop(true);
-				if (task != null)
-					task();
-			}
-		} catch (_:ShutdownException) {}
+		NativeThread.poolShutdown(__h);
 	}
 }
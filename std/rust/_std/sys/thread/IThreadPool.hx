@@ -13,7 +13,7 @@ package sys.thread;
 	- `threadsCount` exposes the number of alive threads.
 
 	How
-	- Implementations are written in pure Haxe and rely on `sys.thread.Thread` + `sys.thread.Deque`.
+	- Both implementations are thin Haxe wrappers over the `hxrt::thread` work-stealing pool.
 **/
 interface IThreadPool {
 	/** Amount of alive threads in this pool. */
@@ -2,6 +2,7 @@ package sys.thread;
 
 import hxrt.thread.NativeThread;
 import sys.thread.Types.ThreadMessage;
+import sys.thread.Types.ThreadOptions;
 
 /**
 	OS thread API for the Rust target.
@@ -16,6 +17,8 @@ import sys.thread.Types.ThreadMessage;
 	- `sendMessage` / `readMessage` implement per-thread message queues with boundary payloads
 	  (`ThreadMessage`).
 	- `events` exposes a per-thread `EventLoop` compatible with `haxe.EntryPoint` expectations.
+	- Rust-target extensions: `createWithOptions` (thread name, stack size, joinable), `join()`, and
+	  `isAlive()`.
 
 	How
 	- Each thread has a small integer id stored in Rust thread-local storage.
@@ -25,9 +28,9 @@ import sys.thread.Types.ThreadMessage;
 	  beginner-friendly Haxe code (no raw `__rust__` snippets in method bodies).
 	- A spawned thread owns its runtime registration through an unwind-safe scope. Normal return,
 	  an uncaught Haxe throw, or a Rust panic removes that registration before the thread ends.
-	- Because this API has no join/result channel, an uncaught Haxe exception terminates only the child
-	  and writes a best-effort diagnostic beginning with `HXRT-THREAD-UNCAUGHT`. Its exact payload prose
-	  is not a compatibility contract.
+	- `join()` has no result channel either, so an uncaught Haxe exception terminates only the child
+	  and writes a best-effort diagnostic beginning with `HXRT-THREAD-UNCAUGHT` (naming the thread when it has a name).
+	  Its exact payload prose is not a compatibility contract.
 **/
 class Thread {
 	final __id:Int;
@@ -61,6 +64,30 @@ class Thread {
 		NativeThread.sendMessage(__id, msg);
 	}
 
+	/**
+		Wait for this thread to finish (Rust-target extension).
+
+		Why: waiting for a worker otherwise needs a hand-built `Lock` handshake.
+		What: only threads created with `createWithOptions` (unless `joinable: false`) can be joined,
+		exactly once; otherwise this throws `HXRT-THREAD-NOT-JOINABLE`. Joining the current thread throws
+		`HXRT-THREAD-SELF-JOIN`. An uncaught Haxe exception in the thread is reported by the thread and
+		`join` returns normally; a Rust panic throws `HXRT-THREAD-PANICKED`.
+		How: HXRT keeps the thread's Rust `JoinHandle` in its thread registry until it is joined.
+	**/
+	public function join():Void {
+		NativeThread.join(__id);
+	}
+
+	/**
+		Whether this thread is still running its job (or its EventLoop) (Rust-target extension).
+
+		The main thread is always alive. Once this returns `false`, `sendMessage` throws
+		`HXRT-THREAD-NOT-ALIVE`.
+	**/
+	public function isAlive():Bool {
+		return NativeThread.isAlive(__id);
+	}
+
 	/** Return a typed wrapper for the current runtime thread id. **/
 	public static function current():Thread {
 		var id:Int = NativeThread.currentId();
@@ -81,6 +108,25 @@ class Thread {
 		return new Thread(id);
 	}
 
+	/**
+		Spawn an OS thread for `job` with Rust-target options (Rust-target extension).
+
+		Why: unnamed threads make debugger and profiler output hard to read, and some workloads need a
+		larger stack or a way to wait for completion.
+		What: see `ThreadOptions`. Omitted fields keep the `create` defaults, except that the thread is
+		joinable unless `joinable: false` is passed. Invalid options (a name containing NUL, a negative
+		stack size) throw `HXRT-THREAD-OPTIONS`.
+		How: HXRT configures a `std::thread::Builder` and uses the same registration scope as `create`.
+	**/
+	public static function createWithOptions(job:() -> Void, options:ThreadOptions):Thread {
+		var name:Null<String> = options.name;
+		var stackSize:Int = options.stackSize == null ? 0 : (options.stackSize : Int);
+		var joinable:Bool = options.joinable != false;
+		var withEventLoop:Bool = options.withEventLoop == true;
+		var id:Int = NativeThread.spawnWithOptions(job, name, stackSize, joinable, withEventLoop);
+		return new Thread(id);
+	}
+
 	/**
 		Run `job` and then drain the current thread's event loop.
 
@@ -11,9 +11,29 @@ import haxe.BoundaryTypes.ThreadMessageBoundaryValue;
 
 	What
 	- `ThreadMessage`: payload type used by `Thread.sendMessage` and `Thread.readMessage`.
+	- `ThreadOptions`: Rust-target settings accepted by `Thread.createWithOptions`.
 
 	How
 	- This alias delegates to `haxe.BoundaryTypes.ThreadMessageBoundaryValue` at the upstream API boundary.
 	- Callers should decode to concrete types immediately after reading messages.
 **/
 typedef ThreadMessage = ThreadMessageBoundaryValue;
+
+/**
+	Options for `Thread.createWithOptions` (Rust-target extension).
+
+	- `name`: OS thread name shown by debuggers, `perf`, panic messages, and `HXRT-THREAD-UNCAUGHT`
+	  diagnostics. Threads are unnamed by default.
+	- `stackSize`: stack size in bytes. Defaults to the platform default.
+	- `joinable`: keep the thread joinable with `Thread.join()`. A joinable thread must be joined
+	  exactly once to release its runtime handle. Defaults to `true`; pass `false` for a detached
+	  fire-and-forget thread.
+	- `withEventLoop`: run the thread's EventLoop after `job`, like `Thread.createWithEventLoop`.
+	  Defaults to `false`.
+**/
+typedef ThreadOptions = {
+	?name:String,
+	?stackSize:Int,
+	?joinable:Bool,
+	?withEventLoop:Bool
+}
@@ -48,8 +48,8 @@ extern class Async {
 	 *
 	 * What:
 	 * - `blockOn(...)` is the canonical sync -> async boundary helper.
-	 * - The implemented preview entry pattern today is: sync `main()`, async helper returns `Future<T>`,
-	 *   `Async.blockOn(...)` bridges the two.
+	 * - Entry points can either stay sync (`main()` + async helper + `Async.blockOn(...)`) or be a Void
+	 *   `@:async static function main()`, which the compiler drives through `hxrt::async_::run_main`.
 	 *
 	 * How:
 	 * - Lowered to `hxrt::async_::block_on`.
@@ -0,0 +1,30 @@
+package rust.async;
+
+/**
+ * rust.async.Stream<T>
+ *
+ * Why:
+ * - Log tailing, paginated APIs and other incremental sources produce async values one at a time.
+ * - Collecting them into arrays first loses backpressure and delays the first item.
+ *
+ * What:
+ * - `Stream<T>` is the Rust-first async sequence type.
+ * - It is opaque at the Haxe level: build and transform it with `rust.async.Streams`, then consume it
+ *   with `for (item in stream)` inside an `@:async` function or with `Streams.collect(...)`.
+ *
+ * How:
+ * - This extern maps to `hxrt::async_::HxStream<T>` (a boxed, pinned `futures::Stream`).
+ * - Streams are single-consumer: combinators and `for` iteration take ownership of the value.
+ * - Part of the experimental `rust_async` preview; cancellation-on-drop of upstream work is not promised.
+ */
+@:native("hxrt::async_::HxStream")
+extern class Stream<T> {
+	/**
+	 * Typing hook for `for (item in stream)`.
+	 *
+	 * Compiler note:
+	 * - The loop is lowered to awaited `hxrt::async_::stream_next` steps and is only valid inside
+	 *   `@:async` / `@:rustAsync` functions. This method is never emitted as a Rust call.
+	 */
+	public function iterator():Iterator<T>;
+}
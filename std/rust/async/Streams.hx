@@ -0,0 +1,61 @@
+package rust.async;
+
+import rust.Option;
+
+/**
+	rust.async.Streams
+
+	Why
+	- `Stream<T>` is an opaque runtime value; producers and combinators live in this namespace so
+	  call sites stay typed without emitting wrapper structs.
+
+	What
+	- Producers: `fromArray(...)`, `generate(...)`.
+	- Combinators: `map(...)`, `filter(...)`, `take(...)`, `buffered(...)`.
+	- Consumers: `collect(...)` (or `for (item in stream)` inside async functions).
+
+	How
+	- Extern binding to `hxrt::async_` stream helpers; `using rust.async.Streams;` enables chaining
+	  such as `stream.filter(...).map(...).take(10)`.
+	- Callbacks run synchronously on the task polling the stream; async per-item work should map to
+	  `Future<T>` values and go through `buffered(...)`.
+	- Experimental `rust_async` preview surface.
+**/
+@:native("hxrt::async_")
+extern class Streams {
+	/**
+		Yield the elements of `values` in order (the array is snapshotted at creation).
+	**/
+	@:native("stream_from_array")
+	public static function fromArray<T>(values:Array<T>):Stream<T>;
+
+	/**
+		Await `next()` repeatedly and yield each `Some(value)`; the stream ends at the first `None`.
+	**/
+	@:native("stream_generate")
+	public static function generate<T>(next:() -> Future<Option<T>>):Stream<T>;
+
+	@:native("stream_map")
+	public static function map<T, U>(stream:Stream<T>, callback:(T) -> U):Stream<U>;
+
+	@:native("stream_filter")
+	public static function filter<T>(stream:Stream<T>, predicate:(T) -> Bool):Stream<T>;
+
+	/**
+		Yield at most `count` items; negative counts behave like `0`.
+	**/
+	@:native("stream_take")
+	public static function take<T>(stream:Stream<T>, count:Int):Stream<T>;
+
+	/**
+		Run up to `limit` futures concurrently while yielding results in source order.
+	**/
+	@:native("stream_buffered")
+	public static function buffered<T>(stream:Stream<Future<T>>, limit:Int):Stream<T>;
+
+	/**
+		Drain the stream into an array.
+	**/
+	@:native("stream_collect")
+	public static function collect<T>(stream:Stream<T>):Future<Array<T>>;
+}
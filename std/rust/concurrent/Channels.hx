@@ -1,5 +1,6 @@
 package rust.concurrent;
 
+import rust.Duration;
 import rust.HxRef;
 import rust.Option;
 import rust.Ref;
@@ -12,7 +13,12 @@ import rust.Ref;
 	  callsites typed while avoiding generated wrapper structs.
 
 	What
-	- `create()`, `send(...)`, `recv(...)`, `tryRecv(...)`.
+	- `create()` (unbounded) and `bounded(capacity)` constructors.
+	- `send` / `trySend` / `sendTimeout`, `recv` / `tryRecv` / `recvTimeout`.
+	- `close` / `isClosed`: sends on a closed channel throw; receivers drain buffered values first and
+	  then throw. Both failures use the catchable `HXRT-CHANNEL-CLOSED` String prefix.
+	- `select(channels)` / `selectTimeout(channels, timeout)`: index of the first channel holding a
+	  value or closed (`-1` on timeout). Selecting does not consume; follow up with `tryRecv`.
 
 	How
 	- This is an extern binding to `hxrt::concurrent`; no wrapper class is emitted.
@@ -22,12 +28,57 @@ extern class Channels {
 	@:native("channel_new")
 	public static function create<T>():HxRef<Channel<T>>;
 
+	/**
+		Create a channel holding at most `capacity` values (minimum `1`); `send` blocks while full.
+	**/
+	@:native("channel_bounded")
+	public static function bounded<T>(capacity:Int):HxRef<Channel<T>>;
+
 	@:native("channel_send")
 	public static function send<T>(channel:Ref<HxRef<Channel<T>>>, value:T):Void;
 
+	/**
+		Send without blocking. Returns `false` (dropping `value`) when a bounded channel is full.
+	**/
+	@:native("channel_try_send")
+	public static function trySend<T>(channel:Ref<HxRef<Channel<T>>>, value:T):Bool;
+
+	/**
+		Send, waiting at most `timeout` for space. Returns `false` (dropping `value`) on timeout.
+	**/
+	@:native("channel_send_timeout")
+	public static function sendTimeout<T>(channel:Ref<HxRef<Channel<T>>>, value:T, timeout:Duration):Bool;
+
 	@:native("channel_recv")
 	public static function recv<T>(channel:Ref<HxRef<Channel<T>>>):T;
 
 	@:native("channel_try_recv")
 	public static function tryRecv<T>(channel:Ref<HxRef<Channel<T>>>):Option<T>;
+
+	/**
+		Receive, waiting at most `timeout`. Returns `None` on timeout.
+	**/
+	@:native("channel_recv_timeout")
+	public static function recvTimeout<T>(channel:Ref<HxRef<Channel<T>>>, timeout:Duration):Option<T>;
+
+	@:native("channel_close")
+	public static function close<T>(channel:Ref<HxRef<Channel<T>>>):Void;
+
+	@:native("channel_is_closed")
+	public static function isClosed<T>(channel:Ref<HxRef<Channel<T>>>):Bool;
+
+	@:native("channel_len")
+	public static function length<T>(channel:Ref<HxRef<Channel<T>>>):Int;
+
+	/**
+		Block until one of `channels` holds a value or is closed; returns its index.
+	**/
+	@:native("channel_select")
+	public static function select<T>(channels:Ref<Array<HxRef<Channel<T>>>>):Int;
+
+	/**
+		Like `select`, but returns `-1` when nothing becomes ready within `timeout`.
+	**/
+	@:native("channel_select_timeout")
+	public static function selectTimeout<T>(channels:Ref<Array<HxRef<Channel<T>>>>, timeout:Duration):Int;
 }
@@ -0,0 +1,46 @@
+package rust.concurrent;
+
+import rust.Ref;
+
+/**
+	rust.concurrent.Parallel
+
+	Why
+	- CPU-bound batch work over arrays otherwise needs hand-written chunking across
+	  `Thread.create` calls plus bespoke result collection and error forwarding.
+
+	What
+	- `map`, `forEach`, `filter`, `reduce`, and `sort` over `Array<T>`, run on the process-wide
+	  work-stealing pool that also backs `rust.concurrent.Tasks`.
+	- Results keep source order (`map`, `filter`), and `sort` is stable and sorts in place.
+	- `reduce` requires an associative `f`; `initial` is folded in exactly once.
+	- If any callback throws, the caller receives the exception of the earliest throwing element in
+	  array order, unchanged. Chunks after it stop early; `forEach` side effects already performed
+	  are not rolled back.
+
+	How
+	- This is an extern binding to `hxrt::concurrent`; no wrapper class is emitted.
+	- The array is split into at most 64 chunks through the runtime slice-borrow path. The chunk
+	  layout depends only on the array length, so results do not vary with the host's core count.
+	- The calling thread works through chunks alongside pool workers, so nested parallel calls
+	  (including from inside a task) cannot starve.
+	- Callbacks run on other threads: they must not assume thread-local state and must protect
+	  shared mutable state (for example with `Mutexes` or `haxe.atomic.*`).
+**/
+@:native("hxrt::concurrent")
+extern class Parallel {
+	@:native("parallel_map")
+	public static function map<T, U>(array:Ref<Array<T>>, f:(T) -> U):Array<U>;
+
+	@:native("parallel_for_each")
+	public static function forEach<T>(array:Ref<Array<T>>, f:(T) -> Void):Void;
+
+	@:native("parallel_filter")
+	public static function filter<T>(array:Ref<Array<T>>, f:(T) -> Bool):Array<T>;
+
+	@:native("parallel_reduce")
+	public static function reduce<T>(array:Ref<Array<T>>, f:(T, T) -> T, initial:T):T;
+
+	@:native("parallel_sort")
+	public static function sort<T>(array:Ref<Array<T>>, compare:(T, T) -> Int):Void;
+}
@@ -12,6 +12,12 @@ import rust.Ref;
 
 	What
 	- Factory namespace for spawning typed tasks.
+	- Tasks run on a process-wide elastic work-stealing pool (the one behind
+	  `sys.thread.ElasticThreadPool`), not one OS thread per task.
+	- At most 512 tasks run at once; later ones queue. The first time every worker is busy the
+	  runtime prints `HXRT-TASK-POOL-SATURATED` to stderr, since a running task joining a queued one
+	  can then deadlock.
+	- `join` returns the result once; a Haxe exception thrown by the job is rethrown by `join`.
 
 	How
 	- This is an extern binding to `hxrt::concurrent`; no wrapper class is emitted.
@@ -0,0 +1,51 @@
+package rust.concurrent;
+
+import hxrt.thread.NativeThread;
+import hxrt.thread.ThreadPoolHandle;
+import rust.HxRef;
+import sys.thread.ElasticThreadPool;
+import sys.thread.FixedThreadPool;
+import sys.thread.ThreadPoolException;
+
+/**
+	rust.concurrent.ThreadPools
+
+	Why
+	- `sys.thread.FixedThreadPool` / `ElasticThreadPool` are `@:coreApi` classes, so they keep the
+	  upstream surface. Waiting for workers to exit and watching the backlog are still useful on the
+	  Rust target, where both pools share one native work-stealing implementation.
+
+	What
+	- `join(pool)` blocks until every worker of a shut-down pool has exited. It throws a
+	  `ThreadPoolException` when the pool is still running, since its workers never exit, and
+	  `HXRT-THREAD-POOL-SELF-JOIN` when called from one of the pool's own workers.
+	- `queueDepth(pool)` reports tasks submitted but not yet picked up by a worker.
+
+	How
+	- Each function has a `FixedThreadPool` and an `ElasticThreadPool` overload; both are
+	  `extern inline`, so the call lowers straight to the `hxrt::thread` pool binding on the pool's
+	  handle.
+**/
+class ThreadPools {
+	overload extern inline public static function join(pool:FixedThreadPool):Void {
+		joinHandle(pool.__h, pool.isShutdown);
+	}
+
+	overload extern inline public static function join(pool:ElasticThreadPool):Void {
+		joinHandle(pool.__h, pool.isShutdown);
+	}
+
+	overload extern inline public static function queueDepth(pool:FixedThreadPool):Int {
+		return NativeThread.poolQueueDepth(pool.__h);
+	}
+
+	overload extern inline public static function queueDepth(pool:ElasticThreadPool):Int {
+		return NativeThread.poolQueueDepth(pool.__h);
+	}
+
+	static function joinHandle(handle:HxRef<ThreadPoolHandle>, isShutdown:Bool):Void {
+		if (!isShutdown)
+			throw new ThreadPoolException("join() requires shutdown() first.");
+		NativeThread.poolJoin(handle);
+	}
+}
@@ -0,0 +1,21 @@
+package rust.http;
+
+/**
+	rust.http.Request
+
+	Why
+	- Handlers need the method, path, headers and body of one request, already parsed and framed.
+
+	What
+	- `method()`, `path()` (percent-encoded, without the query), `query()` (raw text after `?`, or
+	  `null`), `target()` (as sent) and `version()`.
+	- `header(name)` is case-insensitive and joins repeated values with `", "`; `headerValues(name)`
+	  keeps them apart and `headerNames()` lists them lower-case.
+	- `body()` / `bodyString()` return the decoded body (chunked bodies are already joined).
+	- `remoteHost()` / `remotePort()` identify the peer.
+
+	How
+	- Typedef alias to `hxrt.http.HttpRequestHandle`, so the request is the runtime value itself
+	  with no extra wrapper object.
+**/
+typedef Request = hxrt.http.HttpRequestHandle;
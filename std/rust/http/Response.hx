@@ -0,0 +1,74 @@
+package rust.http;
+
+import haxe.io.Bytes;
+import hxrt.http.HttpResponseHandle;
+import hxrt.http.NativeHttpServer;
+
+/**
+	rust.http.Response
+
+	Why
+	- Handlers return one value describing the answer; the server takes care of framing it.
+
+	What
+	- `new Response(?status)` starts an empty response (default `200`); `Response.text(...)` and
+	  `Response.bytes(...)` build the common cases.
+	- Fluent setters: `setStatus`, `header` (replace), `addHeader` (append), `body`, `bodyString`.
+	- `Content-Length`, `Transfer-Encoding` and `Connection` are written by the server; a
+	  `Connection: close` header closes the connection after this response.
+	- Invalid statuses and header text throw `HXRT-HTTP-RESPONSE`.
+
+	How
+	- Wraps a runtime `hxrt.http.HttpResponseHandle`, which the server reads after the handler
+	  returns.
+**/
+class Response {
+	public final handle:HttpResponseHandle;
+
+	public var status(get, never):Int;
+
+	public function new(?status:Int) {
+		handle = NativeHttpServer.response(status == null ? 200 : status);
+	}
+
+	public static function text(body:String, ?status:Int, ?contentType:String):Response {
+		return new Response(status).header("Content-Type", contentType == null ? "text/plain; charset=utf-8" : contentType).bodyString(body);
+	}
+
+	public static function bytes(body:Bytes, ?status:Int, ?contentType:String):Response {
+		return new Response(status).header("Content-Type", contentType == null ? "application/octet-stream" : contentType).body(body);
+	}
+
+	function get_status():Int {
+		return handle.status();
+	}
+
+	public function setStatus(status:Int):Response {
+		handle.setStatus(status);
+		return this;
+	}
+
+	public function header(name:String, value:String):Response {
+		handle.setHeader(name, value);
+		return this;
+	}
+
+	public function addHeader(name:String, value:String):Response {
+		handle.addHeader(name, value);
+		return this;
+	}
+
+	public function getHeader(name:String):Null<String> {
+		return handle.header(name);
+	}
+
+	public function body(body:Bytes):Response {
+		handle.setBody(body);
+		return this;
+	}
+
+	public function bodyString(text:String):Response {
+		handle.setBodyString(text);
+		return this;
+	}
+}
@@ -0,0 +1,88 @@
+package rust.http;
+
+/**
+	rust.http.Router
+
+	Why
+	- Most embedded servers dispatch on method and path; without a router every handler starts with
+	  the same `switch`.
+
+	What
+	- `route(method, pattern, handler)` (and `get` / `post` / `put` / `delete`) registers a handler.
+	  A pattern matches the request path exactly, or as a prefix when it ends in `*`
+	  (`"/static/*"`). Routes are tried in registration order.
+	- `HEAD` requests fall back to `GET` routes (the server drops the body).
+	- No path match answers 404; a path match with no method match answers 405 with `Allow`.
+	- `handle(request)` is the dispatch function: `server.serve(router.handle)`.
+
+	How
+	- Plain Haxe over `Request.path()`; register routes before serving, since the route list is read
+	  concurrently by connection threads.
+**/
+class Router {
+	final routes:Array<Route> = [];
+
+	public function new() {}
+
+	public function route(method:String, pattern:String, handler:Request->Response):Router {
+		routes.push({method: method, pattern: pattern, handler: handler});
+		return this;
+	}
+
+	public inline function get(pattern:String, handler:Request->Response):Router {
+		return route("GET", pattern, handler);
+	}
+
+	public inline function post(pattern:String, handler:Request->Response):Router {
+		return route("POST", pattern, handler);
+	}
+
+	public inline function put(pattern:String, handler:Request->Response):Router {
+		return route("PUT", pattern, handler);
+	}
+
+	public inline function delete(pattern:String, handler:Request->Response):Router {
+		return route("DELETE", pattern, handler);
+	}
+
+	public function handle(request:Request):Response {
+		var method = request.method();
+		var path = request.path();
+		var allowed:Array<String> = [];
+		var fallback:Null<Route> = null;
+		for (route in routes) {
+			if (!matches(route.pattern, path)) {
+				continue;
+			}
+			if (route.method == method) {
+				return route.handler(request);
+			}
+			if (method == "HEAD" && route.method == "GET" && fallback == null) {
+				fallback = route;
+			}
+			if (!allowed.contains(route.method)) {
+				allowed.push(route.method);
+			}
+		}
+		if (fallback != null) {
+			return fallback.handler(request);
+		}
+		if (allowed.length == 0) {
+			return Response.text("Not Found", 404);
+		}
+		return Response.text("Method Not Allowed", 405).header("Allow", allowed.join(", "));
+	}
+
+	static function matches(pattern:String, path:String):Bool {
+		if (StringTools.endsWith(pattern, "*")) {
+			return StringTools.startsWith(path, pattern.substr(0, pattern.length - 1));
+		}
+		return pattern == path;
+	}
+}
+
+private typedef Route = {
+	final method:String;
+	final pattern:String;
+	final handler:Request->Response;
+}
@@ -0,0 +1,89 @@
+package rust.http;
+
+import hxrt.http.HttpServerHandle;
+import hxrt.http.NativeHttpServer;
+
+/**
+	rust.http.Server
+
+	Why
+	- `sys.Http` is client-only, so admin and health endpoints otherwise re-implement HTTP parsing
+	  on `sys.net.Socket`.
+
+	What
+	- `Server.bind(host, port)` listens (port `0` picks a free one, see `port`). `serve(handler)`
+	  blocks the calling thread until `stop()`; `start(handler)` accepts on a background thread and
+	  returns. A server serves once: `serve` / `start` after `stop` throws `HXRT-HTTP-SERVER`.
+	- The handler gets each `Request` and returns a `Response`; pass `Router.handle` to dispatch by
+	  method and path. It runs on the connection's worker thread, so handlers for different
+	  connections run concurrently. An exception thrown by the handler, or a 1xx response, answers 500.
+	- HTTP/1.1 with keep-alive, `Content-Length` and chunked request bodies, and
+	  `Expect: 100-continue`. Malformed requests get 400/505, oversized ones 413/431, and the
+	  handler is not called.
+	- Limits: `setMaxBodySize` (default 8 MiB), `setKeepAliveTimeout` (default 5 s, `0` disables
+	  keep-alive), `setReadTimeout` (default 30 s, stalled requests get 408) and
+	  `setMaxConnections` (default 256, extra connections get 503).
+
+	How
+	- Referencing `rust.http.*` enables the hxrt `http_server` feature. Sockets come from
+	  `hxrt::net`, and each connection runs on its own registered runtime thread, so
+	  `sys.thread.Thread.current()` works inside handlers.
+**/
+class Server {
+	public final handle:HttpServerHandle;
+
+	public var port(get, never):Int;
+	public var host(get, never):String;
+
+	function new(handle:HttpServerHandle) {
+		this.handle = handle;
+	}
+
+	public static function bind(host:String, port:Int):Server {
+		return new Server(NativeHttpServer.bind(host, port));
+	}
+
+	function get_port():Int {
+		return handle.port();
+	}
+
+	function get_host():String {
+		return handle.host();
+	}
+
+	public function setMaxBodySize(bytes:Int):Server {
+		handle.setMaxBodySize(bytes);
+		return this;
+	}
+
+	public function setKeepAliveTimeout(seconds:Float):Server {
+		handle.setKeepAliveTimeout(seconds);
+		return this;
+	}
+
+	public function setReadTimeout(seconds:Float):Server {
+		handle.setReadTimeout(seconds);
+		return this;
+	}
+
+	public function setMaxConnections(connections:Int):Server {
+		handle.setMaxConnections(connections);
+		return this;
+	}
+
+	public function isRunning():Bool {
+		return handle.isRunning();
+	}
+
+	public function serve(handler:Request->Response):Void {
+		handle.serve(request -> handler(request).handle);
+	}
+
+	public function start(handler:Request->Response):Void {
+		handle.start(request -> handler(request).handle);
+	}
+
+	public function stop():Void {
+		handle.stop();
+	}
+}
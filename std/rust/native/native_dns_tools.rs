@@ -0,0 +1,39 @@
+/// `native_dns_tools`
+///
+/// Typed helper module backing `rust.net.Dns`.
+///
+/// Host-name resolution is the one address operation that cannot be compiler-lowered: it calls the
+/// platform resolver through `std::net::ToSocketAddrs`. Keeping it in its own helper lets
+/// `native_socket_addr_tools` stay a conversion-only wrapper while metal code still reaches real
+/// hosts without portable `sys.net`.
+///
+/// What this helper must not become:
+/// - A caching resolver, async lookup runtime, or record-type (MX/TXT/SRV) client.
+/// - Portable `sys.net.Host` compatibility.
+use std::net::ToSocketAddrs;
+
+use crate::native_socket_addr_tools::SocketAddr;
+use crate::native_socket_error_tools::SocketError;
+
+#[derive(Debug)]
+pub struct Dns;
+
+impl Dns {
+    pub fn resolve(host: String, port: i32) -> Result<Vec<SocketAddr>, SocketError> {
+        let port = u16::try_from(port).map_err(|_| {
+            SocketError::invalid_input(format!("socket port out of range: {}", port))
+        })?;
+        let addrs: Vec<SocketAddr> = (host.as_str(), port)
+            .to_socket_addrs()
+            .map_err(|err| SocketError::resolve(format!("failed to resolve {}: {}", host, err)))?
+            .map(SocketAddr::from_std)
+            .collect();
+        if addrs.is_empty() {
+            return Err(SocketError::resolve(format!(
+                "no addresses found for {}",
+                host
+            )));
+        }
+        Ok(addrs)
+    }
+}
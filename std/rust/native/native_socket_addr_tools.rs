@@ -12,13 +12,13 @@
 /// Why this helper exists today:
 /// - Haxe externs can name the public typed surface, but they cannot declare this private wrapper
 ///   field plus crate-private conversions into the TCP/UDP helper modules.
-/// - `SocketAddr.localhost*` and `SocketAddr.port()` are compiler-lowered; this helper is retained
-///   only for representation privacy and resource-helper handoff.
+/// - `SocketAddr.localhost*`, `parse`, `ipv4`, `ipv6`, and the accessors are compiler-lowered;
+///   this helper is retained only for representation privacy and resource-helper handoff.
 /// - Keeping those conversions here avoids app-side raw Rust and avoids assuming Rust's address
 ///   layout from Haxe.
 ///
 /// What this helper must not become:
-/// - DNS or arbitrary host parsing.
+/// - DNS or host-name parsing (`native_dns_tools` owns resolution).
 /// - Portable `sys.net` compatibility.
 /// - A generic address registry, runtime handle, or platform abstraction layer.
 use std::net::SocketAddr as StdSocketAddr;
@@ -8,6 +8,8 @@ pub struct SocketError {
 enum SocketErrorKind {
     InvalidInput,
     Io,
+    Resolve,
+    Timeout,
     Utf8,
 }
 
@@ -21,12 +23,26 @@ impl SocketError {
     }
 
     pub(crate) fn io(error: std::io::Error) -> SocketError {
+        // Read/write timeouts surface as `WouldBlock` on Unix and `TimedOut` on Windows.
+        let kind = match error.kind() {
+            std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut => {
+                SocketErrorKind::Timeout
+            }
+            _ => SocketErrorKind::Io,
+        };
         SocketError {
-            kind: SocketErrorKind::Io,
+            kind,
             message: error.to_string(),
         }
     }
 
+    pub(crate) fn resolve(message: String) -> SocketError {
+        SocketError {
+            kind: SocketErrorKind::Resolve,
+            message,
+        }
+    }
+
     pub(crate) fn utf8(error: std::string::FromUtf8Error) -> SocketError {
         SocketError {
             kind: SocketErrorKind::Utf8,
@@ -43,7 +59,15 @@ impl SocketError {
     }
 
     pub fn isIo(&self) -> bool {
-        matches!(self.kind, SocketErrorKind::Io)
+        matches!(self.kind, SocketErrorKind::Io | SocketErrorKind::Timeout)
+    }
+
+    pub fn isResolve(&self) -> bool {
+        matches!(self.kind, SocketErrorKind::Resolve)
+    }
+
+    pub fn isTimeout(&self) -> bool {
+        matches!(self.kind, SocketErrorKind::Timeout)
     }
 
     pub fn isUtf8(&self) -> bool {
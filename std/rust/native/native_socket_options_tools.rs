@@ -0,0 +1,283 @@
+/// `native_socket_options_tools`
+///
+/// Typed helper module backing `rust.net.SocketOptions`.
+///
+/// `std::net` only exposes TTL and `TCP_NODELAY`. Reuse flags, keepalive timing, linger, and
+/// buffer sizes need `socket2`, and reuse flags must be set before `bind`, so this helper owns
+/// that dependency and the plain TCP/UDP helpers stay `std`-only. An options value is built in
+/// Haxe, then either binds a new listener/UDP socket through `socket2` or is applied to an open
+/// socket; `readFrom*` snapshots the current OS values into the same type.
+///
+/// Setters only record values; validation happens when options are applied so failures surface as
+/// `SocketError::invalid_input` on the `Result` returned there.
+use std::net::{SocketAddr as StdSocketAddr, TcpListener as StdTcpListener};
+use std::time::Duration;
+
+use socket2::{Domain, Protocol, SockRef, Socket, TcpKeepalive, Type};
+
+use crate::native_socket_addr_tools::SocketAddr;
+use crate::native_socket_error_tools::SocketError;
+use crate::native_tcp_tools::{TcpListener, TcpStream};
+use crate::native_udp_tools::UdpSocket;
+
+#[derive(Clone, Debug, Default)]
+pub struct SocketOptions {
+    reuse_address: Option<bool>,
+    reuse_port: Option<bool>,
+    keep_alive: Option<(bool, f64, f64)>,
+    linger: Option<f64>,
+    send_buffer_size: Option<i32>,
+    recv_buffer_size: Option<i32>,
+    ttl: Option<i32>,
+}
+
+fn positive_seconds(seconds: f64) -> Option<Duration> {
+    (seconds.is_finite() && seconds > 0.0).then(|| Duration::from_secs_f64(seconds))
+}
+
+fn buffer_size(label: &str, bytes: i32) -> Result<usize, SocketError> {
+    usize::try_from(bytes)
+        .ok()
+        .filter(|size| *size > 0)
+        .ok_or_else(|| {
+            SocketError::invalid_input(format!("{} buffer size must be positive: {}", label, bytes))
+        })
+}
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+))]
+fn set_reuse_port(sock: &SockRef<'_>, enabled: bool) -> Result<(), SocketError> {
+    sock.set_reuse_port(enabled).map_err(SocketError::io)
+}
+
+#[cfg(not(all(
+    unix,
+    not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+)))]
+fn set_reuse_port(_sock: &SockRef<'_>, enabled: bool) -> Result<(), SocketError> {
+    if enabled {
+        return Err(SocketError::invalid_input(String::from(
+            "SO_REUSEPORT is not available on this platform",
+        )));
+    }
+    Ok(())
+}
+
+fn set_keep_alive(sock: &SockRef<'_>, idle: f64, interval: f64) -> Result<(), SocketError> {
+    let mut params = TcpKeepalive::new();
+    if let Some(idle) = positive_seconds(idle) {
+        params = params.with_time(idle);
+    }
+    #[cfg(any(
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "windows"
+    ))]
+    if let Some(interval) = positive_seconds(interval) {
+        params = params.with_interval(interval);
+    }
+    #[cfg(not(any(
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "windows"
+    )))]
+    let _ = interval;
+    sock.set_tcp_keepalive(&params).map_err(SocketError::io)
+}
+
+fn is_ipv6(sock: &SockRef<'_>) -> std::io::Result<bool> {
+    Ok(sock.local_addr()?.is_ipv6())
+}
+
+#[allow(non_snake_case)]
+impl SocketOptions {
+    pub fn new() -> SocketOptions {
+        SocketOptions::default()
+    }
+
+    pub fn withReuseAddress(&mut self, enabled: bool) {
+        self.reuse_address = Some(enabled);
+    }
+
+    pub fn withReusePort(&mut self, enabled: bool) {
+        self.reuse_port = Some(enabled);
+    }
+
+    pub fn withKeepAlive(&mut self, enabled: bool, idle_seconds: f64, interval_seconds: f64) {
+        self.keep_alive = Some((enabled, idle_seconds, interval_seconds));
+    }
+
+    pub fn withLinger(&mut self, seconds: f64) {
+        self.linger = Some(seconds);
+    }
+
+    pub fn withSendBufferSize(&mut self, bytes: i32) {
+        self.send_buffer_size = Some(bytes);
+    }
+
+    pub fn withRecvBufferSize(&mut self, bytes: i32) {
+        self.recv_buffer_size = Some(bytes);
+    }
+
+    pub fn withTtl(&mut self, ttl: i32) {
+        self.ttl = Some(ttl);
+    }
+
+    pub fn reuseAddress(&self) -> bool {
+        self.reuse_address.unwrap_or(false)
+    }
+
+    pub fn reusePort(&self) -> bool {
+        self.reuse_port.unwrap_or(false)
+    }
+
+    pub fn keepAlive(&self) -> bool {
+        self.keep_alive.is_some_and(|(enabled, _, _)| enabled)
+    }
+
+    /// Linger seconds, or `-1` when lingering is off or unset.
+    pub fn linger(&self) -> f64 {
+        self.linger
+            .filter(|seconds| *seconds >= 0.0)
+            .unwrap_or(-1.0)
+    }
+
+    pub fn sendBufferSize(&self) -> i32 {
+        self.send_buffer_size.unwrap_or(0)
+    }
+
+    pub fn recvBufferSize(&self) -> i32 {
+        self.recv_buffer_size.unwrap_or(0)
+    }
+
+    pub fn ttl(&self) -> i32 {
+        self.ttl.unwrap_or(0)
+    }
+
+    pub fn bindTcp(&self, addr: SocketAddr) -> Result<TcpListener, SocketError> {
+        let socket = self.bound_socket(addr.as_std(), Type::STREAM, Protocol::TCP)?;
+        socket.listen(128).map_err(SocketError::io)?;
+        Ok(TcpListener::from_std(StdTcpListener::from(socket)))
+    }
+
+    pub fn bindUdp(&self, addr: SocketAddr) -> Result<UdpSocket, SocketError> {
+        let socket = self.bound_socket(addr.as_std(), Type::DGRAM, Protocol::UDP)?;
+        Ok(UdpSocket::from_std(socket.into()))
+    }
+
+    pub fn applyToStream(&self, stream: &TcpStream) -> Result<bool, SocketError> {
+        self.apply(&SockRef::from(stream.as_std())).map(|_| true)
+    }
+
+    pub fn applyToUdp(&self, socket: &UdpSocket) -> Result<bool, SocketError> {
+        self.apply(&SockRef::from(socket.as_std())).map(|_| true)
+    }
+
+    pub fn readFromStream(stream: &TcpStream) -> Result<SocketOptions, SocketError> {
+        SocketOptions::read(&SockRef::from(stream.as_std())).map_err(SocketError::io)
+    }
+
+    pub fn readFromListener(listener: &TcpListener) -> Result<SocketOptions, SocketError> {
+        SocketOptions::read(&SockRef::from(listener.as_std())).map_err(SocketError::io)
+    }
+
+    pub fn readFromUdp(socket: &UdpSocket) -> Result<SocketOptions, SocketError> {
+        SocketOptions::read(&SockRef::from(socket.as_std())).map_err(SocketError::io)
+    }
+
+    fn bound_socket(
+        &self,
+        addr: StdSocketAddr,
+        ty: Type,
+        protocol: Protocol,
+    ) -> Result<Socket, SocketError> {
+        let socket =
+            Socket::new(Domain::for_address(addr), ty, Some(protocol)).map_err(SocketError::io)?;
+        self.apply(&SockRef::from(&socket))?;
+        socket.bind(&addr.into()).map_err(SocketError::io)?;
+        Ok(socket)
+    }
+
+    fn apply(&self, sock: &SockRef<'_>) -> Result<(), SocketError> {
+        if let Some(enabled) = self.reuse_address {
+            sock.set_reuse_address(enabled).map_err(SocketError::io)?;
+        }
+        if let Some(enabled) = self.reuse_port {
+            set_reuse_port(sock, enabled)?;
+        }
+        match self.keep_alive {
+            Some((true, idle, interval)) => set_keep_alive(sock, idle, interval)?,
+            Some((false, _, _)) => sock.set_keepalive(false).map_err(SocketError::io)?,
+            None => {}
+        }
+        if let Some(seconds) = self.linger {
+            if seconds.is_nan() {
+                return Err(SocketError::invalid_input(String::from(
+                    "linger must be a number",
+                )));
+            }
+            let linger = (seconds >= 0.0).then(|| Duration::from_secs_f64(seconds.min(65535.0)));
+            sock.set_linger(linger).map_err(SocketError::io)?;
+        }
+        if let Some(bytes) = self.send_buffer_size {
+            let size = buffer_size("send", bytes)?;
+            sock.set_send_buffer_size(size).map_err(SocketError::io)?;
+        }
+        if let Some(bytes) = self.recv_buffer_size {
+            let size = buffer_size("receive", bytes)?;
+            sock.set_recv_buffer_size(size).map_err(SocketError::io)?;
+        }
+        if let Some(ttl) = self.ttl {
+            let ttl = u32::try_from(ttl)
+                .ok()
+                .filter(|ttl| (1..=255).contains(ttl))
+                .ok_or_else(|| {
+                    SocketError::invalid_input(format!("TTL must be between 1 and 255: {}", ttl))
+                })?;
+            // Windows cannot report the family of an unbound socket; treat it as IPv4 there.
+            if is_ipv6(sock).unwrap_or(false) {
+                sock.set_unicast_hops_v6(ttl).map_err(SocketError::io)?;
+            } else {
+                sock.set_ttl(ttl).map_err(SocketError::io)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn read(sock: &SockRef<'_>) -> std::io::Result<SocketOptions> {
+        #[cfg(all(
+            unix,
+            not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+        ))]
+        let reuse_port = sock.reuse_port()?;
+        #[cfg(not(all(
+            unix,
+            not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+        )))]
+        let reuse_port = false;
+        let ttl = if is_ipv6(sock)? {
+            sock.unicast_hops_v6()?
+        } else {
+            sock.ttl()?
+        };
+        Ok(SocketOptions {
+            reuse_address: Some(sock.reuse_address()?),
+            reuse_port: Some(reuse_port),
+            keep_alive: Some((sock.keepalive()?, 0.0, 0.0)),
+            linger: Some(sock.linger()?.map_or(-1.0, |d| d.as_secs_f64())),
+            send_buffer_size: Some(i32::try_from(sock.send_buffer_size()?).unwrap_or(i32::MAX)),
+            recv_buffer_size: Some(i32::try_from(sock.recv_buffer_size()?).unwrap_or(i32::MAX)),
+            ttl: Some(ttl as i32),
+        })
+    }
+}
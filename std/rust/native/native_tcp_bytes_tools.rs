@@ -0,0 +1,80 @@
+/// `native_tcp_bytes_tools`
+///
+/// Typed helper module backing `rust.net.TcpStreamBytes`.
+///
+/// The streaming methods on `native_tcp_tools::TcpStream` move native `Vec<u8>` payloads so that
+/// module stays runtime-free. Metal code that already holds `haxe.io.Bytes` buffers (and therefore
+/// links `hxrt`) uses these helpers to read into / write from a buffer range without copying
+/// through `Vec<Int>`. Ranges are validated here and reported as `SocketError::invalid_input`
+/// instead of throwing Haxe exceptions.
+use std::io::{Read, Write};
+
+use hxrt::bytes::{self, Bytes};
+use hxrt::cell::HxRef;
+
+use crate::native_socket_error_tools::SocketError;
+use crate::native_tcp_tools::TcpStream;
+
+#[derive(Debug)]
+pub struct TcpStreamBytes;
+
+fn checked_range(buf: &HxRef<Bytes>, pos: i32, len: i32) -> Result<(usize, usize), SocketError> {
+    let total = buf.borrow().length();
+    if pos < 0 || len < 0 || pos > total - len {
+        return Err(SocketError::invalid_input(format!(
+            "TCP buffer range out of bounds: pos {} len {} (buffer length {})",
+            pos, len, total
+        )));
+    }
+    Ok((pos as usize, (pos + len) as usize))
+}
+
+#[allow(non_snake_case)]
+impl TcpStreamBytes {
+    pub fn readInto(
+        stream: &mut TcpStream,
+        buf: &HxRef<Bytes>,
+        pos: i32,
+        len: i32,
+    ) -> Result<i32, SocketError> {
+        let (start, end) = checked_range(buf, pos, len)?;
+        let mut tmp = vec![0_u8; end - start];
+        let count = stream
+            .as_std_mut()
+            .read(&mut tmp)
+            .map_err(SocketError::io)?;
+        bytes::write_from_slice(buf, pos, &tmp[..count]);
+        Ok(count as i32)
+    }
+
+    pub fn readExactInto(
+        stream: &mut TcpStream,
+        buf: &HxRef<Bytes>,
+        pos: i32,
+        len: i32,
+    ) -> Result<bool, SocketError> {
+        let (start, end) = checked_range(buf, pos, len)?;
+        let mut tmp = vec![0_u8; end - start];
+        stream
+            .as_std_mut()
+            .read_exact(&mut tmp)
+            .map_err(SocketError::io)?;
+        bytes::write_from_slice(buf, pos, &tmp);
+        Ok(true)
+    }
+
+    pub fn writeFrom(
+        stream: &mut TcpStream,
+        buf: &HxRef<Bytes>,
+        pos: i32,
+        len: i32,
+    ) -> Result<i32, SocketError> {
+        let (start, end) = checked_range(buf, pos, len)?;
+        let data = buf.borrow();
+        stream
+            .as_std_mut()
+            .write_all(&data.as_slice()[start..end])
+            .map(|_| len)
+            .map_err(SocketError::io)
+    }
+}
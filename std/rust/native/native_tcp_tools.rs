@@ -6,9 +6,12 @@
 /// the metal no-hxrt fixture can prove Rust-native blocking TCP behavior without depending on
 /// portable `sys.net` runtime handles, Haxe stream wrappers, `haxe.io.Bytes`, async runtimes, or
 /// TLS setup. Byte-stream helpers validate Haxe `Int` values before converting them into Rust
-/// `u8` buffers.
+/// `u8` buffers. Incremental streaming methods move native `Vec<u8>` payloads and report typed
+/// `SocketError` values directly; the `haxe.io.Bytes` variants live in the hxrt-bridge module
+/// `native_tcp_bytes_tools` so this file stays runtime-free.
 use std::io::{Read, Write};
 use std::net::{Shutdown, TcpListener as StdTcpListener, TcpStream as StdTcpStream};
+use std::time::Duration;
 
 use crate::native_socket_addr_tools::SocketAddr;
 use crate::native_socket_error_tools::SocketError;
@@ -54,6 +57,39 @@ fn u8_vec_to_i32_vec(payload: Vec<u8>) -> Vec<i32> {
     payload.into_iter().map(i32::from).collect()
 }
 
+fn length_to_usize(len: i32) -> Result<usize, SocketError> {
+    usize::try_from(len)
+        .map_err(|_| SocketError::invalid_input(format!("TCP length must be >= 0: {}", len)))
+}
+
+/// `rust.net.Shutdown` values: `0` read, `1` write, `2` both.
+fn shutdown_from_i32(how: i32) -> Result<Shutdown, SocketError> {
+    match how {
+        0 => Ok(Shutdown::Read),
+        1 => Ok(Shutdown::Write),
+        2 => Ok(Shutdown::Both),
+        _ => Err(SocketError::invalid_input(format!(
+            "TCP shutdown mode out of range: {}",
+            how
+        ))),
+    }
+}
+
+/// `seconds <= 0` clears the timeout (blocks forever), matching `sys.net.Socket.setTimeout`.
+fn timeout_from_seconds(seconds: f64) -> Result<Option<Duration>, SocketError> {
+    if seconds.is_nan() {
+        return Err(SocketError::invalid_input(String::from(
+            "TCP timeout must be a number",
+        )));
+    }
+    if seconds <= 0.0 {
+        return Ok(None);
+    }
+    Duration::try_from_secs_f64(seconds)
+        .map(Some)
+        .map_err(|_| SocketError::invalid_input(format!("TCP timeout out of range: {}", seconds)))
+}
+
 #[allow(non_snake_case)]
 impl NativeTcp {
     pub fn bind(addr: SocketAddr) -> Result<TcpListener, String> {
@@ -154,8 +190,100 @@ impl TcpListener {
     }
 }
 
+impl TcpListener {
+    pub(crate) fn from_std(listener: StdTcpListener) -> TcpListener {
+        TcpListener { listener }
+    }
+
+    pub(crate) fn as_std(&self) -> &StdTcpListener {
+        &self.listener
+    }
+}
+
+impl TcpStream {
+    pub(crate) fn as_std(&self) -> &StdTcpStream {
+        &self.stream
+    }
+
+    pub(crate) fn as_std_mut(&mut self) -> &mut StdTcpStream {
+        &mut self.stream
+    }
+}
+
 #[allow(non_snake_case)]
 impl TcpStream {
+    pub fn read(&mut self, max_bytes: i32) -> Result<Vec<u8>, SocketError> {
+        let mut buffer = vec![0_u8; length_to_usize(max_bytes)?];
+        let count = self.stream.read(&mut buffer).map_err(SocketError::io)?;
+        buffer.truncate(count);
+        Ok(buffer)
+    }
+
+    pub fn readExact(&mut self, len: i32) -> Result<Vec<u8>, SocketError> {
+        let mut buffer = vec![0_u8; length_to_usize(len)?];
+        self.stream
+            .read_exact(&mut buffer)
+            .map_err(SocketError::io)?;
+        Ok(buffer)
+    }
+
+    pub fn write(&mut self, payload: Vec<u8>) -> Result<i32, SocketError> {
+        self.stream
+            .write_all(&payload)
+            .map(|_| payload.len() as i32)
+            .map_err(SocketError::io)
+    }
+
+    pub fn writeUtf8(&mut self, payload: String) -> Result<i32, SocketError> {
+        self.write(payload.into_bytes())
+    }
+
+    pub fn writeBytes(&mut self, payload: Vec<i32>) -> Result<i32, SocketError> {
+        self.write(bytes_to_u8_vec_detailed(payload)?)
+    }
+
+    pub fn flush(&mut self) -> Result<bool, SocketError> {
+        self.stream.flush().map(|_| true).map_err(SocketError::io)
+    }
+
+    pub fn shutdown(&self, how: i32) -> Result<bool, SocketError> {
+        let how = shutdown_from_i32(how)?;
+        self.stream
+            .shutdown(how)
+            .map(|_| true)
+            .map_err(SocketError::io)
+    }
+
+    pub fn setReadTimeout(&self, seconds: f64) -> Result<bool, SocketError> {
+        let timeout = timeout_from_seconds(seconds)?;
+        self.stream
+            .set_read_timeout(timeout)
+            .map(|_| true)
+            .map_err(SocketError::io)
+    }
+
+    pub fn setWriteTimeout(&self, seconds: f64) -> Result<bool, SocketError> {
+        let timeout = timeout_from_seconds(seconds)?;
+        self.stream
+            .set_write_timeout(timeout)
+            .map(|_| true)
+            .map_err(SocketError::io)
+    }
+
+    pub fn setNodelay(&self, enabled: bool) -> Result<bool, SocketError> {
+        self.stream
+            .set_nodelay(enabled)
+            .map(|_| true)
+            .map_err(SocketError::io)
+    }
+
+    pub fn peerAddr(&self) -> Result<SocketAddr, SocketError> {
+        self.stream
+            .peer_addr()
+            .map(SocketAddr::from_std)
+            .map_err(SocketError::io)
+    }
+
     pub fn writeUtf8AndShutdownWrite(&mut self, payload: String) -> Result<bool, String> {
         self.stream
             .write_all(payload.as_bytes())
@@ -6,8 +6,10 @@
 /// the metal no-hxrt fixtures can prove Rust-native blocking UDP datagram behavior without
 /// depending on portable `sys.net` runtime handles, Haxe stream wrappers, `haxe.io.Bytes`, async
 /// runtimes, DNS, or TLS setup. Byte datagram helpers validate Haxe `Int` values before converting
-/// them into Rust `u8` buffers.
-use std::net::UdpSocket as StdUdpSocket;
+/// them into Rust `u8` buffers. Multicast group membership and options map one-to-one onto the
+/// `std::net::UdpSocket` v4/v6 methods; group and interface addresses arrive as text and are parsed
+/// into `SocketError::invalid_input` on failure.
+use std::net::{Ipv4Addr, Ipv6Addr, UdpSocket as StdUdpSocket};
 
 use crate::native_socket_addr_tools::SocketAddr;
 use crate::native_socket_error_tools::SocketError;
@@ -20,6 +22,13 @@ pub struct UdpSocket {
     socket: StdUdpSocket,
 }
 
+/// One received datagram together with the full sender address.
+#[derive(Debug)]
+pub struct UdpDatagram {
+    payload: Vec<u8>,
+    sender: SocketAddr,
+}
+
 fn port_to_u16(port: i32) -> Result<u16, String> {
     u16::try_from(port).map_err(|_| format!("UDP port out of range: {}", port))
 }
@@ -70,6 +79,44 @@ fn u8_vec_to_i32_vec(payload: Vec<u8>) -> Vec<i32> {
     payload.into_iter().map(i32::from).collect()
 }
 
+fn multicast_v4(text: &str) -> Result<Ipv4Addr, SocketError> {
+    let group = text.parse::<Ipv4Addr>().map_err(|_| {
+        SocketError::invalid_input(format!("Invalid IPv4 multicast group: {}", text))
+    })?;
+    if !group.is_multicast() {
+        return Err(SocketError::invalid_input(format!(
+            "{} is not an IPv4 multicast group",
+            text
+        )));
+    }
+    Ok(group)
+}
+
+fn multicast_v6(text: &str) -> Result<Ipv6Addr, SocketError> {
+    let group = text.parse::<Ipv6Addr>().map_err(|_| {
+        SocketError::invalid_input(format!("Invalid IPv6 multicast group: {}", text))
+    })?;
+    if !group.is_multicast() {
+        return Err(SocketError::invalid_input(format!(
+            "{} is not an IPv6 multicast group",
+            text
+        )));
+    }
+    Ok(group)
+}
+
+fn interface_v4(text: &str) -> Result<Ipv4Addr, SocketError> {
+    text.parse::<Ipv4Addr>().map_err(|_| {
+        SocketError::invalid_input(format!("Invalid IPv4 multicast interface: {}", text))
+    })
+}
+
+fn multicast_interface_index(index: i32) -> Result<u32, SocketError> {
+    u32::try_from(index).map_err(|_| {
+        SocketError::invalid_input(format!("Multicast interface index out of range: {}", index))
+    })
+}
+
 #[allow(non_snake_case)]
 impl NativeUdp {
     pub fn bind(addr: SocketAddr) -> Result<UdpSocket, String> {
@@ -99,6 +146,16 @@ impl NativeUdp {
     }
 }
 
+impl UdpSocket {
+    pub(crate) fn from_std(socket: StdUdpSocket) -> UdpSocket {
+        UdpSocket { socket }
+    }
+
+    pub(crate) fn as_std(&self) -> &StdUdpSocket {
+        &self.socket
+    }
+}
+
 #[allow(non_snake_case)]
 impl UdpSocket {
     pub fn localAddr(&self) -> Result<SocketAddr, String> {
@@ -246,4 +303,127 @@ impl UdpSocket {
         buffer.truncate(read);
         Ok(u8_vec_to_i32_vec(buffer))
     }
+
+    pub fn sendTo(&self, payload: Vec<u8>, addr: SocketAddr) -> Result<i32, SocketError> {
+        self.socket
+            .send_to(&payload, addr.as_std())
+            .and_then(byte_count_to_i32)
+            .map_err(SocketError::io)
+    }
+
+    pub fn recvFrom(&self, max_bytes: i32) -> Result<UdpDatagram, SocketError> {
+        let max_bytes = positive_len_to_usize_detailed(max_bytes, "UDP receive buffer size")?;
+        let mut buffer = vec![0_u8; max_bytes];
+        let (read, addr) = self.socket.recv_from(&mut buffer).map_err(SocketError::io)?;
+        buffer.truncate(read);
+        Ok(UdpDatagram {
+            payload: buffer,
+            sender: SocketAddr::from_std(addr),
+        })
+    }
+
+    pub fn setBroadcast(&self, enabled: bool) -> Result<bool, SocketError> {
+        self.socket
+            .set_broadcast(enabled)
+            .map(|_| true)
+            .map_err(SocketError::io)
+    }
+
+    pub fn joinMulticastV4(&self, group: String, interface: String) -> Result<bool, SocketError> {
+        let group = multicast_v4(&group)?;
+        let interface = interface_v4(&interface)?;
+        self.socket
+            .join_multicast_v4(&group, &interface)
+            .map(|_| true)
+            .map_err(SocketError::io)
+    }
+
+    pub fn leaveMulticastV4(&self, group: String, interface: String) -> Result<bool, SocketError> {
+        let group = multicast_v4(&group)?;
+        let interface = interface_v4(&interface)?;
+        self.socket
+            .leave_multicast_v4(&group, &interface)
+            .map(|_| true)
+            .map_err(SocketError::io)
+    }
+
+    pub fn joinMulticastV6(
+        &self,
+        group: String,
+        interface_index: i32,
+    ) -> Result<bool, SocketError> {
+        let group = multicast_v6(&group)?;
+        let interface = multicast_interface_index(interface_index)?;
+        self.socket
+            .join_multicast_v6(&group, interface)
+            .map(|_| true)
+            .map_err(SocketError::io)
+    }
+
+    pub fn leaveMulticastV6(
+        &self,
+        group: String,
+        interface_index: i32,
+    ) -> Result<bool, SocketError> {
+        let group = multicast_v6(&group)?;
+        let interface = multicast_interface_index(interface_index)?;
+        self.socket
+            .leave_multicast_v6(&group, interface)
+            .map(|_| true)
+            .map_err(SocketError::io)
+    }
+
+    pub fn setMulticastTtlV4(&self, ttl: i32) -> Result<bool, SocketError> {
+        let ttl = u8::try_from(ttl).map_err(|_| {
+            SocketError::invalid_input(format!("Multicast TTL must be between 0 and 255: {}", ttl))
+        })?;
+        self.socket
+            .set_multicast_ttl_v4(u32::from(ttl))
+            .map(|_| true)
+            .map_err(SocketError::io)
+    }
+
+    pub fn multicastTtlV4(&self) -> Result<i32, SocketError> {
+        self.socket
+            .multicast_ttl_v4()
+            .map(|ttl| ttl as i32)
+            .map_err(SocketError::io)
+    }
+
+    pub fn setMulticastLoopV4(&self, enabled: bool) -> Result<bool, SocketError> {
+        self.socket
+            .set_multicast_loop_v4(enabled)
+            .map(|_| true)
+            .map_err(SocketError::io)
+    }
+
+    pub fn multicastLoopV4(&self) -> Result<bool, SocketError> {
+        self.socket.multicast_loop_v4().map_err(SocketError::io)
+    }
+
+    pub fn setMulticastLoopV6(&self, enabled: bool) -> Result<bool, SocketError> {
+        self.socket
+            .set_multicast_loop_v6(enabled)
+            .map(|_| true)
+            .map_err(SocketError::io)
+    }
+
+    pub fn multicastLoopV6(&self) -> Result<bool, SocketError> {
+        self.socket.multicast_loop_v6().map_err(SocketError::io)
+    }
+}
+
+#[allow(non_snake_case)]
+impl UdpDatagram {
+    pub fn payload(&self) -> Vec<u8> {
+        self.payload.clone()
+    }
+
+    pub fn payloadUtf8(&self) -> Result<String, SocketError> {
+        String::from_utf8(self.payload.clone()).map_err(SocketError::utf8)
+    }
+
+    pub fn sender(&self) -> SocketAddr {
+        self.sender
+    }
 }
@@ -0,0 +1,235 @@
+/// `native_unix_tools`
+///
+/// Typed helper module backing `rust.net.NativeUnix`, `UnixListener`, and `UnixStream`.
+///
+/// This module intentionally uses direct `std::os::unix::net` APIs and owned `Result<_, String>`
+/// values, mirroring `native_tcp_tools`, so metal code can talk to local daemons over socket files
+/// without portable `sys.net` runtime handles. It only compiles for Unix targets, like the std
+/// types it wraps. Byte-stream helpers validate Haxe `Int` values before converting them into
+/// Rust `u8` buffers.
+use std::io::{Read, Write};
+use std::net::Shutdown;
+use std::os::unix::net::{UnixListener as StdUnixListener, UnixStream as StdUnixStream};
+use std::time::Duration;
+
+use crate::native_socket_error_tools::SocketError;
+
+#[derive(Debug)]
+pub struct NativeUnix;
+
+#[derive(Debug)]
+pub struct UnixListener {
+    listener: StdUnixListener,
+}
+
+#[derive(Debug)]
+pub struct UnixStream {
+    stream: StdUnixStream,
+}
+
+fn bytes_to_u8_vec(payload: Vec<i32>) -> Result<Vec<u8>, String> {
+    payload
+        .into_iter()
+        .enumerate()
+        .map(|(index, byte)| {
+            u8::try_from(byte)
+                .map_err(|_| format!("Unix socket byte at index {} out of range: {}", index, byte))
+        })
+        .collect()
+}
+
+fn bytes_to_u8_vec_detailed(payload: Vec<i32>) -> Result<Vec<u8>, SocketError> {
+    bytes_to_u8_vec(payload).map_err(SocketError::invalid_input)
+}
+
+fn u8_vec_to_i32_vec(payload: Vec<u8>) -> Vec<i32> {
+    payload.into_iter().map(i32::from).collect()
+}
+
+/// `seconds <= 0` clears the timeout (blocks forever), matching `sys.net.Socket.setTimeout`.
+fn timeout_from_seconds(seconds: f64) -> Result<Option<Duration>, String> {
+    if seconds.is_nan() {
+        return Err(String::from("Unix socket timeout must be a number"));
+    }
+    if seconds <= 0.0 {
+        return Ok(None);
+    }
+    Duration::try_from_secs_f64(seconds)
+        .map(Some)
+        .map_err(|_| format!("Unix socket timeout out of range: {}", seconds))
+}
+
+fn timeout_from_seconds_detailed(seconds: f64) -> Result<Option<Duration>, SocketError> {
+    timeout_from_seconds(seconds).map_err(SocketError::invalid_input)
+}
+
+fn path_text(addr: std::os::unix::net::SocketAddr) -> String {
+    addr.as_pathname()
+        .map(|path| path.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+#[allow(non_snake_case)]
+impl NativeUnix {
+    pub fn bind(path: String) -> Result<UnixListener, String> {
+        StdUnixListener::bind(path)
+            .map(|listener| UnixListener { listener })
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn bindDetailed(path: String) -> Result<UnixListener, SocketError> {
+        StdUnixListener::bind(path)
+            .map(|listener| UnixListener { listener })
+            .map_err(SocketError::io)
+    }
+
+    pub fn connect(path: String) -> Result<UnixStream, String> {
+        StdUnixStream::connect(path)
+            .map(|stream| UnixStream { stream })
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn connectDetailed(path: String) -> Result<UnixStream, SocketError> {
+        StdUnixStream::connect(path)
+            .map(|stream| UnixStream { stream })
+            .map_err(SocketError::io)
+    }
+}
+
+#[allow(non_snake_case)]
+impl UnixListener {
+    pub fn localPath(&self) -> Result<String, String> {
+        self.listener
+            .local_addr()
+            .map(path_text)
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn localPathDetailed(&self) -> Result<String, SocketError> {
+        self.listener
+            .local_addr()
+            .map(path_text)
+            .map_err(SocketError::io)
+    }
+
+    pub fn accept(&self) -> Result<UnixStream, String> {
+        self.listener
+            .accept()
+            .map(|(stream, _addr)| UnixStream { stream })
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn acceptDetailed(&self) -> Result<UnixStream, SocketError> {
+        self.listener
+            .accept()
+            .map(|(stream, _addr)| UnixStream { stream })
+            .map_err(SocketError::io)
+    }
+}
+
+#[allow(non_snake_case)]
+impl UnixStream {
+    pub fn setReadTimeout(&self, seconds: f64) -> Result<bool, String> {
+        let timeout = timeout_from_seconds(seconds)?;
+        self.stream
+            .set_read_timeout(timeout)
+            .map(|_| true)
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn setReadTimeoutDetailed(&self, seconds: f64) -> Result<bool, SocketError> {
+        let timeout = timeout_from_seconds_detailed(seconds)?;
+        self.stream
+            .set_read_timeout(timeout)
+            .map(|_| true)
+            .map_err(SocketError::io)
+    }
+
+    pub fn setWriteTimeout(&self, seconds: f64) -> Result<bool, String> {
+        let timeout = timeout_from_seconds(seconds)?;
+        self.stream
+            .set_write_timeout(timeout)
+            .map(|_| true)
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn setWriteTimeoutDetailed(&self, seconds: f64) -> Result<bool, SocketError> {
+        let timeout = timeout_from_seconds_detailed(seconds)?;
+        self.stream
+            .set_write_timeout(timeout)
+            .map(|_| true)
+            .map_err(SocketError::io)
+    }
+
+    pub fn writeUtf8AndShutdownWrite(&mut self, payload: String) -> Result<bool, String> {
+        self.stream
+            .write_all(payload.as_bytes())
+            .and_then(|_| self.stream.shutdown(Shutdown::Write))
+            .map(|_| true)
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn writeUtf8AndShutdownWriteDetailed(
+        &mut self,
+        payload: String,
+    ) -> Result<bool, SocketError> {
+        self.stream
+            .write_all(payload.as_bytes())
+            .and_then(|_| self.stream.shutdown(Shutdown::Write))
+            .map(|_| true)
+            .map_err(SocketError::io)
+    }
+
+    pub fn writeBytesAndShutdownWrite(&mut self, payload: Vec<i32>) -> Result<bool, String> {
+        let bytes = bytes_to_u8_vec(payload)?;
+        self.stream
+            .write_all(&bytes)
+            .and_then(|_| self.stream.shutdown(Shutdown::Write))
+            .map(|_| true)
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn writeBytesAndShutdownWriteDetailed(
+        &mut self,
+        payload: Vec<i32>,
+    ) -> Result<bool, SocketError> {
+        let bytes = bytes_to_u8_vec_detailed(payload)?;
+        self.stream
+            .write_all(&bytes)
+            .and_then(|_| self.stream.shutdown(Shutdown::Write))
+            .map(|_| true)
+            .map_err(SocketError::io)
+    }
+
+    pub fn readToString(&mut self) -> Result<String, String> {
+        let mut output = String::new();
+        self.stream
+            .read_to_string(&mut output)
+            .map(|_| output)
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn readToStringDetailed(&mut self) -> Result<String, SocketError> {
+        let mut output = Vec::new();
+        self.stream
+            .read_to_end(&mut output)
+            .map_err(SocketError::io)?;
+        String::from_utf8(output).map_err(SocketError::utf8)
+    }
+
+    pub fn readBytes(&mut self) -> Result<Vec<i32>, String> {
+        let mut output = Vec::new();
+        self.stream
+            .read_to_end(&mut output)
+            .map(|_| u8_vec_to_i32_vec(output))
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn readBytesDetailed(&mut self) -> Result<Vec<i32>, SocketError> {
+        let mut output = Vec::new();
+        self.stream
+            .read_to_end(&mut output)
+            .map_err(SocketError::io)?;
+        Ok(u8_vec_to_i32_vec(output))
+    }
+}
@@ -0,0 +1,33 @@
+package rust.net;
+
+import rust.Result;
+import rust.Vec;
+
+/**
+	`rust.net.Dns`
+
+	Why
+	- `SocketAddr.parse` / `ipv4` / `ipv6` only accept literal addresses. Connecting to a real host
+	  name still needs the platform resolver, and metal code should not fall back to portable
+	  `sys.net.Host` (an `hxrt` runtime surface) just to look up an address.
+
+	What
+	- `resolve(host, port)` returns every address the resolver reports for `host`, in resolver
+	  order, each carrying `port`. Callers typically try them in turn with `NativeTcp.connect`.
+	- Literal IPs resolve to themselves without a lookup.
+	- Out-of-range ports report `SocketError.isInvalidInput()`; lookup failures and empty answers
+	  report `SocketError.isResolve()`.
+
+	How
+	- The extern maps to `crate::native_dns_tools::Dns`, a thin wrapper over
+	  `std::net::ToSocketAddrs` that converts each result into the typed `SocketAddr` wrapper.
+	- The lookup is blocking, like the rest of the `rust.net` native slice. This is not an async
+	  resolver, cache, or DNS record client.
+	- In `metal + rust_no_hxrt`, this must remain free of `hxrt`, `Dynamic`, and portable socket
+	  handles.
+**/
+@:native("crate::native_dns_tools::Dns")
+@:rustExtraSrc("rust/native/native_dns_tools.rs")
+extern class Dns {
+	public static function resolve(host:String, port:Int):Result<Vec<SocketAddr>, SocketError>;
+}
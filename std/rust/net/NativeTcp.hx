@@ -19,12 +19,13 @@ import rust.Result;
 	- `bindLocalhost(port)` binds `127.0.0.1:<port>` and returns a typed `TcpListener`; passing `0`
 	  asks the OS for an ephemeral port.
 	- `connectLocalhost(port)` connects a typed `TcpStream` to `127.0.0.1:<port>`.
-	- `bind(addr)` and `connect(addr)` accept the M60 typed `SocketAddr` value so loopback addresses
-	  can be carried between APIs without stringly host/port plumbing.
+	- `bind(addr)` and `connect(addr)` accept the M60 typed `SocketAddr` value, so any interface or
+	  remote host reached through `SocketAddr.parse` / `ipv4` / `ipv6` or `Dns.resolve` uses the
+	  same typed path as loopback.
 	- The `Detailed` variants keep the same behavior but return `SocketError` so callers can branch
 	  on invalid-input or IO categories without parsing error text.
 	- This is intentionally not portable `sys.net` parity, not TLS, not UDP, not async networking,
-	  not DNS, and not a general external host API yet.
+	  and not DNS itself (`Dns.resolve` owns host-name lookup).
 
 	How
 	- `@:native("crate::native_tcp_tools::NativeTcp")` binds to a small Rust helper module.
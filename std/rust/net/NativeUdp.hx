@@ -17,12 +17,13 @@ import rust.Result;
 	- First M56 Rust-native UDP entry point.
 	- `bindLocalhost(port)` binds `127.0.0.1:<port>` and returns a typed `UdpSocket`; passing `0`
 	  asks the OS for an ephemeral port.
-	- `bind(addr)` accepts the M60 typed `SocketAddr` value so loopback addresses can be carried
-	  between APIs without stringly host/port plumbing.
+	- `bind(addr)` accepts the M60 typed `SocketAddr` value, so `0.0.0.0`, `::`, or a specific
+	  interface built with `SocketAddr.parse` / `ipv4` / `ipv6` binds through the same typed path as
+	  loopback.
 	- `bindLocalhostDetailed(...)` keeps the same bind behavior but returns `SocketError` so callers
 	  can distinguish invalid port values from Rust IO failures without parsing message text.
 	- This is intentionally not portable `sys.net` parity, not TCP, not TLS, not async networking,
-	  not DNS, and not a general external host API yet.
+	  and not DNS itself (`Dns.resolve` owns host-name lookup).
 
 	How
 	- `@:native("crate::native_udp_tools::NativeUdp")` binds to a small Rust helper module.
@@ -0,0 +1,33 @@
+package rust.net;
+
+import rust.Result;
+
+/**
+	`rust.net.NativeUnix`
+
+	Why
+	- Local daemons (container engines, sidecars, databases) commonly listen on Unix socket files.
+	- Portable `sys.net.Socket.connectUnix` goes through the `hxrt.net` runtime; metal code wants the
+	  same direct `std` ownership that `NativeTcp` gives for TCP.
+
+	What
+	- `bind(path)` creates the socket file and returns a typed `UnixListener`; the file is not
+	  removed when the listener is dropped.
+	- `connect(path)` connects a typed `UnixStream` to an existing socket file.
+	- The `Detailed` variants return `SocketError` so callers can branch on IO categories.
+	- Unix-only: generated crates using this facade do not compile for Windows targets, like the
+	  `std::os::unix::net` types behind it. Not async, not datagram, not abstract-namespace sockets.
+
+	How
+	- `@:native("crate::native_unix_tools::NativeUnix")` binds to a small Rust helper module.
+	- `@:rustExtraSrc("rust/native/native_unix_tools.rs")` copies that helper into generated crates.
+	- In `metal + rust_no_hxrt`, this surface stays free of `hxrt`, `Dynamic`, and raw Rust snippets.
+**/
+@:native("crate::native_unix_tools::NativeUnix")
+@:rustExtraSrc("rust/native/native_unix_tools.rs")
+extern class NativeUnix {
+	public static function bind(path:String):Result<UnixListener, String>;
+	public static function bindDetailed(path:String):Result<UnixListener, SocketError>;
+	public static function connect(path:String):Result<UnixStream, String>;
+	public static function connectDetailed(path:String):Result<UnixStream, SocketError>;
+}
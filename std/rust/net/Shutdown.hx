@@ -0,0 +1,21 @@
+package rust.net;
+
+/**
+	rust.net.Shutdown
+
+	Why
+	- Half-closing a connection is how request/response protocols signal "no more requests" while
+	  still reading the reply; callers should name the half, not pass a bare number.
+
+	What
+	- `Read`, `Write`, and `Both`, matching `std::net::Shutdown`.
+
+	How
+	- The abstract lowers to a plain `i32`; `native_tcp_tools` maps it onto `std::net::Shutdown` and
+	  rejects any other value as invalid input.
+**/
+enum abstract Shutdown(Int) to Int {
+	var Read = 0;
+	var Write = 1;
+	var Both = 2;
+}
@@ -21,13 +21,22 @@ import rust.Result;
 	  `SocketError::invalid_input`.
 	- `port()` exposes the concrete port from a constructed address, including OS-assigned ports
 	  returned from `localAddr()`.
+	- `parse(text)` accepts literal `"1.2.3.4:80"` / `"[::1]:80"` addresses; `ipv4(ip, port)` and
+	  `ipv6(ip, port)` build an address from a literal IP of that family. All three report bad
+	  input as `SocketError.isInvalidInput()` and never perform host-name lookups — use
+	  `Dns.resolve(host, port)` for that.
+	- `ip()` returns the textual IP part; `isIpv4()` / `isIpv6()` report the address family.
+	- Any constructed address can be passed to `NativeTcp.bind/connect` or `NativeUdp.bind`, so
+	  binding a specific interface (`0.0.0.0`, `::`, a LAN address) uses the same typed path as
+	  loopback.
 
 	How
 	- The extern maps to `crate::native_socket_addr_tools::SocketAddr`, a small wrapper around
 	  `std::net::SocketAddr`.
 	- TCP/UDP helper modules accept this wrapper and convert it internally to `std::net` values.
-	- `localhost(...)`, `localhostDetailed(...)`, and `port()` are compiler-lowered into direct
-	  `std::net` operations. The helper no longer owns those pure constructor/accessor bodies.
+	- `localhost(...)`, `localhostDetailed(...)`, `parse(...)`, `ipv4(...)`, `ipv6(...)`, `port()`,
+	  `ip()`, `isIpv4()`, and `isIpv6()` are compiler-lowered into direct `std::net` operations.
+	  The helper no longer owns those pure constructor/accessor bodies.
 	- The helper is retained only for representation privacy and crate-private conversion handoff:
 	  current Haxe externs still cannot declare the Rust wrapper field or expose the narrow
 	  `from_std` / `as_std` bridge needed by `TcpListener` / `UdpSocket` helpers without raw Rust or
@@ -42,5 +51,11 @@ import rust.Result;
 extern class SocketAddr {
 	public static function localhost(port:Int):Result<SocketAddr, String>;
 	public static function localhostDetailed(port:Int):Result<SocketAddr, SocketError>;
+	public static function parse(text:String):Result<SocketAddr, SocketError>;
+	public static function ipv4(ip:String, port:Int):Result<SocketAddr, SocketError>;
+	public static function ipv6(ip:String, port:Int):Result<SocketAddr, SocketError>;
 	public function port():Int;
+	public function ip():String;
+	public function isIpv4():Bool;
+	public function isIpv6():Bool;
 }
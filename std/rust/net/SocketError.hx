@@ -16,6 +16,10 @@ package rust.net;
 	- `isInvalidInput()` covers facade contract failures such as out-of-range ports or non-positive
 	  receive buffer sizes.
 	- `isIo()` covers Rust `std::io::Error` paths from bind/connect/accept/read/write/send/receive.
+	- `isTimeout()` narrows `isIo()` to reads/writes that hit a configured socket timeout, so
+	  request/response loops can retry or give up without inspecting the message.
+	- `isResolve()` covers host-name lookups from `Dns.resolve` that failed or returned no
+	  addresses, so callers can tell an unknown host apart from a refused connection.
 	- `isUtf8()` covers byte-to-String decode failures from UTF-8 receive helpers.
 	- `message()` returns human-readable detail for diagnostics; callers should branch on typed
 	  predicates first and use the message only for reporting.
@@ -32,5 +36,7 @@ extern class SocketError {
 	public function message():String;
 	public function isInvalidInput():Bool;
 	public function isIo():Bool;
+	public function isResolve():Bool;
+	public function isTimeout():Bool;
 	public function isUtf8():Bool;
 }
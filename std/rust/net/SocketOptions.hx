@@ -0,0 +1,72 @@
+package rust.net;
+
+import rust.Ref;
+import rust.Result;
+
+/**
+	`rust.net.SocketOptions`
+
+	Why
+	- `NativeTcp`/`NativeUdp` wrap `std::net`, which cannot set `SO_REUSEADDR`, `SO_REUSEPORT`,
+	  keepalive timing, `SO_LINGER`, or buffer sizes. Servers that restart quickly, detect dead
+	  peers, or tune throughput need those without dropping to portable `sys.net.Socket`.
+	- Reuse flags only work when set before `bind`, so an options value has to be able to create
+	  the socket itself.
+
+	What
+	- A typed options value: `withReuseAddress`, `withReusePort` (Unix only), `withKeepAlive`
+	  (idle/interval seconds; `<= 0` keeps OS defaults), `withLinger` (`< 0` off, `0` resets on
+	  close), `withSendBufferSize`, `withRecvBufferSize`, and `withTtl` (`1..255`; IPv6 hop limit).
+	- `bindTcp(addr)` / `bindUdp(addr)` create, configure, and bind a `TcpListener` / `UdpSocket`.
+	- `applyToStream(stream)` / `applyToUdp(socket)` configure an already-open socket, e.g. a
+	  connected or accepted `TcpStream`.
+	- `readFromStream` / `readFromListener` / `readFromUdp` snapshot the OS values; the accessors
+	  (`reuseAddress()`, `keepAlive()`, `linger()`, `sendBufferSize()`, ...) read either a snapshot
+	  or the values set so far (`false` / `0` / `-1` linger when unset). The OS may round buffer
+	  sizes (Linux doubles them).
+	- Invalid values are reported when the options are applied, as `SocketError.isInvalidInput()`;
+	  OS failures as `isIo()`.
+
+	How
+	- The extern maps to `crate::native_socket_options_tools::SocketOptions`, which uses
+	  `socket2` and hands the configured sockets to the TCP/UDP helper wrappers through their
+	  crate-private `from_std` / `as_std` conversions.
+	- `@:rustCargo` adds `socket2` only to crates that use this type, so plain `NativeTcp` /
+	  `NativeUdp` output stays dependency-free.
+	- In `metal + rust_no_hxrt`, this must remain free of `hxrt`, `Dynamic`, and portable socket
+	  handles.
+**/
+@:native("crate::native_socket_options_tools::SocketOptions")
+@:rustExtraSrc("rust/native/native_socket_options_tools.rs")
+@:rustCargo({name: "socket2", version: "0.5", features: ["all"]})
+extern class SocketOptions {
+	public function new();
+	@:rustMutating
+	public function withReuseAddress(enabled:Bool):Void;
+	@:rustMutating
+	public function withReusePort(enabled:Bool):Void;
+	@:rustMutating
+	public function withKeepAlive(enabled:Bool, idleSeconds:Float, intervalSeconds:Float):Void;
+	@:rustMutating
+	public function withLinger(seconds:Float):Void;
+	@:rustMutating
+	public function withSendBufferSize(bytes:Int):Void;
+	@:rustMutating
+	public function withRecvBufferSize(bytes:Int):Void;
+	@:rustMutating
+	public function withTtl(ttl:Int):Void;
+	public function reuseAddress():Bool;
+	public function reusePort():Bool;
+	public function keepAlive():Bool;
+	public function linger():Float;
+	public function sendBufferSize():Int;
+	public function recvBufferSize():Int;
+	public function ttl():Int;
+	public function bindTcp(addr:SocketAddr):Result<TcpListener, SocketError>;
+	public function bindUdp(addr:SocketAddr):Result<UdpSocket, SocketError>;
+	public function applyToStream(stream:Ref<TcpStream>):Result<Bool, SocketError>;
+	public function applyToUdp(socket:Ref<UdpSocket>):Result<Bool, SocketError>;
+	public static function readFromStream(stream:Ref<TcpStream>):Result<SocketOptions, SocketError>;
+	public static function readFromListener(listener:Ref<TcpListener>):Result<SocketOptions, SocketError>;
+	public static function readFromUdp(socket:Ref<UdpSocket>):Result<SocketOptions, SocketError>;
+}
@@ -1,6 +1,7 @@
 package rust.net;
 
 import rust.Result;
+import rust.U8;
 import rust.Vec;
 
 /**
@@ -21,6 +22,19 @@ import rust.Vec;
 	  value is in `0...255`; `readBytes()` reads until EOF and returns owned `rust.Vec<Int>` bytes.
 	- The `Detailed` variants return `SocketError`, including a distinct UTF-8 decode category for
 	  `readToStringDetailed()` and invalid-input category for out-of-range byte values.
+	- Streaming methods keep the connection open for request/response protocols and return
+	  `SocketError` directly:
+	  - `read(maxBytes)` returns whatever one read delivers (an empty `Vec` at EOF);
+	    `readExact(len)` waits for exactly `len` bytes.
+	  - `write(payload)` / `writeUtf8(payload)` write the whole payload and return its length;
+	    `flush()` flushes it. `writeBytes(payload)` takes a Haxe-built `rust.Vec<Int>` instead,
+	    rejecting values outside `0...255` with the invalid-input category before writing.
+	  - `shutdown(Read | Write | Both)` closes one or both halves.
+	  - `setReadTimeout(seconds)` / `setWriteTimeout(seconds)` bound blocking calls (`seconds <= 0`
+	    clears the timeout; expiry reports `isTimeout()`), `setNodelay(...)` toggles Nagle, and
+	    `peerAddr()` reports the remote `SocketAddr`.
+	  - Payloads are native `rust.Vec<rust.U8>` (`Vec<u8>`); `rust.net.TcpStreamBytes` adds
+	    `haxe.io.Bytes` range variants for code that links `hxrt`.
 
 	How
 	- The extern maps to `crate::native_tcp_tools::TcpStream`, a wrapper around
@@ -56,4 +70,28 @@ extern class TcpStream {
 
 	@:rustMutating
 	public function readBytesDetailed():Result<Vec<Int>, SocketError>;
+
+	@:rustMutating
+	public function read(maxBytes:Int):Result<Vec<U8>, SocketError>;
+
+	@:rustMutating
+	public function readExact(len:Int):Result<Vec<U8>, SocketError>;
+
+	@:rustMutating
+	public function write(payload:Vec<U8>):Result<Int, SocketError>;
+
+	@:rustMutating
+	public function writeUtf8(payload:String):Result<Int, SocketError>;
+
+	@:rustMutating
+	public function writeBytes(payload:Vec<Int>):Result<Int, SocketError>;
+
+	@:rustMutating
+	public function flush():Result<Bool, SocketError>;
+
+	public function shutdown(how:Shutdown):Result<Bool, SocketError>;
+	public function setReadTimeout(seconds:Float):Result<Bool, SocketError>;
+	public function setWriteTimeout(seconds:Float):Result<Bool, SocketError>;
+	public function setNodelay(enabled:Bool):Result<Bool, SocketError>;
+	public function peerAddr():Result<SocketAddr, SocketError>;
 }
@@ -0,0 +1,35 @@
+package rust.net;
+
+import haxe.io.Bytes;
+import rust.MutRef;
+import rust.Ref;
+import rust.Result;
+
+/**
+	`rust.net.TcpStreamBytes`
+
+	Why
+	- `TcpStream`'s streaming methods move native `Vec<u8>` payloads so the TCP helper stays free of
+	  `hxrt`. Metal code that already works with `haxe.io.Bytes` buffers still wants to read into and
+	  write from those buffers directly instead of converting through `Vec<Int>`.
+
+	What
+	- Static-extension helpers (`using rust.net.TcpStreamBytes`) over a `TcpStream`:
+	  - `readInto(buf, pos, len)` performs one read into `buf[pos...pos+len]` and returns the byte
+	    count (`0` at EOF).
+	  - `readExactInto(buf, pos, len)` fills the whole range or fails.
+	  - `writeFrom(buf, pos, len)` writes the whole range and returns `len`.
+	- Out-of-range `pos`/`len` values return `SocketError` invalid input instead of throwing.
+
+	How
+	- Maps to `crate::native_tcp_bytes_tools::TcpStreamBytes`, an hxrt-bridge helper that borrows the
+	  stream's `std::net::TcpStream` and the `hxrt::bytes::Bytes` buffer.
+	- Requires `hxrt`; `rust_no_hxrt` builds use the `Vec<U8>` methods on `TcpStream` instead.
+**/
+@:native("crate::native_tcp_bytes_tools::TcpStreamBytes")
+@:rustExtraSrc("rust/native/native_tcp_bytes_tools.rs")
+extern class TcpStreamBytes {
+	public static function readInto(stream:MutRef<TcpStream>, buf:Ref<Bytes>, pos:Int, len:Int):Result<Int, SocketError>;
+	public static function readExactInto(stream:MutRef<TcpStream>, buf:Ref<Bytes>, pos:Int, len:Int):Result<Bool, SocketError>;
+	public static function writeFrom(stream:MutRef<TcpStream>, buf:Ref<Bytes>, pos:Int, len:Int):Result<Int, SocketError>;
+}
@@ -0,0 +1,31 @@
+package rust.net;
+
+import rust.Result;
+import rust.U8;
+import rust.Vec;
+
+/**
+	`rust.net.UdpDatagram`
+
+	Why
+	- A datagram receive that drops the sender cannot answer discovery or request/response
+	  protocols, and IPv6 senders do not fit an IPv4-only host integer.
+
+	What
+	- The result of `UdpSocket.recvFrom(maxBytes)`.
+	- `payload()` returns the received bytes as native `Vec<U8>`; `payloadUtf8()` decodes them and
+	  reports invalid text as `SocketError.isUtf8()`.
+	- `sender()` returns the full IPv4 or IPv6 `SocketAddr`, ready to pass back to
+	  `UdpSocket.sendTo(...)`.
+
+	How
+	- The extern maps to `crate::native_udp_tools::UdpDatagram`, an owned payload plus a typed
+	  `SocketAddr`; accessors copy the payload so the datagram can be read more than once.
+**/
+@:native("crate::native_udp_tools::UdpDatagram")
+@:rustExtraSrc("rust/native/native_udp_tools.rs")
+extern class UdpDatagram {
+	public function payload():Vec<U8>;
+	public function payloadUtf8():Result<String, SocketError>;
+	public function sender():SocketAddr;
+}
@@ -1,6 +1,7 @@
 package rust.net;
 
 import rust.Result;
+import rust.U8;
 import rust.Vec;
 
 /**
@@ -25,13 +26,19 @@ import rust.Vec;
 	- The `Detailed` variants keep the String-error methods source-compatible while returning
 	  `SocketError` for invalid input, IO, and UTF-8 decode categories. Byte APIs use
 	  `SocketError::invalid_input` for out-of-range byte values.
+	- `sendTo(payload, addr)` sends native `Vec<U8>` bytes; `recvFrom(maxBytes)` returns a
+	  `UdpDatagram` carrying the payload and the full IPv4/IPv6 sender `SocketAddr` to reply to.
+	- `setBroadcast(...)`, `joinMulticastV4(group, iface)` / `leaveMulticastV4(...)`,
+	  `joinMulticastV6(group, interfaceIndex)` / `leaveMulticastV6(...)`, and the multicast
+	  TTL/loopback setters mirror the `std::net::UdpSocket` v4/v6 methods. Group and interface
+	  addresses are text; malformed or non-multicast values return `SocketError.isInvalidInput()`.
 
 	How
 	- The extern maps to `crate::native_udp_tools::UdpSocket`, a wrapper around
 	  `std::net::UdpSocket`.
 	- Methods return `rust.Result` instead of throwing Haxe exceptions.
-	- The current surface deliberately exposes only the minimal blocking datagram operations needed
-	  for the localhost no-hxrt proof.
+	- The surface deliberately stays on blocking datagram operations that `std::net` exposes
+	  directly; the IPv6 multicast hop limit and other `socket2`-only options are out of scope.
 	- This is not portable `haxe.io.Bytes`; portable byte buffers still belong to the runtime-backed
 	  Haxe stdlib contract. This facade is a Rust-native datagram contract for metal code.
 **/
@@ -54,4 +61,17 @@ extern class UdpSocket {
 	public function sendBytesToLocalhostDetailed(payload:Vec<Int>, port:Int):Result<Int, SocketError>;
 	public function recvBytes(maxBytes:Int):Result<Vec<Int>, String>;
 	public function recvBytesDetailed(maxBytes:Int):Result<Vec<Int>, SocketError>;
+	public function sendTo(payload:Vec<U8>, addr:SocketAddr):Result<Int, SocketError>;
+	public function recvFrom(maxBytes:Int):Result<UdpDatagram, SocketError>;
+	public function setBroadcast(enabled:Bool):Result<Bool, SocketError>;
+	public function joinMulticastV4(group:String, iface:String):Result<Bool, SocketError>;
+	public function leaveMulticastV4(group:String, iface:String):Result<Bool, SocketError>;
+	public function joinMulticastV6(group:String, interfaceIndex:Int):Result<Bool, SocketError>;
+	public function leaveMulticastV6(group:String, interfaceIndex:Int):Result<Bool, SocketError>;
+	public function setMulticastTtlV4(ttl:Int):Result<Bool, SocketError>;
+	public function multicastTtlV4():Result<Int, SocketError>;
+	public function setMulticastLoopV4(enabled:Bool):Result<Bool, SocketError>;
+	public function multicastLoopV4():Result<Bool, SocketError>;
+	public function setMulticastLoopV6(enabled:Bool):Result<Bool, SocketError>;
+	public function multicastLoopV6():Result<Bool, SocketError>;
 }
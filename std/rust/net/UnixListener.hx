@@ -0,0 +1,28 @@
+package rust.net;
+
+import rust.Result;
+
+/**
+	`rust.net.UnixListener`
+
+	Why
+	- A Unix listener is an RAII handle: dropping the value closes it (the socket file stays).
+
+	What
+	- `localPath()` reports the bound socket file path.
+	- `accept()` waits for one incoming connection and returns a typed `UnixStream`.
+	- The `Detailed` variants return `SocketError` instead of error text.
+
+	How
+	- The extern maps to `crate::native_unix_tools::UnixListener`, a wrapper around
+	  `std::os::unix::net::UnixListener`.
+	- Methods return `rust.Result` instead of throwing Haxe exceptions.
+**/
+@:native("crate::native_unix_tools::UnixListener")
+@:rustExtraSrc("rust/native/native_unix_tools.rs")
+extern class UnixListener {
+	public function localPath():Result<String, String>;
+	public function localPathDetailed():Result<String, SocketError>;
+	public function accept():Result<UnixStream, String>;
+	public function acceptDetailed():Result<UnixStream, SocketError>;
+}
@@ -0,0 +1,57 @@
+package rust.net;
+
+import rust.Result;
+import rust.Vec;
+
+/**
+	`rust.net.UnixStream`
+
+	Why
+	- Request/response exchanges with local daemons need the same owned byte-stream shape as
+	  `TcpStream`, without portable `haxe.io` wrappers.
+
+	What
+	- `writeUtf8AndShutdownWrite(...)` / `writeBytesAndShutdownWrite(...)` write one payload and
+	  shut down only the write half; `readToString()` / `readBytes()` read until EOF.
+	- `setReadTimeout(seconds)` / `setWriteTimeout(seconds)` bound blocking calls; `seconds <= 0`
+	  clears the timeout. A timed-out read returns a `SocketError` with `isTimeout()`.
+	- Byte payloads are `rust.Vec<Int>` values validated to `0...255`.
+
+	How
+	- The extern maps to `crate::native_unix_tools::UnixStream`, a wrapper around
+	  `std::os::unix::net::UnixStream`.
+	- Mutating methods are marked `@:rustMutating` so generated Rust owns the handle as `mut` only
+	  where stream state actually changes.
+**/
+@:native("crate::native_unix_tools::UnixStream")
+@:rustExtraSrc("rust/native/native_unix_tools.rs")
+extern class UnixStream {
+	public function setReadTimeout(seconds:Float):Result<Bool, String>;
+	public function setReadTimeoutDetailed(seconds:Float):Result<Bool, SocketError>;
+	public function setWriteTimeout(seconds:Float):Result<Bool, String>;
+	public function setWriteTimeoutDetailed(seconds:Float):Result<Bool, SocketError>;
+
+	@:rustMutating
+	public function writeUtf8AndShutdownWrite(payload:String):Result<Bool, String>;
+
+	@:rustMutating
+	public function writeUtf8AndShutdownWriteDetailed(payload:String):Result<Bool, SocketError>;
+
+	@:rustMutating
+	public function writeBytesAndShutdownWrite(payload:Vec<Int>):Result<Bool, String>;
+
+	@:rustMutating
+	public function writeBytesAndShutdownWriteDetailed(payload:Vec<Int>):Result<Bool, SocketError>;
+
+	@:rustMutating
+	public function readToString():Result<String, String>;
+
+	@:rustMutating
+	public function readToStringDetailed():Result<String, SocketError>;
+
+	@:rustMutating
+	public function readBytes():Result<Vec<Int>, String>;
+
+	@:rustMutating
+	public function readBytesDetailed():Result<Vec<Int>, SocketError>;
+}
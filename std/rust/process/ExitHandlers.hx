@@ -0,0 +1,36 @@
+package rust.process;
+
+import hxrt.sys.NativeSys;
+
+/**
+	rust.process.ExitHandlers
+
+	Why
+	- The process used to end straight through `std::process::exit`, so buffered output, open files,
+	  database connections, and pending `EventLoop` work were abandoned on `Sys.exit`.
+
+	What
+	- `add(handler)` registers a callback and returns an id; `remove(id)` unregisters it (`false`
+	  when it already ran or was removed).
+	- Handlers run once, newest first, when the program ends through `Sys.exit`, a normal return from
+	  `main` (sync or `@:async`), or an uncaught exception leaving `main`. Standard output and error
+	  are flushed afterwards.
+	- A throwing handler is reported on stderr as `[HXRT-EXIT-HANDLER] <value>` and the remaining
+	  handlers still run. Calling `Sys.exit` from a handler continues the same sequence.
+
+	How
+	- The registry lives in `hxrt::sys`. Referencing this class makes the compiler bind
+	  `hxrt::sys::main_exit_guard()` at the top of the generated `main`, whose destructor runs the
+	  handlers on return and during unwinding.
+	- Handlers do not run when the process is killed by a signal; pair them with
+	  `rust.process.Signals.onShutdown` for graceful termination.
+**/
+class ExitHandlers {
+	public static function add(handler:() -> Void):Int {
+		return NativeSys.addExitHandler(handler);
+	}
+
+	public static function remove(id:Int):Bool {
+		return NativeSys.removeExitHandler(id);
+	}
+}
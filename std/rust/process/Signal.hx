@@ -0,0 +1,22 @@
+package rust.process;
+
+/**
+	rust.process.Signal
+
+	Why
+	- Signal subscriptions should name signals by meaning, not by platform numbers scattered through
+	  application code.
+
+	What
+	- The POSIX signals `rust.process.Signals` can deliver: `Interrupt` (`SIGINT`, Ctrl-C),
+	  `Terminate` (`SIGTERM`, service stop), and `Hangup` (`SIGHUP`, conventionally "reload").
+
+	How
+	- Values are the POSIX numbers, which are identical on Linux and macOS, so the abstract lowers
+	  to a plain `i32`.
+**/
+enum abstract Signal(Int) to Int {
+	var Hangup = 1;
+	var Interrupt = 2;
+	var Terminate = 15;
+}
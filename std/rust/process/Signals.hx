@@ -0,0 +1,59 @@
+package rust.process;
+
+import hxrt.signal.NativeSignal;
+import rust.HxRef;
+import rust.concurrent.Channel;
+import sys.thread.EventLoop;
+import sys.thread.Thread;
+
+/**
+	rust.process.Signals
+
+	Why
+	- Long-running services must shut down gracefully on `SIGTERM`/`SIGINT` and reload on `SIGHUP`
+	  instead of dying with pending work.
+	- OS signal handlers cannot run Haxe code, so signals have to be delivered to a normal thread.
+
+	What
+	- `listen(signals, events, callback)`: queue `callback(signal)` on a `sys.thread.EventLoop`
+	  each time one of `signals` arrives.
+	- `notify(signals, channel)`: send each arriving signal on a `rust.concurrent.Channel`.
+	  Delivery never blocks; a full bounded channel drops that signal, and closing the channel ends
+	  the subscription.
+	- `onShutdown(callback)`: graceful-shutdown hook for `Interrupt` and `Terminate` on the calling
+	  thread's event loop (the main loop when called from `main`).
+	- `cancel(id)` removes a subscription. Once no subscription covers a signal, its default action
+	  (terminating the process) applies again.
+	- `isSupported(signal)`: `false` on platforms without POSIX signals, where subscribing throws
+	  a String beginning with `HXRT-SIGNAL-UNSUPPORTED`.
+
+	How
+	- Handlers are installed process-wide the first time a signal is subscribed and forward to one
+	  runtime dispatcher thread (`hxrt::signal`).
+	- Event-loop callbacks run from that loop's `progress()` / `loop()`, so a throwing callback
+	  behaves like a throwing `EventLoop.run` callback.
+	- A subscription does not keep its event loop alive; a service loop is normally kept alive by
+	  its sockets, timers, or a `promise()`. It ends by itself when the loop's thread finishes.
+**/
+@:access(sys.thread.EventLoop)
+class Signals {
+	public static function listen(signals:Array<Signal>, events:EventLoop, callback:(Signal) -> Void):Int {
+		return NativeSignal.listen(signals, events.__threadId, callback);
+	}
+
+	public static function notify(signals:Array<Signal>, channel:HxRef<Channel<Signal>>):Int {
+		return NativeSignal.notify(signals, channel);
+	}
+
+	public static function onShutdown(callback:(Signal) -> Void):Int {
+		return listen([Interrupt, Terminate], Thread.current().events, callback);
+	}
+
+	public static function cancel(subscriptionId:Int):Void {
+		NativeSignal.cancel(subscriptionId);
+	}
+
+	public static function isSupported(signal:Signal):Bool {
+		return NativeSignal.isSupported(signal);
+	}
+}
@@ -0,0 +1,20 @@
+package rust.tracing;
+
+/**
+	rust.tracing.Span
+
+	Why
+	- Application code needs a value for "the work happening now" that runtime spans (socket reads,
+	  DB queries, event-loop callbacks) nest under.
+
+	What
+	- Created by `Tracing.span(...)`. `enter()` makes it the current span on this thread and
+	  `exit()` leaves it; spans and events started in between become its children.
+	- `isEnabled()` is `false` when no subscriber records the span, so callers can skip building
+	  expensive fields.
+
+	How
+	- Typedef alias to `hxrt.trace.SpanHandle`, so the span is the runtime value itself with no
+	  extra wrapper object.
+**/
+typedef Span = hxrt.trace.SpanHandle;
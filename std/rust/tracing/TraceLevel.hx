@@ -0,0 +1,23 @@
+package rust.tracing;
+
+/**
+	rust.tracing.TraceLevel
+
+	Why
+	- Subscriber thresholds and span/event levels should read as names, not as the runtime's level
+	  numbers.
+
+	What
+	- The five `tracing` levels, from most verbose (`Trace`) to most severe (`Error`). A subscriber
+	  installed at level `L` records spans and events at `L` or above.
+
+	How
+	- The abstract lowers to a plain `i32`; `hxrt::trace` maps it onto `tracing::Level`.
+**/
+enum abstract TraceLevel(Int) to Int {
+	var Trace = 0;
+	var Debug = 1;
+	var Info = 2;
+	var Warn = 3;
+	var Error = 4;
+}
@@ -0,0 +1,90 @@
+package rust.tracing;
+
+import hxrt.trace.NativeTrace;
+
+/**
+	rust.tracing.Tracing
+
+	Why
+	- Diagnosing a slow `sys.Http` call or database query should not need hand-written timing code:
+	  the runtime already knows when sockets connect, files open, queries run and event-loop
+	  callbacks fire.
+
+	What
+	- `useStderr(?level)` prints spans and events as readable lines on stderr;
+	  `useJsonLines(?level, ?path)` writes one JSON object per event (with its span stack) to
+	  `path`, appending, or to stderr. Only one subscriber can be installed per process; a second
+	  call throws `HXRT-TRACE-INIT`. The default level is `Info`.
+	- `span(name, ?fields, ?level)` creates an application span (see `rust.tracing.Span`);
+	  `event(level, message, ?fields)` and the `trace` ... `error` helpers emit events inside the
+	  current span.
+	- Runtime spans: `net.connect`, `net.accept`, `net.tls_handshake`, `net.read`, `net.write`,
+	  `process.spawn`, `fs.open`, `fs.read`, `fs.write`, `db.query` and `event_loop.callback`, at
+	  `Debug` or `Trace` level.
+
+	How
+	- Referencing this class, or compiling with `-D rust_hxrt_tracing`, enables the hxrt `tracing`
+	  feature. Without an installed subscriber each span costs one disabled-callsite check; builds
+	  without the feature carry no instrumentation at all.
+	- Fields are strings, at most 16 per span (15 per event besides the message). Keys are sorted so
+	  the same field set always maps to the same runtime callsite.
+**/
+class Tracing {
+	public static function useStderr(?level:TraceLevel):Void {
+		NativeTrace.useStderr(level == null ? TraceLevel.Info : level);
+	}
+
+	public static function useJsonLines(?level:TraceLevel, ?path:String):Void {
+		NativeTrace.useJsonLines(level == null ? TraceLevel.Info : level, path == null ? "" : path);
+	}
+
+	public static function span(name:String, ?fields:Map<String, String>, ?level:TraceLevel):Span {
+		var keys = fieldKeys(fields);
+		return NativeTrace.span(level == null ? TraceLevel.Info : level, name, keys, fieldValues(fields, keys));
+	}
+
+	public static function event(level:TraceLevel, message:String, ?fields:Map<String, String>):Void {
+		var keys = fieldKeys(fields);
+		NativeTrace.event(level, message, keys, fieldValues(fields, keys));
+	}
+
+	public static inline function trace(message:String, ?fields:Map<String, String>):Void {
+		event(TraceLevel.Trace, message, fields);
+	}
+
+	public static inline function debug(message:String, ?fields:Map<String, String>):Void {
+		event(TraceLevel.Debug, message, fields);
+	}
+
+	public static inline function info(message:String, ?fields:Map<String, String>):Void {
+		event(TraceLevel.Info, message, fields);
+	}
+
+	public static inline function warn(message:String, ?fields:Map<String, String>):Void {
+		event(TraceLevel.Warn, message, fields);
+	}
+
+	public static inline function error(message:String, ?fields:Map<String, String>):Void {
+		event(TraceLevel.Error, message, fields);
+	}
+
+	static function fieldKeys(fields:Null<Map<String, String>>):Array<String> {
+		var keys:Array<String> = [];
+		if (fields == null) {
+			return keys;
+		}
+		for (key in fields.keys()) {
+			keys.push(key);
+		}
+		keys.sort((a, b) -> a < b ? -1 : (a > b ? 1 : 0));
+		return keys;
+	}
+
+	static function fieldValues(fields:Null<Map<String, String>>, keys:Array<String>):Array<String> {
+		var values:Array<String> = [];
+		for (key in keys) {
+			values.push(fields.get(key));
+		}
+		return values;
+	}
+}
@@ -32,6 +32,7 @@ pub fn close_handle(handle: &HxRef<SqliteConnectionHandle>) {
 }
 
 pub fn request(handle: &HxRef<SqliteConnectionHandle>, sql: &str) -> HxRef<QueryResult> {
+    let _query = hxrt::db::query_span("sqlite", sql);
     let binding = handle.borrow();
     let mut conn_guard = binding.conn.lock().unwrap_or_else(|e| e.into_inner());
     let conn = conn_guard
@@ -12,16 +12,20 @@ pub fn new_udp() -> HxRef<SocketHandle> {
     hxrt::net::socket_new_udp()
 }
 
-pub fn host_resolve(name: &str) -> i32 {
-    hxrt::net::host_resolve(name)
+pub fn host_resolve(name: &str) -> String {
+    hxrt::net::host_to_string(hxrt::net::host_resolve(name))
+}
+
+pub fn host_ipv4(address: &str) -> i32 {
+    hxrt::net::ip_to_ipv4_int(hxrt::net::ip_parse(address))
 }
 
 pub fn host_to_string(ip: i32) -> String {
-    hxrt::net::host_to_string(ip)
+    hxrt::net::host_to_string(hxrt::net::ip_from_ipv4_int(ip))
 }
 
-pub fn host_reverse(ip: i32) -> String {
-    hxrt::net::host_reverse(ip)
+pub fn host_reverse(address: &str) -> String {
+    hxrt::net::host_reverse(hxrt::net::ip_parse(address))
 }
 
 pub fn host_local() -> String {
@@ -32,8 +36,24 @@ pub fn close(handle: &HxRef<SocketHandle>) {
     handle.borrow_mut().close();
 }
 
-pub fn connect(handle: &HxRef<SocketHandle>, host: i32, port: i32) {
-    handle.borrow_mut().connect(host, port);
+pub fn connect(handle: &HxRef<SocketHandle>, host: &str, port: i32) {
+    handle.borrow_mut().connect(hxrt::net::ip_parse(host), port);
+}
+
+pub fn connect_unix(handle: &HxRef<SocketHandle>, path: &str) {
+    handle.borrow_mut().connect_unix(path);
+}
+
+pub fn bind_unix(handle: &HxRef<SocketHandle>, path: &str) {
+    handle.borrow_mut().bind_unix(path);
+}
+
+pub fn unix_path(handle: &HxRef<SocketHandle>) -> String {
+    handle.borrow().unix_local_path()
+}
+
+pub fn unix_peer_path(handle: &HxRef<SocketHandle>) -> String {
+    handle.borrow().unix_peer_path()
 }
 
 pub fn listen(handle: &HxRef<SocketHandle>, connections: i32) {
@@ -44,17 +64,17 @@ pub fn shutdown(handle: &HxRef<SocketHandle>, read: bool, write: bool) {
     handle.borrow_mut().shutdown(read, write);
 }
 
-pub fn bind(handle: &HxRef<SocketHandle>, host: i32, port: i32) {
-    handle.borrow_mut().bind(host, port);
+pub fn bind(handle: &HxRef<SocketHandle>, host: &str, port: i32) {
+    handle.borrow_mut().bind(hxrt::net::ip_parse(host), port);
 }
 
 pub fn accept(handle: &HxRef<SocketHandle>) -> HxRef<SocketHandle> {
     handle.borrow_mut().accept()
 }
 
-pub fn peer_ip(handle: &HxRef<SocketHandle>) -> i32 {
+pub fn peer_ip(handle: &HxRef<SocketHandle>) -> String {
     let (ip, _) = handle.borrow().peer();
-    ip
+    hxrt::net::host_to_string(ip)
 }
 
 pub fn peer_port(handle: &HxRef<SocketHandle>) -> i32 {
@@ -62,9 +82,9 @@ pub fn peer_port(handle: &HxRef<SocketHandle>) -> i32 {
     port
 }
 
-pub fn host_ip(handle: &HxRef<SocketHandle>) -> i32 {
+pub fn host_ip(handle: &HxRef<SocketHandle>) -> String {
     let (ip, _) = handle.borrow().host();
-    ip
+    hxrt::net::host_to_string(ip)
 }
 
 pub fn host_port(handle: &HxRef<SocketHandle>) -> i32 {
@@ -80,6 +100,16 @@ pub fn wait_for_read(handle: &HxRef<SocketHandle>) {
     let _ = hxrt::net::socket_select(vec![handle.clone()], vec![], vec![], Some(-1.0));
 }
 
+pub fn watch(
+    thread_id: i32,
+    handle: &HxRef<SocketHandle>,
+    readable: bool,
+    writable: bool,
+    callback: HxDynRef<dyn Fn() + Send + Sync>,
+) -> i32 {
+    hxrt::net::socket_watch(thread_id, handle, readable, writable, callback)
+}
+
 pub fn set_blocking(handle: &HxRef<SocketHandle>, blocking: bool) {
     handle.borrow_mut().set_blocking(blocking);
 }
@@ -88,6 +118,69 @@ pub fn set_fast_send(handle: &HxRef<SocketHandle>, fast_send: bool) {
     handle.borrow_mut().set_fast_send(fast_send);
 }
 
+pub fn set_reuse_address(handle: &HxRef<SocketHandle>, enabled: bool) {
+    handle.borrow_mut().set_reuse_address(enabled);
+}
+
+pub fn reuse_address(handle: &HxRef<SocketHandle>) -> bool {
+    handle.borrow().reuse_address()
+}
+
+pub fn set_reuse_port(handle: &HxRef<SocketHandle>, enabled: bool) {
+    handle.borrow_mut().set_reuse_port(enabled);
+}
+
+pub fn reuse_port(handle: &HxRef<SocketHandle>) -> bool {
+    handle.borrow().reuse_port()
+}
+
+pub fn set_keep_alive(
+    handle: &HxRef<SocketHandle>,
+    enabled: bool,
+    idle_seconds: f64,
+    interval_seconds: f64,
+) {
+    handle
+        .borrow_mut()
+        .set_keep_alive(enabled, idle_seconds, interval_seconds);
+}
+
+pub fn keep_alive(handle: &HxRef<SocketHandle>) -> bool {
+    handle.borrow().keep_alive()
+}
+
+pub fn set_linger(handle: &HxRef<SocketHandle>, seconds: f64) {
+    handle.borrow_mut().set_linger(seconds);
+}
+
+pub fn linger(handle: &HxRef<SocketHandle>) -> f64 {
+    handle.borrow().linger()
+}
+
+pub fn set_send_buffer_size(handle: &HxRef<SocketHandle>, bytes: i32) {
+    handle.borrow_mut().set_send_buffer_size(bytes);
+}
+
+pub fn send_buffer_size(handle: &HxRef<SocketHandle>) -> i32 {
+    handle.borrow().send_buffer_size()
+}
+
+pub fn set_recv_buffer_size(handle: &HxRef<SocketHandle>, bytes: i32) {
+    handle.borrow_mut().set_recv_buffer_size(bytes);
+}
+
+pub fn recv_buffer_size(handle: &HxRef<SocketHandle>) -> i32 {
+    handle.borrow().recv_buffer_size()
+}
+
+pub fn set_ttl(handle: &HxRef<SocketHandle>, ttl: i32) {
+    handle.borrow_mut().set_ttl(ttl);
+}
+
+pub fn ttl(handle: &HxRef<SocketHandle>) -> i32 {
+    handle.borrow().ttl()
+}
+
 pub fn tls_handshake_client<S>(
     handle: &HxRef<SocketHandle>,
     hostname: S,
@@ -188,6 +281,34 @@ pub fn udp_set_broadcast(handle: &HxRef<SocketHandle>, enabled: bool) {
     handle.borrow_mut().udp_set_broadcast(enabled);
 }
 
+pub fn udp_join_multicast(handle: &HxRef<SocketHandle>, group: &str, interface: &str, interface_index: i32) {
+    handle
+        .borrow_mut()
+        .udp_join_multicast(hxrt::net::ip_parse(group), hxrt::net::ip_parse(interface), interface_index);
+}
+
+pub fn udp_leave_multicast(handle: &HxRef<SocketHandle>, group: &str, interface: &str, interface_index: i32) {
+    handle
+        .borrow_mut()
+        .udp_leave_multicast(hxrt::net::ip_parse(group), hxrt::net::ip_parse(interface), interface_index);
+}
+
+pub fn udp_set_multicast_ttl(handle: &HxRef<SocketHandle>, ttl: i32) {
+    handle.borrow_mut().udp_set_multicast_ttl(ttl);
+}
+
+pub fn udp_multicast_ttl(handle: &HxRef<SocketHandle>) -> i32 {
+    handle.borrow().udp_multicast_ttl()
+}
+
+pub fn udp_set_multicast_loop(handle: &HxRef<SocketHandle>, enabled: bool) {
+    handle.borrow_mut().udp_set_multicast_loop(enabled);
+}
+
+pub fn udp_multicast_loop(handle: &HxRef<SocketHandle>) -> bool {
+    handle.borrow().udp_multicast_loop()
+}
+
 pub fn udp_send_to(
     handle: &HxRef<SocketHandle>,
     bytes_ref: &HxRef<Bytes>,
@@ -200,7 +321,17 @@ pub fn udp_send_to(
     let data = bytes_binding.as_slice();
     let start = pos as usize;
     let end = (pos + len) as usize;
-    handle.borrow_mut().udp_send_to(&data[start..end], host, port)
+    handle
+        .borrow_mut()
+        .udp_send_to(&data[start..end], hxrt::net::ip_from_ipv4_int(host), port)
+}
+
+pub fn unix_send_to(handle: &HxRef<SocketHandle>, bytes_ref: &HxRef<Bytes>, pos: i32, len: i32, path: &str) -> i32 {
+    let bytes_binding = bytes_ref.borrow();
+    let data = bytes_binding.as_slice();
+    let start = pos as usize;
+    let end = (pos + len) as usize;
+    handle.borrow_mut().unix_send_to(&data[start..end], path)
 }
 
 pub fn udp_read_from(handle: &HxRef<SocketHandle>, bytes_ref: &HxRef<Bytes>, pos: i32, len: i32) -> Array<i32> {
@@ -211,7 +342,29 @@ pub fn udp_read_from(handle: &HxRef<SocketHandle>, bytes_ref: &HxRef<Bytes>, pos
     }
 
     bytes::write_from_slice(bytes_ref, pos, &tmp[0..(n as usize)]);
-    Array::<i32>::from_vec(vec![n, ip, port])
+    Array::<i32>::from_vec(vec![n, hxrt::net::ip_to_ipv4_int(ip), port])
+}
+
+pub fn udp_send_to_address(
+    handle: &HxRef<SocketHandle>,
+    bytes_ref: &HxRef<Bytes>,
+    pos: i32,
+    len: i32,
+    address: &str,
+    port: i32,
+) -> i32 {
+    let bytes_binding = bytes_ref.borrow();
+    let data = bytes_binding.as_slice();
+    let start = pos as usize;
+    let end = (pos + len) as usize;
+    handle
+        .borrow_mut()
+        .udp_send_to(&data[start..end], hxrt::net::ip_parse(address), port)
+}
+
+pub fn udp_last_sender_ip(handle: &HxRef<SocketHandle>) -> String {
+    let (ip, _) = handle.borrow().udp_last_sender();
+    hxrt::net::host_to_string(ip)
 }
 
 pub fn select_groups(
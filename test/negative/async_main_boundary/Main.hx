@@ -2,5 +2,7 @@ import rust.async.Future;
 
 class Main {
 	@:rustAsync
-	static function main():Void {}
+	static function main():Future<Int> {
+		return 1;
+	}
 }
@@ -0,0 +1,28 @@
+import rust.async.Async;
+import rust.async.Future;
+import rust.async.Stream;
+
+using rust.async.Streams;
+
+class Main {
+	@:async
+	static function square(value:Int):Future<Int> {
+		@:await Async.sleepMs(1);
+		return value * value;
+	}
+
+	@:async
+	static function sumOddSquares(values:Stream<Int>):Future<Int> {
+		var total = 0;
+		for (squared in values.filter(value -> value % 2 == 1).map(value -> square(value)).buffered(2).take(3)) {
+			Sys.println("square=" + squared);
+			total += squared;
+		}
+		return total;
+	}
+
+	static function main():Void {
+		var total = Async.blockOn(sumOddSquares(Streams.fromArray([1, 2, 3, 4, 5, 6, 7])));
+		Sys.println("total=" + total);
+	}
+}
@@ -0,0 +1,18 @@
+import rust.async.Async;
+import rust.async.Future;
+
+class Main {
+	@:async
+	static function double(value:Int):Future<Int> {
+		@:await Async.sleepMs(1);
+		return value * 2;
+	}
+
+	@:async
+	static function main() {
+		var doubled = @:await double(21);
+		Sys.println("doubled=" + doubled);
+		@:await Async.sleepMs(1);
+		Sys.println("done");
+	}
+}
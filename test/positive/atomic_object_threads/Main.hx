@@ -0,0 +1,64 @@
+import haxe.atomic.AtomicInt;
+import haxe.atomic.AtomicObject;
+import sys.thread.Lock;
+import sys.thread.Thread;
+
+class Node {
+	public final value:Int;
+	public final next:Node;
+
+	public function new(value:Int, next:Node) {
+		this.value = value;
+		this.next = next;
+	}
+}
+
+class Main {
+	static inline final WORKERS = 4;
+	static inline final PUSHES_PER_WORKER = 250;
+
+	static function main():Void {
+		var empty = new Node(-1, null);
+		var head = new AtomicObject<Node>(empty);
+		var pushes = new AtomicInt(0);
+		var done = new Lock();
+
+		// Treiber-stack pushes from several threads: every compareExchange retry must observe the
+		// node another thread published, so no push is lost.
+		for (worker in 0...WORKERS) {
+			Thread.create(() -> {
+				for (i in 0...PUSHES_PER_WORKER) {
+					var value = worker * PUSHES_PER_WORKER + i;
+					while (true) {
+						var current = head.load();
+						if (head.compareExchange(current, new Node(value, current)) == current) {
+							break;
+						}
+					}
+					pushes.add(1);
+				}
+				done.release();
+			});
+		}
+		for (_ in 0...WORKERS) {
+			done.wait();
+		}
+
+		var count = 0;
+		var sum = 0;
+		var node = head.load();
+		while (node != empty) {
+			count++;
+			sum += node.value;
+			node = node.next;
+		}
+		Sys.println("nodes=" + count + " pushes=" + pushes.load());
+		Sys.println("sum=" + sum);
+
+		var top = head.load();
+		var stranger = new Node(0, null);
+		Sys.println("stale=" + (head.compareExchange(stranger, stranger) == top) + " unchanged=" + (head.load() == top));
+		Sys.println("exchange=" + (head.exchange(empty) == top) + " reset=" + (head.load() == empty));
+		Sys.println("store=" + (head.store(top) == top) + " restored=" + (head.load() == top));
+	}
+}
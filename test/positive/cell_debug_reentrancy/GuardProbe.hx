@@ -0,0 +1,7 @@
+import rust.Ref;
+
+@:native("crate::guard_probe")
+extern class GuardProbe {
+	@:native("with_write_guard")
+	public static function withWriteGuard(value:Ref<Counter>, callback:Void->Void):Void;
+}
@@ -0,0 +1,27 @@
+class Counter {
+	public var value:Int;
+
+	public function new(value:Int) {
+		this.value = value;
+	}
+}
+
+class Main {
+	static function main():Void {
+		var counter = new Counter(1);
+		try {
+			GuardProbe.withWriteGuard(counter, () -> {
+				counter.value = counter.value + 1;
+			});
+			Sys.println("reentrant=unreported");
+		} catch (e:String) {
+			Sys.println("reentrant=" + StringTools.startsWith(e, "HXRT-BORROW-REENTRANCY: borrow"));
+			Sys.println("namesType=" + (e.indexOf("Counter") >= 0));
+			Sys.println("namesGuardSite=" + (e.indexOf("guard_probe.rs:") >= 0));
+		}
+
+		// The guard was released while unwinding, so the object is usable again.
+		counter.value = counter.value + 10;
+		Sys.println("value=" + counter.value);
+	}
+}
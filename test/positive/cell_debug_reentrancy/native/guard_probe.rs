@@ -0,0 +1,9 @@
+//! Holds a write guard on a Haxe object across a Haxe callback, the way a faulty native helper
+//! would; `cell_debug` turns the resulting same-thread borrow into `HXRT-BORROW-REENTRANCY`.
+
+use hxrt::cell::{HxDynRef, HxRef};
+
+pub fn with_write_guard<T>(value: &HxRef<T>, callback: HxDynRef<dyn Fn() + Send + Sync>) {
+    let _guard = value.borrow_mut();
+    callback();
+}
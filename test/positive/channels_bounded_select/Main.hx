@@ -0,0 +1,60 @@
+import rust.DurationTools;
+import rust.HxRef;
+import rust.concurrent.Channel;
+import rust.concurrent.Channels;
+import rust.concurrent.Task;
+import rust.concurrent.Tasks;
+
+class Main {
+	static function main():Void {
+		var bounded:HxRef<Channel<Int>> = Channels.bounded(2);
+		Sys.println("trySend=" + Channels.trySend(bounded, 1) + "," + Channels.trySend(bounded, 2) + "," + Channels.trySend(bounded, 3));
+		Sys.println("sendTimeoutWhenFull=" + Channels.sendTimeout(bounded, 3, DurationTools.fromMillis(20)));
+		Sys.println("length=" + Channels.length(bounded));
+
+		// A sender blocked on the full channel resumes once a value is taken.
+		var senderChannel = bounded;
+		var sender:HxRef<Task<Int>> = Tasks.spawn(() -> {
+			Channels.send(senderChannel, 4);
+			return 4;
+		});
+		Sys.println("recv=" + Channels.recv(bounded));
+		Sys.println("blockedSender=" + Tasks.join(sender) + " length=" + Channels.length(bounded));
+
+		Channels.close(bounded);
+		Sys.println("closed=" + Channels.isClosed(bounded));
+		Sys.println("drain=" + Channels.recv(bounded) + "," + Channels.recv(bounded));
+		try {
+			Channels.recv(bounded);
+			Sys.println("recvAfterClose=value");
+		} catch (e:String) {
+			Sys.println("recvAfterClose=" + StringTools.startsWith(e, "HXRT-CHANNEL-CLOSED"));
+		}
+		try {
+			Channels.send(bounded, 5);
+			Sys.println("sendAfterClose=sent");
+		} catch (e:String) {
+			Sys.println("sendAfterClose=" + StringTools.startsWith(e, "HXRT-CHANNEL-CLOSED"));
+		}
+
+		var first:HxRef<Channel<String>> = Channels.create();
+		var second:HxRef<Channel<String>> = Channels.create();
+		var timedOut = switch (Channels.recvTimeout(first, DurationTools.fromMillis(20))) {
+			case None: true;
+			case Some(_): false;
+		};
+		Sys.println("recvTimeoutEmpty=" + timedOut);
+
+		Channels.send(second, "from-second");
+		var channels = [first, second];
+		var ready = Channels.select(channels);
+		var value = switch (Channels.tryRecv(second)) {
+			case Some(text): text;
+			case None: "none";
+		};
+		Sys.println("select=" + ready + " value=" + value);
+		Sys.println("selectTimeout=" + Channels.selectTimeout(channels, DurationTools.fromMillis(20)));
+		Channels.close(first);
+		Sys.println("selectClosed=" + Channels.select(channels));
+	}
+}
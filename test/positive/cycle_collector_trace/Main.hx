@@ -0,0 +1,39 @@
+import rust.CycleCollector;
+
+class Parent {
+	public final name:String;
+	public final children:Array<Child> = [];
+
+	public function new(name:String) {
+		this.name = name;
+	}
+}
+
+class Child {
+	public final parent:Parent;
+
+	public function new(parent:Parent) {
+		this.parent = parent;
+	}
+}
+
+class Main {
+	static function buildGarbageTree():Void {
+		var root = new Parent("root");
+		for (_ in 0...3) {
+			root.children.push(new Child(root));
+		}
+	}
+
+	static function main():Void {
+		var before = CycleCollector.trackedCount();
+		buildGarbageTree();
+		var kept = new Parent("kept");
+		kept.children.push(new Child(kept));
+
+		Sys.println("tracked=" + (CycleCollector.trackedCount() - before));
+		Sys.println("freed=" + CycleCollector.collect());
+		Sys.println("tracked after collect=" + (CycleCollector.trackedCount() - before));
+		Sys.println("kept children=" + kept.children.length + " report empty=" + (CycleCollector.report() == ""));
+	}
+}
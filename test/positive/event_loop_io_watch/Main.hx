@@ -0,0 +1,65 @@
+import haxe.io.Eof;
+import sys.io.Process;
+import sys.net.Host;
+import sys.net.Socket;
+import sys.thread.EventLoop;
+import sys.thread.EventHandler;
+import sys.thread.Thread;
+
+/**
+	`Socket.watch` and `Process.watchStdout` drive callbacks from the main thread's `EventLoop`:
+	- a socket watch fires once a queued `run` event has written to the peer, and cancelling it
+	  from the callback lets `loop()` return;
+	- a stdout watch keeps firing (level-style) until the child's pipe reports end of file.
+**/
+class Main {
+	static function main():Void {
+		final events = Thread.current().events;
+		socketWatch(events);
+		processWatch(events);
+	}
+
+	static function socketWatch(events:EventLoop):Void {
+		final server = new Socket();
+		server.bind(new Host("127.0.0.1"), 0);
+		server.listen(1);
+		final client = new Socket();
+		client.connect(new Host("127.0.0.1"), server.host().port);
+		final connection = server.accept();
+
+		final order:Array<String> = [];
+		final handlers:Array<EventHandler> = [];
+		handlers.push(connection.watch(events, true, false, () -> {
+			order.push("read:" + connection.input.readLine());
+			events.cancel(handlers[0]);
+		}));
+		events.run(() -> {
+			order.push("write");
+			client.output.writeString("hello\n");
+			client.output.flush();
+		});
+		events.loop();
+		Sys.println("socket " + order.join(" "));
+
+		client.close();
+		connection.close();
+		server.close();
+	}
+
+	static function processWatch(events:EventLoop):Void {
+		final process = new Process("sh", ["-c", "printf 'one\\ntwo\\n'"]);
+		final lines:Array<String> = [];
+		final handlers:Array<EventHandler> = [];
+		handlers.push(process.watchStdout(events, () -> {
+			try {
+				lines.push(process.stdout.readLine());
+			} catch (e:Eof) {
+				lines.push("eof");
+				events.cancel(handlers[0]);
+			}
+		}));
+		events.loop();
+		Sys.println("process " + lines.join(" ") + " exit=" + process.exitCode());
+		process.close();
+	}
+}
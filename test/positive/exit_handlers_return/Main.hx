@@ -0,0 +1,15 @@
+import rust.process.ExitHandlers;
+
+/**
+	Exit handlers run newest first once `main` returns normally, after all of `main`'s own output;
+	a removed handler does not run.
+**/
+class Main {
+	static function main():Void {
+		ExitHandlers.add(() -> Sys.println("handler registered first"));
+		final removed = ExitHandlers.add(() -> Sys.println("removed handler ran"));
+		ExitHandlers.add(() -> Sys.println("handler registered second"));
+		Sys.println("removed=" + ExitHandlers.remove(removed) + " again=" + ExitHandlers.remove(removed));
+		Sys.println("main returns");
+	}
+}
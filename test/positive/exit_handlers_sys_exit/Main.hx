@@ -0,0 +1,20 @@
+import rust.process.ExitHandlers;
+
+/**
+	`Sys.exit` from a nested call runs the exit handlers newest first before the process ends; a
+	throwing handler is reported on stderr and the older handlers still run.
+**/
+class Main {
+	static function main():Void {
+		ExitHandlers.add(() -> Sys.println("handler registered first"));
+		ExitHandlers.add(() -> throw "handler failed");
+		ExitHandlers.add(() -> Sys.println("handler registered third"));
+		stop();
+		Sys.println("after exit");
+	}
+
+	static function stop():Void {
+		Sys.println("calling Sys.exit");
+		Sys.exit(0);
+	}
+}
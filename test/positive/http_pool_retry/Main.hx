@@ -0,0 +1,92 @@
+import sys.Http;
+import sys.net.Host;
+import sys.net.Socket;
+import sys.thread.Mutex;
+import sys.thread.Thread;
+
+/**
+	Keep-alive retry rules of the pooled `sys.Http` client against a loopback server that logs every
+	request it receives:
+	- a request that times out on a reused connection is reported, not sent again;
+	- a GET that the server drops unanswered on a reused connection is sent again on a new one;
+	- a POST dropped the same way is reported, not sent again.
+**/
+class Main {
+	static final received:Array<String> = [];
+	static final receivedLock = new Mutex();
+
+	static function main():Void {
+		var server = new Socket();
+		server.bind(new Host("127.0.0.1"), 0);
+		server.listen(8);
+		var port = server.host().port;
+		Thread.create(() -> {
+			while (true) {
+				var client = server.accept();
+				Thread.create(() -> serveConnection(client));
+			}
+		});
+
+		var base = "http://127.0.0.1:" + port;
+		run("first", base + "/ok", false);
+		run("stalled", base + "/stall", false);
+		run("before dropped get", base + "/drop-next", false);
+		run("dropped get", base + "/ok", false);
+		run("before dropped post", base + "/drop-next", false);
+		run("dropped post", base + "/ok", true);
+
+		receivedLock.acquire();
+		Sys.println("server saw " + received.length + ": " + received.join(" "));
+		receivedLock.release();
+	}
+
+	static function run(label:String, url:String, post:Bool):Void {
+		var http = new Http(url);
+		http.cnxTimeout = 0.5;
+		if (post)
+			http.setPostData("data");
+		var result = "";
+		http.onData = data -> result = "ok " + data;
+		http.onError = _ -> result = "error";
+		http.request(post);
+		Sys.println(label + ": " + result);
+	}
+
+	static function serveConnection(client:Socket):Void {
+		var dropNext = false;
+		try {
+			while (true) {
+				var requestLine = client.input.readLine();
+				var length = 0;
+				while (true) {
+					var line = client.input.readLine();
+					if (line == "")
+						break;
+					if (StringTools.startsWith(line.toLowerCase(), "content-length:")) {
+						var parsed = Std.parseInt(StringTools.trim(line.substr(15)));
+						length = parsed == null ? 0 : parsed;
+					}
+				}
+				if (length > 0)
+					client.input.read(length);
+
+				var parts = requestLine.split(" ");
+				var path = parts[1];
+				receivedLock.acquire();
+				received.push((dropNext ? "dropped:" : "") + parts[0] + path);
+				receivedLock.release();
+
+				// Closing right after reading a request looks like an idle-timeout close racing it.
+				if (dropNext)
+					break;
+				if (path == "/stall") {
+					Sys.sleep(1.5);
+					break;
+				}
+				client.output.writeString("HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nhi");
+				dropNext = path == "/drop-next";
+			}
+		} catch (_:Dynamic) {}
+		client.close();
+	}
+}
@@ -0,0 +1,105 @@
+import haxe.io.Bytes;
+import sys.Http;
+import sys.net.Host;
+import sys.net.Socket;
+import sys.thread.Lock;
+import sys.thread.Thread;
+
+/**
+	Redirect state of `sys.Http` against scripted loopback servers that log every request:
+	- a relative `Location` resolves against a scheme-less request URL as plain HTTP;
+	- a `303` after `POST` follows with a body-less `GET` that leaves out the caller's
+	  `Content-Type`;
+	- the same `Http` sends its configured body again on the next request.
+**/
+class Main {
+	static function main():Void {
+		final schemelessLog:Array<String> = [];
+		final schemelessDone = new Lock();
+		final schemelessPort = startScriptedServer([
+			reply("302 Found", "Location: next?x=1", ""),
+			reply("200 OK", null, "relative-ok")
+		], schemelessLog, schemelessDone);
+		final schemeless = new Http("127.0.0.1:" + schemelessPort + "/dir/start");
+		final schemelessData = run(schemeless, false);
+		schemelessDone.wait();
+		Sys.println("schemeless requests=" + schemelessLog.join("|") + " data=" + schemelessData + " finalUrl="
+			+ (schemeless.finalUrl == "http://127.0.0.1:" + schemelessPort + "/dir/next?x=1"));
+
+		final formLog:Array<String> = [];
+		final formDone = new Lock();
+		final formPort = startScriptedServer([
+			reply("303 See Other", "Location: /done", ""),
+			reply("200 OK", null, "done"),
+			reply("200 OK", null, "again")
+		], formLog, formDone);
+		final form = new Http("http://127.0.0.1:" + formPort + "/form");
+		form.setHeader("Content-Type", "text/plain");
+		form.setPostData("a=1");
+		final first = run(form, true);
+		Sys.println("post requests=" + formLog.join("|") + " data=" + first);
+		final second = run(form, true);
+		formDone.wait();
+		Sys.println("repeat request=" + formLog[2] + " data=" + second);
+	}
+
+	static function run(http:Http, post:Bool):String {
+		var result = "";
+		http.onData = data -> result = data;
+		http.onError = msg -> result = "error " + msg;
+		http.request(post);
+		return result;
+	}
+
+	/** Answers one connection per entry of `replies`, in order, then releases `done`. **/
+	static function startScriptedServer(replies:Array<String>, log:Array<String>, done:Lock):Int {
+		final server = new Socket();
+		server.bind(new Host("127.0.0.1"), 0);
+		server.listen(replies.length);
+		final port = server.host().port;
+		Thread.create(() -> {
+			for (response in replies) {
+				final client = server.accept();
+				log.push(readRequest(client));
+				client.output.writeString(response);
+				client.close();
+			}
+			server.close();
+			done.release();
+		});
+		return port;
+	}
+
+	static function readRequest(client:Socket):String {
+		final requestLine = client.input.readLine();
+		var entry = requestLine.substr(0, requestLine.lastIndexOf(" "));
+		var length = 0;
+		while (true) {
+			final line = client.input.readLine();
+			if (line == "")
+				break;
+			final sep = line.indexOf(":");
+			if (sep < 0)
+				continue;
+			final name = line.substr(0, sep).toLowerCase();
+			final value = StringTools.trim(line.substr(sep + 1));
+			if (name == "content-length") {
+				final parsed = Std.parseInt(value);
+				length = parsed == null ? 0 : parsed;
+			} else if (name == "content-type") {
+				entry += " ct=" + value;
+			}
+		}
+		if (length > 0) {
+			final body = Bytes.alloc(length);
+			client.input.readFullBytes(body, 0, length);
+			entry += " body=" + body.toString();
+		}
+		return entry;
+	}
+
+	static function reply(status:String, header:Null<String>, body:String):String {
+		return "HTTP/1.1 " + status + "\r\n" + (header == null ? "" : header + "\r\n") + "Content-Length: " + body.length
+			+ "\r\nConnection: close\r\n\r\n" + body;
+	}
+}
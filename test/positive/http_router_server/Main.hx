@@ -0,0 +1,58 @@
+import rust.http.Request;
+import rust.http.Response;
+import rust.http.Router;
+import rust.http.Server;
+import sys.net.Host;
+import sys.net.Socket;
+
+class Main {
+	static function main():Void {
+		var router = new Router()
+			.get("/hello", request -> Response.text("hello"))
+			.post("/hello", request -> Response.text("posted " + request.bodyString()))
+			.get("/static/*", request -> Response.text("static " + request.path()))
+			.delete("/items/*", request -> Response.text("deleted " + request.path()));
+		var server = Server.bind("127.0.0.1", 0);
+		server.start(router.handle);
+
+		send(server.port, "GET", "/hello", null);
+		send(server.port, "POST", "/hello", "x");
+		send(server.port, "HEAD", "/hello", null);
+		send(server.port, "GET", "/static/css/site.css", null);
+		send(server.port, "PUT", "/hello", "x");
+		send(server.port, "GET", "/items/3", null);
+		send(server.port, "DELETE", "/items/3", null);
+		send(server.port, "GET", "/missing", null);
+
+		server.stop();
+		Sys.println("running=" + server.isRunning());
+	}
+
+	static function send(port:Int, method:String, path:String, body:Null<String>):Void {
+		var client = new Socket();
+		client.connect(new Host("127.0.0.1"), port);
+		var request = method + " " + path + " HTTP/1.1\r\nHost: 127.0.0.1\r\nConnection: close\r\n";
+		if (body != null) {
+			request += "Content-Length: " + body.length + "\r\n";
+		}
+		client.output.writeString(request + "\r\n" + (body == null ? "" : body));
+		var response = client.input.readAll().toString();
+		client.close();
+
+		var split = response.indexOf("\r\n\r\n");
+		var lines = response.substr(0, split).split("\r\n");
+		var status = lines[0].substr(9, 3);
+		var allow = "";
+		var length = "";
+		for (line in lines) {
+			var lower = line.toLowerCase();
+			if (StringTools.startsWith(lower, "allow: ")) {
+				allow = " allow=" + line.substr(7);
+			}
+			if (StringTools.startsWith(lower, "content-length: ")) {
+				length = line.substr(16);
+			}
+		}
+		Sys.println(method + " " + path + " -> " + status + allow + " length=" + length + " body=" + response.substr(split + 4));
+	}
+}
@@ -0,0 +1,107 @@
+import rust.Result;
+import rust.net.NativeUnix;
+import rust.net.UnixListener;
+import rust.net.UnixStream;
+
+class Main {
+	static inline var PATH = "metal_native_unix.sock";
+
+	static function main() {
+		switch (NativeUnix.bind(PATH)) {
+			case Ok(listener):
+				checkPath(listener);
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function checkPath(listener:UnixListener) {
+		switch (listener.localPath()) {
+			case Ok(path):
+				if (path != PATH) {
+					fail();
+				}
+				connectAndExchange(listener);
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function connectAndExchange(listener:UnixListener) {
+		switch (NativeUnix.connect(PATH)) {
+			case Ok(client):
+				acceptAndExchange(listener, client);
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function acceptAndExchange(listener:UnixListener, client:UnixStream) {
+		switch (listener.accept()) {
+			case Ok(server):
+				clientToServer(client, server);
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function clientToServer(client:UnixStream, server:UnixStream) {
+		switch (server.setReadTimeout(5.0)) {
+			case Ok(_):
+			case Err(_):
+				fail();
+		}
+
+		switch (client.writeUtf8AndShutdownWrite("unix-client-to-server")) {
+			case Ok(wrote):
+				if (!wrote) {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+
+		switch (server.readToString()) {
+			case Ok(message):
+				if (message != "unix-client-to-server") {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+
+		serverToClient(client, server);
+	}
+
+	static function serverToClient(client:UnixStream, server:UnixStream) {
+		switch (server.writeUtf8AndShutdownWrite("unix-server-to-client")) {
+			case Ok(wrote):
+				if (!wrote) {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+
+		switch (client.readToString()) {
+			case Ok(message):
+				if (message != "unix-server-to-client") {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function fail() {
+		trap(1);
+	}
+
+	static function trap(code:Int) {
+		var zero = code - code;
+		var trap = 1 % zero;
+		if (trap == -1) {
+			return;
+		}
+	}
+}
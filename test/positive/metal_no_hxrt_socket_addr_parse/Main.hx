@@ -0,0 +1,196 @@
+import rust.Option;
+import rust.Result;
+import rust.Vec;
+import rust.net.Dns;
+import rust.net.NativeTcp;
+import rust.net.SocketAddr;
+import rust.net.SocketError;
+import rust.net.TcpListener;
+import rust.net.TcpStream;
+
+class Main {
+	static function main() {
+		inspectParsedIpv6();
+		inspectRejectedInputs();
+		inspectResolve();
+		bindAnyInterface();
+		tcpRoundTrip();
+	}
+
+	static function inspectParsedIpv6() {
+		switch (SocketAddr.parse("[::1]:8080")) {
+			case Ok(addr):
+				if (!addr.isIpv6() || addr.isIpv4() || addr.ip() != "::1" || addr.port() != 8080) {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+		switch (SocketAddr.ipv6("fe80::1", 443)) {
+			case Ok(addr):
+				if (!addr.isIpv6() || addr.ip() != "fe80::1" || addr.port() != 443) {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function inspectRejectedInputs() {
+		expectInvalid(SocketAddr.parse("localhost:80"));
+		expectInvalid(SocketAddr.parse("127.0.0.1"));
+		expectInvalid(SocketAddr.ipv4("::1", 80));
+		expectInvalid(SocketAddr.ipv4("127.0.0.1", 70000));
+		expectInvalid(SocketAddr.ipv6("127.0.0.1", 80));
+		expectInvalid(SocketAddr.ipv6("::1", -1));
+	}
+
+	static function expectInvalid(result:Result<SocketAddr, SocketError>) {
+		switch (result) {
+			case Ok(_):
+				fail();
+			case Err(error):
+				if (!error.isInvalidInput() || error.isResolve() || error.isIo()) {
+					fail();
+				}
+		}
+	}
+
+	static function inspectResolve() {
+		switch (Dns.resolve("localhost", 8080)) {
+			case Ok(addrs):
+				var first:Option<SocketAddr> = addrs.pop();
+				switch (first) {
+					case Some(addr):
+						if (addr.port() != 8080) {
+							fail();
+						}
+					case None:
+						fail();
+				}
+			case Err(_):
+				fail();
+		}
+		switch (Dns.resolve("127.0.0.1", 80)) {
+			case Ok(addrs):
+				assertSingleLoopback(addrs);
+			case Err(_):
+				fail();
+		}
+		switch (Dns.resolve("localhost", 70000)) {
+			case Ok(_):
+				fail();
+			case Err(error):
+				if (!error.isInvalidInput() || error.isResolve()) {
+					fail();
+				}
+		}
+	}
+
+	static function assertSingleLoopback(addrs:Vec<SocketAddr>) {
+		var only:Option<SocketAddr> = addrs.pop();
+		switch (only) {
+			case Some(addr):
+				if (!addr.isIpv4() || addr.ip() != "127.0.0.1" || addr.port() != 80) {
+					fail();
+				}
+			case None:
+				fail();
+		}
+		switch (addrs.pop()) {
+			case Some(_):
+				fail();
+			case None:
+		}
+	}
+
+	static function bindAnyInterface() {
+		switch (SocketAddr.ipv4("0.0.0.0", 0)) {
+			case Ok(addr):
+				switch (NativeTcp.bindDetailed(addr)) {
+					case Ok(listener):
+						switch (listener.localAddrDetailed()) {
+							case Ok(bound):
+								if (bound.ip() != "0.0.0.0" || bound.port() <= 0) {
+									fail();
+								}
+							case Err(_):
+								fail();
+						}
+					case Err(_):
+						fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function tcpRoundTrip() {
+		switch (SocketAddr.parse("127.0.0.1:0")) {
+			case Ok(bindAddr):
+				switch (NativeTcp.bindDetailed(bindAddr)) {
+					case Ok(listener):
+						connectTcp(listener);
+					case Err(_):
+						fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function connectTcp(listener:TcpListener) {
+		switch (listener.localAddrDetailed()) {
+			case Ok(bound):
+				switch (SocketAddr.ipv4("127.0.0.1", bound.port())) {
+					case Ok(addr):
+						switch (NativeTcp.connectDetailed(addr)) {
+							case Ok(client):
+								acceptTcp(listener, client);
+							case Err(_):
+								fail();
+						}
+					case Err(_):
+						fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function acceptTcp(listener:TcpListener, client:TcpStream) {
+		switch (listener.acceptDetailed()) {
+			case Ok(server):
+				switch (client.writeUtf8AndShutdownWriteDetailed("socket-addr-parse")) {
+					case Ok(wrote):
+						if (!wrote) {
+							fail();
+						}
+					case Err(_):
+						fail();
+				}
+				switch (server.readToStringDetailed()) {
+					case Ok(message):
+						if (message != "socket-addr-parse") {
+							fail();
+						}
+					case Err(_):
+						fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function fail() {
+		trap(1);
+	}
+
+	static function trap(code:Int) {
+		var zero = code - code;
+		var trap = 1 % zero;
+		if (trap == -1) {
+			return;
+		}
+	}
+}
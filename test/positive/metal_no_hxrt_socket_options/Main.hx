@@ -0,0 +1,157 @@
+import rust.Result;
+import rust.net.NativeTcp;
+import rust.net.SocketAddr;
+import rust.net.SocketError;
+import rust.net.SocketOptions;
+import rust.net.TcpListener;
+import rust.net.TcpStream;
+import rust.net.UdpSocket;
+
+class Main {
+	static function main() {
+		rejectInvalidValues();
+		switch (SocketAddr.parse("127.0.0.1:0")) {
+			case Ok(addr):
+				tcpWithOptions(addr);
+				udpWithOptions(addr);
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function rejectInvalidValues() {
+		var options = new SocketOptions();
+		options.withTtl(0);
+		switch (SocketAddr.parse("127.0.0.1:0")) {
+			case Ok(addr):
+				expectInvalid(options.bindUdp(addr));
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function tcpWithOptions(addr:SocketAddr) {
+		var options = new SocketOptions();
+		options.withReuseAddress(true);
+		options.withKeepAlive(true, 30.0, 5.0);
+		options.withLinger(2.0);
+		options.withTtl(32);
+		switch (options.bindTcp(addr)) {
+			case Ok(listener):
+				checkListener(listener);
+				connectAndTune(listener);
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function checkListener(listener:TcpListener) {
+		switch (SocketOptions.readFromListener(listener)) {
+			case Ok(current):
+				if (!current.reuseAddress() || !current.keepAlive() || current.linger() != 2.0 || current.ttl() != 32) {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function connectAndTune(listener:TcpListener) {
+		switch (listener.localAddrDetailed()) {
+			case Ok(local):
+				switch (NativeTcp.connectDetailed(local)) {
+					case Ok(client):
+						acceptAndTune(listener, client);
+					case Err(_):
+						fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function acceptAndTune(listener:TcpListener, client:TcpStream) {
+		switch (listener.acceptDetailed()) {
+			case Ok(server):
+				var tuning = new SocketOptions();
+				tuning.withKeepAlive(true, 0.0, 0.0);
+				tuning.withRecvBufferSize(32 * 1024);
+				switch (tuning.applyToStream(server)) {
+					case Ok(applied):
+						if (!applied) {
+							fail();
+						}
+					case Err(_):
+						fail();
+				}
+				checkStream(server);
+				switch (client.writeUtf8("ping")) {
+					case Ok(wrote):
+						if (wrote != 4) {
+							fail();
+						}
+					case Err(_):
+						fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function checkStream(stream:TcpStream) {
+		switch (SocketOptions.readFromStream(stream)) {
+			case Ok(current):
+				if (!current.keepAlive() || current.recvBufferSize() < 32 * 1024) {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function udpWithOptions(addr:SocketAddr) {
+		var options = new SocketOptions();
+		options.withSendBufferSize(64 * 1024);
+		options.withTtl(8);
+		switch (options.bindUdp(addr)) {
+			case Ok(socket):
+				checkUdp(socket);
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function checkUdp(socket:UdpSocket) {
+		switch (SocketOptions.readFromUdp(socket)) {
+			case Ok(current):
+				if (current.sendBufferSize() < 64 * 1024 || current.ttl() != 8) {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function expectInvalid<T>(result:Result<T, SocketError>) {
+		switch (result) {
+			case Ok(_):
+				fail();
+			case Err(error):
+				if (!error.isInvalidInput()) {
+					fail();
+				}
+		}
+	}
+
+	static function fail() {
+		trap(1);
+	}
+
+	static function trap(code:Int) {
+		var zero = code - code;
+		var trap = 1 % zero;
+		if (trap == -1) {
+			return;
+		}
+	}
+}
@@ -0,0 +1,164 @@
+import rust.Result;
+import rust.U8;
+import rust.Vec;
+import rust.net.NativeTcp;
+import rust.net.Shutdown;
+import rust.net.SocketError;
+import rust.net.TcpListener;
+import rust.net.TcpStream;
+
+class Main {
+	static function main() {
+		switch (NativeTcp.bindLocalhostDetailed(0)) {
+			case Ok(listener):
+				switch (listener.localPortDetailed()) {
+					case Ok(port):
+						connect(listener, port);
+					case Err(_):
+						fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function connect(listener:TcpListener, port:Int) {
+		switch (NativeTcp.connectLocalhostDetailed(port)) {
+			case Ok(client):
+				switch (listener.acceptDetailed()) {
+					case Ok(server):
+						configure(client, server, port);
+					case Err(_):
+						fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function configure(client:TcpStream, server:TcpStream, port:Int) {
+		expectOk(client.setNodelay(true));
+		expectOk(server.setReadTimeout(5.0));
+		expectOk(client.setWriteTimeout(5.0));
+		switch (client.peerAddr()) {
+			case Ok(addr):
+				if (addr.port() != port) {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+		requestResponse(client, server);
+	}
+
+	static function requestResponse(client:TcpStream, server:TcpStream) {
+		for (_ in 0...2) {
+			switch (client.writeUtf8("ping")) {
+				case Ok(count):
+					if (count != 4) {
+						fail();
+					}
+				case Err(_):
+					fail();
+			}
+			expectOk(client.flush());
+
+			switch (server.readExact(4)) {
+				case Ok(request):
+					if (request.isEmpty()) {
+						fail();
+					}
+					echo(server, request);
+				case Err(_):
+					fail();
+			}
+
+			switch (client.readExact(4)) {
+				case Ok(reply):
+					if (reply.isEmpty()) {
+						fail();
+					}
+				case Err(_):
+					fail();
+			}
+		}
+
+		writeHaxeBuiltBytes(client, server);
+
+		expectOk(client.shutdown(Shutdown.Write));
+		switch (server.read(16)) {
+			case Ok(rest):
+				if (!rest.isEmpty()) {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+		expectOk(server.shutdown(Shutdown.Both));
+	}
+
+	static function writeHaxeBuiltBytes(client:TcpStream, server:TcpStream) {
+		var payload = new Vec<Int>();
+		payload.push(0);
+		payload.push(127);
+		payload.push(255);
+		switch (client.writeBytes(payload)) {
+			case Ok(count):
+				if (count != 3) {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+		switch (server.readExact(3)) {
+			case Ok(received):
+				if (received.isEmpty()) {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+
+		var outOfRange = new Vec<Int>();
+		outOfRange.push(256);
+		switch (client.writeBytes(outOfRange)) {
+			case Ok(_):
+				fail();
+			case Err(error):
+				if (!error.isInvalidInput()) {
+					fail();
+				}
+		}
+	}
+
+	static function echo(server:TcpStream, request:Vec<U8>) {
+		switch (server.write(request)) {
+			case Ok(count):
+				if (count != 4) {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function expectOk(result:Result<Bool, SocketError>) {
+		switch (result) {
+			case Ok(_):
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function fail() {
+		trap(1);
+	}
+
+	static function trap(code:Int) {
+		var zero = code - code;
+		var trap = 1 % zero;
+		if (trap == -1) {
+			return;
+		}
+	}
+}
@@ -0,0 +1,137 @@
+import rust.Result;
+import rust.net.NativeUdp;
+import rust.net.SocketAddr;
+import rust.net.SocketError;
+import rust.net.UdpDatagram;
+import rust.net.UdpSocket;
+
+class Main {
+	static inline var GROUP = "239.255.77.79";
+
+	static function main() {
+		switch (SocketAddr.parse("0.0.0.0:0")) {
+			case Ok(any):
+				switch (NativeUdp.bindDetailed(any)) {
+					case Ok(receiver):
+						join(receiver);
+					case Err(_):
+						fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function join(receiver:UdpSocket) {
+		expectInvalid(receiver.joinMulticastV4("10.0.0.1", "127.0.0.1"));
+		expectInvalid(receiver.joinMulticastV6("ff02::1", -1));
+		expectOk(receiver.joinMulticastV4(GROUP, "127.0.0.1"));
+		switch (receiver.localPortDetailed()) {
+			case Ok(port):
+				send(receiver, port);
+			case Err(_):
+				fail();
+		}
+		expectOk(receiver.leaveMulticastV4(GROUP, "127.0.0.1"));
+	}
+
+	static function send(receiver:UdpSocket, port:Int) {
+		// A sender bound to 127.0.0.1 routes group traffic over the loopback interface.
+		switch (NativeUdp.bindLocalhostDetailed(0)) {
+			case Ok(sender):
+				expectOk(sender.setMulticastTtlV4(1));
+				expectOk(sender.setMulticastLoopV4(true));
+				switch (SocketAddr.ipv4(GROUP, port)) {
+					case Ok(group):
+						switch (sender.sendUtf8ToDetailed("beacon", group)) {
+							case Ok(count):
+								if (count != 6) {
+									fail();
+								}
+							case Err(_):
+								fail();
+						}
+					case Err(_):
+						fail();
+				}
+				receive(receiver, sender);
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function receive(receiver:UdpSocket, sender:UdpSocket) {
+		switch (receiver.recvFrom(64)) {
+			case Ok(datagram):
+				checkDatagram(datagram, sender);
+				reply(receiver, datagram);
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function checkDatagram(datagram:UdpDatagram, sender:UdpSocket) {
+		switch (datagram.payloadUtf8()) {
+			case Ok(text):
+				if (text != "beacon") {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+		var from = datagram.sender();
+		if (!from.isIpv4() || from.ip() != "127.0.0.1") {
+			fail();
+		}
+		switch (sender.localPortDetailed()) {
+			case Ok(port):
+				if (from.port() != port) {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function reply(receiver:UdpSocket, datagram:UdpDatagram) {
+		switch (receiver.sendTo(datagram.payload(), datagram.sender())) {
+			case Ok(count):
+				if (count != 6) {
+					fail();
+				}
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function expectOk(result:Result<Bool, SocketError>) {
+		switch (result) {
+			case Ok(_):
+			case Err(_):
+				fail();
+		}
+	}
+
+	static function expectInvalid(result:Result<Bool, SocketError>) {
+		switch (result) {
+			case Ok(_):
+				fail();
+			case Err(error):
+				if (!error.isInvalidInput()) {
+					fail();
+				}
+		}
+	}
+
+	static function fail() {
+		trap(1);
+	}
+
+	static function trap(code:Int) {
+		var zero = code - code;
+		var trap = 1 % zero;
+		if (trap == -1) {
+			return;
+		}
+	}
+}
@@ -0,0 +1,64 @@
+import haxe.io.Bytes;
+import rust.net.NativeTcp;
+import rust.net.TcpListener;
+import rust.net.TcpStream;
+
+using rust.net.TcpStreamBytes;
+
+class Main {
+	static function main() {
+		switch (NativeTcp.bindLocalhostDetailed(0)) {
+			case Ok(listener):
+				switch (listener.localPortDetailed()) {
+					case Ok(port):
+						connect(listener, port);
+					case Err(_):
+						throw "bind port";
+				}
+			case Err(error):
+				throw error.message();
+		}
+	}
+
+	static function connect(listener:TcpListener, port:Int) {
+		switch (NativeTcp.connectLocalhostDetailed(port)) {
+			case Ok(client):
+				switch (listener.acceptDetailed()) {
+					case Ok(server):
+						exchange(client, server);
+					case Err(error):
+						throw error.message();
+				}
+			case Err(error):
+				throw error.message();
+		}
+	}
+
+	static function exchange(client:TcpStream, server:TcpStream) {
+		var request = Bytes.ofString("..hello..");
+		switch (client.writeFrom(request, 2, 5)) {
+			case Ok(count):
+				if (count != 5)
+					throw "short write";
+			case Err(error):
+				throw error.message();
+		}
+
+		var received = Bytes.alloc(8);
+		switch (server.readExactInto(received, 1, 5)) {
+			case Ok(_):
+				if (received.getString(1, 5) != "hello")
+					throw "unexpected payload";
+			case Err(error):
+				throw error.message();
+		}
+
+		switch (server.readInto(received, 6, 3)) {
+			case Ok(_):
+				throw "out-of-range read accepted";
+			case Err(error):
+				if (!error.isInvalidInput())
+					throw error.message();
+		}
+	}
+}
@@ -0,0 +1,41 @@
+import sys.net.Host;
+import sys.net.Socket;
+
+/**
+	IPv6 through `sys.net.Host`: `new Host("::1")` keeps the IPv6 address (with `ip == 0`) and
+	connects over IPv6, and a listener bound to `new Host("::")` accepts both IPv6 and IPv4 clients.
+**/
+class Main {
+	static function main():Void {
+		final loopback = new Host("::1");
+		Sys.println("host=" + loopback.toString() + " ip=" + loopback.ip);
+
+		final v6Server = new Socket();
+		v6Server.bind(loopback, 0);
+		v6Server.listen(1);
+		Sys.println("v6 " + exchange(v6Server, loopback, "over-v6"));
+		v6Server.close();
+
+		final dualStack = new Socket();
+		dualStack.bind(new Host("::"), 0);
+		dualStack.listen(2);
+		Sys.println("bound=" + dualStack.host().host.toString());
+		Sys.println("dual v6 " + exchange(dualStack, new Host("::1"), "from-v6"));
+		Sys.println("dual v4 " + exchange(dualStack, new Host("127.0.0.1"), "from-v4"));
+		dualStack.close();
+	}
+
+	/** Connect to `server` through `target`, send one line, and report what the server saw. **/
+	static function exchange(server:Socket, target:Host, message:String):String {
+		final client = new Socket();
+		client.connect(target, server.host().port);
+		final connection = server.accept();
+		client.output.writeString(message + "\n");
+		client.output.flush();
+		final received = connection.input.readLine();
+		final peer = connection.peer().host;
+		client.close();
+		connection.close();
+		return "peer=" + peer.toString() + " ipv4=" + (peer.ip != 0) + " received=" + received;
+	}
+}
@@ -0,0 +1,49 @@
+import haxe.atomic.AtomicInt;
+import rust.concurrent.Parallel;
+
+class Main {
+	static function main():Void {
+		// 200 elements span several chunks, so every operation runs on more than one thread.
+		final inputs:Array<Int> = [for (i in 1...201) i];
+
+		final squares = Parallel.map(inputs, n -> n * n);
+		Sys.println("map length=" + squares.length + " head=" + squares.slice(0, 5).join(",") + " last=" + squares[199]);
+
+		final evens = Parallel.filter(inputs, n -> n % 2 == 0);
+		Sys.println("filter length=" + evens.length + " head=" + evens.slice(0, 5).join(",") + " last=" + evens[99]);
+
+		Sys.println("reduce=" + Parallel.reduce(inputs, (a, b) -> a + b, 1000));
+
+		final visited = new AtomicInt(0);
+		final total = new AtomicInt(0);
+		Parallel.forEach(inputs, n -> {
+			visited.add(1);
+			total.add(n);
+		});
+		Sys.println("forEach visited=" + visited.load() + " total=" + total.load());
+
+		// Equal lengths keep their original relative order.
+		final words = ["ccc", "a", "bb", "d", "ee", "fff", "g"];
+		Parallel.sort(words, (a, b) -> a.length - b.length);
+		Sys.println("sort=" + words.join(","));
+
+		final numbers:Array<Int> = [for (i in 0...200) (i * 37) % 200];
+		Parallel.sort(numbers, (a, b) -> a - b);
+		var ordered = true;
+		for (i in 0...numbers.length)
+			if (numbers[i] != i)
+				ordered = false;
+		Sys.println("sortNumbers ordered=" + ordered);
+
+		try {
+			Parallel.map(inputs, n -> {
+				if (n == 150 || n == 50)
+					throw "bad " + n;
+				return n;
+			});
+			Sys.println("mapError=none");
+		} catch (e:String) {
+			Sys.println("mapError=" + e);
+		}
+	}
+}
@@ -0,0 +1,79 @@
+import rust.Runtime;
+import rust.RuntimeStats;
+import sys.thread.Thread;
+
+class Node {
+	public final value:Int;
+
+	public function new(value:Int) {
+		this.value = value;
+	}
+}
+
+/**
+	`-D rust_hxrt_stats` counters seen through `Runtime.stats()`: per-type allocations and live
+	objects, exception throws, and the calling thread's message and event queue depths. Counters are
+	Floats, so the case prints them through `Std.int`.
+**/
+class Main {
+	static function main():Void {
+		final before = Runtime.stats();
+
+		final kept:Array<Node> = [];
+		for (i in 0...100) {
+			final node = new Node(i);
+			if (i % 5 < 2)
+				kept.push(node);
+		}
+		final nodes = nodeStats(Runtime.stats());
+		Sys.println("Node allocations=" + Std.int(nodes.allocations) + " live=" + Std.int(nodes.live) + " kept=" + kept.length);
+
+		for (i in 0...3) {
+			try {
+				throw "counted " + i;
+			} catch (e:String) {}
+		}
+		final afterThrows = Runtime.stats();
+		Sys.println("throws=" + Std.int(afterThrows.throws - before.throws));
+		Sys.println("totals cover Node=" + (afterThrows.allocations - before.allocations >= 100));
+
+		final current = Thread.current();
+		current.sendMessage("first");
+		current.sendMessage("second");
+		current.events.run(() -> {});
+		final repeat = current.events.repeat(() -> {}, 60000);
+		final queues = threadStats(Runtime.stats());
+		Sys.println("thread messages=" + queues.messages + " events=" + queues.events + " regularEvents=" + queues.regularEvents);
+		current.events.cancel(repeat);
+		Thread.readMessage(false);
+		Thread.readMessage(false);
+
+		kept.resize(0);
+		Sys.println("Node live after clear=" + Std.int(nodeStats(Runtime.stats()).live));
+		Sys.println("json has types=" + (Runtime.statsJson().indexOf("\"types\":[") >= 0));
+	}
+
+	static function nodeStats(stats:RuntimeStats):RuntimeTypeStats {
+		for (type in stats.types)
+			if (StringTools.endsWith(type.name, "::Node"))
+				return type;
+		return {
+			name: "missing",
+			allocations: 0,
+			live: 0,
+			lockWaits: 0
+		};
+	}
+
+	static function threadStats(stats:RuntimeStats):RuntimeThreadStats {
+		for (thread in stats.threads)
+			if (thread.messages > 0)
+				return thread;
+		return {
+			id: -1,
+			messages: 0,
+			events: 0,
+			regularEvents: 0
+		};
+	}
+}
@@ -0,0 +1,52 @@
+import rust.DurationTools;
+import rust.HxRef;
+import rust.concurrent.Channel;
+import rust.concurrent.Channels;
+import rust.process.Signal;
+import rust.process.Signals;
+import sys.io.Process;
+import sys.thread.Thread;
+
+/**
+	`SIGHUP` sent to this process by a child shell (`kill -HUP $PPID`) reaches a `Channel`
+	subscription and then an `EventLoop` subscription instead of terminating the process.
+**/
+class Main {
+	static function main():Void {
+		Sys.println("supported=" + Signals.isSupported(Hangup));
+
+		final channel:HxRef<Channel<Signal>> = Channels.create();
+		final channelId = Signals.notify([Hangup], channel);
+		hangupSelf();
+		final received = switch (Channels.recvTimeout(channel, DurationTools.fromMillis(5000))) {
+			case Some(signal): signal == Hangup ? "hangup" : "signal " + (signal : Int);
+			case None: "timeout";
+		};
+		Sys.println("channel=" + received);
+
+		// Subscribe the loop before dropping the channel subscription so SIGHUP stays handled.
+		final events = Thread.current().events;
+		final delivered:Array<String> = [];
+		events.promise();
+		final loopId = Signals.listen([Hangup], events, signal -> {
+			delivered.push("hangup=" + (signal : Int));
+			events.runPromised(() -> {});
+		});
+		Signals.cancel(channelId);
+		hangupSelf();
+		events.loop();
+		Sys.println("eventLoop=" + delivered.join(","));
+		final channelQuiet = switch (Channels.tryRecv(channel)) {
+			case Some(_): false;
+			case None: true;
+		};
+		Sys.println("cancelledChannelQuiet=" + channelQuiet);
+		Signals.cancel(loopId);
+	}
+
+	static function hangupSelf():Void {
+		final kill = new Process("sh", ["-c", "kill -HUP $PPID"]);
+		kill.exitCode();
+		kill.close();
+	}
+}
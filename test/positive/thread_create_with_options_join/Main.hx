@@ -0,0 +1,31 @@
+import haxe.atomic.AtomicInt;
+import sys.thread.Thread;
+
+class Main {
+	static function main():Void {
+		var finished = new AtomicInt(0);
+
+		// No `joinable` field: threads from createWithOptions are joinable by default.
+		var named = Thread.createWithOptions(() -> {
+			Sys.sleep(0.01);
+			finished.add(1);
+		}, {name: "hx-joined"});
+		named.join();
+		Sys.println("joined=" + finished.load() + " alive=" + named.isAlive());
+
+		var detached = Thread.createWithOptions(() -> finished.add(1), {joinable: false});
+		try {
+			detached.join();
+			Sys.println("detached join returned");
+		} catch (error:String) {
+			Sys.println("detached join rejected=" + StringTools.startsWith(error, "HXRT-THREAD-NOT-JOINABLE"));
+		}
+
+		try {
+			named.join();
+			Sys.println("second join returned");
+		} catch (error:String) {
+			Sys.println("second join rejected=" + StringTools.startsWith(error, "HXRT-THREAD-NOT-JOINABLE"));
+		}
+	}
+}
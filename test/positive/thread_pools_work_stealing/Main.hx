@@ -0,0 +1,119 @@
+import rust.HxRef;
+import rust.concurrent.Task;
+import rust.concurrent.Tasks;
+import rust.concurrent.ThreadPools;
+import sys.thread.ElasticThreadPool;
+import sys.thread.FixedThreadPool;
+import sys.thread.Lock;
+import sys.thread.Mutex;
+import sys.thread.ThreadPoolException;
+
+class Main {
+	static function main():Void {
+		fixedPool();
+		elasticPool();
+		tasks();
+	}
+
+	static function fixedPool():Void {
+		final pool = new FixedThreadPool(1);
+		final started = new Lock();
+		final gate = new Lock();
+		final mutex = new Mutex();
+		final values:Array<Int> = [];
+
+		// Park the only worker so every later task stays queued.
+		final startedBlocker = started;
+		final gateBlocker = gate;
+		pool.run(() -> block(startedBlocker, gateBlocker));
+		started.wait();
+		pool.run(() -> throw "task failed");
+		for (i in 1...4) {
+			final mutexTask = mutex;
+			final valuesTask = values;
+			pool.run(() -> record(mutexTask, valuesTask, i));
+		}
+		Sys.println("fixedQueueDepth=" + ThreadPools.queueDepth(pool));
+		try {
+			ThreadPools.join(pool);
+			Sys.println("joinBeforeShutdown=joined");
+		} catch (e:ThreadPoolException) {
+			Sys.println("joinBeforeShutdown=rejected");
+		}
+
+		// The single worker runs the throwing task first and must survive it to run the rest.
+		gate.release();
+		pool.shutdown();
+		ThreadPools.join(pool);
+		Sys.println("fixedAfterThrow=" + values.join(","));
+		Sys.println("fixedQueueDepth=" + ThreadPools.queueDepth(pool) + " threadsCount=" + pool.threadsCount);
+		try {
+			pool.run(() -> {});
+			Sys.println("runAfterShutdown=accepted");
+		} catch (e:ThreadPoolException) {
+			Sys.println("runAfterShutdown=rejected");
+		}
+	}
+
+	static function elasticPool():Void {
+		final pool = new ElasticThreadPool(1, 5);
+		final started = new Lock();
+		final gate = new Lock();
+		final extra = new Lock();
+		final mutex = new Mutex();
+		final values:Array<Int> = [];
+
+		final startedBlocker = started;
+		final gateBlocker = gate;
+		pool.run(() -> block(startedBlocker, gateBlocker));
+		started.wait();
+		final mutexQueued = mutex;
+		final valuesQueued = values;
+		pool.run(() -> record(mutexQueued, valuesQueued, 10));
+		Sys.println("elasticQueueDepth=" + ThreadPools.queueDepth(pool) + " threadsCount=" + pool.threadsCount);
+
+		// Raising the cap lets the next run start a second worker beside the parked one.
+		pool.maxThreadsCount = 2;
+		final extraDone = extra;
+		pool.run(() -> extraDone.release());
+		Sys.println("elasticGrew=" + extra.wait(5.0) + " threadsCount=" + pool.threadsCount);
+		Sys.println("elasticQueued=" + values.join(","));
+
+		gate.release();
+		pool.shutdown();
+		ThreadPools.join(pool);
+		Sys.println("elasticJoined threadsCount=" + pool.threadsCount + " queueDepth=" + ThreadPools.queueDepth(pool));
+	}
+
+	static function tasks():Void {
+		final failing:HxRef<Task<Int>> = Tasks.spawn(() -> {
+			throw "task boom";
+			return 0;
+		});
+		try {
+			Tasks.join(failing);
+			Sys.println("taskError=none");
+		} catch (e:String) {
+			Sys.println("taskError=" + e);
+		}
+
+		final squares:Array<HxRef<Task<Int>>> = [];
+		for (i in 1...5)
+			squares.push(Tasks.spawn(() -> i * i));
+		final results:Array<Int> = [];
+		for (task in squares)
+			results.push(Tasks.join(task));
+		Sys.println("tasks=" + results.join(","));
+	}
+
+	static function block(started:Lock, gate:Lock):Void {
+		started.release();
+		gate.wait();
+	}
+
+	static function record(mutex:Mutex, values:Array<Int>, value:Int):Void {
+		mutex.acquire();
+		values.push(value);
+		mutex.release();
+	}
+}
@@ -0,0 +1,46 @@
+import rust.tracing.TraceLevel;
+import rust.tracing.Tracing;
+import sys.FileSystem;
+import sys.io.File;
+
+/**
+	`-D rust_hxrt_tracing` with a JSON-lines subscriber at `Debug`: events carry their fields and the
+	enclosing application span, events below the level are dropped, and a second subscriber is
+	rejected with `HXRT-TRACE-INIT`.
+**/
+class Main {
+	static function main():Void {
+		final path = "tracing_json_lines.jsonl";
+		if (FileSystem.exists(path))
+			FileSystem.deleteFile(path);
+		Tracing.useJsonLines(TraceLevel.Debug, path);
+
+		final batch = Tracing.span("import", ["batch" => "7"]);
+		Sys.println("span enabled=" + batch.isEnabled() + " traceSpan enabled=" + Tracing.span("noisy", null, TraceLevel.Trace).isEnabled());
+		batch.enter();
+		Tracing.info("loaded", ["rows" => "3"]);
+		Tracing.debug("detail");
+		Tracing.trace("dropped below level");
+		batch.exit();
+		Tracing.warn("outside");
+
+		try {
+			Tracing.useStderr();
+			Sys.println("secondSubscriber=installed");
+		} catch (e:String) {
+			Sys.println("secondSubscriber rejected=" + StringTools.startsWith(e, "HXRT-TRACE-INIT"));
+		}
+
+		final lines = File.getContent(path).split("\n").filter(line -> line.length > 0);
+		Sys.println("lines=" + lines.length);
+		Sys.println("loaded info=" + has(lines[0], '"level":"INFO"') + " message=" + has(lines[0], '"message":"loaded"')
+			+ " rows=" + has(lines[0], '"rows":"3"') + " span=" + has(lines[0], '"name":"import"') + " batch=" + has(lines[0], '"batch":"7"'));
+		Sys.println("detail debug=" + has(lines[1], '"level":"DEBUG"') + " span=" + has(lines[1], '"name":"import"'));
+		Sys.println("outside warn=" + has(lines[2], '"level":"WARN"') + " span=" + has(lines[2], '"name":"import"'));
+		FileSystem.deleteFile(path);
+	}
+
+	static function has(line:String, fragment:String):Bool {
+		return line.indexOf(fragment) >= 0;
+	}
+}
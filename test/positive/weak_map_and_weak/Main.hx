@@ -0,0 +1,40 @@
+import haxe.ds.WeakMap;
+import rust.Weak;
+
+class Key {
+	public final id:Int;
+
+	public function new(id:Int) {
+		this.id = id;
+	}
+}
+
+class Main {
+	static function addTemporaryEntry(map:WeakMap<Key, String>):Void {
+		map.set(new Key(2), "temporary");
+	}
+
+	static function weakToTemporary():Weak<Key> {
+		return new Weak(new Key(3));
+	}
+
+	static function main():Void {
+		var map = new WeakMap<Key, String>();
+		var kept = new Key(1);
+		map.set(kept, "kept");
+		addTemporaryEntry(map);
+
+		var liveKeys = [];
+		for (key in map.keys()) {
+			liveKeys.push(key.id);
+		}
+		Sys.println("live keys=" + liveKeys.join(","));
+		Sys.println("get=" + map.get(kept) + " exists=" + map.exists(kept));
+		Sys.println("remove=" + map.remove(kept) + " exists after remove=" + map.exists(kept));
+
+		var weakKept = new Weak(kept);
+		Sys.println("kept alive=" + weakKept.isAlive() + " id=" + weakKept.get().id);
+		var weakTemporary = weakToTemporary();
+		Sys.println("temporary alive=" + weakTemporary.isAlive() + " cleared=" + (weakTemporary.get() == null));
+	}
+}
@@ -183,6 +183,8 @@ function main() {
   assert.deepStrictEqual(rustAsyncTypes.map((entry) => entry.name), [
     'rust.async.Async',
     'rust.async.Future',
+    'rust.async.Stream',
+    'rust.async.Streams',
     'rust.async.Task',
     'rust.async.Tasks',
     'rust.async.TokioRuntime'
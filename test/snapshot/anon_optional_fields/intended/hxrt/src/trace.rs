@@ -0,0 +1,361 @@
+//! `hxrt::trace`
+//!
+//! `tracing` subscribers and application spans for `rust.tracing.Tracing`.
+//!
+//! Why
+//! - The runtime emitted nothing observable, so finding a slow `sys.Http` call or database query
+//!   meant adding timing code by hand.
+//!
+//! What
+//! - With the `tracing` feature, runtime operations open `tracing` spans: socket connect, accept,
+//!   read, write and TLS handshakes (`net.*`), `process.spawn`, file open/read/write (`fs.*`),
+//!   `sys.db` requests (`db.query`), and each event-loop callback (`event_loop.callback`).
+//! - `trace_use_stderr` / `trace_use_json_lines` install a process-wide subscriber printing
+//!   human-readable lines or one JSON object per line.
+//! - `trace_span` / `trace_event` let Haxe code add its own spans and events with runtime names and
+//!   string fields; `SpanHandle::enter` / `exit` scope them on the current thread.
+//!
+//! How
+//! - Runtime spans use `#[tracing::instrument]` behind `cfg_attr`, so builds without the feature
+//!   are unchanged. Without an installed subscriber (Haxe or host Rust code), spans cost one
+//!   disabled-callsite check.
+//! - `tracing` callsites are static, but Haxe span names and field keys are only known at run
+//!   time: each distinct (kind, level, name, field keys) combination gets one leaked callsite,
+//!   registered once and reused.
+//! - Entered spans are kept on a thread-local stack because `tracing` guards cannot leave the
+//!   thread that entered them.
+
+use crate::array::Array;
+use crate::{dynamic, exception};
+use parking_lot::Mutex as ParkMutex;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use tracing::callsite::{Callsite, Identifier};
+use tracing::field::{FieldSet, Value};
+use tracing::metadata::Kind;
+use tracing::subscriber::Interest;
+use tracing::{Level, Metadata, Span};
+
+const TRACE_INIT_ERROR: &str = "HXRT-TRACE-INIT";
+const TRACE_FIELDS_ERROR: &str = "HXRT-TRACE-FIELDS";
+
+/// Most fields one Haxe span or event may carry.
+const MAX_FIELDS: usize = 16;
+
+fn throw_msg(msg: String) -> ! {
+    exception::throw(dynamic::from(msg))
+}
+
+/// `rust.tracing.TraceLevel` (0 = trace ... 4 = error).
+fn level_from_haxe(level: i32) -> Level {
+    match level {
+        0 => Level::TRACE,
+        1 => Level::DEBUG,
+        2 => Level::INFO,
+        3 => Level::WARN,
+        _ => Level::ERROR,
+    }
+}
+
+// ---- Subscribers ------------------------------------------------------------
+
+fn install(result: Result<(), tracing::subscriber::SetGlobalDefaultError>) {
+    if let Err(error) = result {
+        throw_msg(format!("{TRACE_INIT_ERROR}: {error}"));
+    }
+}
+
+/// Print spans and events at `level` or above as human-readable lines on stderr.
+///
+/// Only one subscriber can be installed per process; a second call throws `HXRT-TRACE-INIT`.
+pub fn trace_use_stderr(level: i32) {
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(level_from_haxe(level))
+        .with_writer(std::io::stderr)
+        .with_ansi(false)
+        .finish();
+    install(tracing::subscriber::set_global_default(subscriber));
+}
+
+/// Write events at `level` or above as JSON lines (with their span context) to the file at
+/// `path`, appending, or to stderr when `path` is empty.
+pub fn trace_use_json_lines<P: AsRef<str>>(level: i32, path: P) {
+    let path = path.as_ref();
+    let builder = tracing_subscriber::fmt()
+        .json()
+        .with_max_level(level_from_haxe(level))
+        .with_current_span(true)
+        .with_span_list(true);
+    if path.is_empty() {
+        install(tracing::subscriber::set_global_default(
+            builder.with_writer(std::io::stderr).finish(),
+        ));
+        return;
+    }
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .unwrap_or_else(|error| throw_msg(format!("{TRACE_INIT_ERROR}: {path}: {error}")));
+    install(tracing::subscriber::set_global_default(
+        builder.with_writer(std::sync::Mutex::new(file)).finish(),
+    ));
+}
+
+// ---- Runtime-named callsites --------------------------------------------------
+
+struct HaxeCallsite {
+    metadata: OnceLock<Metadata<'static>>,
+}
+
+impl Callsite for HaxeCallsite {
+    fn set_interest(&self, _interest: Interest) {}
+
+    fn metadata(&self) -> &Metadata<'static> {
+        self.metadata
+            .get()
+            .expect("hxrt::trace callsite used before its metadata was set")
+    }
+}
+
+type CallsiteKey = (bool, i32, String, Vec<String>);
+
+static CALLSITES: OnceLock<ParkMutex<HashMap<CallsiteKey, &'static HaxeCallsite>>> =
+    OnceLock::new();
+
+fn leak_str(value: &str) -> &'static str {
+    Box::leak(value.to_string().into_boxed_str())
+}
+
+/// The registered callsite for one span name or event shape, created on first use.
+fn callsite(event: bool, level: i32, name: &str, keys: &[String]) -> &'static Metadata<'static> {
+    let key: CallsiteKey = (event, level, name.to_string(), keys.to_vec());
+    let mut callsites = CALLSITES
+        .get_or_init(|| ParkMutex::new(HashMap::new()))
+        .lock();
+    let callsite = *callsites.entry(key).or_insert_with(|| {
+        let callsite: &'static HaxeCallsite = Box::leak(Box::new(HaxeCallsite {
+            metadata: OnceLock::new(),
+        }));
+        let names: Vec<&'static str> = keys.iter().map(|key| leak_str(key)).collect();
+        let fields = FieldSet::new(Box::leak(names.into_boxed_slice()), Identifier(callsite));
+        let _ = callsite.metadata.set(Metadata::new(
+            leak_str(name),
+            "haxe",
+            level_from_haxe(level),
+            None,
+            None,
+            None,
+            fields,
+            if event { Kind::EVENT } else { Kind::SPAN },
+        ));
+        tracing::callsite::register(callsite);
+        callsite
+    });
+    callsite.metadata()
+}
+
+fn field_pairs<K, V>(keys: Array<K>, values: Array<V>) -> (Vec<String>, Vec<String>)
+where
+    K: AsRef<str> + Clone + Send + Sync + 'static,
+    V: AsRef<str> + Clone + Send + Sync + 'static,
+{
+    let keys: Vec<String> = keys
+        .iter_borrowed()
+        .map(|key| key.as_ref().to_string())
+        .collect();
+    let values: Vec<String> = values
+        .iter_borrowed()
+        .map(|value| value.as_ref().to_string())
+        .collect();
+    if keys.len() != values.len() || keys.len() > MAX_FIELDS {
+        throw_msg(format!(
+            "{TRACE_FIELDS_ERROR}: expected matching key/value lists of at most {MAX_FIELDS} \
+             fields, got {} keys and {} values",
+            keys.len(),
+            values.len()
+        ));
+    }
+    (keys, values)
+}
+
+fn enabled(metadata: &'static Metadata<'static>) -> bool {
+    tracing::level_filters::LevelFilter::current() >= *metadata.level()
+        && tracing::dispatcher::get_default(|dispatch| dispatch.enabled(metadata))
+}
+
+/// Run `f` with the field values of `metadata` (`values[i]` belongs to the `i`th key).
+///
+/// `tracing` value sets have a fixed length, so unused slots repeat the first field without a
+/// value, which subscribers skip.
+fn with_values<R>(
+    metadata: &'static Metadata<'static>,
+    values: &[String],
+    f: impl FnOnce(&tracing::field::ValueSet<'_>) -> R,
+) -> R {
+    let fields = metadata.fields();
+    let field_list: Vec<tracing::field::Field> = fields.iter().collect();
+    let Some(first) = field_list.first() else {
+        let empty: [(&tracing::field::Field, Option<&dyn Value>); 0] = [];
+        return f(&fields.value_set(&empty));
+    };
+    let slots: [(&tracing::field::Field, Option<&dyn Value>); MAX_FIELDS] =
+        std::array::from_fn(|index| match (field_list.get(index), values.get(index)) {
+            (Some(field), Some(value)) => (field, Some(value as &dyn Value)),
+            _ => (first, None),
+        });
+    f(&fields.value_set(&slots))
+}
+
+// ---- Spans and events ---------------------------------------------------------
+
+thread_local! {
+    static ENTERED: RefCell<Vec<(tracing::Id, tracing::span::EnteredSpan)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// One application span (`rust.tracing.Span`).
+///
+/// Clones refer to the same span; it closes once every clone is gone and it is no longer entered.
+#[derive(Clone, Debug)]
+pub struct SpanHandle {
+    span: Span,
+}
+
+impl SpanHandle {
+    /// Make this span the current one on this thread, until the matching `exit`.
+    pub fn enter(&self) {
+        if let Some(id) = self.span.id() {
+            let entered = self.span.clone().entered();
+            ENTERED.with(|stack| stack.borrow_mut().push((id, entered)));
+        }
+    }
+
+    /// Leave the most recent `enter` of this span on this thread; does nothing if it is not entered.
+    pub fn exit(&self) {
+        let Some(id) = self.span.id() else {
+            return;
+        };
+        let entered = ENTERED.with(|stack| {
+            let mut stack = stack.borrow_mut();
+            let index = stack.iter().rposition(|(entered, _)| *entered == id)?;
+            Some(stack.remove(index))
+        });
+        drop(entered);
+    }
+
+    /// Whether an installed subscriber records this span.
+    pub fn is_enabled(&self) -> bool {
+        !self.span.is_disabled()
+    }
+}
+
+/// A new span named `name` at `level`, child of the current span, with `keys[i] = values[i]`.
+pub fn trace_span<N, K, V>(level: i32, name: N, keys: Array<K>, values: Array<V>) -> SpanHandle
+where
+    N: AsRef<str>,
+    K: AsRef<str> + Clone + Send + Sync + 'static,
+    V: AsRef<str> + Clone + Send + Sync + 'static,
+{
+    let (keys, values) = field_pairs(keys, values);
+    let metadata = callsite(false, level, name.as_ref(), &keys);
+    if !enabled(metadata) {
+        return SpanHandle { span: Span::none() };
+    }
+    SpanHandle {
+        span: with_values(metadata, &values, |values| Span::new(metadata, values)),
+    }
+}
+
+/// Emit one event with `message` and `keys[i] = values[i]` inside the current span.
+pub fn trace_event<M, K, V>(level: i32, message: M, keys: Array<K>, values: Array<V>)
+where
+    M: AsRef<str>,
+    K: AsRef<str> + Clone + Send + Sync + 'static,
+    V: AsRef<str> + Clone + Send + Sync + 'static,
+{
+    let (mut keys, mut values) = field_pairs(keys, values);
+    if keys.len() == MAX_FIELDS {
+        throw_msg(format!(
+            "{TRACE_FIELDS_ERROR}: events carry at most {} fields besides the message",
+            MAX_FIELDS - 1
+        ));
+    }
+    keys.insert(0, String::from("message"));
+    values.insert(0, message.as_ref().to_string());
+    let metadata = callsite(true, level, "haxe event", &keys);
+    if enabled(metadata) {
+        with_values(metadata, &values, |values| {
+            tracing::Event::dispatch(metadata, values)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn strings(values: &[&str]) -> Array<String> {
+        Array::from_vec(values.iter().map(|value| value.to_string()).collect())
+    }
+
+    #[test]
+    fn haxe_spans_and_events_reach_a_json_subscriber() {
+        let captured = Captured::default();
+        let writer = captured.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .json()
+            .with_max_level(Level::TRACE)
+            .with_current_span(true)
+            .with_span_list(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        tracing::subscriber::with_default(subscriber, || {
+            let span = trace_span(2, "load_user", strings(&["user"]), strings(&["42"]));
+            assert!(span.is_enabled());
+            span.enter();
+            trace_event(3, "slow lookup", strings(&["ms"]), strings(&["250"]));
+            span.exit();
+            span.exit();
+            trace_event(2, "outside", strings(&[]), strings(&[]));
+        });
+        let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2, "{output}");
+        assert!(lines[0].contains("\"level\":\"WARN\""));
+        assert!(lines[0].contains("\"message\":\"slow lookup\""));
+        assert!(lines[0].contains("\"ms\":\"250\""));
+        assert!(lines[0].contains("\"span\":{\"user\":\"42\",\"name\":\"load_user\"}"));
+        assert!(!lines[1].contains("load_user"));
+    }
+
+    #[test]
+    fn spans_are_disabled_without_a_subscriber_and_fields_are_checked() {
+        let span = trace_span(0, "quiet", strings(&[]), strings(&[]));
+        assert!(!span.is_enabled());
+        span.enter();
+        span.exit();
+
+        let error =
+            exception::catch_unwind(|| trace_span(2, "bad", strings(&["a", "b"]), strings(&["1"])))
+                .unwrap_err();
+        assert!(error.to_haxe_string().starts_with(TRACE_FIELDS_ERROR));
+    }
+}
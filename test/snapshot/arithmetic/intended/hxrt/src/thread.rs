@@ -7,11 +7,17 @@
 //!
 //! What
 //! - `thread_spawn` + message queues (`thread_send_message`, `thread_read_message`).
+//! - `thread_spawn_with_options`: named threads, custom stack sizes, and joinable threads
+//!   (`thread_join`, `thread_is_alive`).
+//! - Per-thread `EventLoop` state, including I/O readiness watches (`event_loop_watch`) that make
+//!   the loop block in one `mio::Poll` for sockets, pipes, timers, and cross-thread `run` calls.
 //! - Synchronization primitives compatible with Haxe std semantics:
 //!   - `Lock` (counting lock: `release` unblocks exactly one `wait`)
 //!   - `Mutex` (re-entrant by owner thread, per Haxe docs)
 //!   - `Condition` (internal mutex + signal/broadcast + wait)
 //!   - `Semaphore` (counting semaphore with optional timeout)
+//! - `ThreadPoolHandle`: work-stealing pool behind `FixedThreadPool`, `ElasticThreadPool`, and
+//!   `rust.concurrent.Tasks`.
 //!
 //! How
 //! - We use `parking_lot` for fast primitives and explicit control over timeouts.
@@ -21,13 +27,15 @@
 use crate::cell::{HxDynRef, HxRc, HxRef};
 use crate::dynamic::Dynamic;
 use crate::{dynamic, exception};
+use mio::{Events, Interest, Poll, Registry, Token, Waker};
 use parking_lot::{Condvar, Mutex as ParkMutex};
 use std::cell::Cell;
 use std::collections::{HashMap, VecDeque};
 use std::io::Write;
 use std::sync::atomic::AtomicU64;
-use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicUsize, Ordering};
 use std::sync::{Arc, OnceLock};
+use std::thread::JoinHandle;
 use std::time::Duration;
 use std::time::Instant;
 
@@ -37,11 +45,20 @@ fn throw_msg(msg: &str) -> ! {
 
 const THREAD_NOT_ALIVE_ERROR: &str = "HXRT-THREAD-NOT-ALIVE: thread is not alive";
 const THREAD_SPAWN_ERROR: &str = "HXRT-THREAD-SPAWN";
+const THREAD_OPTIONS_ERROR: &str = "HXRT-THREAD-OPTIONS";
+const THREAD_NOT_JOINABLE_ERROR: &str =
+    "HXRT-THREAD-NOT-JOINABLE: thread was not created joinable or was already joined";
+const THREAD_SELF_JOIN_ERROR: &str = "HXRT-THREAD-SELF-JOIN: a thread cannot join itself";
+const THREAD_PANICKED_ERROR: &str = "HXRT-THREAD-PANICKED";
 const THREAD_UNCAUGHT_ERROR: &str = "HXRT-THREAD-UNCAUGHT";
+const TASK_POOL_SATURATED_WARNING: &str = "HXRT-TASK-POOL-SATURATED";
+const THREAD_POOL_SELF_JOIN_ERROR: &str =
+    "HXRT-THREAD-POOL-SELF-JOIN: a pool worker cannot wait for its own pool to terminate";
 const EVENT_LOOP_PROMISE_UNDERFLOW_ERROR: &str =
     "HXRT-EVENTLOOP-PROMISE-UNDERFLOW: runPromised requires one unmatched promise";
 const EVENT_LOOP_PROMISE_OVERFLOW_ERROR: &str =
     "HXRT-EVENTLOOP-PROMISE-OVERFLOW: promise count exceeded the runtime limit";
+pub(crate) const EVENT_LOOP_IO_ERROR: &str = "HXRT-EVENTLOOP-IO";
 
 // ---- Threads + messages ----------------------------------------------------
 
@@ -52,6 +69,9 @@ struct ThreadState {
     // Per-thread event loop (used by `sys.thread.EventLoop`).
     events: ParkMutex<EventLoopState>,
     events_cv: Condvar,
+    // Created by the first I/O watch; from then on the loop waits in `mio::Poll` instead of
+    // `events_cv`. Lock order: `events` before `io`.
+    io: ParkMutex<Option<Arc<EventLoopIo>>>,
 }
 
 impl ThreadState {
@@ -61,25 +81,35 @@ impl ThreadState {
             cv: Condvar::new(),
             events: ParkMutex::new(EventLoopState::default()),
             events_cv: Condvar::new(),
+            io: ParkMutex::new(None),
         }
     }
 }
 
 static NEXT_THREAD_ID: AtomicI32 = AtomicI32::new(1);
 static NEXT_EVENT_ID: AtomicU64 = AtomicU64::new(1);
-static THREADS: OnceLock<ParkMutex<HashMap<i32, Arc<ThreadState>>>> = OnceLock::new();
+static THREADS: OnceLock<ParkMutex<ThreadRegistry>> = OnceLock::new();
 static START: OnceLock<Instant> = OnceLock::new();
 
 thread_local! {
     static CURRENT_THREAD_ID: Cell<i32> = const { Cell::new(0) };
 }
 
-fn threads() -> &'static ParkMutex<HashMap<i32, Arc<ThreadState>>> {
+#[derive(Default)]
+struct ThreadRegistry {
+    // Threads whose registered callback scope is still running.
+    live: HashMap<i32, Arc<ThreadState>>,
+    // Handles of threads spawned joinable and not yet joined; entries outlive `live` so a finished
+    // thread can still be joined.
+    joinable: HashMap<i32, JoinHandle<()>>,
+}
+
+fn threads() -> &'static ParkMutex<ThreadRegistry> {
     THREADS.get_or_init(|| {
         // The main thread (id 0) is always considered alive.
-        let mut map = HashMap::new();
-        map.insert(0, Arc::new(ThreadState::new()));
-        ParkMutex::new(map)
+        let mut registry = ThreadRegistry::default();
+        registry.live.insert(0, Arc::new(ThreadState::new()));
+        ParkMutex::new(registry)
     })
 }
 
@@ -88,7 +118,8 @@ fn now_seconds() -> f64 {
 }
 
 fn ensure_thread_state(id: i32) -> Arc<ThreadState> {
-    let mut map = threads().lock();
+    let mut registry = threads().lock();
+    let map = &mut registry.live;
     if let Some(s) = map.get(&id) {
         return s.clone();
     }
@@ -101,8 +132,7 @@ fn remove_thread_state(id: i32) {
     if id == 0 {
         return;
     }
-    let mut map = threads().lock();
-    map.remove(&id);
+    threads().lock().live.remove(&id);
 }
 
 /// Own a spawned thread's registration for exactly the callback/event-loop lifetime.
@@ -142,7 +172,10 @@ impl Drop for ThreadRegistrationGuard {
 fn report_uncaught_thread_exception(error: &Dynamic) {
     let stderr = std::io::stderr();
     let mut handle = stderr.lock();
-    let _ = writeln!(handle, "[{THREAD_UNCAUGHT_ERROR}] {error}");
+    let _ = match std::thread::current().name() {
+        Some(name) => writeln!(handle, "[{THREAD_UNCAUGHT_ERROR}] thread '{name}': {error}"),
+        None => writeln!(handle, "[{THREAD_UNCAUGHT_ERROR}] {error}"),
+    };
 }
 
 /// Run one registered spawned-thread lifecycle.
@@ -174,17 +207,75 @@ fn run_registered_thread(id: i32, job: HxRc<dyn Fn() + Send + Sync>, with_event_
     }
 }
 
-fn spawn_registered_thread(job: HxRc<dyn Fn() + Send + Sync>, with_event_loop: bool) -> i32 {
+/// OS-level settings for one spawned thread.
+#[derive(Default)]
+struct ThreadSpawnOptions {
+    // OS thread name; `None` leaves the thread unnamed.
+    name: Option<String>,
+    // Stack size in bytes; `None` keeps the Rust default.
+    stack_size: Option<usize>,
+    // Keep the `JoinHandle` in the registry until `thread_join` collects it.
+    joinable: bool,
+}
+
+fn try_spawn_registered_thread(
+    job: HxRc<dyn Fn() + Send + Sync>,
+    with_event_loop: bool,
+    options: ThreadSpawnOptions,
+) -> std::io::Result<i32> {
     let id = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
     let _ = ensure_thread_state(id);
-    let spawn = std::thread::Builder::new().spawn(move || {
+    let mut builder = std::thread::Builder::new();
+    if let Some(name) = options.name {
+        builder = builder.name(name);
+    }
+    if let Some(stack_size) = options.stack_size {
+        builder = builder.stack_size(stack_size);
+    }
+    // Hold the registry lock across the spawn so a joinable handle is recorded before the thread
+    // id can be observed by anyone else.
+    let mut registry = options.joinable.then(|| threads().lock());
+    let spawn = builder.spawn(move || {
         run_registered_thread(id, job, with_event_loop);
     });
-    if let Err(error) = spawn {
-        remove_thread_state(id);
-        throw_msg(&format!("{THREAD_SPAWN_ERROR}: {error}"));
+    match spawn {
+        Ok(handle) => {
+            if let Some(registry) = registry.as_mut() {
+                registry.joinable.insert(id, handle);
+            }
+            Ok(id)
+        }
+        Err(error) => {
+            drop(registry);
+            remove_thread_state(id);
+            Err(error)
+        }
     }
-    id
+}
+
+fn spawn_registered_thread(
+    job: HxRc<dyn Fn() + Send + Sync>,
+    with_event_loop: bool,
+    options: ThreadSpawnOptions,
+) -> i32 {
+    match try_spawn_registered_thread(job, with_event_loop, options) {
+        Ok(id) => id,
+        Err(error) => throw_msg(&format!("{THREAD_SPAWN_ERROR}: {error}")),
+    }
+}
+
+/// Spawn a named registered thread for runtime-owned workers (for example `hxrt::http_server`
+/// connections), so Haxe code they run sees a normal `Thread.current()`.
+#[cfg_attr(not(feature = "http_server"), allow(dead_code))]
+pub(crate) fn spawn_named_thread(
+    job: HxRc<dyn Fn() + Send + Sync>,
+    name: &str,
+) -> std::io::Result<i32> {
+    let options = ThreadSpawnOptions {
+        name: Some(name.to_owned()),
+        ..ThreadSpawnOptions::default()
+    };
+    try_spawn_registered_thread(job, false, options)
 }
 
 pub fn thread_current_id() -> i32 {
@@ -196,7 +287,7 @@ pub fn thread_spawn(job: HxDynRef<dyn Fn() + Send + Sync>) -> i32 {
         Some(rc) => rc.clone(),
         None => throw_msg("Null Access"),
     };
-    spawn_registered_thread(job, false)
+    spawn_registered_thread(job, false, ThreadSpawnOptions::default())
 }
 
 pub fn thread_spawn_with_event_loop(job: HxDynRef<dyn Fn() + Send + Sync>) -> i32 {
@@ -204,13 +295,86 @@ pub fn thread_spawn_with_event_loop(job: HxDynRef<dyn Fn() + Send + Sync>) -> i3
         Some(rc) => rc.clone(),
         None => throw_msg("Null Access"),
     };
-    spawn_registered_thread(job, true)
+    spawn_registered_thread(job, true, ThreadSpawnOptions::default())
+}
+
+/// Spawn a thread with an OS name, a stack size, and optional join support.
+///
+/// - `name`: `null` leaves the thread unnamed. The name shows up in debuggers, `perf`, Rust panic
+///   messages, and `HXRT-THREAD-UNCAUGHT` diagnostics.
+/// - `stack_size`: bytes; `0` keeps the platform default.
+/// - `joinable`: the thread's `JoinHandle` is kept until `thread_join` collects it, so a joinable
+///   thread must be joined exactly once to release it.
+///
+/// Invalid options throw a catchable String beginning with `HXRT-THREAD-OPTIONS`.
+pub fn thread_spawn_with_options(
+    job: HxDynRef<dyn Fn() + Send + Sync>,
+    name: impl Into<crate::string::HxString>,
+    stack_size: i32,
+    joinable: bool,
+    with_event_loop: bool,
+) -> i32 {
+    let job: HxRc<dyn Fn() + Send + Sync> = match job.as_arc_opt() {
+        Some(rc) => rc.clone(),
+        None => throw_msg("Null Access"),
+    };
+    let name: crate::string::HxString = name.into();
+    let name = if name.is_null() {
+        None
+    } else if name.as_str().contains('\0') {
+        throw_msg(&format!(
+            "{THREAD_OPTIONS_ERROR}: thread name must not contain NUL characters"
+        ))
+    } else {
+        Some(name.as_str().to_owned())
+    };
+    let stack_size = match stack_size {
+        0 => None,
+        size if size > 0 => Some(size as usize),
+        size => throw_msg(&format!(
+            "{THREAD_OPTIONS_ERROR}: stack size must not be negative (got {size})"
+        )),
+    };
+    let options = ThreadSpawnOptions {
+        name,
+        stack_size,
+        joinable,
+    };
+    spawn_registered_thread(job, with_event_loop, options)
+}
+
+/// Wait for a joinable thread to finish.
+///
+/// An uncaught Haxe exception in the thread has already been reported by the thread itself, so
+/// join returns normally; a Rust panic surfaces here as `HXRT-THREAD-PANICKED`. Joining a thread
+/// that was not created joinable, or joining twice, throws `HXRT-THREAD-NOT-JOINABLE`; joining the
+/// current thread throws `HXRT-THREAD-SELF-JOIN`.
+pub fn thread_join(thread_id: i32) {
+    if thread_id == thread_current_id() {
+        throw_msg(THREAD_SELF_JOIN_ERROR);
+    }
+    let Some(handle) = threads().lock().joinable.remove(&thread_id) else {
+        throw_msg(THREAD_NOT_JOINABLE_ERROR);
+    };
+    let name = handle.thread().name().unwrap_or("<unnamed>").to_owned();
+    if handle.join().is_err() {
+        throw_msg(&format!(
+            "{THREAD_PANICKED_ERROR}: thread '{name}' panicked"
+        ));
+    }
+}
+
+/// Whether the thread's registered callback scope (job plus optional EventLoop) is still running.
+///
+/// The main thread (id 0) is always alive.
+pub fn thread_is_alive(thread_id: i32) -> bool {
+    threads().lock().live.contains_key(&thread_id)
 }
 
 pub fn thread_send_message(thread_id: i32, msg: Dynamic) {
     let state = {
-        let map = threads().lock();
-        map.get(&thread_id).cloned()
+        let registry = threads().lock();
+        registry.live.get(&thread_id).cloned()
     };
     let Some(state) = state else {
         throw_msg(THREAD_NOT_ALIVE_ERROR);
@@ -238,6 +402,32 @@ pub fn thread_read_message(block: bool) -> Dynamic {
     }
 }
 
+/// Message and event-loop queue depths of every live thread, by id (`hxrt::stats`).
+#[cfg(feature = "stats")]
+pub(crate) fn stats_thread_queues() -> Vec<crate::stats::ThreadQueueStats> {
+    let states: Vec<(i32, Arc<ThreadState>)> = threads()
+        .lock()
+        .live
+        .iter()
+        .map(|(id, state)| (*id, state.clone()))
+        .collect();
+    let mut queues: Vec<crate::stats::ThreadQueueStats> = states
+        .into_iter()
+        .map(|(thread_id, state)| {
+            let messages = state.queue.lock().len();
+            let events = state.events.lock();
+            crate::stats::ThreadQueueStats {
+                thread_id,
+                messages,
+                events: events.one_time.len(),
+                regular_events: events.regular.len(),
+            }
+        })
+        .collect();
+    queues.sort_by_key(|queue| queue.thread_id);
+    queues
+}
+
 // ---- EventLoop ------------------------------------------------------------
 
 #[derive(Clone)]
@@ -255,10 +445,18 @@ struct EventLoopState {
     regular: Vec<RegularEvent>, // kept sorted by `next_run`
 }
 
+/// Wake the owning thread's loop, whichever wait (condition variable or `mio::Poll`) it is in.
+fn wake_event_loop(state: &ThreadState) {
+    state.events_cv.notify_one();
+    if let Some(io) = state.io.lock().as_ref() {
+        let _ = io.waker.wake();
+    }
+}
+
 fn with_thread_state_or_throw<R>(thread_id: i32, f: impl FnOnce(&Arc<ThreadState>) -> R) -> R {
     let state = {
-        let map = threads().lock();
-        map.get(&thread_id).cloned()
+        let registry = threads().lock();
+        registry.live.get(&thread_id).cloned()
     };
     let Some(state) = state else {
         throw_msg(THREAD_NOT_ALIVE_ERROR);
@@ -282,11 +480,25 @@ pub fn event_loop_run(thread_id: i32, event: HxDynRef<dyn Fn() + Send + Sync>) {
         Some(rc) => rc.clone(),
         None => throw_msg("Null Access"),
     };
-    with_thread_state_or_throw(thread_id, |state| {
-        let mut st = state.events.lock();
-        st.one_time.push_back(event);
-        state.events_cv.notify_one();
-    });
+    if !event_loop_post(thread_id, event) {
+        throw_msg(THREAD_NOT_ALIVE_ERROR);
+    }
+}
+
+/// Queue a one-time event without throwing; returns `false` when `thread_id` is not alive.
+///
+/// Used by runtime producers (for example `hxrt::signal`) that run outside any Haxe `try`.
+pub(crate) fn event_loop_post(thread_id: i32, event: HxRc<dyn Fn() + Send + Sync>) -> bool {
+    let state = {
+        let registry = threads().lock();
+        registry.live.get(&thread_id).cloned()
+    };
+    let Some(state) = state else {
+        return false;
+    };
+    state.events.lock().one_time.push_back(event);
+    wake_event_loop(&state);
+    true
 }
 
 pub fn event_loop_run_promised(thread_id: i32, event: HxDynRef<dyn Fn() + Send + Sync>) {
@@ -302,7 +514,7 @@ pub fn event_loop_run_promised(thread_id: i32, event: HxDynRef<dyn Fn() + Send +
         }
         st.one_time.push_back(event);
         st.promised -= 1;
-        state.events_cv.notify_one();
+        wake_event_loop(state);
     });
 }
 
@@ -336,22 +548,29 @@ pub fn event_loop_repeat(
             .position(|x| ev.next_run < x.next_run)
             .unwrap_or(st.regular.len());
         st.regular.insert(idx, ev);
-        state.events_cv.notify_one();
+        wake_event_loop(state);
     });
     id as i32
 }
 
+/// Cancel a repeating event or an I/O watch; both share the `EventHandler` id space.
 pub fn event_loop_cancel(thread_id: i32, event_id: i32) {
     let id = event_id as u64;
     with_thread_state_or_throw(thread_id, |state| {
         let mut st = state.events.lock();
         st.regular.retain(|e| e.id != id);
-        state.events_cv.notify_one();
+        if let Some(io) = state.io.lock().as_ref() {
+            io.unwatch(id);
+        }
+        wake_event_loop(state);
     });
 }
 
 /// Progress the event loop once.
 ///
+/// Ready I/O watch callbacks were queued as one-time events by `event_loop_wait`, so they run here
+/// like `run` callbacks. A loop with live watches never reports "Never".
+///
 /// Returns a float "next event time" marker:
 /// - `-2.0` => Now (one or more events executed)
 /// - `-1.0` => Never (no more events expected)
@@ -361,6 +580,7 @@ pub fn event_loop_progress(thread_id: i32) -> f64 {
     let mut regular_to_run: Vec<(u64, HxRc<dyn Fn() + Send + Sync>)> = Vec::new();
     let mut next_at: f64 = -1.0;
     let mut promised: u32 = 0;
+    let mut watching = false;
     let now = now_seconds();
 
     with_thread_state_or_throw(thread_id, |state| {
@@ -393,7 +613,7 @@ pub fn event_loop_progress(thread_id: i32) -> f64 {
                 .any(|candidate| candidate.id == *event_id)
         });
         if still_registered {
-            event();
+            run_event_callback(thread_id, "repeat", event);
         }
     }
 
@@ -404,6 +624,7 @@ pub fn event_loop_progress(thread_id: i32) -> f64 {
             one_time_to_run.push(event);
         }
         promised = st.promised;
+        watching = state.io.lock().as_ref().is_some_and(|io| io.is_watching());
         next_at = st
             .regular
             .first()
@@ -412,7 +633,7 @@ pub fn event_loop_progress(thread_id: i32) -> f64 {
     });
 
     for event in one_time_to_run.iter() {
-        event();
+        run_event_callback(thread_id, "once", event);
     }
 
     if !regular_to_run.is_empty() || !one_time_to_run.is_empty() {
@@ -421,17 +642,36 @@ pub fn event_loop_progress(thread_id: i32) -> f64 {
     if next_at >= 0.0 {
         return next_at;
     }
-    if promised > 0 {
+    if promised > 0 || watching {
         return -3.0;
     }
     -1.0
 }
 
+/// Run one event-loop callback (in an `event_loop.callback` span with the `tracing` feature).
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", name = "event_loop.callback", skip(callback))
+)]
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+fn run_event_callback(thread_id: i32, kind: &'static str, callback: &HxRc<dyn Fn() + Send + Sync>) {
+    callback();
+}
+
+/// Block until work may be ready.
+///
+/// Without I/O watches this waits on the loop's condition variable. Once the loop has an I/O
+/// reactor it waits in `mio::Poll` instead, which also wakes for `run`/`repeat`/`cancel` calls
+/// from other threads (through the reactor's `Waker`) and queues ready watch callbacks.
 pub fn event_loop_wait(thread_id: i32, timeout_seconds: Option<f64>) -> bool {
     with_thread_state_or_throw(thread_id, |state| {
         let mut st = state.events.lock();
+        let io = state.io.lock().clone();
 
-        let has_any_pending = !st.one_time.is_empty() || !st.regular.is_empty() || st.promised > 0;
+        let has_any_pending = !st.one_time.is_empty()
+            || !st.regular.is_empty()
+            || st.promised > 0
+            || io.as_ref().is_some_and(|io| io.is_watching());
         if !has_any_pending {
             return false;
         }
@@ -445,6 +685,14 @@ pub fn event_loop_wait(thread_id: i32, timeout_seconds: Option<f64>) -> bool {
             return true;
         }
 
+        if let Some(io) = io {
+            drop(st);
+            let timeout = timeout_seconds
+                .filter(|t| *t >= 0.0)
+                .map(|t| Duration::from_millis((t * 1000.0) as u64));
+            return io.wait(state, timeout);
+        }
+
         match timeout_seconds {
             None => {
                 state.events_cv.wait(&mut st);
@@ -482,6 +730,566 @@ pub fn event_loop_loop(thread_id: i32) {
     }
 }
 
+// ---- EventLoop I/O readiness ------------------------------------------------
+
+// Event and watch ids come from `NEXT_EVENT_ID`, which starts at 1, so token 0 is free.
+const EVENT_LOOP_WAKE_TOKEN: Token = Token(0);
+
+/// An OS handle an event loop can wait on: a clone of a socket or pipe, owned by its watch.
+pub type IoSource = Box<dyn mio::event::Source + Send>;
+
+struct IoWatch {
+    source: IoSource,
+    interest: Interest,
+    callback: HxRc<dyn Fn() + Send + Sync>,
+}
+
+/// Per-thread `mio::Poll` behind an `EventLoop` that watches I/O.
+///
+/// Why
+/// - Reacting to socket data and timers on one loop otherwise means polling `Socket.select` with a
+///   timeout in a busy loop.
+///
+/// What
+/// - Owns the poll, a `Waker` for cross-thread `run`/`repeat`/`cancel`, and the registered watches.
+/// - Watches behave level-triggered: a source is re-armed after each callback, so a callback that
+///   leaves data unread is called again on the next pass.
+///
+/// How
+/// - Only the owning thread polls; other threads register, cancel, and wake through a cloned
+///   `Registry` and the `Waker`, neither of which needs the poll lock.
+/// - Ready watches are queued as one-time events, so their callbacks run from `progress` with the
+///   same exception behavior as `run` callbacks. A throwing callback still re-arms its watch.
+struct EventLoopIo {
+    poll: ParkMutex<Poll>,
+    registry: Registry,
+    waker: Waker,
+    watches: ParkMutex<HashMap<u64, IoWatch>>,
+}
+
+fn throw_event_loop_io(error: std::io::Error) -> ! {
+    throw_msg(&format!("{EVENT_LOOP_IO_ERROR}: {error}"))
+}
+
+impl EventLoopIo {
+    fn new() -> std::io::Result<Self> {
+        let poll = Poll::new()?;
+        let registry = poll.registry().try_clone()?;
+        let waker = Waker::new(poll.registry(), EVENT_LOOP_WAKE_TOKEN)?;
+        Ok(Self {
+            poll: ParkMutex::new(poll),
+            registry,
+            waker,
+            watches: ParkMutex::new(HashMap::new()),
+        })
+    }
+
+    fn is_watching(&self) -> bool {
+        !self.watches.lock().is_empty()
+    }
+
+    fn unwatch(&self, id: u64) {
+        if let Some(mut watch) = self.watches.lock().remove(&id) {
+            let _ = self.registry.deregister(&mut watch.source);
+        }
+    }
+
+    fn rearm(&self, id: u64) {
+        if let Some(watch) = self.watches.lock().get_mut(&id) {
+            let _ = self
+                .registry
+                .reregister(&mut watch.source, Token(id as usize), watch.interest);
+        }
+    }
+
+    /// Poll once and queue the callbacks of ready watches; returns `false` on timeout.
+    fn wait(self: &Arc<Self>, state: &ThreadState, timeout: Option<Duration>) -> bool {
+        let mut events = Events::with_capacity(64);
+        {
+            let mut poll = self.poll.lock();
+            loop {
+                match poll.poll(&mut events, timeout) {
+                    Ok(()) => break,
+                    Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+                    Err(error) => throw_event_loop_io(error),
+                }
+            }
+        }
+
+        let ready: Vec<HxRc<dyn Fn() + Send + Sync>> = {
+            let watches = self.watches.lock();
+            events
+                .iter()
+                .filter(|event| event.token() != EVENT_LOOP_WAKE_TOKEN)
+                .filter_map(|event| {
+                    let id = event.token().0 as u64;
+                    let watch = watches.get(&id)?;
+                    Some(self.dispatch(id, watch.callback.clone()))
+                })
+                .collect()
+        };
+        if !ready.is_empty() {
+            state.events.lock().one_time.extend(ready);
+        }
+        !events.is_empty()
+    }
+
+    /// Wrap a ready watch callback: skip it if the watch was cancelled meanwhile, re-arm after.
+    fn dispatch(
+        self: &Arc<Self>,
+        id: u64,
+        callback: HxRc<dyn Fn() + Send + Sync>,
+    ) -> HxRc<dyn Fn() + Send + Sync> {
+        struct Rearm<'a> {
+            io: &'a EventLoopIo,
+            id: u64,
+        }
+        impl Drop for Rearm<'_> {
+            fn drop(&mut self) {
+                self.io.rearm(self.id);
+            }
+        }
+
+        let io = self.clone();
+        HxRc::new(move || {
+            if !io.watches.lock().contains_key(&id) {
+                return;
+            }
+            let _rearm = Rearm { io: &io, id };
+            callback();
+        })
+    }
+}
+
+/// Register `source` with the event loop of `thread_id`; `callback` then runs on that thread each
+/// time the source is ready for `interest`.
+///
+/// Returns an event id that `event_loop_cancel` (Haxe `EventLoop.cancel`) accepts. This is the
+/// shared entry point behind the socket and process-pipe helpers in `hxrt::net` /
+/// `hxrt::process`; native helper modules can register other `mio` sources the same way.
+pub fn event_loop_watch(
+    thread_id: i32,
+    mut source: IoSource,
+    interest: Interest,
+    callback: HxDynRef<dyn Fn() + Send + Sync>,
+) -> i32 {
+    let callback: HxRc<dyn Fn() + Send + Sync> = match callback.as_arc_opt() {
+        Some(rc) => rc.clone(),
+        None => throw_msg("Null Access"),
+    };
+    let id = NEXT_EVENT_ID.fetch_add(1, Ordering::Relaxed);
+    with_thread_state_or_throw(thread_id, |state| {
+        let io = {
+            let mut slot = state.io.lock();
+            match slot.as_ref() {
+                Some(io) => io.clone(),
+                None => {
+                    let io =
+                        Arc::new(EventLoopIo::new().unwrap_or_else(|e| throw_event_loop_io(e)));
+                    *slot = Some(io.clone());
+                    io
+                }
+            }
+        };
+        if let Err(error) = io
+            .registry
+            .register(&mut source, Token(id as usize), interest)
+        {
+            throw_event_loop_io(error);
+        }
+        io.watches.lock().insert(
+            id,
+            IoWatch {
+                source,
+                interest,
+                callback,
+            },
+        );
+        // A loop already blocked on its condition variable must switch over to polling.
+        wake_event_loop(state);
+    });
+    id as i32
+}
+
+// ---- Thread pool -------------------------------------------------------------
+
+/// One queued unit of pool work.
+pub(crate) type PoolJob = Box<dyn FnOnce() + Send + 'static>;
+
+/// Work-stealing pool shared by `sys.thread.FixedThreadPool`, `sys.thread.ElasticThreadPool`, and
+/// `rust.concurrent.Tasks`.
+///
+/// Why
+/// - A pure-Haxe pool funnels every dispatch through one `Deque` lock and shuts workers down by
+///   throwing a private exception through each of them.
+///
+/// What
+/// - One deque per worker slot plus a global injector queue. Work submitted from inside a worker
+///   goes to that worker's own deque; work from other threads goes to the injector.
+/// - Workers pop their own deque LIFO, then the injector, then steal FIFO from sibling deques.
+/// - `shutdown` rejects new work and lets workers exit once every queued job has run; `join` waits
+///   for the last worker to exit.
+/// - Elastic pools start workers on demand (up to `max_workers`) and retire workers that stay idle
+///   for `idle_timeout`. `max_workers` can change later: raising it adds worker slots, lowering it
+///   stops new workers from starting while surplus ones retire once idle.
+///
+/// How
+/// - `queued`/`idle`/`alive` are atomics, so a saturated pool dispatches without touching the
+///   shared `state` lock. Sleep/wake and worker lifecycle changes go through `state` + `Condvar`.
+/// - `queued` is incremented before a job becomes visible and `idle` before a worker re-checks
+///   `queued` and sleeps, so a submission either sees an idle worker or is seen by one.
+/// - Workers are registered Haxe threads (`Thread.current()` works inside a job). A Haxe exception
+///   escaping a job is reported as `HXRT-THREAD-UNCAUGHT` and the worker keeps running.
+#[derive(Debug)]
+pub struct ThreadPoolHandle {
+    shared: Arc<PoolShared>,
+}
+
+struct PoolShared {
+    injector: ParkMutex<VecDeque<PoolJob>>,
+    locals: Box<[ParkMutex<VecDeque<PoolJob>>]>,
+    queued: AtomicUsize,
+    idle: AtomicUsize,
+    alive: AtomicUsize,
+    shutdown: AtomicBool,
+    state: ParkMutex<PoolState>,
+    work_available: Condvar,
+    terminated: Condvar,
+    max_workers: AtomicUsize,
+    idle_timeout: Option<Duration>,
+}
+
+impl std::fmt::Debug for PoolShared {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PoolShared")
+            .field("queued", &self.queued.load(Ordering::Relaxed))
+            .field("alive", &self.alive.load(Ordering::Relaxed))
+            .field("max_workers", &self.max_workers.load(Ordering::Relaxed))
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Default)]
+struct PoolState {
+    free_slots: Vec<usize>,
+    // Slots handed out so far; slots past `locals.len()` (added by raising `max_workers`) have no
+    // own deque and submit to the injector instead.
+    slot_count: usize,
+}
+
+thread_local! {
+    // (pool identity, worker slot) of the pool worker running on this thread; identity 0 = none.
+    static CURRENT_POOL_WORKER: Cell<(usize, usize)> = const { Cell::new((0, 0)) };
+}
+
+impl PoolShared {
+    fn new(max_workers: usize, idle_timeout: Option<Duration>) -> Arc<Self> {
+        let max_workers = max_workers.max(1);
+        Arc::new(Self {
+            injector: ParkMutex::new(VecDeque::new()),
+            locals: (0..max_workers)
+                .map(|_| ParkMutex::new(VecDeque::new()))
+                .collect(),
+            queued: AtomicUsize::new(0),
+            idle: AtomicUsize::new(0),
+            alive: AtomicUsize::new(0),
+            shutdown: AtomicBool::new(false),
+            state: ParkMutex::new(PoolState {
+                free_slots: (0..max_workers).rev().collect(),
+                slot_count: max_workers,
+            }),
+            work_available: Condvar::new(),
+            terminated: Condvar::new(),
+            max_workers: AtomicUsize::new(max_workers),
+            idle_timeout,
+        })
+    }
+
+    fn identity(self: &Arc<Self>) -> usize {
+        Arc::as_ptr(self) as usize
+    }
+
+    fn current_slot(self: &Arc<Self>) -> Option<usize> {
+        let (pool, slot) = CURRENT_POOL_WORKER.with(Cell::get);
+        (pool == self.identity()).then_some(slot)
+    }
+
+    /// Queue `job`; returns `false` once the pool is shut down.
+    fn submit(self: &Arc<Self>, job: PoolJob) -> bool {
+        if self.shutdown.load(Ordering::SeqCst) {
+            return false;
+        }
+        self.queued.fetch_add(1, Ordering::SeqCst);
+        match self.current_slot().and_then(|slot| self.locals.get(slot)) {
+            Some(local) => local.lock().push_back(job),
+            None => self.injector.lock().push_back(job),
+        }
+        if self.idle.load(Ordering::SeqCst) > 0 {
+            let _state = self.state.lock();
+            self.work_available.notify_one();
+        } else if self.alive.load(Ordering::SeqCst) < self.max_workers.load(Ordering::SeqCst) {
+            let mut state = self.state.lock();
+            if self.idle.load(Ordering::SeqCst) > 0 {
+                self.work_available.notify_one();
+            } else {
+                self.spawn_worker(&mut state);
+            }
+        }
+        true
+    }
+
+    /// Every worker slot is taken and none is idle, so queued jobs wait for a running one.
+    fn is_saturated(&self) -> bool {
+        self.alive.load(Ordering::SeqCst) >= self.max_workers.load(Ordering::SeqCst)
+            && self.idle.load(Ordering::SeqCst) == 0
+            && self.queued.load(Ordering::SeqCst) > 0
+    }
+
+    /// Start one worker in a free slot (caller holds `state`). Spawn failures are tolerated while
+    /// another worker is alive to drain the queue.
+    fn spawn_worker(self: &Arc<Self>, state: &mut PoolState) {
+        let Some(slot) = state.free_slots.pop() else {
+            return;
+        };
+        self.alive.fetch_add(1, Ordering::SeqCst);
+        let shared = self.clone();
+        let job: HxRc<dyn Fn() + Send + Sync> = HxRc::new(move || shared.worker_loop(slot));
+        let options = ThreadSpawnOptions {
+            name: Some(format!("hxrt-pool-{slot}")),
+            ..ThreadSpawnOptions::default()
+        };
+        if let Err(error) = try_spawn_registered_thread(job, false, options) {
+            self.alive.fetch_sub(1, Ordering::SeqCst);
+            state.free_slots.push(slot);
+            if self.alive.load(Ordering::SeqCst) == 0 {
+                throw_msg(&format!("{THREAD_SPAWN_ERROR}: {error}"));
+            }
+        }
+    }
+
+    fn take_job(&self, slot: usize) -> Option<PoolJob> {
+        // Release the own-deque guard before touching any other deque: two workers stealing from
+        // each other while still holding their own lock would deadlock.
+        let own = self
+            .locals
+            .get(slot)
+            .and_then(|local| local.lock().pop_back());
+        let job = own
+            .or_else(|| self.injector.lock().pop_front())
+            .or_else(|| {
+                (1..=self.locals.len())
+                    .map(|offset| (slot + offset) % self.locals.len())
+                    .filter(|&victim| victim != slot)
+                    .find_map(|victim| self.locals[victim].lock().pop_front())
+            });
+        if job.is_some() {
+            self.queued.fetch_sub(1, Ordering::SeqCst);
+        }
+        job
+    }
+
+    fn worker_loop(self: &Arc<Self>, slot: usize) {
+        /// Releases the worker's slot if the loop unwinds on a non-Haxe panic.
+        struct WorkerExit<'a> {
+            pool: &'a PoolShared,
+            slot: usize,
+            retired: bool,
+        }
+        impl Drop for WorkerExit<'_> {
+            fn drop(&mut self) {
+                CURRENT_POOL_WORKER.with(|current| current.set((0, 0)));
+                if !self.retired {
+                    let mut state = self.pool.state.lock();
+                    self.pool.alive.fetch_sub(1, Ordering::SeqCst);
+                    self.pool.release_slot(&mut state, self.slot);
+                }
+            }
+        }
+
+        CURRENT_POOL_WORKER.with(|current| current.set((self.identity(), slot)));
+        let mut exit = WorkerExit {
+            pool: self,
+            slot,
+            retired: false,
+        };
+        loop {
+            if let Some(job) = self.take_job(slot) {
+                if let Err(error) = exception::catch_unwind(job) {
+                    report_uncaught_thread_exception(&error);
+                }
+                continue;
+            }
+
+            let mut state = self.state.lock();
+            self.idle.fetch_add(1, Ordering::SeqCst);
+            let wants_exit = if self.queued.load(Ordering::SeqCst) > 0 {
+                false
+            } else if self.shutdown.load(Ordering::SeqCst) {
+                true
+            } else {
+                match self.idle_timeout {
+                    Some(timeout) => self
+                        .work_available
+                        .wait_for(&mut state, timeout)
+                        .timed_out(),
+                    None => {
+                        self.work_available.wait(&mut state);
+                        false
+                    }
+                }
+            };
+            self.idle.fetch_sub(1, Ordering::SeqCst);
+            // Give up the alive count before the final `queued` check: a concurrent `submit` then
+            // either sees this job picked up or sees room to start a replacement worker.
+            if wants_exit {
+                self.alive.fetch_sub(1, Ordering::SeqCst);
+                if self.queued.load(Ordering::SeqCst) == 0 {
+                    exit.retired = true;
+                    self.release_slot(&mut state, slot);
+                    return;
+                }
+                self.alive.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    /// Return a retired worker's slot (caller holds `state`) and wake `join` after the last one.
+    fn release_slot(&self, state: &mut PoolState, slot: usize) {
+        state.free_slots.push(slot);
+        if self.alive.load(Ordering::SeqCst) == 0 {
+            self.terminated.notify_all();
+        }
+    }
+
+    /// Change the worker cap. Raising it hands out fresh slots so `submit` can start more workers;
+    /// lowering it only stops new starts, and surplus workers retire on their idle timeout.
+    fn set_max_workers(&self, max_workers: usize) {
+        let max_workers = max_workers.max(1);
+        let mut state = self.state.lock();
+        while state.slot_count < max_workers {
+            let slot = state.slot_count;
+            state.free_slots.insert(0, slot);
+            state.slot_count += 1;
+        }
+        self.max_workers.store(max_workers, Ordering::SeqCst);
+    }
+
+    fn shutdown(&self) {
+        let _state = self.state.lock();
+        self.shutdown.store(true, Ordering::SeqCst);
+        self.work_available.notify_all();
+    }
+
+    fn join(self: &Arc<Self>) {
+        if self.current_slot().is_some() {
+            throw_msg(THREAD_POOL_SELF_JOIN_ERROR);
+        }
+        let mut state = self.state.lock();
+        while self.alive.load(Ordering::SeqCst) > 0 {
+            self.terminated.wait(&mut state);
+        }
+    }
+}
+
+fn pool_shared(pool: &HxRef<ThreadPoolHandle>) -> Arc<PoolShared> {
+    pool.borrow().shared.clone()
+}
+
+/// Create a pool that keeps `workers` threads alive until shutdown.
+pub fn pool_new_fixed(workers: i32) -> HxRef<ThreadPoolHandle> {
+    let shared = PoolShared::new(workers.max(1) as usize, None);
+    {
+        let mut state = shared.state.lock();
+        for _ in 0..shared.max_workers.load(Ordering::SeqCst) {
+            shared.spawn_worker(&mut state);
+        }
+    }
+    HxRef::new(ThreadPoolHandle { shared })
+}
+
+/// Create a pool that starts up to `max_workers` threads on demand and retires a worker after
+/// `idle_timeout_seconds` without work.
+pub fn pool_new_elastic(max_workers: i32, idle_timeout_seconds: f64) -> HxRef<ThreadPoolHandle> {
+    let timeout = Duration::from_secs_f64(idle_timeout_seconds.max(0.0));
+    HxRef::new(ThreadPoolHandle {
+        shared: PoolShared::new(max_workers.max(1) as usize, Some(timeout)),
+    })
+}
+
+/// Queue `job`; returns `false` (without running it) once the pool is shut down.
+pub fn pool_run(pool: &HxRef<ThreadPoolHandle>, job: HxDynRef<dyn Fn() + Send + Sync>) -> bool {
+    let job: HxRc<dyn Fn() + Send + Sync> = match job.as_arc_opt() {
+        Some(rc) => rc.clone(),
+        None => throw_msg("Null Access"),
+    };
+    pool_shared(pool).submit(Box::new(move || job()))
+}
+
+/// Change an elastic pool's worker cap (clamped to at least 1). Takes effect on the next `pool_run`.
+pub fn pool_set_max_workers(pool: &HxRef<ThreadPoolHandle>, max_workers: i32) {
+    pool_shared(pool).set_max_workers(max_workers.max(1) as usize);
+}
+
+/// Reject new work; queued jobs still run. Idempotent.
+pub fn pool_shutdown(pool: &HxRef<ThreadPoolHandle>) {
+    pool_shared(pool).shutdown();
+}
+
+pub fn pool_is_shutdown(pool: &HxRef<ThreadPoolHandle>) -> bool {
+    pool_shared(pool).shutdown.load(Ordering::SeqCst)
+}
+
+/// Block until every worker has exited. Only terminates after `pool_shutdown`; calling it from a
+/// worker of the same pool throws `HXRT-THREAD-POOL-SELF-JOIN` instead of deadlocking.
+pub fn pool_join(pool: &HxRef<ThreadPoolHandle>) {
+    pool_shared(pool).join();
+}
+
+/// Number of alive worker threads.
+pub fn pool_threads_count(pool: &HxRef<ThreadPoolHandle>) -> i32 {
+    pool_shared(pool).alive.load(Ordering::SeqCst) as i32
+}
+
+/// Number of submitted jobs not yet picked up by a worker.
+pub fn pool_queue_depth(pool: &HxRef<ThreadPoolHandle>) -> i32 {
+    pool_shared(pool).queued.load(Ordering::SeqCst) as i32
+}
+
+/// Upper bound on concurrently running `rust.concurrent.Tasks` jobs. Tasks may block on other
+/// tasks, so the bound is generous; queued tasks wait only once this many are in flight.
+///
+/// Past the cap a task that blocks on a still-queued task can wait forever, so the first
+/// submission that finds every worker busy reports `HXRT-TASK-POOL-SATURATED` on stderr (once per
+/// process).
+const TASK_POOL_MAX_WORKERS: usize = 512;
+const TASK_POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(30);
+
+static TASK_POOL: OnceLock<Arc<PoolShared>> = OnceLock::new();
+static TASK_POOL_SATURATION_REPORTED: AtomicBool = AtomicBool::new(false);
+
+/// Jobs waiting on the `rust.concurrent.Tasks` pool (`hxrt::stats`).
+#[cfg(feature = "stats")]
+pub(crate) fn stats_task_queue_depth() -> usize {
+    TASK_POOL
+        .get()
+        .map_or(0, |pool| pool.queued.load(Ordering::SeqCst))
+}
+
+/// Run `job` on the process-wide elastic pool used by `rust.concurrent.Tasks`.
+pub(crate) fn task_pool_submit(job: PoolJob) {
+    let pool = TASK_POOL
+        .get_or_init(|| PoolShared::new(TASK_POOL_MAX_WORKERS, Some(TASK_POOL_IDLE_TIMEOUT)));
+    let _ = pool.submit(job);
+    if pool.is_saturated() && !TASK_POOL_SATURATION_REPORTED.swap(true, Ordering::SeqCst) {
+        let _ = writeln!(
+            std::io::stderr().lock(),
+            "[{TASK_POOL_SATURATED_WARNING}] all {TASK_POOL_MAX_WORKERS} rust.concurrent.Tasks \
+             workers are busy; new tasks queue until one finishes, and a task joining a queued \
+             task can deadlock"
+        );
+    }
+}
+
 // ---- Lock -----------------------------------------------------------------
 
 #[derive(Debug, Default)]
@@ -782,6 +1590,182 @@ mod tests {
         );
     }
 
+    fn pool_job(f: impl Fn() + Send + Sync + 'static) -> HxDynRef<dyn Fn() + Send + Sync> {
+        let job: HxRc<dyn Fn() + Send + Sync> = HxRc::new(f);
+        HxDynRef::new(job)
+    }
+
+    #[test]
+    fn fixed_pool_runs_all_jobs_then_joins_after_shutdown() {
+        let pool = pool_new_fixed(3);
+        assert_eq!(pool_threads_count(&pool), 3);
+        let done = Arc::new(AtomicUsize::new(0));
+        for _ in 0..200 {
+            let done = done.clone();
+            assert!(pool_run(
+                &pool,
+                pool_job(move || {
+                    done.fetch_add(1, AtomicOrdering::SeqCst);
+                })
+            ));
+        }
+        pool_shutdown(&pool);
+        assert!(pool_is_shutdown(&pool));
+        assert!(!pool_run(&pool, pool_job(|| {})));
+        pool_join(&pool);
+        assert_eq!(done.load(AtomicOrdering::SeqCst), 200);
+        assert_eq!(pool_threads_count(&pool), 0);
+        assert_eq!(pool_queue_depth(&pool), 0);
+    }
+
+    #[test]
+    fn pool_reports_queue_depth_and_survives_throwing_jobs() {
+        let pool = pool_new_fixed(1);
+        let gate = Arc::new(LockHandle::default());
+        let blocker = gate.clone();
+        assert!(pool_run(
+            &pool,
+            pool_job(move || {
+                blocker.wait(None);
+            })
+        ));
+        assert!(pool_run(
+            &pool,
+            pool_job(|| exception::throw(dynamic::from(String::from("job failed"))))
+        ));
+        let ran = Arc::new(AtomicBool::new(false));
+        let flag = ran.clone();
+        assert!(pool_run(
+            &pool,
+            pool_job(move || flag.store(true, AtomicOrdering::SeqCst))
+        ));
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while pool_queue_depth(&pool) != 2 && Instant::now() < deadline {
+            std::thread::yield_now();
+        }
+        assert_eq!(pool_queue_depth(&pool), 2);
+        assert!(pool_shared(&pool).is_saturated());
+
+        gate.release();
+        pool_shutdown(&pool);
+        pool_join(&pool);
+        assert!(ran.load(AtomicOrdering::SeqCst));
+        assert!(!pool_shared(&pool).is_saturated());
+    }
+
+    #[test]
+    fn worker_submissions_are_stolen_by_idle_workers() {
+        let pool = pool_new_fixed(4);
+        let done = Arc::new(AtomicUsize::new(0));
+        let threads = Arc::new(ParkMutex::new(std::collections::HashSet::new()));
+        let inner_pool = pool.clone();
+        let (outer_done, outer_threads) = (done.clone(), threads.clone());
+        assert!(pool_run(
+            &pool,
+            pool_job(move || {
+                for _ in 0..64 {
+                    let (done, threads) = (outer_done.clone(), outer_threads.clone());
+                    pool_run(
+                        &inner_pool,
+                        pool_job(move || {
+                            std::thread::sleep(Duration::from_millis(2));
+                            threads.lock().insert(std::thread::current().id());
+                            done.fetch_add(1, AtomicOrdering::SeqCst);
+                        }),
+                    );
+                }
+                let error = exception::catch_unwind(|| pool_join(&inner_pool)).unwrap_err();
+                assert_haxe_error_prefix(error, "HXRT-THREAD-POOL-SELF-JOIN");
+            })
+        ));
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while done.load(AtomicOrdering::SeqCst) < 64 && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        pool_shutdown(&pool);
+        pool_join(&pool);
+        assert_eq!(done.load(AtomicOrdering::SeqCst), 64);
+        assert!(threads.lock().len() > 1, "local work was never stolen");
+    }
+
+    #[test]
+    fn elastic_pool_grows_on_demand_and_retires_idle_workers() {
+        let pool = pool_new_elastic(4, 0.05);
+        assert_eq!(pool_threads_count(&pool), 0);
+        let done = Arc::new(AtomicUsize::new(0));
+        for _ in 0..8 {
+            let done = done.clone();
+            assert!(pool_run(
+                &pool,
+                pool_job(move || {
+                    std::thread::sleep(Duration::from_millis(5));
+                    done.fetch_add(1, AtomicOrdering::SeqCst);
+                })
+            ));
+        }
+        assert!(pool_threads_count(&pool) <= 4);
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while pool_threads_count(&pool) > 0 && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(done.load(AtomicOrdering::SeqCst), 8);
+        assert_eq!(pool_threads_count(&pool), 0);
+
+        let again = done.clone();
+        assert!(pool_run(
+            &pool,
+            pool_job(move || {
+                again.fetch_add(1, AtomicOrdering::SeqCst);
+            })
+        ));
+        pool_shutdown(&pool);
+        pool_join(&pool);
+        assert_eq!(done.load(AtomicOrdering::SeqCst), 9);
+    }
+
+    #[test]
+    fn elastic_pool_max_workers_change_applies_to_later_runs() {
+        let pool = pool_new_elastic(1, 0.05);
+        let gate = Arc::new(LockHandle::default());
+        let started = Arc::new(AtomicUsize::new(0));
+        let submit_blocked = |pool: &HxRef<ThreadPoolHandle>| {
+            let (gate, started) = (gate.clone(), started.clone());
+            assert!(pool_run(
+                pool,
+                pool_job(move || {
+                    started.fetch_add(1, AtomicOrdering::SeqCst);
+                    gate.wait(None);
+                })
+            ));
+        };
+        let wait_started = |count: usize| {
+            let deadline = Instant::now() + Duration::from_secs(5);
+            while started.load(AtomicOrdering::SeqCst) < count && Instant::now() < deadline {
+                std::thread::yield_now();
+            }
+            assert_eq!(started.load(AtomicOrdering::SeqCst), count);
+        };
+
+        submit_blocked(&pool);
+        wait_started(1);
+        pool_set_max_workers(&pool, 3);
+        submit_blocked(&pool);
+        submit_blocked(&pool);
+        wait_started(3);
+        assert_eq!(pool_threads_count(&pool), 3);
+
+        pool_set_max_workers(&pool, 1);
+        submit_blocked(&pool);
+        assert_eq!(pool_threads_count(&pool), 3);
+        assert_eq!(pool_queue_depth(&pool), 1);
+        for _ in 0..4 {
+            gate.release();
+        }
+        pool_shutdown(&pool);
+        pool_join(&pool);
+        assert_eq!(started.load(AtomicOrdering::SeqCst), 4);
+    }
+
     #[test]
     fn thread_registration_guard_removes_state_on_rust_unwind() {
         let id = test_thread_id();
@@ -790,7 +1774,7 @@ mod tests {
             panic!("injected child panic");
         }));
         assert!(unwind.is_err());
-        assert!(!threads().lock().contains_key(&id));
+        assert!(!threads().lock().live.contains_key(&id));
     }
 
     #[test]
@@ -804,6 +1788,124 @@ mod tests {
         assert_eq!(msg.downcast::<String>().unwrap().as_str(), "child_ready");
     }
 
+    #[test]
+    fn joinable_thread_uses_options_and_joins_once() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let release = Arc::new(LockHandle::default());
+        let gate = release.clone();
+        let job: HxRc<dyn Fn() + Send + Sync> = HxRc::new(move || {
+            let name = std::thread::current().name().map(str::to_owned);
+            tx.send(name).unwrap();
+            gate.wait(None);
+        });
+        let id = thread_spawn_with_options(
+            HxDynRef::new(job),
+            crate::string::HxString::from("hx-worker"),
+            256 * 1024,
+            true,
+            false,
+        );
+        assert_eq!(rx.recv().unwrap().as_deref(), Some("hx-worker"));
+        assert!(thread_is_alive(id));
+        release.release();
+        thread_join(id);
+        assert!(!thread_is_alive(id));
+
+        let error = exception::catch_unwind(|| thread_join(id)).unwrap_err();
+        assert!(error
+            .to_haxe_string()
+            .starts_with("HXRT-THREAD-NOT-JOINABLE"));
+        assert!(thread_is_alive(0));
+    }
+
+    #[test]
+    fn thread_join_reports_rust_panics_and_rejects_bad_options() {
+        let job: HxRc<dyn Fn() + Send + Sync> = HxRc::new(|| panic!("injected child panic"));
+        let id = thread_spawn_with_options(
+            HxDynRef::new(job.clone()),
+            crate::string::HxString::from("hx-panicky"),
+            0,
+            true,
+            false,
+        );
+        let error = exception::catch_unwind(|| thread_join(id)).unwrap_err();
+        assert_eq!(
+            error.to_haxe_string(),
+            "HXRT-THREAD-PANICKED: thread 'hx-panicky' panicked"
+        );
+
+        let error = exception::catch_unwind(|| {
+            thread_spawn_with_options(
+                HxDynRef::new(job),
+                crate::string::HxString::null(),
+                -1,
+                false,
+                false,
+            )
+        })
+        .unwrap_err();
+        assert!(error.to_haxe_string().starts_with("HXRT-THREAD-OPTIONS"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn event_loop_io_watch_wakes_for_readiness_and_cross_thread_run() {
+        use std::io::{Read, Write};
+        use std::os::unix::net::UnixStream;
+
+        let tid = test_thread_id();
+        let (local, mut peer) = UnixStream::pair().unwrap();
+        let source = mio::net::UnixStream::from_std(local.try_clone().unwrap());
+        let reader = Arc::new(ParkMutex::new(local));
+        let received = Arc::new(ParkMutex::new(Vec::new()));
+        let (read_from, record) = (reader.clone(), received.clone());
+        let on_ready: HxRc<dyn Fn() + Send + Sync> = HxRc::new(move || {
+            let mut byte = [0u8; 1];
+            let n = read_from.lock().read(&mut byte).unwrap();
+            record.lock().extend_from_slice(&byte[..n]);
+        });
+        let watch = event_loop_watch(
+            tid,
+            Box::new(source),
+            Interest::READABLE,
+            HxDynRef::new(on_ready),
+        );
+        assert_eq!(event_loop_progress(tid), -3.0);
+
+        // Two bytes but one read per callback: the re-armed watch must fire a second time.
+        let writer = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            peer.write_all(b"hi").unwrap();
+            peer
+        });
+        while received.lock().len() < 2 {
+            assert!(event_loop_wait(tid, Some(5.0)));
+            event_loop_progress(tid);
+        }
+        assert_eq!(received.lock().as_slice(), b"hi");
+        let _peer = writer.join().unwrap();
+
+        let ran = Arc::new(AtomicBool::new(false));
+        let flag = ran.clone();
+        let remote = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            let event: HxRc<dyn Fn() + Send + Sync> =
+                HxRc::new(move || flag.store(true, AtomicOrdering::SeqCst));
+            event_loop_run(tid, HxDynRef::new(event));
+        });
+        let started = Instant::now();
+        assert!(event_loop_wait(tid, Some(5.0)));
+        assert!(started.elapsed() < Duration::from_secs(4));
+        remote.join().unwrap();
+        assert_eq!(event_loop_progress(tid), -2.0);
+        assert!(ran.load(AtomicOrdering::SeqCst));
+
+        event_loop_cancel(tid, watch);
+        assert_eq!(event_loop_progress(tid), -1.0);
+        assert!(!event_loop_wait(tid, Some(0.0)));
+        remove_thread_state(tid);
+    }
+
     #[test]
     fn event_loop_run_executes_on_progress() {
         let tid = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
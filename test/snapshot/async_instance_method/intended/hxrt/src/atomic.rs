@@ -0,0 +1,302 @@
+//! `hxrt::atomic`
+//!
+//! Runtime storage for `haxe.atomic.AtomicInt`, `AtomicBool`, and `AtomicObject`.
+//!
+//! Why
+//! - Upstream `haxe.atomic.*` are core-type abstracts with no portable implementation; the Rust
+//!   target has to provide real atomics that stay coherent across `sys.thread` workers.
+//!
+//! What
+//! - `AtomicIntHandle` / `AtomicBoolHandle`: shared `AtomicI32` / `AtomicBool` cells.
+//! - `AtomicObjectHandle<T>`: a shared slot holding one Haxe object handle, compared by identity.
+//! - Every read-modify-write returns the value the Haxe API documents: the *previous* value for
+//!   `add`/`sub`/`and`/`or`/`xor`/`exchange`/`compareExchange`, the *stored* value for `store`.
+//!
+//! How
+//! - Handles are cheap `Arc` clones, so Haxe assignment aliases the same cell (reference semantics)
+//!   and the values are `Send + Sync` without an `HxCell` lock around them.
+//! - Integer and boolean operations use `SeqCst` ordering, matching the sequential consistency the
+//!   Haxe atomics API documents.
+//! - Object slots are `arc_swap::ArcSwap` pointers to an immutable copy of the stored handle, so
+//!   `load`/`store`/`exchange` are lock-free pointer swaps. The extra `Arc` keeps one thin pointer
+//!   per value even for `HxDynRef<dyn Trait>` handles, which are fat pointers.
+//! - `compare_exchange` checks the stored handle's identity, then publishes with a pointer CAS on
+//!   the slot; a CAS that loses a race retries against the value that won it.
+
+use crate::hxref::HxRefLike;
+use arc_swap::ArcSwap;
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+use std::sync::Arc;
+
+/// Shared 32-bit integer cell backing `haxe.atomic.AtomicInt`.
+#[derive(Clone, Debug, Default)]
+pub struct AtomicIntHandle {
+    value: Arc<AtomicI32>,
+}
+
+impl PartialEq for AtomicIntHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.value, &other.value)
+    }
+}
+
+impl AtomicIntHandle {
+    pub fn new(value: i32) -> Self {
+        Self {
+            value: Arc::new(AtomicI32::new(value)),
+        }
+    }
+
+    /// Wrapping add; returns the previous value.
+    pub fn add(&self, b: i32) -> i32 {
+        self.value.fetch_add(b, Ordering::SeqCst)
+    }
+
+    /// Wrapping subtract; returns the previous value.
+    pub fn sub(&self, b: i32) -> i32 {
+        self.value.fetch_sub(b, Ordering::SeqCst)
+    }
+
+    pub fn and(&self, b: i32) -> i32 {
+        self.value.fetch_and(b, Ordering::SeqCst)
+    }
+
+    pub fn or(&self, b: i32) -> i32 {
+        self.value.fetch_or(b, Ordering::SeqCst)
+    }
+
+    pub fn xor(&self, b: i32) -> i32 {
+        self.value.fetch_xor(b, Ordering::SeqCst)
+    }
+
+    /// Store `replacement` when the current value equals `expected`; returns the previous value
+    /// whether or not the exchange happened.
+    pub fn compare_exchange(&self, expected: i32, replacement: i32) -> i32 {
+        match self
+            .value
+            .compare_exchange(expected, replacement, Ordering::SeqCst, Ordering::SeqCst)
+        {
+            Ok(previous) | Err(previous) => previous,
+        }
+    }
+
+    pub fn exchange(&self, value: i32) -> i32 {
+        self.value.swap(value, Ordering::SeqCst)
+    }
+
+    pub fn load(&self) -> i32 {
+        self.value.load(Ordering::SeqCst)
+    }
+
+    /// Stores `value` and returns it.
+    pub fn store(&self, value: i32) -> i32 {
+        self.value.store(value, Ordering::SeqCst);
+        value
+    }
+}
+
+/// Shared boolean cell backing `haxe.atomic.AtomicBool`.
+#[derive(Clone, Debug, Default)]
+pub struct AtomicBoolHandle {
+    value: Arc<AtomicBool>,
+}
+
+impl PartialEq for AtomicBoolHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.value, &other.value)
+    }
+}
+
+impl AtomicBoolHandle {
+    pub fn new(value: bool) -> Self {
+        Self {
+            value: Arc::new(AtomicBool::new(value)),
+        }
+    }
+
+    /// Store `replacement` when the current value equals `expected`; returns the previous value.
+    pub fn compare_exchange(&self, expected: bool, replacement: bool) -> bool {
+        match self
+            .value
+            .compare_exchange(expected, replacement, Ordering::SeqCst, Ordering::SeqCst)
+        {
+            Ok(previous) | Err(previous) => previous,
+        }
+    }
+
+    pub fn exchange(&self, value: bool) -> bool {
+        self.value.swap(value, Ordering::SeqCst)
+    }
+
+    pub fn load(&self) -> bool {
+        self.value.load(Ordering::SeqCst)
+    }
+
+    /// Stores `value` and returns it.
+    pub fn store(&self, value: bool) -> bool {
+        self.value.store(value, Ordering::SeqCst);
+        value
+    }
+}
+
+/// Shared object slot backing `haxe.atomic.AtomicObject<T>`.
+///
+/// `T` is a Haxe object handle (`HxRef` / `HxDynRef`); `compare_exchange` compares by reference
+/// identity, which is what Haxe `==` means for objects.
+#[derive(Debug)]
+pub struct AtomicObjectHandle<T> {
+    slot: Arc<ArcSwap<T>>,
+}
+
+impl<T> Clone for AtomicObjectHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            slot: self.slot.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for AtomicObjectHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.slot, &other.slot)
+    }
+}
+
+impl<T: Clone + HxRefLike> AtomicObjectHandle<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            slot: Arc::new(ArcSwap::from_pointee(value)),
+        }
+    }
+
+    /// Store `replacement` when the current object is `expected` (by identity); returns the
+    /// previous object whether or not the exchange happened.
+    pub fn compare_exchange(&self, expected: T, replacement: T) -> T {
+        let replacement = Arc::new(replacement);
+        let mut current = self.slot.load_full();
+        loop {
+            // `T::ptr_usize` names the stored handle, not the slot's own `Arc`.
+            if T::ptr_usize(&current) != expected.ptr_usize() {
+                return (*current).clone();
+            }
+            let previous = self.slot.compare_and_swap(&current, replacement.clone());
+            if Arc::ptr_eq(&previous, &current) {
+                return (*current).clone();
+            }
+            current = arc_swap::Guard::into_inner(previous);
+        }
+    }
+
+    pub fn exchange(&self, value: T) -> T {
+        (*self.slot.swap(Arc::new(value))).clone()
+    }
+
+    pub fn load(&self) -> T {
+        (**self.slot.load()).clone()
+    }
+
+    /// Stores `value` and returns it.
+    pub fn store(&self, value: T) -> T {
+        self.slot.store(Arc::new(value.clone()));
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell::HxRef;
+
+    #[test]
+    fn int_operations_return_documented_values() {
+        let atomic = AtomicIntHandle::new(5);
+        let alias = atomic.clone();
+        assert_eq!(atomic.add(3), 5);
+        assert_eq!(alias.sub(1), 8);
+        assert_eq!(atomic.compare_exchange(1, 100), 7);
+        assert_eq!(atomic.load(), 7);
+        assert_eq!(atomic.compare_exchange(7, 100), 7);
+        assert_eq!(atomic.exchange(0b1100), 100);
+        assert_eq!(atomic.and(0b1010), 0b1100);
+        assert_eq!(atomic.or(0b0001), 0b1000);
+        assert_eq!(atomic.xor(0b1111), 0b1001);
+        assert_eq!(atomic.store(i32::MAX), i32::MAX);
+        assert_eq!(atomic.add(1), i32::MAX);
+        assert_eq!(alias.load(), i32::MIN);
+    }
+
+    #[test]
+    fn int_add_is_atomic_across_threads() {
+        let counter = AtomicIntHandle::new(0);
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let counter = counter.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..1000 {
+                        counter.add(1);
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+        assert_eq!(counter.load(), 4000);
+    }
+
+    #[test]
+    fn bool_operations_return_documented_values() {
+        let flag = AtomicBoolHandle::new(false);
+        assert!(!flag.compare_exchange(true, false));
+        assert!(!flag.compare_exchange(false, true));
+        assert!(flag.load());
+        assert!(flag.exchange(false));
+        assert!(flag.store(true));
+        assert!(flag.clone().load());
+    }
+
+    #[test]
+    fn object_compare_exchange_uses_identity() {
+        let first = HxRef::new(1);
+        let twin = HxRef::new(1);
+        let second = HxRef::new(2);
+        let slot = AtomicObjectHandle::new(first.clone());
+
+        assert!(slot.compare_exchange(twin, second.clone()).ptr_eq(&first));
+        assert!(slot.load().ptr_eq(&first));
+        assert!(slot
+            .compare_exchange(first.clone(), second.clone())
+            .ptr_eq(&first));
+        assert!(slot.load().ptr_eq(&second));
+        assert!(slot.exchange(first.clone()).ptr_eq(&second));
+        assert!(slot.store(second.clone()).ptr_eq(&second));
+    }
+
+    #[test]
+    fn object_compare_exchange_loops_lose_no_updates_across_threads() {
+        let slot = AtomicObjectHandle::new(HxRef::new(0));
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let slot = slot.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..500 {
+                        loop {
+                            let current = slot.load();
+                            let next = HxRef::new(*current.borrow() + 1);
+                            if slot
+                                .compare_exchange(current.clone(), next)
+                                .ptr_eq(&current)
+                            {
+                                break;
+                            }
+                        }
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+        assert_eq!(*slot.load().borrow(), 2000);
+    }
+}
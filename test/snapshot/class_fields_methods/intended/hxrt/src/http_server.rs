@@ -0,0 +1,1154 @@
+//! `hxrt::http_server`
+//!
+//! Embedded HTTP/1.1 server for `rust.http.Server`.
+//!
+//! Why
+//! - `sys.Http` is client-only, so every admin or health endpoint re-implemented request parsing
+//!   on `sys.net.Socket`, usually one connection at a time and without keep-alive.
+//!
+//! What
+//! - `http_server_bind` opens a listening socket. `HttpServerHandle::serve` (blocking) or `start`
+//!   (background accept thread) pass every parsed request to one Haxe handler, which returns the
+//!   response; `stop` ends the accept loop.
+//! - Requests: request line and headers, `Content-Length` or `chunked` bodies (trailers are
+//!   dropped), `Expect: 100-continue`, keep-alive (HTTP/1.1 default, HTTP/1.0 on request).
+//! - Responses: `Content-Length` is always computed from the body, and `HEAD`, `204` and `304`
+//!   responses carry no body.
+//! - Malformed or oversized requests are answered (400, 408, 413, 417, 431, 501, 505) and the
+//!   connection closed without calling the handler. A handler exception, or a handler response
+//!   with a 1xx status, answers 500.
+//!
+//! How
+//! - Built on `hxrt::net::SocketHandle`, so listener/connection sockets get the same timeouts,
+//!   socket options and tracing spans as `sys.net.Socket`.
+//! - Each accepted connection runs on its own registered runtime thread (`hxrt-http-conn`), so
+//!   handlers can use `sys.thread` APIs. Past `max_connections` a connection gets 503 and is closed;
+//!   the 503 is sent from a short-lived `hxrt-http-reject` thread with a fixed deadline, so a slow
+//!   peer cannot stall the accept loop. Past `MAX_PENDING_REJECTS` such threads, extra connections
+//!   are closed without a response.
+//! - The listener is non-blocking and the accept loop waits in a `mio::Poll` on it plus a `Waker`:
+//!   `stop` sets the stop flag and wakes the poll, so stopping never depends on reaching the
+//!   listener over the network. The poll also times out every `ACCEPT_POLL_INTERVAL`, which
+//!   rechecks the flag and retries accepts that failed transiently.
+
+use crate::array::Array;
+use crate::bytes::Bytes;
+use crate::cell::{HxDynRef, HxRc, HxRef};
+use crate::net::SocketHandle;
+use crate::{dynamic, exception, net, thread};
+use mio::{Events, Interest, Poll, Token, Waker};
+use parking_lot::{Condvar, Mutex as ParkMutex};
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const HTTP_CONFIG_ERROR: &str = "HXRT-HTTP-CONFIG";
+const HTTP_SERVER_ERROR: &str = "HXRT-HTTP-SERVER";
+const HTTP_RESPONSE_ERROR: &str = "HXRT-HTTP-RESPONSE";
+
+/// Largest request line plus header block.
+const MAX_HEAD_BYTES: usize = 64 * 1024;
+/// Most header fields in one request.
+const MAX_HEADERS: usize = 100;
+const LISTEN_BACKLOG: i32 = 128;
+const READ_CHUNK: usize = 8 * 1024;
+const REJECT_DRAIN_TIMEOUT: Duration = Duration::from_millis(200);
+/// Upper bound on draining unread input after an error response.
+const REJECT_DRAIN_DEADLINE: Duration = Duration::from_secs(1);
+/// Write timeout for the 503 sent to connections past `max_connections`.
+const BUSY_WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+/// Most connections being answered 503 at once.
+const MAX_PENDING_REJECTS: usize = 64;
+/// Longest the accept loop waits before rechecking the stop flag on its own.
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+const LISTENER_TOKEN: Token = Token(0);
+const STOP_TOKEN: Token = Token(1);
+
+type Handler = HxRc<dyn Fn(HttpRequestHandle) -> HttpResponseHandle + Send + Sync>;
+
+fn throw_msg(msg: String) -> ! {
+    exception::throw(dynamic::from(msg))
+}
+
+#[derive(Clone, Copy, Debug)]
+struct ServerConfig {
+    max_body_size: usize,
+    // `None` disables keep-alive: every response closes the connection.
+    keep_alive_timeout: Option<Duration>,
+    read_timeout: Duration,
+    max_connections: usize,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            max_body_size: 8 * 1024 * 1024,
+            keep_alive_timeout: Some(Duration::from_secs(5)),
+            read_timeout: Duration::from_secs(30),
+            max_connections: 256,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ServeState {
+    Ready,
+    Serving,
+    Stopped,
+}
+
+/// Readiness poll of the accept loop: the non-blocking listener clone plus the stop `Waker`.
+struct AcceptPoll {
+    poll: Poll,
+    // Keeps the listener registration alive; never read from.
+    _listener: thread::IoSource,
+}
+
+impl std::fmt::Debug for AcceptPoll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AcceptPoll").finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug)]
+struct ServerShared {
+    listener: HxRef<SocketHandle>,
+    local: (IpAddr, i32),
+    accept_poll: ParkMutex<AcceptPoll>,
+    stop_waker: Waker,
+    config: ParkMutex<ServerConfig>,
+    state: ParkMutex<ServeState>,
+    state_changed: Condvar,
+    stopping: AtomicBool,
+    active: AtomicUsize,
+    rejecting: AtomicUsize,
+}
+
+/// Listening server (`rust.http.Server`). Clones refer to the same server.
+#[derive(Clone, Debug)]
+pub struct HttpServerHandle {
+    shared: Arc<ServerShared>,
+}
+
+/// Bind a server to `host:port`; port `0` picks a free port (see `port()`).
+///
+/// Invalid ports throw a catchable String beginning with `HXRT-HTTP-CONFIG`; resolve and bind
+/// failures throw the same `haxe.io.Error` values as `sys.net.Socket.bind`.
+pub fn http_server_bind<H: AsRef<str>>(host: H, port: i32) -> HttpServerHandle {
+    if !(0..=65535).contains(&port) {
+        throw_msg(format!(
+            "{HTTP_CONFIG_ERROR}: port must be in 0..65535 (got {port})"
+        ));
+    }
+    let ip = net::host_resolve(host.as_ref());
+    let listener = net::socket_new_tcp();
+    listener.borrow_mut().bind(ip, port);
+    listener.borrow_mut().listen(LISTEN_BACKLOG);
+    let local = listener.borrow().host();
+    let (accept_poll, stop_waker) = accept_poll(&listener).unwrap_or_else(|error| {
+        let _ = exception::catch_unwind(|| listener.borrow_mut().close());
+        throw_msg(format!(
+            "{HTTP_SERVER_ERROR}: could not poll the listener: {error}"
+        ))
+    });
+    HttpServerHandle {
+        shared: Arc::new(ServerShared {
+            listener,
+            local,
+            accept_poll: ParkMutex::new(accept_poll),
+            stop_waker,
+            config: ParkMutex::new(ServerConfig::default()),
+            state: ParkMutex::new(ServeState::Ready),
+            state_changed: Condvar::new(),
+            stopping: AtomicBool::new(false),
+            active: AtomicUsize::new(0),
+            rejecting: AtomicUsize::new(0),
+        }),
+    }
+}
+
+/// Register a non-blocking clone of `listener` and a stop `Waker` with a fresh poll.
+fn accept_poll(listener: &HxRef<SocketHandle>) -> std::io::Result<(AcceptPoll, Waker)> {
+    let poll = Poll::new()?;
+    let mut source = net::socket_nonblocking_source(listener)
+        .ok_or_else(|| std::io::Error::other("listener has no OS handle"))?;
+    poll.registry()
+        .register(&mut source, LISTENER_TOKEN, Interest::READABLE)?;
+    let waker = Waker::new(poll.registry(), STOP_TOKEN)?;
+    Ok((
+        AcceptPoll {
+            poll,
+            _listener: source,
+        },
+        waker,
+    ))
+}
+
+fn handler_rc(
+    handler: HxDynRef<dyn Fn(HttpRequestHandle) -> HttpResponseHandle + Send + Sync>,
+) -> Handler {
+    match handler.as_arc_opt() {
+        Some(rc) => rc.clone(),
+        None => throw_msg(String::from("Null Access")),
+    }
+}
+
+fn positive_seconds(what: &str, seconds: f64) -> Duration {
+    match Duration::try_from_secs_f64(seconds) {
+        Ok(duration) if !duration.is_zero() => duration,
+        _ => throw_msg(format!(
+            "{HTTP_CONFIG_ERROR}: {what} must be a positive number of seconds (got {seconds})"
+        )),
+    }
+}
+
+impl HttpServerHandle {
+    /// Bound port (the real one when bound to port `0`).
+    pub fn port(&self) -> i32 {
+        self.shared.local.1
+    }
+
+    /// Bound address as text (`"127.0.0.1"`, `"::"`).
+    pub fn host(&self) -> String {
+        self.shared.local.0.to_string()
+    }
+
+    /// Largest accepted request body in bytes (default 8 MiB); larger bodies get 413.
+    pub fn set_max_body_size(&self, bytes: i32) {
+        if bytes < 0 {
+            throw_msg(format!(
+                "{HTTP_CONFIG_ERROR}: max body size must not be negative (got {bytes})"
+            ));
+        }
+        self.shared.config.lock().max_body_size = bytes as usize;
+    }
+
+    /// How long an idle keep-alive connection waits for its next request (default 5 s);
+    /// `0` disables keep-alive.
+    pub fn set_keep_alive_timeout(&self, seconds: f64) {
+        let timeout = if seconds == 0.0 {
+            None
+        } else {
+            Some(positive_seconds("keep-alive timeout", seconds))
+        };
+        self.shared.config.lock().keep_alive_timeout = timeout;
+    }
+
+    /// Socket read/write timeout while a request is in progress (default 30 s); a stalled request
+    /// gets 408.
+    pub fn set_read_timeout(&self, seconds: f64) {
+        self.shared.config.lock().read_timeout = positive_seconds("read timeout", seconds);
+    }
+
+    /// Most connections served at once (default 256); extra connections get 503.
+    pub fn set_max_connections(&self, connections: i32) {
+        if connections < 1 {
+            throw_msg(format!(
+                "{HTTP_CONFIG_ERROR}: max connections must be at least 1 (got {connections})"
+            ));
+        }
+        self.shared.config.lock().max_connections = connections as usize;
+    }
+
+    /// `true` between `serve` / `start` and the end of the accept loop.
+    pub fn is_running(&self) -> bool {
+        *self.shared.state.lock() == ServeState::Serving
+    }
+
+    /// Accept connections on the calling thread until `stop` is called (from a handler or another
+    /// thread).
+    ///
+    /// A server serves once: calling `serve` / `start` while it is running or after `stop` throws
+    /// `HXRT-HTTP-SERVER`.
+    pub fn serve(
+        &self,
+        handler: HxDynRef<dyn Fn(HttpRequestHandle) -> HttpResponseHandle + Send + Sync>,
+    ) {
+        let handler = handler_rc(handler);
+        self.begin_serving();
+        accept_loop(&self.shared, &handler);
+    }
+
+    /// Like `serve`, but accepts on a background runtime thread and returns immediately.
+    pub fn start(
+        &self,
+        handler: HxDynRef<dyn Fn(HttpRequestHandle) -> HttpResponseHandle + Send + Sync>,
+    ) {
+        let handler = handler_rc(handler);
+        self.begin_serving();
+        let shared = self.shared.clone();
+        let job: HxRc<dyn Fn() + Send + Sync> = HxRc::new(move || accept_loop(&shared, &handler));
+        if let Err(error) = thread::spawn_named_thread(job, "hxrt-http-accept") {
+            *self.shared.state.lock() = ServeState::Ready;
+            throw_msg(format!(
+                "{HTTP_SERVER_ERROR}: could not start the accept thread: {error}"
+            ));
+        }
+    }
+
+    /// Stop accepting and close the listener, waiting for the accept loop to end.
+    ///
+    /// In-flight requests still complete on their worker threads, then their connections close.
+    /// Stopping twice is a no-op.
+    pub fn stop(&self) {
+        let shared = &self.shared;
+        shared.stopping.store(true, Ordering::SeqCst);
+        {
+            let mut state = shared.state.lock();
+            match *state {
+                ServeState::Stopped => return,
+                ServeState::Ready => {
+                    *state = ServeState::Stopped;
+                    drop(state);
+                    let _ = exception::catch_unwind(|| shared.listener.borrow_mut().close());
+                    return;
+                }
+                ServeState::Serving => {}
+            }
+        }
+        let _ = shared.stop_waker.wake();
+        let mut state = shared.state.lock();
+        while *state == ServeState::Serving {
+            shared.state_changed.wait(&mut state);
+        }
+    }
+
+    fn begin_serving(&self) {
+        let mut state = self.shared.state.lock();
+        match *state {
+            ServeState::Ready => *state = ServeState::Serving,
+            ServeState::Serving => {
+                throw_msg(format!("{HTTP_SERVER_ERROR}: server is already running"))
+            }
+            ServeState::Stopped => throw_msg(format!("{HTTP_SERVER_ERROR}: server is stopped")),
+        }
+    }
+}
+
+fn accept_loop(shared: &Arc<ServerShared>, handler: &Handler) {
+    let mut events = Events::with_capacity(8);
+    while !shared.stopping.load(Ordering::SeqCst) {
+        // Readiness is edge-triggered, so every pass (event or timeout) drains pending connections
+        // until `accept` fails: would-block ends the batch, and a transient failure (peer reset,
+        // fd exhaustion) is retried on the next pass.
+        let polled = shared
+            .accept_poll
+            .lock()
+            .poll
+            .poll(&mut events, Some(ACCEPT_POLL_INTERVAL));
+        if let Err(error) = polled {
+            if error.kind() != std::io::ErrorKind::Interrupted {
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            continue;
+        }
+        while !shared.stopping.load(Ordering::SeqCst) {
+            match exception::catch_unwind(|| shared.listener.borrow_mut().try_accept_tcp()) {
+                Ok(Some(socket)) => dispatch(shared, handler, socket),
+                _ => break,
+            }
+        }
+    }
+    let _ = exception::catch_unwind(|| shared.listener.borrow_mut().close());
+    *shared.state.lock() = ServeState::Stopped;
+    shared.state_changed.notify_all();
+}
+
+/// Decrements a connection counter (`active` or `rejecting`) when a worker ends, including by
+/// unwinding.
+struct SlotGuard {
+    shared: Arc<ServerShared>,
+    counter: fn(&ServerShared) -> &AtomicUsize,
+}
+
+impl SlotGuard {
+    fn active(shared: &Arc<ServerShared>) -> Self {
+        Self {
+            shared: shared.clone(),
+            counter: |shared| &shared.active,
+        }
+    }
+
+    fn rejecting(shared: &Arc<ServerShared>) -> Self {
+        Self {
+            shared: shared.clone(),
+            counter: |shared| &shared.rejecting,
+        }
+    }
+}
+
+impl Drop for SlotGuard {
+    fn drop(&mut self) {
+        (self.counter)(&self.shared).fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn dispatch(shared: &Arc<ServerShared>, handler: &Handler, socket: HxRef<SocketHandle>) {
+    let max_connections = shared.config.lock().max_connections;
+    if shared.active.fetch_add(1, Ordering::SeqCst) >= max_connections {
+        shared.active.fetch_sub(1, Ordering::SeqCst);
+        reject_busy(shared, socket);
+        return;
+    }
+    let guard = ParkMutex::new(Some((SlotGuard::active(shared), Connection::new(socket))));
+    let shared_for_job = shared.clone();
+    let handler_for_job = handler.clone();
+    let job: HxRc<dyn Fn() + Send + Sync> = HxRc::new(move || {
+        if let Some((_active, conn)) = guard.lock().take() {
+            serve_connection(&shared_for_job, &handler_for_job, conn);
+        }
+    });
+    // A failed spawn drops the job, and with it the connection and its active slot: the peer
+    // sees the connection close.
+    let _ = thread::spawn_named_thread(job, "hxrt-http-conn");
+}
+
+/// Answer a connection past `max_connections` with 503 off the accept thread.
+fn reject_busy(shared: &Arc<ServerShared>, socket: HxRef<SocketHandle>) {
+    let conn = Connection::new(socket);
+    if shared.rejecting.fetch_add(1, Ordering::SeqCst) >= MAX_PENDING_REJECTS {
+        shared.rejecting.fetch_sub(1, Ordering::SeqCst);
+        conn.close();
+        return;
+    }
+    let guard = ParkMutex::new(Some((SlotGuard::rejecting(shared), conn)));
+    let job: HxRc<dyn Fn() + Send + Sync> = HxRc::new(move || {
+        if let Some((_rejecting, mut conn)) = guard.lock().take() {
+            conn.reject(503, BUSY_WRITE_TIMEOUT);
+        }
+    });
+    let _ = thread::spawn_named_thread(job, "hxrt-http-reject");
+}
+
+/// One accepted socket plus the bytes read from it but not yet consumed.
+struct Connection {
+    socket: HxRef<SocketHandle>,
+    buf: Vec<u8>,
+    start: usize,
+}
+
+/// Why a request could not be read.
+enum ReadFailure {
+    /// Peer closed, or the idle keep-alive timeout passed, before a new request began.
+    Idle,
+    /// Answer with this status, then close.
+    Status(u16),
+}
+
+impl Connection {
+    fn new(socket: HxRef<SocketHandle>) -> Self {
+        Self {
+            socket,
+            buf: Vec::new(),
+            start: 0,
+        }
+    }
+
+    fn set_timeout(&self, timeout: Duration) {
+        let seconds = timeout.as_secs_f64();
+        let _ = exception::catch_unwind(|| self.socket.borrow_mut().set_timeout(seconds));
+    }
+
+    fn buffered(&self) -> &[u8] {
+        &self.buf[self.start..]
+    }
+
+    fn consume(&mut self, len: usize) {
+        self.start += len;
+        if self.start == self.buf.len() {
+            self.buf.clear();
+            self.start = 0;
+        }
+    }
+
+    /// Read more bytes from the socket; `false` on EOF, timeout or socket error.
+    fn fill(&mut self) -> bool {
+        if self.start > 0 {
+            self.buf.drain(..self.start);
+            self.start = 0;
+        }
+        let mut chunk = [0u8; READ_CHUNK];
+        let socket = &self.socket;
+        match exception::catch_unwind(|| socket.borrow_mut().read_stream(&mut chunk)) {
+            Ok(read) if read > 0 => {
+                self.buf.extend_from_slice(&chunk[..read as usize]);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Next line without its `\n` / `\r\n`; `limit` bounds the line length.
+    fn read_line(&mut self, limit: usize, too_long: u16) -> Result<Vec<u8>, ReadFailure> {
+        let mut scanned = 0;
+        loop {
+            let buffered = self.buffered();
+            if let Some(index) = buffered[scanned..].iter().position(|&b| b == b'\n') {
+                let end = scanned + index;
+                let mut line = buffered[..end].to_vec();
+                if line.last() == Some(&b'\r') {
+                    line.pop();
+                }
+                self.consume(end + 1);
+                return Ok(line);
+            }
+            scanned = buffered.len();
+            if scanned > limit {
+                return Err(ReadFailure::Status(too_long));
+            }
+            if !self.fill() {
+                return Err(ReadFailure::Status(408));
+            }
+        }
+    }
+
+    fn read_exact(&mut self, len: usize, out: &mut Vec<u8>) -> Result<(), ReadFailure> {
+        let mut remaining = len;
+        while remaining > 0 {
+            if self.buffered().is_empty() && !self.fill() {
+                return Err(ReadFailure::Status(408));
+            }
+            let take = remaining.min(self.buffered().len());
+            out.extend_from_slice(&self.buffered()[..take]);
+            self.consume(take);
+            remaining -= take;
+        }
+        Ok(())
+    }
+
+    fn write_all(&self, mut data: &[u8]) -> bool {
+        while !data.is_empty() {
+            match exception::catch_unwind(|| self.socket.borrow_mut().write_stream(data)) {
+                Ok(written) if written > 0 => data = &data[written as usize..],
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// Answer `status` with a short text body and close.
+    ///
+    /// Closing with unread request bytes makes the OS send a reset, which can destroy the response
+    /// before the peer reads it; so the write side is shut first and pending input drained briefly,
+    /// for at most `REJECT_DRAIN_DEADLINE` however slowly the peer trickles bytes.
+    fn reject(&mut self, status: u16, write_timeout: Duration) {
+        self.set_timeout(write_timeout);
+        let response = HttpResponse::text(status, reason_phrase(status));
+        if self.write_all(&response.encode(false, false, false)) {
+            let socket = &self.socket;
+            let _ = exception::catch_unwind(|| socket.borrow_mut().shutdown(false, true));
+            let deadline = Instant::now() + REJECT_DRAIN_DEADLINE;
+            let mut drained = 0;
+            while drained < MAX_HEAD_BYTES {
+                let left = deadline.saturating_duration_since(Instant::now());
+                if left.is_zero() {
+                    break;
+                }
+                self.set_timeout(left.min(REJECT_DRAIN_TIMEOUT));
+                if !self.fill() {
+                    break;
+                }
+                drained += self.buffered().len();
+                self.consume(self.buffered().len());
+            }
+        }
+        self.close();
+    }
+
+    fn close(&self) {
+        let _ = exception::catch_unwind(|| self.socket.borrow_mut().close());
+    }
+}
+
+fn serve_connection(shared: &ServerShared, handler: &Handler, mut conn: Connection) {
+    let remote = exception::catch_unwind(|| conn.socket.borrow().peer()).ok();
+    let mut first = true;
+    loop {
+        let config = *shared.config.lock();
+        let wait = if first {
+            config.read_timeout
+        } else {
+            match config.keep_alive_timeout {
+                Some(timeout) => timeout,
+                None => break,
+            }
+        };
+        first = false;
+        conn.set_timeout(wait);
+        let request = match read_request(&mut conn, &config, remote) {
+            Ok(request) => request,
+            Err(ReadFailure::Idle) => break,
+            Err(ReadFailure::Status(status)) => {
+                conn.reject(status, config.read_timeout);
+                return;
+            }
+        };
+        let head_only = request.method == "HEAD";
+        let http10 = request.version == "HTTP/1.0";
+        let keep_alive = request.keep_alive
+            && config.keep_alive_timeout.is_some()
+            && !shared.stopping.load(Ordering::SeqCst);
+        let request = HttpRequestHandle {
+            inner: Arc::new(request),
+        };
+        // Interim 1xx responses belong to the server (`100 Continue`); one returned as the final
+        // answer would leave the client waiting for a response that never comes.
+        let response = match exception::catch_unwind(|| handler(request)) {
+            Ok(response) => Some(response.inner.lock().clone()).filter(|r| r.status >= 200),
+            Err(_) => None,
+        }
+        .unwrap_or_else(|| HttpResponse::text(500, reason_phrase(500)));
+        let keep_alive = keep_alive && !response.wants_close();
+        if !conn.write_all(&response.encode(head_only, keep_alive, http10)) || !keep_alive {
+            break;
+        }
+    }
+    conn.close();
+}
+
+fn is_token(text: &str) -> bool {
+    !text.is_empty()
+        && text
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b))
+}
+
+fn read_request(
+    conn: &mut Connection,
+    config: &ServerConfig,
+    remote: Option<(IpAddr, i32)>,
+) -> Result<HttpRequest, ReadFailure> {
+    // Wait for the first byte under the idle timeout; once a request has begun, a stall is a 408.
+    while conn.buffered().is_empty() {
+        if !conn.fill() {
+            return Err(ReadFailure::Idle);
+        }
+    }
+    conn.set_timeout(config.read_timeout);
+
+    // RFC 9112 2.2: ignore empty lines before the request line.
+    let mut head_bytes = 0;
+    let line = loop {
+        let line = conn.read_line(MAX_HEAD_BYTES, 431)?;
+        head_bytes += line.len() + 2;
+        if head_bytes > MAX_HEAD_BYTES {
+            return Err(ReadFailure::Status(431));
+        }
+        if !line.is_empty() {
+            break line;
+        }
+    };
+    let line = String::from_utf8(line).map_err(|_| ReadFailure::Status(400))?;
+    let mut parts = line.split(' ');
+    let (Some(method), Some(target), Some(version), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(ReadFailure::Status(400));
+    };
+    if !is_token(method) || target.is_empty() {
+        return Err(ReadFailure::Status(400));
+    }
+    match version {
+        "HTTP/1.1" | "HTTP/1.0" => {}
+        other if other.starts_with("HTTP/") => return Err(ReadFailure::Status(505)),
+        _ => return Err(ReadFailure::Status(400)),
+    }
+
+    let mut headers: Vec<(String, String)> = Vec::new();
+    loop {
+        let line = conn.read_line(MAX_HEAD_BYTES, 431)?;
+        head_bytes += line.len() + 2;
+        if head_bytes > MAX_HEAD_BYTES {
+            return Err(ReadFailure::Status(431));
+        }
+        if line.is_empty() {
+            break;
+        }
+        if headers.len() == MAX_HEADERS {
+            return Err(ReadFailure::Status(431));
+        }
+        // Obsolete line folding (leading whitespace) is rejected, as RFC 9112 5.2 allows.
+        let line = String::from_utf8(line).map_err(|_| ReadFailure::Status(400))?;
+        let Some((name, value)) = line.split_once(':') else {
+            return Err(ReadFailure::Status(400));
+        };
+        if !is_token(name) {
+            return Err(ReadFailure::Status(400));
+        }
+        headers.push((
+            name.to_ascii_lowercase(),
+            value.trim_matches([' ', '\t']).to_owned(),
+        ));
+    }
+
+    let mut request = HttpRequest::new(method, target, version, headers, remote);
+    if version == "HTTP/1.1" && request.header("host").is_none() {
+        return Err(ReadFailure::Status(400));
+    }
+    let body = read_body(conn, config, &request)?;
+    request.body = body;
+    Ok(request)
+}
+
+fn read_body(
+    conn: &mut Connection,
+    config: &ServerConfig,
+    request: &HttpRequest,
+) -> Result<Vec<u8>, ReadFailure> {
+    let transfer_encoding = request.header("transfer-encoding");
+    let content_length = request.header("content-length");
+    let length = match (&transfer_encoding, &content_length) {
+        // Both framing headers together are a request-smuggling vector; refuse them.
+        (Some(_), Some(_)) => return Err(ReadFailure::Status(400)),
+        (Some(coding), None) => {
+            if !coding.eq_ignore_ascii_case("chunked") {
+                return Err(ReadFailure::Status(501));
+            }
+            None
+        }
+        (None, Some(value)) => Some(parse_content_length(value)?),
+        (None, None) => Some(0),
+    };
+    if length.is_some_and(|length| length > config.max_body_size) {
+        return Err(ReadFailure::Status(413));
+    }
+    if length != Some(0) {
+        match request.header("expect") {
+            None => {}
+            Some(expect) if expect.eq_ignore_ascii_case("100-continue") => {
+                if !conn.write_all(b"HTTP/1.1 100 Continue\r\n\r\n") {
+                    return Err(ReadFailure::Idle);
+                }
+            }
+            Some(_) => return Err(ReadFailure::Status(417)),
+        }
+    }
+    let mut body = Vec::new();
+    match length {
+        Some(length) => conn.read_exact(length, &mut body)?,
+        None => read_chunked(conn, config, &mut body)?,
+    }
+    Ok(body)
+}
+
+/// Repeated `Content-Length` values are joined by `header`; they must all agree.
+fn parse_content_length(value: &str) -> Result<usize, ReadFailure> {
+    let mut parsed = None;
+    for part in value.split(',') {
+        let part = part.trim();
+        if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ReadFailure::Status(400));
+        }
+        // Too many digits for `usize` is certainly over the body limit.
+        let length = part
+            .parse::<usize>()
+            .map_err(|_| ReadFailure::Status(413))?;
+        if parsed.is_some_and(|previous| previous != length) {
+            return Err(ReadFailure::Status(400));
+        }
+        parsed = Some(length);
+    }
+    parsed.ok_or(ReadFailure::Status(400))
+}
+
+fn read_chunked(
+    conn: &mut Connection,
+    config: &ServerConfig,
+    body: &mut Vec<u8>,
+) -> Result<(), ReadFailure> {
+    loop {
+        let line = conn.read_line(1024, 400)?;
+        let line = String::from_utf8(line).map_err(|_| ReadFailure::Status(400))?;
+        let size = line.split(';').next().unwrap_or_default().trim();
+        if size.is_empty() || size.len() > 16 || !size.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ReadFailure::Status(400));
+        }
+        let size = usize::from_str_radix(size, 16).map_err(|_| ReadFailure::Status(413))?;
+        if size == 0 {
+            break;
+        }
+        if body.len().saturating_add(size) > config.max_body_size {
+            return Err(ReadFailure::Status(413));
+        }
+        conn.read_exact(size, body)?;
+        if !conn.read_line(2, 400)?.is_empty() {
+            return Err(ReadFailure::Status(400));
+        }
+    }
+    // Trailer fields are read and dropped.
+    let mut trailer_bytes = 0;
+    loop {
+        let line = conn.read_line(MAX_HEAD_BYTES, 431)?;
+        trailer_bytes += line.len() + 2;
+        if trailer_bytes > MAX_HEAD_BYTES {
+            return Err(ReadFailure::Status(431));
+        }
+        if line.is_empty() {
+            return Ok(());
+        }
+    }
+}
+
+#[derive(Debug)]
+struct HttpRequest {
+    method: String,
+    target: String,
+    path: String,
+    query: Option<String>,
+    version: String,
+    // Names are lower-cased; order and repeats are kept.
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    remote: Option<(IpAddr, i32)>,
+    keep_alive: bool,
+}
+
+impl HttpRequest {
+    fn new(
+        method: &str,
+        target: &str,
+        version: &str,
+        headers: Vec<(String, String)>,
+        remote: Option<(IpAddr, i32)>,
+    ) -> Self {
+        // Absolute-form targets (`http://host/path`) are reduced to their path.
+        let origin = match target.find("://") {
+            Some(scheme_end) if !target.starts_with('/') => {
+                let rest = &target[scheme_end + 3..];
+                match rest.find(['/', '?']) {
+                    Some(index) => &rest[index..],
+                    None => "/",
+                }
+            }
+            _ => target,
+        };
+        let (path, query) = match origin.split_once('?') {
+            Some((path, query)) => (path, Some(query.to_owned())),
+            None => (origin, None),
+        };
+        let mut request = Self {
+            method: method.to_owned(),
+            target: target.to_owned(),
+            path: if path.is_empty() { "/" } else { path }.to_owned(),
+            query,
+            version: version.to_owned(),
+            headers,
+            body: Vec::new(),
+            remote,
+            keep_alive: false,
+        };
+        let connection = request.header("connection").unwrap_or_default();
+        let has = |token: &str| {
+            connection
+                .split(',')
+                .any(|part| part.trim().eq_ignore_ascii_case(token))
+        };
+        request.keep_alive = if version == "HTTP/1.0" {
+            has("keep-alive")
+        } else {
+            !has("close")
+        };
+        request
+    }
+
+    /// All values of `name` joined with `", "`, as RFC 9110 5.3 allows.
+    fn header(&self, name: &str) -> Option<String> {
+        let mut values = self
+            .headers
+            .iter()
+            .filter(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str());
+        let first = values.next()?;
+        Some(values.fold(first.to_owned(), |mut joined, value| {
+            joined.push_str(", ");
+            joined.push_str(value);
+            joined
+        }))
+    }
+}
+
+/// One received request (`rust.http.Request`), read-only. Clones share the request.
+#[derive(Clone, Debug)]
+pub struct HttpRequestHandle {
+    inner: Arc<HttpRequest>,
+}
+
+impl HttpRequestHandle {
+    /// Request method as sent (`"GET"`, `"POST"`); methods are case-sensitive.
+    pub fn method(&self) -> String {
+        self.inner.method.clone()
+    }
+
+    /// Request target exactly as sent on the request line.
+    pub fn target(&self) -> String {
+        self.inner.target.clone()
+    }
+
+    /// Target path without the query string, still percent-encoded.
+    pub fn path(&self) -> String {
+        self.inner.path.clone()
+    }
+
+    /// Raw query string after `?`, or `null` without one.
+    pub fn query(&self) -> Option<String> {
+        self.inner.query.clone()
+    }
+
+    /// `"HTTP/1.1"` or `"HTTP/1.0"`.
+    pub fn version(&self) -> String {
+        self.inner.version.clone()
+    }
+
+    /// Case-insensitive header lookup; repeated headers are joined with `", "`.
+    pub fn header<N: AsRef<str>>(&self, name: N) -> Option<String> {
+        self.inner.header(name.as_ref())
+    }
+
+    /// Every value of a repeated header, in arrival order.
+    pub fn header_values<S, N>(&self, name: N) -> Array<S>
+    where
+        S: From<String>,
+        N: AsRef<str>,
+    {
+        let name = name.as_ref();
+        Array::from_vec(
+            self.inner
+                .headers
+                .iter()
+                .filter(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| S::from(value.clone()))
+                .collect(),
+        )
+    }
+
+    /// Distinct header names (lower-case) in arrival order.
+    pub fn header_names<S: From<String>>(&self) -> Array<S> {
+        let mut names: Vec<&str> = Vec::new();
+        for (name, _) in &self.inner.headers {
+            if !names.contains(&name.as_str()) {
+                names.push(name);
+            }
+        }
+        Array::from_vec(
+            names
+                .into_iter()
+                .map(|name| S::from(name.to_owned()))
+                .collect(),
+        )
+    }
+
+    /// Request body (empty without one); chunked bodies are already decoded.
+    pub fn body(&self) -> HxRef<Bytes> {
+        HxRef::new(Bytes::from_vec(self.inner.body.clone()))
+    }
+
+    /// Request body as UTF-8 text; invalid sequences become U+FFFD.
+    pub fn body_string(&self) -> String {
+        String::from_utf8_lossy(&self.inner.body).into_owned()
+    }
+
+    /// Peer address text, or `""` if the socket no longer knows it.
+    pub fn remote_host(&self) -> String {
+        self.inner
+            .remote
+            .map(|(ip, _)| ip.to_string())
+            .unwrap_or_default()
+    }
+
+    /// Peer port, or `0` if unknown.
+    pub fn remote_port(&self) -> i32 {
+        self.inner.remote.map_or(0, |(_, port)| port)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct HttpResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl HttpResponse {
+    fn text(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            headers: vec![(
+                String::from("Content-Type"),
+                String::from("text/plain; charset=utf-8"),
+            )],
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    fn wants_close(&self) -> bool {
+        self.headers.iter().any(|(name, value)| {
+            name.eq_ignore_ascii_case("connection")
+                && value
+                    .split(',')
+                    .any(|token| token.trim().eq_ignore_ascii_case("close"))
+        })
+    }
+
+    /// Serialize status line, headers and body. Framing headers are always written here.
+    fn encode(&self, head_only: bool, keep_alive: bool, http10: bool) -> Vec<u8> {
+        let mut out = format!(
+            "HTTP/1.1 {} {}\r\n",
+            self.status,
+            reason_phrase(self.status)
+        );
+        for (name, value) in &self.headers {
+            if ["content-length", "transfer-encoding", "connection"]
+                .iter()
+                .any(|framing| name.eq_ignore_ascii_case(framing))
+            {
+                continue;
+            }
+            out.push_str(&format!("{name}: {value}\r\n"));
+        }
+        match (keep_alive, http10) {
+            (true, true) => out.push_str("Connection: keep-alive\r\n"),
+            (true, false) => {}
+            (false, _) => out.push_str("Connection: close\r\n"),
+        }
+        let bodiless = self.status == 204 || self.status == 304 || self.status < 200;
+        if !bodiless {
+            out.push_str(&format!("Content-Length: {}\r\n", self.body.len()));
+        }
+        out.push_str("\r\n");
+        let mut out = out.into_bytes();
+        if !bodiless && !head_only {
+            out.extend_from_slice(&self.body);
+        }
+        out
+    }
+}
+
+/// Response under construction (`rust.http.Response`). Clones share the same response.
+#[derive(Clone, Debug)]
+pub struct HttpResponseHandle {
+    inner: Arc<ParkMutex<HttpResponse>>,
+}
+
+/// New response with `status` and no headers or body.
+///
+/// Statuses outside 100..599 throw a catchable String beginning with `HXRT-HTTP-RESPONSE`.
+pub fn http_response(status: i32) -> HttpResponseHandle {
+    HttpResponseHandle {
+        inner: Arc::new(ParkMutex::new(HttpResponse {
+            status: checked_status(status),
+            headers: Vec::new(),
+            body: Vec::new(),
+        })),
+    }
+}
+
+fn checked_status(status: i32) -> u16 {
+    if !(100..=599).contains(&status) {
+        throw_msg(format!(
+            "{HTTP_RESPONSE_ERROR}: status must be in 100..599 (got {status})"
+        ));
+    }
+    status as u16
+}
+
+/// Header names must be tokens and values must not contain CR, LF or NUL (header injection).
+fn checked_header(name: &str, value: &str) -> (String, String) {
+    if !is_token(name) {
+        throw_msg(format!(
+            "{HTTP_RESPONSE_ERROR}: invalid header name {name:?}"
+        ));
+    }
+    if value.contains(['\r', '\n', '\0']) {
+        throw_msg(format!(
+            "{HTTP_RESPONSE_ERROR}: header {name} value must not contain CR, LF or NUL"
+        ));
+    }
+    (name.to_owned(), value.to_owned())
+}
+
+impl HttpResponseHandle {
+    pub fn status(&self) -> i32 {
+        i32::from(self.inner.lock().status)
+    }
+
+    pub fn set_status(&self, status: i32) {
+        self.inner.lock().status = checked_status(status);
+    }
+
+    /// Replace every value of the header `name` (case-insensitive) with `value`.
+    pub fn set_header<N: AsRef<str>, V: AsRef<str>>(&self, name: N, value: V) {
+        let (name, value) = checked_header(name.as_ref(), value.as_ref());
+        let mut response = self.inner.lock();
+        response
+            .headers
+            .retain(|(key, _)| !key.eq_ignore_ascii_case(&name));
+        response.headers.push((name, value));
+    }
+
+    /// Append one more value for `name` (for example a second `Set-Cookie`).
+    pub fn add_header<N: AsRef<str>, V: AsRef<str>>(&self, name: N, value: V) {
+        let header = checked_header(name.as_ref(), value.as_ref());
+        self.inner.lock().headers.push(header);
+    }
+
+    /// First value of the header `name`, or `null`.
+    pub fn header<N: AsRef<str>>(&self, name: N) -> Option<String> {
+        let name = name.as_ref();
+        self.inner
+            .lock()
+            .headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.clone())
+    }
+
+    pub fn set_body(&self, body: &HxRef<Bytes>) {
+        let body = body.borrow().as_slice().to_vec();
+        self.inner.lock().body = body;
+    }
+
+    /// Set the body to UTF-8 `text`.
+    pub fn set_body_string<T: AsRef<str>>(&self, text: T) {
+        self.inner.lock().body = text.as_ref().as_bytes().to_vec();
+    }
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        100 => "Continue",
+        101 => "Switching Protocols",
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        204 => "No Content",
+        206 => "Partial Content",
+        301 => "Moved Permanently",
+        302 => "Found",
+        303 => "See Other",
+        304 => "Not Modified",
+        307 => "Temporary Redirect",
+        308 => "Permanent Redirect",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        409 => "Conflict",
+        410 => "Gone",
+        413 => "Content Too Large",
+        415 => "Unsupported Media Type",
+        417 => "Expectation Failed",
+        422 => "Unprocessable Content",
+        429 => "Too Many Requests",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        501 => "Not Implemented",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        505 => "HTTP Version Not Supported",
+        _ => "",
+    }
+}
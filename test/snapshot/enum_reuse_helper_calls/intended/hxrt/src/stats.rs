@@ -0,0 +1,462 @@
+//! `hxrt::stats`
+//!
+//! Opt-in runtime counters for `rust.Runtime.stats()`.
+//!
+//! Why
+//! - Long-running generated services had no way to tell where memory goes: which object types
+//!   accumulate, whether threads queue up on shared objects, or how often exceptions are thrown.
+//!
+//! What
+//! - Per Rust type name: `HxRef::new` allocations, objects still alive, and `HxCell` lock waits
+//!   (a `borrow()`/`borrow_mut()` that had to block on another guard).
+//! - Process-wide: total lock waits and time spent waiting, `exception::throw` calls, and the
+//!   current depth of every live thread's message queue and event loop plus the task pool queue.
+//! - `stats_snapshot` returns a point-in-time `StatsSnapshot`; `stats_json` renders one as JSON,
+//!   which `stats_dump` writes to stderr or a file. Dumps can also run at exit (`HXRT_STATS_DUMP`
+//!   or `stats_set_dump_at_exit`) and whenever a signal arrives (`stats_dump_on_signal`).
+//!
+//! How
+//! - The `stats` cargo feature compiles the counting into `HxRef::new`, `HxCell` locking, and
+//!   `exception::throw`; without it none of this code exists.
+//! - Counters are relaxed atomics. Each type's counters are allocated once, leaked, and found by
+//!   type name, so a live object keeps a `&'static` pointer to decrement when it drops.
+//! - Lock waits are detected with `try_read`/`try_write` before the blocking acquire, so the
+//!   uncontended path only adds one failed-or-successful try.
+
+use crate::{dynamic, exception};
+use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::Instant;
+
+const STATS_DUMP_ERROR: &str = "HXRT-STATS-DUMP";
+
+/// Counters for one Rust type.
+#[derive(Debug, Default)]
+pub struct TypeCounters {
+    allocated: AtomicU64,
+    live: AtomicI64,
+    lock_waits: AtomicU64,
+}
+
+static TYPES: OnceLock<RwLock<HashMap<&'static str, &'static TypeCounters>>> = OnceLock::new();
+static LOCK_WAITS: AtomicU64 = AtomicU64::new(0);
+static LOCK_WAIT_NANOS: AtomicU64 = AtomicU64::new(0);
+static THROWS: AtomicU64 = AtomicU64::new(0);
+
+fn types() -> &'static RwLock<HashMap<&'static str, &'static TypeCounters>> {
+    TYPES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+fn counters<T>() -> &'static TypeCounters {
+    let name = std::any::type_name::<T>();
+    if let Some(counters) = types().read().get(name) {
+        return counters;
+    }
+    types()
+        .write()
+        .entry(name)
+        .or_insert_with(|| Box::leak(Box::default()))
+}
+
+/// Held by each `HxCell` allocated through `HxRef::new`; dropping it ends the object's "live" count.
+///
+/// Standalone cells (statics, locals) carry an empty token and are not counted.
+#[derive(Default)]
+pub struct LiveToken(Option<&'static TypeCounters>);
+
+impl std::fmt::Debug for LiveToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("LiveToken")
+    }
+}
+
+impl Drop for LiveToken {
+    fn drop(&mut self) {
+        if let Some(counters) = self.0 {
+            counters.live.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Count one `HxRef::new` of a `T`.
+pub(crate) fn record_alloc<T>() -> LiveToken {
+    let counters = counters::<T>();
+    counters.allocated.fetch_add(1, Ordering::Relaxed);
+    counters.live.fetch_add(1, Ordering::Relaxed);
+    LiveToken(Some(counters))
+}
+
+fn record_lock_wait<T>(started: Instant) {
+    let nanos = u64::try_from(started.elapsed().as_nanos()).unwrap_or(u64::MAX);
+    LOCK_WAITS.fetch_add(1, Ordering::Relaxed);
+    LOCK_WAIT_NANOS.fetch_add(nanos, Ordering::Relaxed);
+    counters::<T>().lock_waits.fetch_add(1, Ordering::Relaxed);
+}
+
+/// `lock.read()`, counting a wait when another guard forced it to block.
+pub(crate) fn read<T>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    if let Some(guard) = lock.try_read() {
+        return guard;
+    }
+    let started = Instant::now();
+    let guard = lock.read();
+    record_lock_wait::<T>(started);
+    guard
+}
+
+/// `lock.write()`, counting a wait when another guard forced it to block.
+pub(crate) fn write<T>(lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    if let Some(guard) = lock.try_write() {
+        return guard;
+    }
+    let started = Instant::now();
+    let guard = lock.write();
+    record_lock_wait::<T>(started);
+    guard
+}
+
+/// Count one `exception::throw`.
+pub(crate) fn record_throw() {
+    THROWS.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Counters of one type at snapshot time.
+#[derive(Clone, Debug)]
+pub struct TypeStats {
+    pub name: String,
+    pub allocated: u64,
+    pub live: u64,
+    pub lock_waits: u64,
+}
+
+/// Queue depths of one live Haxe thread at snapshot time.
+#[derive(Clone, Debug, Default)]
+pub struct ThreadQueueStats {
+    pub thread_id: i32,
+    /// `Thread.sendMessage` values not yet read.
+    pub messages: usize,
+    /// One-time `EventLoop` events waiting to run.
+    pub events: usize,
+    /// Repeating `EventLoop` events.
+    pub regular_events: usize,
+}
+
+/// Point-in-time copy of the runtime counters (`rust.Runtime.stats()`).
+///
+/// Types are sorted by live count (highest first), threads by id.
+#[derive(Clone, Debug)]
+pub struct StatsSnapshot {
+    types: Vec<TypeStats>,
+    lock_waits: u64,
+    lock_wait_nanos: u64,
+    throws: u64,
+    threads: Vec<ThreadQueueStats>,
+    task_queue: usize,
+}
+
+pub fn stats_snapshot() -> StatsSnapshot {
+    let mut types: Vec<TypeStats> = types()
+        .read()
+        .iter()
+        .map(|(name, counters)| TypeStats {
+            name: (*name).to_string(),
+            allocated: counters.allocated.load(Ordering::Relaxed),
+            live: counters.live.load(Ordering::Relaxed).max(0) as u64,
+            lock_waits: counters.lock_waits.load(Ordering::Relaxed),
+        })
+        .collect();
+    types.sort_by(|a, b| b.live.cmp(&a.live).then_with(|| a.name.cmp(&b.name)));
+    StatsSnapshot {
+        types,
+        lock_waits: LOCK_WAITS.load(Ordering::Relaxed),
+        lock_wait_nanos: LOCK_WAIT_NANOS.load(Ordering::Relaxed),
+        throws: THROWS.load(Ordering::Relaxed),
+        threads: crate::thread::stats_thread_queues(),
+        task_queue: crate::thread::stats_task_queue_depth(),
+    }
+}
+
+fn json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+impl StatsSnapshot {
+    pub fn types(&self) -> &[TypeStats] {
+        &self.types
+    }
+
+    pub fn threads(&self) -> &[ThreadQueueStats] {
+        &self.threads
+    }
+
+    // Haxe-facing accessors (`hxrt.stats.StatsSnapshot`). Counters are `Float` so they cannot
+    // overflow a Haxe `Int`.
+
+    pub fn allocations(&self) -> f64 {
+        self.types.iter().map(|t| t.allocated as f64).sum()
+    }
+
+    pub fn live_objects(&self) -> f64 {
+        self.types.iter().map(|t| t.live as f64).sum()
+    }
+
+    pub fn lock_waits(&self) -> f64 {
+        self.lock_waits as f64
+    }
+
+    pub fn lock_wait_seconds(&self) -> f64 {
+        self.lock_wait_nanos as f64 / 1e9
+    }
+
+    pub fn throws(&self) -> f64 {
+        self.throws as f64
+    }
+
+    pub fn task_queue_depth(&self) -> i32 {
+        self.task_queue as i32
+    }
+
+    pub fn type_count(&self) -> i32 {
+        self.types.len() as i32
+    }
+
+    pub fn type_name(&self, index: i32) -> String {
+        self.type_at(index).name.clone()
+    }
+
+    pub fn type_allocations(&self, index: i32) -> f64 {
+        self.type_at(index).allocated as f64
+    }
+
+    pub fn type_live(&self, index: i32) -> f64 {
+        self.type_at(index).live as f64
+    }
+
+    pub fn type_lock_waits(&self, index: i32) -> f64 {
+        self.type_at(index).lock_waits as f64
+    }
+
+    pub fn thread_count(&self) -> i32 {
+        self.threads.len() as i32
+    }
+
+    pub fn thread_id(&self, index: i32) -> i32 {
+        self.thread_at(index).thread_id
+    }
+
+    pub fn thread_messages(&self, index: i32) -> i32 {
+        self.thread_at(index).messages as i32
+    }
+
+    pub fn thread_events(&self, index: i32) -> i32 {
+        self.thread_at(index).events as i32
+    }
+
+    pub fn thread_regular_events(&self, index: i32) -> i32 {
+        self.thread_at(index).regular_events as i32
+    }
+
+    fn type_at(&self, index: i32) -> &TypeStats {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| self.types.get(index))
+            .unwrap_or_else(|| exception::throw(dynamic::from(String::from("Outside bounds"))))
+    }
+
+    fn thread_at(&self, index: i32) -> &ThreadQueueStats {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| self.threads.get(index))
+            .unwrap_or_else(|| exception::throw(dynamic::from(String::from("Outside bounds"))))
+    }
+
+    /// The snapshot as one JSON object (field names match `rust.RuntimeStats`).
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{");
+        out.push_str(&format!(
+            "\"allocations\":{},\"liveObjects\":{},\"lockWaits\":{},\"lockWaitSeconds\":{},\
+             \"throws\":{},\"taskQueue\":{},\"types\":[",
+            self.allocations(),
+            self.live_objects(),
+            self.lock_waits,
+            self.lock_wait_seconds(),
+            self.throws,
+            self.task_queue
+        ));
+        for (index, t) in self.types.iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            out.push_str("{\"name\":");
+            json_string(&mut out, &t.name);
+            out.push_str(&format!(
+                ",\"allocations\":{},\"live\":{},\"lockWaits\":{}}}",
+                t.allocated, t.live, t.lock_waits
+            ));
+        }
+        out.push_str("],\"threads\":[");
+        for (index, t) in self.threads.iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            out.push_str(&format!(
+                "{{\"id\":{},\"messages\":{},\"events\":{},\"regularEvents\":{}}}",
+                t.thread_id, t.messages, t.events, t.regular_events
+            ));
+        }
+        out.push_str("]}");
+        out
+    }
+}
+
+/// Current counters as JSON.
+pub fn stats_json() -> String {
+    stats_snapshot().to_json()
+}
+
+fn write_dump(target: &str) -> std::io::Result<()> {
+    let json = stats_json();
+    if target.is_empty() || target == "-" || target == "1" {
+        writeln!(std::io::stderr().lock(), "{json}")
+    } else {
+        std::fs::write(target, json + "\n")
+    }
+}
+
+/// Write the current counters as JSON to the file at `path`, or to stderr when `path` is empty.
+///
+/// The file is replaced on each dump. Write failures throw `HXRT-STATS-DUMP`.
+pub fn stats_dump<P: AsRef<str>>(path: P) {
+    let path = path.as_ref();
+    if let Err(error) = write_dump(path) {
+        exception::throw(dynamic::from(format!(
+            "{STATS_DUMP_ERROR}: {path}: {error}"
+        )))
+    }
+}
+
+static DUMP_AT_EXIT: OnceLock<parking_lot::Mutex<Option<String>>> = OnceLock::new();
+static DUMPED_AT_EXIT: AtomicBool = AtomicBool::new(false);
+
+fn dump_at_exit_target() -> &'static parking_lot::Mutex<Option<String>> {
+    DUMP_AT_EXIT.get_or_init(|| parking_lot::Mutex::new(None))
+}
+
+/// Dump the counters when the program exits: to the file at `path`, or stderr when it is empty.
+pub fn stats_set_dump_at_exit<P: AsRef<str>>(path: P) {
+    *dump_at_exit_target().lock() = Some(path.as_ref().to_string());
+}
+
+/// Exit-time dump requested by `stats_set_dump_at_exit` or `HXRT_STATS_DUMP` (runs at most once).
+///
+/// Failures are reported on stderr: this runs from exit handling, where nothing can catch them.
+pub(crate) fn dump_at_exit() {
+    let target = dump_at_exit_target()
+        .lock()
+        .clone()
+        .or_else(|| std::env::var("HXRT_STATS_DUMP").ok());
+    let Some(target) = target else {
+        return;
+    };
+    if DUMPED_AT_EXIT.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    if let Err(error) = write_dump(&target) {
+        let _ = writeln!(
+            std::io::stderr().lock(),
+            "[{STATS_DUMP_ERROR}] {target}: {error}"
+        );
+    }
+}
+
+/// Dump the counters every time `signal` arrives; returns a `rust.process.Signals` subscription id.
+///
+/// The dump is written from the signal dispatcher thread, so it does not wait for any event loop.
+/// Failures are reported on stderr.
+pub fn stats_dump_on_signal<P: AsRef<str>>(signal: i32, path: P) -> i32 {
+    let target = path.as_ref().to_string();
+    crate::signal::signal_native(
+        &[signal],
+        crate::cell::HxRc::new(move |_| {
+            if let Err(error) = write_dump(&target) {
+                let _ = writeln!(
+                    std::io::stderr().lock(),
+                    "[{STATS_DUMP_ERROR}] {target}: {error}"
+                );
+            }
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cell::HxRef;
+
+    struct Tracked;
+    struct Contended;
+
+    fn type_stats(snapshot: &StatsSnapshot, name: &str) -> TypeStats {
+        snapshot
+            .types()
+            .iter()
+            .find(|t| t.name.ends_with(name))
+            .cloned()
+            .unwrap()
+    }
+
+    #[test]
+    fn counts_allocations_live_objects_and_lock_waits() {
+        let kept = HxRef::new(Tracked);
+        drop(HxRef::new(Tracked));
+        let stats = type_stats(&stats_snapshot(), "stats::tests::Tracked");
+        assert_eq!((stats.allocated, stats.live), (2, 1));
+        drop(kept);
+        assert_eq!(type_stats(&stats_snapshot(), "::Tracked").live, 0);
+
+        let shared = HxRef::new(Contended);
+        let guard = shared.borrow_mut();
+        let (started, waiting) = std::sync::mpsc::channel();
+        let waiter = {
+            let shared = shared.clone();
+            std::thread::spawn(move || {
+                started.send(()).unwrap();
+                drop(shared.borrow());
+            })
+        };
+        waiting.recv().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        drop(guard);
+        waiter.join().unwrap();
+        assert_eq!(type_stats(&stats_snapshot(), "::Contended").lock_waits, 1);
+    }
+
+    #[test]
+    fn counts_throws_and_renders_json() {
+        let before = stats_snapshot().throws();
+        let _ = exception::catch_unwind(|| exception::throw(dynamic::from(String::from("x"))));
+        let snapshot = stats_snapshot();
+        assert!(snapshot.throws() > before);
+
+        let json = snapshot.to_json();
+        assert!(json.starts_with("{\"allocations\":"));
+        assert!(json.contains("\"threads\":[{\"id\":0,"));
+        assert!(json.ends_with("]}"));
+
+        let mut escaped = String::new();
+        json_string(&mut escaped, "a\"b\\c\n");
+        assert_eq!(escaped, "\"a\\\"b\\\\c\\u000a\"");
+    }
+}
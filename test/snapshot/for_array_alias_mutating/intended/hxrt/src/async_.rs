@@ -12,16 +12,26 @@
 //! - `spawn`: async task helper that runs a future on separate execution.
 //! - `select_first`: race two futures and return whichever value resolves first.
 //! - `timeout`, `timeout_ms`: race a future against a timer and return `Option<T>`.
+//! - `run_main` / `run_main_tokio`: drive an `@:async static function main()` entry future.
+//! - `HxStream<T>`: boxed + pinned `futures::Stream` shape behind `rust.async.Stream<T>`, with
+//!   producers (`stream_from_array`, `stream_generate`), combinators (`stream_map`,
+//!   `stream_filter`, `stream_take`, `stream_buffered`), `stream_collect`, and the `stream_next`
+//!   step used by compiler-lowered `for` loops inside async functions.
 //! - `enable_tokio_runtime` / `disable_tokio_runtime` / `is_tokio_runtime_enabled`:
 //!   adapter toggles (feature-gated by `async_tokio`).
 
 use futures::future::{select, Either};
+use futures::stream::{Stream, StreamExt};
 use std::future::Future;
 use std::pin::Pin;
 use std::time::Duration;
 
+#[cfg(feature = "core")]
+use crate::array::Array;
 #[cfg(feature = "thread")]
-use crate::cell::{HxDynRef, HxRc, HxRef};
+use crate::cell::HxRef;
+#[cfg(feature = "core")]
+use crate::cell::{HxDynRef, HxRc};
 #[cfg(feature = "thread")]
 use crate::concurrent::TaskHandle;
 
@@ -30,6 +40,11 @@ use std::sync::atomic::{AtomicBool, Ordering};
 
 pub type HxFuture<T> = Pin<Box<dyn Future<Output = T> + Send + 'static>>;
 
+/// Boxed + pinned async sequence shape shared by every `rust.async.Stream<T>` value.
+///
+/// Streams are single-consumer: combinators and `for` iteration take ownership of the stream.
+pub type HxStream<T> = Pin<Box<dyn Stream<Item = T> + Send + 'static>>;
+
 #[cfg(feature = "async_tokio")]
 static TOKIO_RUNTIME_ENABLED: AtomicBool = AtomicBool::new(false);
 
@@ -111,6 +126,170 @@ pub fn sleep_ms(ms: i32) -> HxFuture<()> {
     sleep(Duration::from_millis(clamped))
 }
 
+#[cfg(feature = "core")]
+const ASYNC_MAIN_UNCAUGHT_ERROR: &str = "HXRT-ASYNC-MAIN-UNCAUGHT";
+
+/// Drive an async program entry future and return the process exit code it maps to.
+///
+/// Why
+/// - Generated `fn main()` for `@:async static function main()` must behave like a sync Haxe
+///   program: buffered stdout reaches the terminal and an uncaught throw fails the process.
+///
+/// What
+/// - Runs `entry` with `runner`, then the `hxrt::sys` exit handlers (which flush stdout/stderr),
+///   and returns `0` on normal completion or `1` after reporting an uncaught Haxe exception on
+///   stderr.
+///
+/// How
+/// - `exception::catch_unwind` consumes only Haxe throw payloads; a non-Haxe Rust panic keeps its
+///   default unwinding behavior. Flush failures are ignored because no Haxe frame can catch them.
+#[cfg(feature = "core")]
+fn run_entry(entry: HxFuture<()>, runner: fn(HxFuture<()>)) -> i32 {
+    use std::io::Write;
+
+    let outcome = crate::exception::catch_unwind(move || runner(entry));
+    let _ = std::io::stdout().flush();
+    let code = match outcome {
+        Ok(()) => 0,
+        Err(error) => {
+            let _ = writeln!(
+                std::io::stderr().lock(),
+                "[{ASYNC_MAIN_UNCAUGHT_ERROR}] {error}"
+            );
+            1
+        }
+    };
+    crate::sys::run_exit_handlers();
+    code
+}
+
+#[cfg(feature = "core")]
+fn exit_with(code: i32) {
+    if code != 0 {
+        std::process::exit(code);
+    }
+}
+
+/// Run an `@:async` `main` body on the runtime adapter selected for this process.
+///
+/// The default adapter is the lightweight `pollster` bridge; a program that enabled the tokio
+/// adapter before reaching this call keeps using it. Exits with code `1` on an uncaught throw.
+#[cfg(feature = "core")]
+pub fn run_main(entry: HxFuture<()>) {
+    exit_with(run_entry(entry, block_on));
+}
+
+/// Run an `@:async` `main` body on a tokio runtime (`-D async_tokio_adapter`).
+///
+/// Enables the tokio adapter before the entry future starts, so `spawn`, `sleep`, `timeout` and
+/// `select_first` calls made from `main` all resolve through the same runtime family.
+#[cfg(all(feature = "core", feature = "async_tokio"))]
+pub fn run_main_tokio(entry: HxFuture<()>) {
+    enable_tokio_runtime();
+    exit_with(run_entry(entry, tokio_block_on));
+}
+
+#[cfg(feature = "core")]
+fn callback_rc<F: ?Sized>(callback: HxDynRef<F>) -> HxRc<F> {
+    match callback.as_arc_opt() {
+        Some(rc) => rc.clone(),
+        None => crate::exception::throw(crate::dynamic::from(String::from("Null Access"))),
+    }
+}
+
+/// Build a stream that yields the current elements of `values` in order.
+///
+/// The array is snapshotted when the stream is created, matching Haxe `for` over an array.
+#[cfg(feature = "core")]
+pub fn stream_from_array<T>(values: Array<T>) -> HxStream<T>
+where
+    T: Clone + Send + 'static,
+{
+    Box::pin(futures::stream::iter(values.to_vec()))
+}
+
+/// Build a stream by awaiting `next()` until it resolves to `None`.
+///
+/// Why
+/// - Log tailing and paginated APIs produce values one request at a time; `next` owns the cursor
+///   state in its captured Haxe closure, so no generator syntax is needed.
+#[cfg(feature = "core")]
+pub fn stream_generate<T>(
+    next: HxDynRef<dyn Fn() -> HxFuture<Option<T>> + Send + Sync>,
+) -> HxStream<T>
+where
+    T: Send + 'static,
+{
+    let next = callback_rc(next);
+    Box::pin(futures::stream::unfold(next, |next| async move {
+        let item = next().await;
+        item.map(|value| (value, next))
+    }))
+}
+
+/// Transform every item with a synchronous callback.
+#[cfg(feature = "core")]
+pub fn stream_map<T, U>(
+    stream: HxStream<T>,
+    callback: HxDynRef<dyn Fn(T) -> U + Send + Sync>,
+) -> HxStream<U>
+where
+    T: Send + 'static,
+    U: Send + 'static,
+{
+    let callback = callback_rc(callback);
+    Box::pin(stream.map(move |value| callback(value)))
+}
+
+/// Keep only the items for which `predicate` returns `true`.
+#[cfg(feature = "core")]
+pub fn stream_filter<T>(
+    stream: HxStream<T>,
+    predicate: HxDynRef<dyn Fn(T) -> bool + Send + Sync>,
+) -> HxStream<T>
+where
+    T: Clone + Send + 'static,
+{
+    let predicate = callback_rc(predicate);
+    Box::pin(stream.filter(move |value| std::future::ready(predicate(value.clone()))))
+}
+
+/// Yield at most `count` items, then end the stream without polling the source again.
+pub fn stream_take<T>(stream: HxStream<T>, count: i32) -> HxStream<T>
+where
+    T: Send + 'static,
+{
+    let clamped = if count < 0 { 0 } else { count as usize };
+    Box::pin(stream.take(clamped))
+}
+
+/// Poll up to `limit` pending futures at once while keeping their results in source order.
+pub fn stream_buffered<T>(stream: HxStream<HxFuture<T>>, limit: i32) -> HxStream<T>
+where
+    T: Send + 'static,
+{
+    let clamped = if limit < 1 { 1 } else { limit as usize };
+    Box::pin(stream.buffered(clamped))
+}
+
+/// Drain a stream into a Haxe array.
+#[cfg(feature = "core")]
+pub fn stream_collect<T>(stream: HxStream<T>) -> HxFuture<Array<T>>
+where
+    T: Send + 'static,
+{
+    Box::pin(async move { Array::from_vec(stream.collect::<Vec<T>>().await) })
+}
+
+/// Await the next item of a stream; `None` means the stream has ended.
+///
+/// Compiler note: `for (item in stream)` inside an `@:async` function lowers to a loop over this
+/// helper, so generated crates do not need their own `futures` dependency.
+#[inline]
+pub fn stream_next<T>(stream: &mut HxStream<T>) -> futures::stream::Next<'_, HxStream<T>> {
+    stream.next()
+}
+
 /// Spawn an async job into a thread-backed task handle.
 #[cfg(feature = "thread")]
 pub fn task_spawn<T>(job: HxDynRef<dyn Fn() -> HxFuture<T> + Send + Sync>) -> HxRef<TaskHandle<T>>
@@ -308,6 +487,88 @@ mod tests {
         disable_tokio_runtime();
     }
 
+    #[cfg(feature = "core")]
+    #[test]
+    fn run_entry_maps_completion_and_uncaught_throw_to_exit_codes() {
+        // `run_entry` drains the process-wide exit handler registry.
+        let _lock = crate::sys::EXIT_HANDLERS_TEST_LOCK.lock();
+        let ok = Box::pin(async move {
+            sleep_ms(1).await;
+        });
+        assert_eq!(run_entry(ok, block_on), 0);
+
+        let failing: HxFuture<()> = Box::pin(async move {
+            sleep_ms(1).await;
+            crate::exception::throw(crate::dynamic::from(String::from("boom")));
+        });
+        assert_eq!(run_entry(failing, block_on), 1);
+    }
+
+    #[cfg(feature = "core")]
+    #[test]
+    fn stream_combinators_preserve_order() {
+        let source = stream_from_array(Array::from_vec(vec![1, 2, 3, 4, 5, 6]));
+        let even: HxRc<dyn Fn(i32) -> bool + Send + Sync> = HxRc::new(|value: i32| value % 2 == 0);
+        let double: HxRc<dyn Fn(i32) -> i32 + Send + Sync> = HxRc::new(|value: i32| value * 2);
+        let mapped = stream_map(
+            stream_filter(source, HxDynRef::new(even)),
+            HxDynRef::new(double),
+        );
+        let collected = block_on(stream_collect(stream_take(mapped, 2)));
+        assert_eq!(collected.to_vec(), vec![4, 8]);
+    }
+
+    #[cfg(feature = "core")]
+    #[test]
+    fn stream_generate_stops_on_none_and_next_drains() {
+        let counter = std::sync::Arc::new(std::sync::atomic::AtomicI32::new(0));
+        let next: HxRc<dyn Fn() -> HxFuture<Option<i32>> + Send + Sync> = HxRc::new(move || {
+            let current = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Box::pin(async move {
+                sleep_ms(1).await;
+                if current < 3 {
+                    Some(current)
+                } else {
+                    None
+                }
+            })
+        });
+        let mut stream = stream_generate(HxDynRef::new(next));
+        let seen = block_on(Box::pin(async move {
+            let mut seen = Vec::new();
+            while let Some(value) = stream_next(&mut stream).await {
+                seen.push(value);
+            }
+            seen
+        }));
+        assert_eq!(seen, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn stream_buffered_keeps_source_order() {
+        let futures: Vec<HxFuture<i32>> = vec![
+            Box::pin(async move {
+                sleep_ms(15).await;
+                1
+            }),
+            Box::pin(async move {
+                sleep_ms(1).await;
+                2
+            }),
+            ready(3),
+        ];
+        let source: HxStream<HxFuture<i32>> = Box::pin(futures::stream::iter(futures));
+        let mut buffered = stream_buffered(source, 3);
+        let values = block_on(Box::pin(async move {
+            let mut values = Vec::new();
+            while let Some(value) = stream_next(&mut buffered).await {
+                values.push(value);
+            }
+            values
+        }));
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
     #[cfg(feature = "async_tokio")]
     #[test]
     fn tokio_runtime_adapter_roundtrip() {
@@ -0,0 +1,386 @@
+//! `hxrt::weak`
+//!
+//! Non-owning object handles for `rust.Weak<T>` and `haxe.ds.WeakMap<K, V>`.
+//!
+//! Why
+//! - Haxe object references are reference counted on this target, so code had no way to refer to an
+//!   object without keeping it alive: back-references leaked, and `haxe.ds.WeakMap` had no
+//!   implementation at all.
+//!
+//! What
+//! - `HxWeakable`: downgrade/upgrade for every runtime object handle shape (`HxRef<T>`,
+//!   `HxDynRef<T>`, and the non-null `HxRc<dyn Trait>` interface values).
+//! - `WeakHandle<K>`: one weak reference (`rust.Weak<T>`); `get` returns null once the object is
+//!   gone.
+//! - `WeakMapHandle<K, V>`: identity-keyed map whose entries disappear once their key object is
+//!   dropped.
+//!
+//! How
+//! - Entries are keyed by the key's allocation address and hold a `Weak` to it. A live `Weak` keeps
+//!   the allocation (not the object) reserved, so a dead key's address cannot be reused by a new
+//!   object while its entry still exists.
+//! - Lookups treat dead keys as absent; dead entries are pruned on iteration and whenever the map
+//!   doubles in size. Values stay strongly held until then, and a value that references its own key
+//!   keeps that key alive (there are no ephemerons).
+
+use crate::cell::{HxDynRef, HxRc, HxRef, HxWeak};
+use crate::hxref::HxRefLike;
+use crate::{dynamic, exception};
+use parking_lot::Mutex as ParkMutex;
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+
+/// Runtime object handles that can be held without ownership.
+pub trait HxWeakable: HxRefLike + Sized {
+    type Weak: Clone;
+
+    /// `None` for a null handle.
+    fn downgrade(&self) -> Option<Self::Weak>;
+
+    /// `None` once the object has been dropped.
+    fn upgrade(weak: &Self::Weak) -> Option<Self>;
+}
+
+impl<T> HxWeakable for HxRef<T> {
+    type Weak = HxWeak<T>;
+
+    fn downgrade(&self) -> Option<Self::Weak> {
+        self.is_some().then(|| HxRef::downgrade(self))
+    }
+
+    fn upgrade(weak: &Self::Weak) -> Option<Self> {
+        let value = weak.upgrade();
+        value.is_some().then_some(value)
+    }
+}
+
+impl<T: ?Sized> HxWeakable for HxDynRef<T> {
+    type Weak = Weak<T>;
+
+    fn downgrade(&self) -> Option<Self::Weak> {
+        self.as_arc_opt().map(HxRc::downgrade)
+    }
+
+    fn upgrade(weak: &Self::Weak) -> Option<Self> {
+        weak.upgrade().map(HxDynRef::new)
+    }
+}
+
+impl<T: ?Sized> HxWeakable for Arc<T> {
+    type Weak = Weak<T>;
+
+    fn downgrade(&self) -> Option<Self::Weak> {
+        Some(Arc::downgrade(self))
+    }
+
+    fn upgrade(weak: &Self::Weak) -> Option<Self> {
+        weak.upgrade()
+    }
+}
+
+fn throw_null_access() -> ! {
+    exception::throw(dynamic::from(String::from("Null Access")))
+}
+
+/// One weak reference backing `rust.Weak<T>`.
+pub struct WeakHandle<K: HxWeakable> {
+    weak: Option<K::Weak>,
+}
+
+impl<K: HxWeakable> Clone for WeakHandle<K> {
+    fn clone(&self) -> Self {
+        Self {
+            weak: self.weak.clone(),
+        }
+    }
+}
+
+impl<K: HxWeakable> std::fmt::Debug for WeakHandle<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(if self.is_alive() {
+            "Weak(alive)"
+        } else {
+            "Weak(dead)"
+        })
+    }
+}
+
+impl<K: HxWeakable> WeakHandle<K> {
+    /// Weak reference to `value`; a null `value` gives a handle that is never alive.
+    pub fn new(value: K) -> Self {
+        Self {
+            weak: value.downgrade(),
+        }
+    }
+
+    pub fn upgrade(&self) -> Option<K> {
+        self.weak.as_ref().and_then(K::upgrade)
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.upgrade().is_some()
+    }
+
+    /// The referenced object, or null once it has been dropped.
+    pub fn get(&self) -> K
+    where
+        K: Default,
+    {
+        self.upgrade().unwrap_or_default()
+    }
+}
+
+struct WeakEntries<K: HxWeakable, V> {
+    entries: HashMap<usize, (K::Weak, V)>,
+    prune_at: usize,
+}
+
+/// Dead entries are pruned once the map doubles past the last live size (and at least this big).
+const PRUNE_FLOOR: usize = 16;
+
+impl<K: HxWeakable, V> WeakEntries<K, V> {
+    fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            prune_at: PRUNE_FLOOR,
+        }
+    }
+
+    fn prune(&mut self) {
+        self.entries
+            .retain(|_, (weak, _)| K::upgrade(weak).is_some());
+        self.prune_at = (self.entries.len() * 2).max(PRUNE_FLOOR);
+    }
+
+    /// The live entry for `key`, dropping it first when its key object has died.
+    fn live(&mut self, key: &K) -> Option<&mut V> {
+        let address = key.ptr_usize();
+        let alive = self
+            .entries
+            .get(&address)
+            .is_some_and(|(weak, _)| K::upgrade(weak).is_some());
+        if !alive {
+            self.entries.remove(&address);
+            return None;
+        }
+        self.entries.get_mut(&address).map(|(_, value)| value)
+    }
+
+    fn live_pairs(&mut self) -> Vec<(K, &V)> {
+        self.entries
+            .retain(|_, (weak, _)| K::upgrade(weak).is_some());
+        self.entries
+            .values()
+            .filter_map(|(weak, value)| K::upgrade(weak).map(|key| (key, value)))
+            .collect()
+    }
+}
+
+/// Storage backing `haxe.ds.WeakMap<K, V>`.
+///
+/// Cloning aliases the same storage (the Haxe map object owns one handle); `copy` makes an
+/// independent map with the same live entries.
+pub struct WeakMapHandle<K: HxWeakable, V> {
+    inner: Arc<ParkMutex<WeakEntries<K, V>>>,
+}
+
+impl<K: HxWeakable, V> Clone for WeakMapHandle<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<K: HxWeakable, V> Default for WeakMapHandle<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: HxWeakable, V: std::fmt::Debug> std::fmt::Debug for WeakMapHandle<K, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.debug_string())
+    }
+}
+
+impl<K: HxWeakable, V> WeakMapHandle<K, V> {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(ParkMutex::new(WeakEntries::new())),
+        }
+    }
+
+    /// Map `key` to `value`; a null key throws "Null Access".
+    pub fn set(&self, key: K, value: V) {
+        let Some(weak) = key.downgrade() else {
+            throw_null_access();
+        };
+        let mut storage = self.inner.lock();
+        storage.entries.insert(key.ptr_usize(), (weak, value));
+        if storage.entries.len() >= storage.prune_at {
+            storage.prune();
+        }
+    }
+
+    pub fn get(&self, key: K) -> Option<V>
+    where
+        V: Clone,
+    {
+        self.inner.lock().live(&key).map(|value| value.clone())
+    }
+
+    pub fn exists(&self, key: K) -> bool {
+        self.inner.lock().live(&key).is_some()
+    }
+
+    pub fn remove(&self, key: K) -> bool {
+        let mut storage = self.inner.lock();
+        let existed = storage.live(&key).is_some();
+        storage.entries.remove(&key.ptr_usize());
+        existed
+    }
+
+    pub fn keys(&self) -> crate::iter::Iter<K> {
+        let keys: Vec<K> = self
+            .inner
+            .lock()
+            .live_pairs()
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect();
+        crate::iter::Iter::from_vec(keys)
+    }
+
+    pub fn values(&self) -> crate::iter::Iter<V>
+    where
+        V: Clone,
+    {
+        let values: Vec<V> = self
+            .inner
+            .lock()
+            .live_pairs()
+            .into_iter()
+            .map(|(_, value)| value.clone())
+            .collect();
+        crate::iter::Iter::from_vec(values)
+    }
+
+    pub fn key_values(&self) -> crate::iter::Iter<HxRef<crate::anon::Anon>>
+    where
+        K: Clone + Send + Sync + 'static,
+        V: Clone + Send + Sync + 'static,
+    {
+        let pairs: Vec<HxRef<crate::anon::Anon>> = self
+            .inner
+            .lock()
+            .live_pairs()
+            .into_iter()
+            .map(|(key, value)| crate::iter::key_value(key, value.clone()))
+            .collect();
+        crate::iter::Iter::from_vec(pairs)
+    }
+
+    /// Number of entries whose key is still alive.
+    pub fn count(&self) -> i32 {
+        self.inner.lock().live_pairs().len() as i32
+    }
+
+    pub fn copy(&self) -> Self
+    where
+        V: Clone,
+    {
+        let mut storage = self.inner.lock();
+        storage.prune();
+        let mut copy = WeakEntries::new();
+        copy.entries = storage.entries.clone();
+        copy.prune_at = storage.prune_at;
+        Self {
+            inner: Arc::new(ParkMutex::new(copy)),
+        }
+    }
+
+    pub fn clear(&self) {
+        let mut storage = self.inner.lock();
+        storage.entries.clear();
+        storage.prune_at = PRUNE_FLOOR;
+    }
+
+    /// `{value, ...}` over the live entries, in the same identity-keyed spirit as `ObjectMap`.
+    pub fn debug_string(&self) -> String
+    where
+        V: std::fmt::Debug,
+    {
+        let mut storage = self.inner.lock();
+        let values: Vec<String> = storage
+            .live_pairs()
+            .into_iter()
+            .map(|(key, value)| format!("{:x} => {value:?}", key.ptr_usize()))
+            .collect();
+        format!("{{{}}}", values.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Node {
+        parent: HxWeak<Node>,
+    }
+
+    #[test]
+    fn weak_handles_do_not_keep_objects_alive() {
+        let parent = HxRef::new(Node {
+            parent: HxWeak::null(),
+        });
+        let child = HxRef::new(Node {
+            parent: parent.downgrade(),
+        });
+        assert!(child.borrow().parent.upgrade().ptr_eq(&parent));
+
+        let handle = WeakHandle::new(parent.clone());
+        assert!(handle.is_alive() && handle.clone().get().ptr_eq(&parent));
+        drop(parent);
+        assert!(!child.borrow().parent.is_alive());
+        assert!(child.borrow().parent.upgrade().is_null());
+        assert!(handle.get().is_null());
+
+        assert!(!WeakHandle::new(HxRef::<Node>::null()).is_alive());
+    }
+
+    #[test]
+    fn weak_map_entries_disappear_with_their_keys() {
+        let map: WeakMapHandle<HxRef<i32>, String> = WeakMapHandle::new();
+        let kept = HxRef::new(1);
+        let dropped = HxRef::new(2);
+        map.set(kept.clone(), "kept".to_string());
+        map.set(dropped.clone(), "dropped".to_string());
+        assert_eq!(map.count(), 2);
+        assert_eq!(map.get(dropped.clone()).as_deref(), Some("dropped"));
+
+        let copy = map.copy();
+        drop(dropped);
+        assert_eq!(map.count(), 1);
+        assert_eq!(copy.count(), 1);
+        assert_eq!(*map.keys().next().borrow(), 1);
+        assert_eq!(map.values().next(), "kept");
+
+        assert!(map.remove(kept.clone()));
+        assert!(!map.exists(kept.clone()));
+        assert!(copy.exists(kept));
+    }
+
+    #[test]
+    fn weak_map_prunes_dead_entries_as_it_grows() {
+        let map: WeakMapHandle<HxRef<usize>, usize> = WeakMapHandle::new();
+        for index in 0..1000 {
+            map.set(HxRef::new(index), index);
+        }
+        assert!(map.inner.lock().entries.len() < PRUNE_FLOOR);
+
+        let error = exception::catch_unwind(|| map.set(HxRef::null(), 0)).unwrap_err();
+        assert_eq!(error.to_haxe_string(), "Null Access");
+    }
+}
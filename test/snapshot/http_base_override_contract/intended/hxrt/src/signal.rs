@@ -0,0 +1,294 @@
+//! `hxrt::signal`
+//!
+//! POSIX signal delivery for `rust.process.Signals`.
+//!
+//! Why
+//! - Long-running services need to shut down gracefully on `SIGTERM`/`SIGINT` and reload on
+//!   `SIGHUP`; without a handler the process simply dies and pending work is lost.
+//! - Haxe callbacks cannot run inside an OS signal handler, so delivery has to hop onto a normal
+//!   thread first.
+//!
+//! What
+//! - Subscriptions that forward `SIGINT`, `SIGTERM`, and `SIGHUP` either as callbacks queued on a
+//!   chosen `sys.thread.EventLoop` (`signal_listen`) or as values sent on a
+//!   `rust.concurrent.Channel` (`signal_notify`). `signal_cancel` removes a subscription.
+//! - A signal with no remaining subscription gets its default action, so cancelling every
+//!   subscription restores "the process terminates".
+//!
+//! How
+//! - `signal-hook` installs the OS handlers lazily, the first time a signal is subscribed, and
+//!   forwards them through its self-pipe to one dispatcher thread (`hxrt-signals`).
+//! - The dispatcher never runs Haxe code: it queues event-loop callbacks (so they run from
+//!   `progress`/`loop` with ordinary exception behavior) and offers channel values without
+//!   blocking. A full bounded channel drops that delivery; a closed channel or finished loop
+//!   thread ends the subscription.
+//! - Other platforms throw `HXRT-SIGNAL-UNSUPPORTED` when subscribing.
+
+use crate::array::Array;
+use crate::cell::{HxDynRef, HxRc, HxRef};
+use crate::concurrent::{ChannelHandle, ChannelOffer};
+use crate::{dynamic, exception};
+use parking_lot::Mutex as ParkMutex;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::OnceLock;
+
+fn throw_msg(msg: &str) -> ! {
+    exception::throw(dynamic::from(crate::string::HxString::from(msg)))
+}
+
+const SIGNAL_UNSUPPORTED_ERROR: &str = "HXRT-SIGNAL-UNSUPPORTED";
+const SIGNAL_INSTALL_ERROR: &str = "HXRT-SIGNAL-INSTALL";
+
+/// Signals a subscription may name (`rust.process.Signal`).
+#[cfg(unix)]
+const SUPPORTED_SIGNALS: &[i32] = &[
+    signal_hook::consts::SIGHUP,
+    signal_hook::consts::SIGINT,
+    signal_hook::consts::SIGTERM,
+];
+
+#[cfg(not(unix))]
+const SUPPORTED_SIGNALS: &[i32] = &[];
+
+#[derive(Clone)]
+enum SignalTarget {
+    EventLoop {
+        thread_id: i32,
+        callback: HxRc<dyn Fn(i32) + Send + Sync>,
+    },
+    Channel(HxRef<ChannelHandle<i32>>),
+    /// Runtime-internal handler run on the dispatcher thread; it must not run Haxe code.
+    #[cfg_attr(not(feature = "stats"), allow(dead_code))]
+    Native(HxRc<dyn Fn(i32) + Send + Sync>),
+}
+
+impl SignalTarget {
+    /// Hand `signal` to the target; returns `false` once the target can never receive again.
+    fn deliver(&self, signal: i32) -> bool {
+        match self {
+            SignalTarget::EventLoop {
+                thread_id,
+                callback,
+            } => {
+                let callback = callback.clone();
+                crate::thread::event_loop_post(*thread_id, HxRc::new(move || callback(signal)))
+            }
+            SignalTarget::Channel(channel) => {
+                crate::concurrent::channel_offer(channel, signal) != ChannelOffer::Closed
+            }
+            SignalTarget::Native(handler) => {
+                handler(signal);
+                true
+            }
+        }
+    }
+}
+
+struct Subscription {
+    signals: Vec<i32>,
+    target: SignalTarget,
+}
+
+struct SignalHub {
+    // Keyed by subscription id, so one signal reaches its subscribers in registration order.
+    subscriptions: ParkMutex<BTreeMap<i32, Subscription>>,
+    #[cfg(unix)]
+    handle: signal_hook::iterator::Handle,
+}
+
+static HUB: OnceLock<SignalHub> = OnceLock::new();
+static NEXT_SUBSCRIPTION_ID: AtomicI32 = AtomicI32::new(1);
+
+#[cfg(unix)]
+fn hub() -> &'static SignalHub {
+    HUB.get_or_init(|| {
+        let mut signals = signal_hook::iterator::Signals::new(std::iter::empty::<i32>())
+            .unwrap_or_else(|error| throw_msg(&format!("{SIGNAL_INSTALL_ERROR}: {error}")));
+        let handle = signals.handle();
+        std::thread::Builder::new()
+            .name("hxrt-signals".to_string())
+            .spawn(move || {
+                for signal in signals.forever() {
+                    dispatch(signal);
+                }
+            })
+            .unwrap_or_else(|error| throw_msg(&format!("{SIGNAL_INSTALL_ERROR}: {error}")));
+        SignalHub {
+            subscriptions: ParkMutex::new(BTreeMap::new()),
+            handle,
+        }
+    })
+}
+
+#[cfg(not(unix))]
+fn hub() -> &'static SignalHub {
+    throw_msg(&format!(
+        "{SIGNAL_UNSUPPORTED_ERROR}: signal handling is not available on this platform"
+    ))
+}
+
+/// Deliver one received signal to every matching subscription (dispatcher thread only).
+#[cfg(unix)]
+fn dispatch(signal: i32) {
+    let hub = hub();
+    let targets: Vec<(i32, SignalTarget)> = hub
+        .subscriptions
+        .lock()
+        .iter()
+        .filter(|(_, subscription)| subscription.signals.contains(&signal))
+        .map(|(id, subscription)| (*id, subscription.target.clone()))
+        .collect();
+    if targets.is_empty() {
+        let _ = signal_hook::low_level::emulate_default_handler(signal);
+        return;
+    }
+    let ended: Vec<i32> = targets
+        .into_iter()
+        .filter(|(_, target)| !target.deliver(signal))
+        .map(|(id, _)| id)
+        .collect();
+    if !ended.is_empty() {
+        let mut subscriptions = hub.subscriptions.lock();
+        for id in ended {
+            subscriptions.remove(&id);
+        }
+    }
+}
+
+fn subscribe(signals: Vec<i32>, target: SignalTarget) -> i32 {
+    if let Some(signal) = signals
+        .iter()
+        .find(|signal| !SUPPORTED_SIGNALS.contains(signal))
+    {
+        throw_msg(&format!(
+            "{SIGNAL_UNSUPPORTED_ERROR}: signal {signal} cannot be subscribed"
+        ));
+    }
+    let hub = hub();
+    #[cfg(unix)]
+    for signal in &signals {
+        if let Err(error) = hub.handle.add_signal(*signal) {
+            throw_msg(&format!("{SIGNAL_INSTALL_ERROR}: signal {signal}: {error}"));
+        }
+    }
+    let id = NEXT_SUBSCRIPTION_ID.fetch_add(1, Ordering::Relaxed);
+    hub.subscriptions
+        .lock()
+        .insert(id, Subscription { signals, target });
+    id
+}
+
+/// Queue `callback(signal)` on the event loop of `thread_id` whenever one of `signals` arrives.
+///
+/// Returns a subscription id for `signal_cancel`. The subscription does not keep the loop alive,
+/// and it ends by itself once that thread has finished.
+pub fn signal_listen(
+    signals: &Array<i32>,
+    thread_id: i32,
+    callback: HxDynRef<dyn Fn(i32) + Send + Sync>,
+) -> i32 {
+    let callback: HxRc<dyn Fn(i32) + Send + Sync> = match callback.as_arc_opt() {
+        Some(rc) => rc.clone(),
+        None => throw_msg("Null Access"),
+    };
+    subscribe(
+        signals.iter().collect(),
+        SignalTarget::EventLoop {
+            thread_id,
+            callback,
+        },
+    )
+}
+
+/// Send each arriving signal in `signals` on `channel` until the channel is closed.
+///
+/// Delivery never blocks: when a bounded channel is full, that signal is dropped for this
+/// subscription.
+pub fn signal_notify(signals: &Array<i32>, channel: &HxRef<ChannelHandle<i32>>) -> i32 {
+    if channel.is_null() {
+        throw_msg("Null Access");
+    }
+    subscribe(
+        signals.iter().collect(),
+        SignalTarget::Channel(channel.clone()),
+    )
+}
+
+/// Run a runtime-internal `handler` on the dispatcher thread whenever one of `signals` arrives.
+#[cfg(feature = "stats")]
+pub(crate) fn signal_native(signals: &[i32], handler: HxRc<dyn Fn(i32) + Send + Sync>) -> i32 {
+    subscribe(signals.to_vec(), SignalTarget::Native(handler))
+}
+
+/// Remove a subscription. Unknown or already-ended ids are ignored.
+pub fn signal_cancel(subscription_id: i32) {
+    if let Some(hub) = HUB.get() {
+        hub.subscriptions.lock().remove(&subscription_id);
+    }
+}
+
+/// Whether `signal` can be subscribed on this platform.
+pub fn signal_is_supported(signal: i32) -> bool {
+    SUPPORTED_SIGNALS.contains(&signal)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use signal_hook::consts::{SIGHUP, SIGKILL};
+    use std::sync::atomic::AtomicI32;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    fn signal_list(signals: &[i32]) -> Array<i32> {
+        Array::from_vec(signals.to_vec())
+    }
+
+    #[test]
+    fn raised_signal_reaches_channel_and_event_loop() {
+        // Only this test raises signals: every raise happens while a subscription is active, so
+        // the default "terminate" action never runs inside the test binary.
+        let channel = crate::concurrent::channel_new::<i32>();
+        let via_channel = signal_notify(&signal_list(&[SIGHUP]), &channel);
+
+        let seen = Arc::new(AtomicI32::new(0));
+        let record = seen.clone();
+        let callback: HxRc<dyn Fn(i32) + Send + Sync> =
+            HxRc::new(move |signal| record.store(signal, Ordering::SeqCst));
+        let via_loop = signal_listen(&signal_list(&[SIGHUP]), 0, HxDynRef::new(callback));
+
+        signal_hook::low_level::raise(SIGHUP).unwrap();
+        let received = crate::concurrent::channel_recv_timeout(&channel, Duration::from_secs(5));
+        assert_eq!(received, Some(SIGHUP));
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while seen.load(Ordering::SeqCst) == 0 && Instant::now() < deadline {
+            crate::thread::event_loop_progress(0);
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(seen.load(Ordering::SeqCst), SIGHUP);
+
+        // A closed channel ends its subscription; the event-loop one keeps the signal handled.
+        crate::concurrent::channel_close(&channel);
+        signal_hook::low_level::raise(SIGHUP).unwrap();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while hub().subscriptions.lock().contains_key(&via_channel) && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert!(!hub().subscriptions.lock().contains_key(&via_channel));
+
+        signal_cancel(via_loop);
+        assert!(!hub().subscriptions.lock().contains_key(&via_loop));
+    }
+
+    #[test]
+    fn unsupported_signals_are_rejected() {
+        assert!(signal_is_supported(SIGHUP));
+        assert!(!signal_is_supported(SIGKILL));
+        let channel = crate::concurrent::channel_new::<i32>();
+        let error = exception::catch_unwind(|| signal_notify(&signal_list(&[SIGKILL]), &channel))
+            .unwrap_err();
+        assert!(error.to_haxe_string().starts_with(SIGNAL_UNSUPPORTED_ERROR));
+    }
+}
@@ -5,9 +5,13 @@ pub mod array;
 #[cfg(feature = "async")]
 pub mod async_;
 #[cfg(feature = "core")]
+pub mod atomic;
+#[cfg(feature = "core")]
 pub mod bytes;
 #[cfg(feature = "core")]
 pub mod cell;
+#[cfg(feature = "compression")]
+pub mod compression;
 #[cfg(feature = "thread")]
 pub mod concurrent;
 #[cfg(feature = "date")]
@@ -20,6 +24,12 @@ pub mod dynamic;
 pub mod exception;
 #[cfg(feature = "fs")]
 pub mod fs;
+#[cfg(feature = "net")]
+pub mod http_pool;
+#[cfg(feature = "net")]
+pub mod http_proxy;
+#[cfg(feature = "http_server")]
+pub mod http_server;
 #[cfg(feature = "core")]
 pub mod hxref;
 #[cfg(feature = "io")]
@@ -34,13 +44,21 @@ pub mod net;
 pub mod path;
 #[cfg(feature = "process")]
 pub mod process;
+#[cfg(feature = "signal")]
+pub mod signal;
 #[cfg(feature = "ssl")]
 pub mod ssl;
+#[cfg(feature = "stats")]
+pub mod stats;
 #[cfg(feature = "core")]
 pub mod string;
 #[cfg(feature = "core")]
 pub mod sys;
 #[cfg(feature = "thread")]
 pub mod thread;
+#[cfg(feature = "tracing")]
+pub mod trace;
+#[cfg(feature = "core")]
+pub mod weak;
 
 pub fn init() {}
@@ -55,6 +55,7 @@ impl FileHandle {
         })
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", name = "fs.read", skip_all, fields(capacity = buf.len()), ret))]
     pub fn read_into(&mut self, buf: &mut [u8]) -> i32 {
         self.with_file_mut(|file| match io::Read::read(file, buf) {
             Ok(0) => -1,
@@ -63,6 +64,7 @@ impl FileHandle {
         })
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", name = "fs.write", skip_all, fields(len = buf.len())))]
     pub fn write_all(&mut self, buf: &[u8]) {
         self.with_file_mut(|file| match io::Write::write_all(file, buf) {
             Ok(()) => (),
@@ -125,6 +127,10 @@ impl FileHandle {
     }
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", name = "fs.open", fields(mode = "read"))
+)]
 pub fn open_read(path: &str) -> HxRef<FileHandle> {
     let file = match fs::File::open(path) {
         Ok(f) => f,
@@ -133,6 +139,10 @@ pub fn open_read(path: &str) -> HxRef<FileHandle> {
     HxRef::new(FileHandle { file: Some(file) })
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", name = "fs.open", fields(mode = "write"))
+)]
 pub fn open_write_truncate(path: &str) -> HxRef<FileHandle> {
     let file = match fs::OpenOptions::new()
         .create(true)
@@ -146,6 +156,10 @@ pub fn open_write_truncate(path: &str) -> HxRef<FileHandle> {
     HxRef::new(FileHandle { file: Some(file) })
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", name = "fs.open", fields(mode = "append"))
+)]
 pub fn open_append(path: &str) -> HxRef<FileHandle> {
     let file = match fs::OpenOptions::new().create(true).append(true).open(path) {
         Ok(f) => f,
@@ -154,6 +168,10 @@ pub fn open_append(path: &str) -> HxRef<FileHandle> {
     HxRef::new(FileHandle { file: Some(file) })
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", name = "fs.open", fields(mode = "update"))
+)]
 pub fn open_update(path: &str) -> HxRef<FileHandle> {
     let file = match fs::OpenOptions::new()
         .create(true)
@@ -1,6 +1,27 @@
-use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use parking_lot::RwLock;
+#[cfg(not(feature = "cell_debug"))]
+use parking_lot::{RwLockReadGuard, RwLockWriteGuard};
 use std::sync::{Arc, Weak};
 
+#[cfg(feature = "cell_debug")]
+pub use debug::{HxReadGuard, HxWriteGuard};
+
+#[cfg(feature = "cycle_collector")]
+pub(crate) use collector::report_at_exit as collector_report_at_exit;
+#[cfg(feature = "cycle_collector")]
+pub use collector::{
+    collector_collect, collector_report, collector_set_report_at_exit, collector_set_threshold,
+    collector_track, collector_tracked_count, HxTrace, HxTraceEdges, HxTracer,
+};
+
+/// Read guard returned by `HxCell::borrow()`.
+#[cfg(not(feature = "cell_debug"))]
+pub type HxReadGuard<'a, T> = RwLockReadGuard<'a, T>;
+
+/// Write guard returned by `HxCell::borrow_mut()`.
+#[cfg(not(feature = "cell_debug"))]
+pub type HxWriteGuard<'a, T> = RwLockWriteGuard<'a, T>;
+
 /// Thread-safe shared reference type used across generated crates and the hxrt runtime.
 ///
 /// Why
@@ -18,12 +39,20 @@ use std::sync::{Arc, Weak};
 /// - The current `RwLock` permits concurrent readers and thread-safe interior mutation. It does not
 ///   make nested access to the same handle re-entrant; lowering must keep guards scoped and must not
 ///   hold a conflicting guard across user work.
+/// - With the `cell_debug` feature, each cell records which thread holds its guards and where they
+///   were acquired. A same-thread conflicting `borrow()` / `borrow_mut()` then throws a catchable
+///   `HXRT-BORROW-REENTRANCY` String naming the type and both call sites instead of deadlocking.
 pub type HxRc<T> = Arc<T>;
 
 #[derive(Debug, Default)]
 pub struct HxCell<T> {
     self_weak: Option<Weak<HxCell<T>>>,
     lock: RwLock<T>,
+    #[cfg(feature = "cell_debug")]
+    tracker: debug::BorrowTracker,
+    #[cfg(feature = "stats")]
+    // Ends the object's live count when the cell drops.
+    _live: crate::stats::LiveToken,
 }
 
 impl<T> HxCell<T> {
@@ -32,6 +61,10 @@ impl<T> HxCell<T> {
         Self {
             self_weak: None,
             lock: RwLock::new(value),
+            #[cfg(feature = "cell_debug")]
+            tracker: debug::BorrowTracker::default(),
+            #[cfg(feature = "stats")]
+            _live: crate::stats::LiveToken::default(),
         }
     }
 
@@ -60,17 +93,47 @@ impl<T> HxCell<T> {
         }
     }
 
+    #[cfg(not(feature = "cell_debug"))]
     #[inline]
-    pub fn borrow(&self) -> RwLockReadGuard<'_, T> {
-        self.lock.read()
+    pub fn borrow(&self) -> HxReadGuard<'_, T> {
+        lock_read(&self.lock)
     }
 
+    #[cfg(not(feature = "cell_debug"))]
     #[inline]
-    pub fn borrow_mut(&self) -> RwLockWriteGuard<'_, T> {
-        self.lock.write()
+    pub fn borrow_mut(&self) -> HxWriteGuard<'_, T> {
+        lock_write(&self.lock)
+    }
+
+    #[cfg(feature = "cell_debug")]
+    #[track_caller]
+    pub fn borrow(&self) -> HxReadGuard<'_, T> {
+        debug::read(&self.lock, &self.tracker)
+    }
+
+    #[cfg(feature = "cell_debug")]
+    #[track_caller]
+    pub fn borrow_mut(&self) -> HxWriteGuard<'_, T> {
+        debug::write(&self.lock, &self.tracker)
     }
 }
 
+// With the `stats` feature, blocking acquisitions are counted as lock waits.
+#[cfg(not(feature = "stats"))]
+#[inline]
+fn lock_read<T>(lock: &RwLock<T>) -> parking_lot::RwLockReadGuard<'_, T> {
+    lock.read()
+}
+
+#[cfg(not(feature = "stats"))]
+#[inline]
+fn lock_write<T>(lock: &RwLock<T>) -> parking_lot::RwLockWriteGuard<'_, T> {
+    lock.write()
+}
+
+#[cfg(feature = "stats")]
+use crate::stats::{read as lock_read, write as lock_write};
+
 /// Nullable reference to an interior-mutable Haxe value.
 ///
 /// Why
@@ -118,6 +181,10 @@ impl<T> HxRef<T> {
         Self(Some(HxRc::new_cyclic(|weak| HxCell {
             self_weak: Some(weak.clone()),
             lock: RwLock::new(value),
+            #[cfg(feature = "cell_debug")]
+            tracker: debug::BorrowTracker::default(),
+            #[cfg(feature = "stats")]
+            _live: crate::stats::record_alloc::<T>(),
         })))
     }
 
@@ -154,12 +221,14 @@ impl<T> HxRef<T> {
     }
 
     #[inline]
-    pub fn borrow(&self) -> RwLockReadGuard<'_, T> {
+    #[cfg_attr(feature = "cell_debug", track_caller)]
+    pub fn borrow(&self) -> HxReadGuard<'_, T> {
         self.unwrap_cell().borrow()
     }
 
     #[inline]
-    pub fn borrow_mut(&self) -> RwLockWriteGuard<'_, T> {
+    #[cfg_attr(feature = "cell_debug", track_caller)]
+    pub fn borrow_mut(&self) -> HxWriteGuard<'_, T> {
         self.unwrap_cell().borrow_mut()
     }
 }
@@ -185,6 +254,59 @@ impl<T> HxRef<T> {
     pub fn as_arc_opt(&self) -> Option<&HxRc<HxCell<T>>> {
         self.0.as_ref()
     }
+
+    /// Non-owning handle to the same object; a null reference downgrades to a dead handle.
+    #[inline]
+    pub fn downgrade(&self) -> HxWeak<T> {
+        HxWeak(self.0.as_ref().map(HxRc::downgrade))
+    }
+}
+
+/// Non-owning counterpart of `HxRef<T>`.
+///
+/// Why
+/// - `HxRef` graphs are reference counted, so a back-reference (child -> parent, observer ->
+///   subject) written as a strong handle keeps the whole structure alive.
+///
+/// What
+/// - `upgrade()` returns the object while some `HxRef` still owns it, and a null `HxRef` after that.
+/// - `is_alive()` reports the same without producing a handle.
+///
+/// How
+/// - Wraps `Weak<HxCell<T>>`. Cloning a weak handle never extends the object's lifetime.
+#[derive(Debug)]
+pub struct HxWeak<T>(Option<Weak<HxCell<T>>>);
+
+impl<T> Clone for HxWeak<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Default for HxWeak<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+impl<T> HxWeak<T> {
+    /// A handle that never upgrades.
+    #[inline]
+    pub fn null() -> Self {
+        Self(None)
+    }
+
+    #[inline]
+    pub fn upgrade(&self) -> HxRef<T> {
+        HxRef(self.0.as_ref().and_then(Weak::upgrade))
+    }
+
+    #[inline]
+    pub fn is_alive(&self) -> bool {
+        self.0.as_ref().is_some_and(|weak| weak.strong_count() > 0)
+    }
 }
 
 /// Nullable shared reference for unsized values (trait objects, closures, etc.).
@@ -269,3 +391,747 @@ impl<T: ?Sized> HxDynRef<T> {
         self.0.as_ref()
     }
 }
+
+/// Same-thread borrow tracking for the `cell_debug` runtime mode.
+///
+/// Why
+/// - A conflicting nested borrow on the thread that already holds a guard can never make progress:
+///   `parking_lot::RwLock` is not re-entrant, so the program silently hangs.
+///
+/// What
+/// - Each cell records the writer (thread + call site) and every live reader.
+/// - Conflicts detected before blocking: write-while-writing, read-while-writing, and
+///   write-while-reading on the same thread. They throw `HXRT-BORROW-REENTRANCY: ...`.
+/// - Nested same-thread reads are allowed and use `read_recursive` so a queued writer on another
+///   thread cannot wedge them.
+///
+/// How
+/// - The bookkeeping mutex is never held while waiting on the cell lock. Only the current thread
+///   adds or removes its own entries, so the pre-acquisition check stays valid for that thread.
+/// - Cross-thread waits are ordinary lock contention and are not reported.
+#[cfg(feature = "cell_debug")]
+mod debug {
+    use parking_lot::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
+    use std::ops::{Deref, DerefMut};
+    use std::panic::Location;
+    use std::thread::{self, ThreadId};
+
+    type Site = &'static Location<'static>;
+
+    #[derive(Debug, Default)]
+    pub(super) struct BorrowTracker {
+        state: Mutex<TrackerState>,
+    }
+
+    #[derive(Debug, Default)]
+    struct TrackerState {
+        writer: Option<(ThreadId, Site)>,
+        readers: Vec<(ThreadId, Site)>,
+    }
+
+    pub struct HxReadGuard<'a, T> {
+        guard: RwLockReadGuard<'a, T>,
+        tracker: &'a BorrowTracker,
+        entry: (ThreadId, Site),
+    }
+
+    pub struct HxWriteGuard<'a, T> {
+        guard: RwLockWriteGuard<'a, T>,
+        tracker: &'a BorrowTracker,
+    }
+
+    fn conflict<T>(site: Site, requested: &str, held: &str, held_site: Site) -> ! {
+        crate::exception::throw(crate::dynamic::from(format!(
+            "HXRT-BORROW-REENTRANCY: {requested} of `{}` at {site} while this thread holds a {held} \
+             acquired at {held_site}",
+            std::any::type_name::<T>()
+        )))
+    }
+
+    #[track_caller]
+    pub(super) fn read<'a, T>(
+        lock: &'a RwLock<T>,
+        tracker: &'a BorrowTracker,
+    ) -> HxReadGuard<'a, T> {
+        let site = Location::caller();
+        let me = thread::current().id();
+        let nested = {
+            let state = tracker.state.lock();
+            if let Some((owner, held_site)) = state.writer {
+                if owner == me {
+                    drop(state);
+                    conflict::<T>(site, "borrow()", "write guard", held_site);
+                }
+            }
+            state.readers.iter().any(|(owner, _)| *owner == me)
+        };
+        let guard = if nested {
+            lock.read_recursive()
+        } else {
+            super::lock_read(lock)
+        };
+        tracker.state.lock().readers.push((me, site));
+        HxReadGuard {
+            guard,
+            tracker,
+            entry: (me, site),
+        }
+    }
+
+    #[track_caller]
+    pub(super) fn write<'a, T>(
+        lock: &'a RwLock<T>,
+        tracker: &'a BorrowTracker,
+    ) -> HxWriteGuard<'a, T> {
+        let site = Location::caller();
+        let me = thread::current().id();
+        {
+            let state = tracker.state.lock();
+            let held = match state.writer {
+                Some((owner, held_site)) if owner == me => Some(("write guard", held_site)),
+                _ => state
+                    .readers
+                    .iter()
+                    .find(|(owner, _)| *owner == me)
+                    .map(|(_, held_site)| ("read guard", *held_site)),
+            };
+            if let Some((held, held_site)) = held {
+                drop(state);
+                conflict::<T>(site, "borrow_mut()", held, held_site);
+            }
+        }
+        let guard = super::lock_write(lock);
+        tracker.state.lock().writer = Some((me, site));
+        HxWriteGuard { guard, tracker }
+    }
+
+    impl<T> Drop for HxReadGuard<'_, T> {
+        fn drop(&mut self) {
+            let mut state = self.tracker.state.lock();
+            if let Some(index) = state.readers.iter().rposition(|(owner, site)| {
+                *owner == self.entry.0 && std::ptr::eq(*site, self.entry.1)
+            }) {
+                state.readers.swap_remove(index);
+            }
+        }
+    }
+
+    impl<T> Drop for HxWriteGuard<'_, T> {
+        fn drop(&mut self) {
+            self.tracker.state.lock().writer = None;
+        }
+    }
+
+    impl<T> Deref for HxReadGuard<'_, T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            &self.guard
+        }
+    }
+
+    impl<T> Deref for HxWriteGuard<'_, T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            &self.guard
+        }
+    }
+
+    impl<T> DerefMut for HxWriteGuard<'_, T> {
+        fn deref_mut(&mut self) -> &mut T {
+            &mut self.guard
+        }
+    }
+
+    impl<T: std::fmt::Debug> std::fmt::Debug for HxReadGuard<'_, T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.guard.fmt(f)
+        }
+    }
+
+    impl<T: std::fmt::Debug> std::fmt::Debug for HxWriteGuard<'_, T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.guard.fmt(f)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::cell::HxRef;
+        use crate::exception;
+
+        fn message(result: Result<(), crate::dynamic::Dynamic>) -> String {
+            result
+                .unwrap_err()
+                .downcast_ref::<String>()
+                .cloned()
+                .expect("String payload")
+        }
+
+        #[test]
+        fn nested_write_reports_both_sites() {
+            let value = HxRef::new(1i32);
+            let outer = value.borrow_mut();
+            let text = message(exception::catch_unwind(|| {
+                let _inner = value.borrow_mut();
+            }));
+            drop(outer);
+            assert!(text.starts_with("HXRT-BORROW-REENTRANCY: borrow_mut() of `i32` at "));
+            assert_eq!(text.matches("cell.rs:").count(), 2, "{text}");
+            *value.borrow_mut() = 2;
+            assert_eq!(*value.borrow(), 2);
+        }
+
+        #[test]
+        fn read_while_writing_and_upgrade_are_rejected() {
+            let value = HxRef::new(String::from("x"));
+            {
+                let _writer = value.borrow_mut();
+                let text = message(exception::catch_unwind(|| {
+                    let _reader = value.borrow();
+                }));
+                assert!(
+                    text.contains("borrow() of `alloc::string::String`"),
+                    "{text}"
+                );
+                assert!(text.contains("holds a write guard"), "{text}");
+            }
+            {
+                let _reader = value.borrow();
+                let text = message(exception::catch_unwind(|| {
+                    let _writer = value.borrow_mut();
+                }));
+                assert!(text.contains("holds a read guard"), "{text}");
+            }
+            value.borrow_mut().push('y');
+            assert_eq!(value.borrow().as_str(), "xy");
+        }
+
+        #[test]
+        fn nested_reads_and_other_threads_are_allowed() {
+            let value = HxRef::new(3i32);
+            let first = value.borrow();
+            let second = value.borrow();
+            assert_eq!(*first + *second, 6);
+            drop((first, second));
+
+            let writer = value.borrow_mut();
+            let other = value.clone();
+            let handle = std::thread::spawn(move || *other.borrow());
+            drop(writer);
+            assert_eq!(handle.join().unwrap(), 3);
+        }
+    }
+}
+
+/// Opt-in cycle collector for `HxRef` object graphs (`cycle_collector` feature).
+///
+/// Why
+/// - `HxRef` is reference counted, so parent/child back-references, observer lists, and
+///   doubly-linked structures keep themselves alive after the program drops its last handle to
+///   them. Every other Haxe target reclaims such graphs with a tracing GC.
+///
+/// What
+/// - `HxTrace`: implemented by the compiler for generated classes under
+///   `-D rust_hxrt_cycle_collector`; reports the class's traced `HxRef` / `Array` fields and can
+///   clear them. `HxTraceEdges` is the per-field half of that contract.
+/// - `collector_track`: generated constructors register each new object.
+/// - `collector_collect`: frees every registered object that is reachable only from garbage
+///   cycles and returns how many objects it released. `collector_set_threshold` runs it
+///   automatically after a number of tracked allocations.
+/// - `collector_report`: lists the objects currently kept alive only by cycles, grouped per
+///   cycle and by type. With `HXRT_CYCLE_REPORT=1` (or `collector_set_report_at_exit(true)`) the
+///   report is printed to stderr when the program exits.
+///
+/// How
+/// - Trial deletion: discover the graph reachable from registered objects through traced edges,
+///   subtract each object's traced in-edges from its strong count, and treat any object with
+///   references left over (locals, statics, `Dynamic`, closures, untraced fields) as a root.
+///   Objects that no root reaches are garbage.
+/// - Imprecision only ever adds roots: untraced edges and objects locked during the scan keep
+///   their targets alive, so a missed edge leaks instead of freeing live data.
+/// - Before clearing, every garbage object is re-checked to be held only by other garbage; any
+///   change aborts that round. Collection still expects other threads not to be mutating traced
+///   graphs while it runs.
+#[cfg(feature = "cycle_collector")]
+mod collector {
+    use super::{HxCell, HxRc, HxRef};
+    use parking_lot::Mutex;
+    use std::collections::{BTreeMap, HashMap};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Weak;
+
+    /// Child traversal for a value stored inside an `HxRef`.
+    pub trait HxTrace {
+        /// Report every traced edge held by `self`.
+        fn trace(&self, tracer: &mut HxTracer);
+
+        /// Drop every traced edge. Only called on objects already proven to be garbage.
+        fn clear_refs(&mut self);
+    }
+
+    /// Field-level tracing used by compiler-generated `HxTrace` impls.
+    pub trait HxTraceEdges {
+        fn trace_edges(&self, tracer: &mut HxTracer);
+
+        fn clear_edges(&mut self);
+    }
+
+    impl<T: HxTrace + Send + Sync + 'static> HxTraceEdges for HxRef<T> {
+        fn trace_edges(&self, tracer: &mut HxTracer) {
+            if let Some(rc) = &self.0 {
+                tracer.edges.push(node_of(rc));
+            }
+        }
+
+        fn clear_edges(&mut self) {
+            *self = HxRef::null();
+        }
+    }
+
+    // `Array<T>` storage: the `Vec` lives in its own `HxRef`, so arrays are graph nodes too.
+    impl<T: HxTraceEdges> HxTrace for Vec<T> {
+        fn trace(&self, tracer: &mut HxTracer) {
+            for item in self {
+                item.trace_edges(tracer);
+            }
+        }
+
+        fn clear_refs(&mut self) {
+            self.clear();
+        }
+    }
+
+    /// Edge sink passed to `HxTrace::trace`.
+    pub struct HxTracer {
+        edges: Vec<Weak<dyn Node>>,
+    }
+
+    /// Type-erased view of a tracked `HxCell<T>`.
+    trait Node: Send + Sync {
+        fn type_name(&self) -> &'static str;
+
+        /// Trace the current value; `false` when the cell is locked and could not be inspected.
+        fn trace_node(&self, tracer: &mut HxTracer) -> bool;
+
+        fn clear_node(&self);
+    }
+
+    impl<T: HxTrace + Send + Sync + 'static> Node for HxCell<T> {
+        fn type_name(&self) -> &'static str {
+            std::any::type_name::<T>()
+        }
+
+        fn trace_node(&self, tracer: &mut HxTracer) -> bool {
+            match self.lock.try_read() {
+                Some(value) => {
+                    value.trace(tracer);
+                    true
+                }
+                None => false,
+            }
+        }
+
+        fn clear_node(&self) {
+            if let Some(mut value) = self.lock.try_write() {
+                value.clear_refs();
+            }
+        }
+    }
+
+    fn node_of<T: HxTrace + Send + Sync + 'static>(rc: &HxRc<HxCell<T>>) -> Weak<dyn Node> {
+        let weak: Weak<HxCell<T>> = HxRc::downgrade(rc);
+        weak
+    }
+
+    fn address(node: &Weak<dyn Node>) -> usize {
+        node.as_ptr() as *const () as usize
+    }
+
+    /// The tracked list is pruned of dead entries whenever it doubles past this size.
+    const PRUNE_FLOOR: usize = 1024;
+
+    struct Registry {
+        tracked: Vec<Weak<dyn Node>>,
+        prune_at: usize,
+        since_collect: usize,
+        threshold: usize,
+    }
+
+    static REGISTRY: Mutex<Registry> = parking_lot::const_mutex(Registry {
+        tracked: Vec::new(),
+        prune_at: PRUNE_FLOOR,
+        since_collect: 0,
+        threshold: 0,
+    });
+    static COLLECTING: AtomicBool = AtomicBool::new(false);
+    static REPORT_AT_EXIT: AtomicBool = AtomicBool::new(false);
+    static EXIT_REPORTED: AtomicBool = AtomicBool::new(false);
+
+    /// Register a newly constructed object as a collection candidate.
+    ///
+    /// Runs a collection first when the allocation threshold set by `collector_set_threshold` has
+    /// been reached.
+    pub fn collector_track<T: HxTrace + Send + Sync + 'static>(value: &HxRef<T>) {
+        let Some(rc) = &value.0 else {
+            return;
+        };
+        let due = {
+            let mut registry = REGISTRY.lock();
+            registry.tracked.push(node_of(rc));
+            if registry.tracked.len() >= registry.prune_at {
+                registry.tracked.retain(|node| node.strong_count() > 0);
+                registry.prune_at = (registry.tracked.len() * 2).max(PRUNE_FLOOR);
+            }
+            registry.since_collect += 1;
+            registry.threshold > 0 && registry.since_collect >= registry.threshold
+        };
+        if due {
+            collector_collect();
+        }
+    }
+
+    /// Collect automatically after `allocations` tracked objects; `0` or less turns it off.
+    pub fn collector_set_threshold(allocations: i32) {
+        REGISTRY.lock().threshold = allocations.max(0) as usize;
+    }
+
+    /// Number of registered objects that are still alive.
+    pub fn collector_tracked_count() -> i32 {
+        let mut registry = REGISTRY.lock();
+        registry.tracked.retain(|node| node.strong_count() > 0);
+        registry.tracked.len() as i32
+    }
+
+    /// Free every registered object kept alive only by reference cycles.
+    ///
+    /// Returns how many objects were released. A call made while another collection is running
+    /// returns `0` immediately.
+    pub fn collector_collect() -> i32 {
+        if COLLECTING.swap(true, Ordering::Acquire) {
+            return 0;
+        }
+        struct Finished;
+        impl Drop for Finished {
+            fn drop(&mut self) {
+                COLLECTING.store(false, Ordering::Release);
+            }
+        }
+        let _finished = Finished;
+
+        let candidates = {
+            let mut registry = REGISTRY.lock();
+            registry.since_collect = 0;
+            live_tracked(&mut registry)
+        };
+        let graph = Graph::discover(candidates);
+        let garbage: Vec<(usize, HxRc<dyn Node>)> = graph
+            .garbage()
+            .into_iter()
+            .filter_map(|index| graph.nodes[index].upgrade().map(|node| (index, node)))
+            .collect();
+        // Each garbage object must now be held by its traced in-edges plus the handle taken above.
+        let unchanged = garbage
+            .iter()
+            .all(|(index, node)| HxRc::strong_count(node) == graph.in_edges[*index] + 1);
+        if !unchanged {
+            return 0;
+        }
+        for (_, node) in &garbage {
+            node.clear_node();
+        }
+        // Dropping the last handles here releases the whole garbage set.
+        garbage.len() as i32
+    }
+
+    /// Describe the objects currently kept alive only by reference cycles.
+    ///
+    /// Returns an empty string when there are none; otherwise one header line followed by one line
+    /// per cycle (connected garbage cluster) with its object count per type.
+    pub fn collector_report() -> String {
+        let candidates = live_tracked(&mut REGISTRY.lock());
+        let graph = Graph::discover(candidates);
+        let garbage = graph.garbage();
+        if garbage.is_empty() {
+            return String::new();
+        }
+
+        let position: HashMap<usize, usize> = garbage
+            .iter()
+            .enumerate()
+            .map(|(slot, index)| (*index, slot))
+            .collect();
+        let mut parent: Vec<usize> = (0..garbage.len()).collect();
+        fn find(parent: &mut [usize], mut slot: usize) -> usize {
+            while parent[slot] != slot {
+                parent[slot] = parent[parent[slot]];
+                slot = parent[slot];
+            }
+            slot
+        }
+        for (slot, index) in garbage.iter().enumerate() {
+            for target in graph.edges[*index].iter().flatten() {
+                if let Some(other) = position.get(target) {
+                    let a = find(&mut parent, slot);
+                    let b = find(&mut parent, *other);
+                    parent[a] = b;
+                }
+            }
+        }
+
+        let mut clusters: BTreeMap<usize, BTreeMap<&'static str, usize>> = BTreeMap::new();
+        let mut objects = 0;
+        for (slot, index) in garbage.iter().enumerate() {
+            if let Some(node) = graph.nodes[*index].upgrade() {
+                let root = find(&mut parent, slot);
+                *clusters
+                    .entry(root)
+                    .or_default()
+                    .entry(node.type_name())
+                    .or_default() += 1;
+                objects += 1;
+            }
+        }
+        let mut clusters: Vec<(usize, String)> = clusters
+            .into_values()
+            .map(|types| {
+                let size = types.values().sum();
+                let names: Vec<String> = types
+                    .into_iter()
+                    .map(|(name, count)| format!("{name} x{count}"))
+                    .collect();
+                (size, names.join(", "))
+            })
+            .collect();
+        clusters.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+
+        let mut lines = vec![format!(
+            "HXRT-CYCLE-LEAK: {objects} object(s) in {} unreachable cycle(s)",
+            clusters.len()
+        )];
+        for (number, (size, names)) in clusters.iter().enumerate() {
+            lines.push(format!("  cycle {} ({size} objects): {names}", number + 1));
+        }
+        lines.join("\n")
+    }
+
+    /// Print the leak report on exit even without `HXRT_CYCLE_REPORT`.
+    pub fn collector_set_report_at_exit(enabled: bool) {
+        REPORT_AT_EXIT.store(enabled, Ordering::SeqCst);
+    }
+
+    /// Exit path: print `collector_report()` to stderr once when the report is enabled.
+    pub fn report_at_exit() {
+        let requested = REPORT_AT_EXIT.load(Ordering::SeqCst)
+            || std::env::var_os("HXRT_CYCLE_REPORT")
+                .is_some_and(|value| !value.is_empty() && value != "0");
+        if !requested || EXIT_REPORTED.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let report = collector_report();
+        if !report.is_empty() {
+            eprintln!("{report}");
+        }
+    }
+
+    fn live_tracked(registry: &mut Registry) -> Vec<Weak<dyn Node>> {
+        registry.tracked.retain(|node| node.strong_count() > 0);
+        registry.tracked.clone()
+    }
+
+    /// Snapshot of the traced graph reachable from the registered objects.
+    struct Graph {
+        nodes: Vec<Weak<dyn Node>>,
+        /// Traced children per node; `None` for dead or locked nodes.
+        edges: Vec<Option<Vec<usize>>>,
+        in_edges: Vec<usize>,
+        index: HashMap<usize, usize>,
+    }
+
+    impl Graph {
+        fn discover(candidates: Vec<Weak<dyn Node>>) -> Self {
+            let mut graph = Graph {
+                nodes: Vec::new(),
+                edges: Vec::new(),
+                in_edges: Vec::new(),
+                index: HashMap::new(),
+            };
+            for node in candidates {
+                graph.intern(node);
+            }
+            let mut next = 0;
+            while next < graph.nodes.len() {
+                let traced = graph.nodes[next].upgrade().and_then(|node| {
+                    let mut tracer = HxTracer { edges: Vec::new() };
+                    node.trace_node(&mut tracer).then_some(tracer.edges)
+                });
+                graph.edges[next] =
+                    traced.map(|edges| edges.into_iter().map(|edge| graph.intern(edge)).collect());
+                next += 1;
+            }
+            graph.in_edges = vec![0; graph.nodes.len()];
+            for target in graph.edges.iter().flatten().flatten() {
+                graph.in_edges[*target] += 1;
+            }
+            graph
+        }
+
+        fn intern(&mut self, node: Weak<dyn Node>) -> usize {
+            let key = address(&node);
+            if let Some(index) = self.index.get(&key) {
+                return *index;
+            }
+            let index = self.nodes.len();
+            self.nodes.push(node);
+            self.edges.push(None);
+            self.index.insert(key, index);
+            index
+        }
+
+        /// Live nodes that no root reaches through traced edges.
+        fn garbage(&self) -> Vec<usize> {
+            let count = self.nodes.len();
+            let mut reachable = vec![false; count];
+            let mut pending: Vec<usize> = (0..count)
+                .filter(|index| {
+                    let strong = self.nodes[*index].strong_count();
+                    strong > 0 && (self.edges[*index].is_none() || strong > self.in_edges[*index])
+                })
+                .collect();
+            for index in &pending {
+                reachable[*index] = true;
+            }
+            while let Some(index) = pending.pop() {
+                for target in self.edges[index].iter().flatten() {
+                    if !reachable[*target] {
+                        reachable[*target] = true;
+                        pending.push(*target);
+                    }
+                }
+            }
+            (0..count)
+                .filter(|index| !reachable[*index] && self.nodes[*index].strong_count() > 0)
+                .collect()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::array::Array;
+        use std::sync::atomic::AtomicUsize;
+        use std::sync::Arc;
+
+        // The registry is process-wide; keep these tests from collecting each other's graphs
+        // mid-construction.
+        static SERIAL: Mutex<()> = parking_lot::const_mutex(());
+
+        #[derive(Debug)]
+        struct Node {
+            parent: HxRef<Node>,
+            children: Array<HxRef<Node>>,
+            // Deliberately untraced: strong handles here must keep their targets alive.
+            untraced: HxRef<Node>,
+            drops: Arc<AtomicUsize>,
+        }
+
+        impl Drop for Node {
+            fn drop(&mut self) {
+                self.drops.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        impl HxTrace for Node {
+            fn trace(&self, tracer: &mut HxTracer) {
+                self.parent.trace_edges(tracer);
+                self.children.trace_edges(tracer);
+            }
+
+            fn clear_refs(&mut self) {
+                self.parent.clear_edges();
+                self.children.clear_edges();
+            }
+        }
+
+        fn node(drops: &Arc<AtomicUsize>) -> HxRef<Node> {
+            let value = HxRef::new(Node {
+                parent: HxRef::null(),
+                children: Array::new(),
+                untraced: HxRef::null(),
+                drops: drops.clone(),
+            });
+            collector_track(&value);
+            value
+        }
+
+        fn adopt(parent: &HxRef<Node>, child: &HxRef<Node>) {
+            child.borrow_mut().parent = parent.clone();
+            parent.borrow().children.push(child.clone());
+        }
+
+        #[test]
+        fn collects_unreachable_cycles_and_keeps_rooted_ones() {
+            let _serial = SERIAL.lock();
+            let drops = Arc::new(AtomicUsize::new(0));
+            let root = node(&drops);
+            let child = node(&drops);
+            adopt(&root, &child);
+            drop(child);
+
+            collector_collect();
+            assert_eq!(drops.load(Ordering::SeqCst), 0);
+            assert_eq!(root.borrow().children.len(), 1);
+
+            drop(root);
+            collector_collect();
+            assert_eq!(drops.load(Ordering::SeqCst), 2);
+        }
+
+        #[test]
+        fn untraced_references_keep_cycles_alive() {
+            let _serial = SERIAL.lock();
+            let drops = Arc::new(AtomicUsize::new(0));
+            let holder = node(&drops);
+            let left = node(&drops);
+            let right = node(&drops);
+            adopt(&left, &right);
+            holder.borrow_mut().untraced = left.clone();
+            drop((left, right));
+
+            collector_collect();
+            assert_eq!(drops.load(Ordering::SeqCst), 0);
+
+            holder.borrow_mut().untraced = HxRef::null();
+            collector_collect();
+            assert_eq!(drops.load(Ordering::SeqCst), 2);
+            drop(holder);
+            assert_eq!(drops.load(Ordering::SeqCst), 3);
+        }
+
+        #[test]
+        fn report_lists_leaked_cycles_and_threshold_collects() {
+            let _serial = SERIAL.lock();
+            let drops = Arc::new(AtomicUsize::new(0));
+            let parent = node(&drops);
+            adopt(&parent, &node(&drops));
+            adopt(&parent, &node(&drops));
+            drop(parent);
+
+            let report = collector_report();
+            assert!(report.starts_with("HXRT-CYCLE-LEAK: "), "{report}");
+            // The three `children` arrays are graph nodes of the same cycle.
+            assert!(report.contains("in 1 unreachable cycle(s)"), "{report}");
+            assert!(report.contains("cycle 1 (6 objects): "), "{report}");
+            assert!(report.contains("collector::tests::Node x3"), "{report}");
+            assert!(report.contains("alloc::vec::Vec<"), "{report}");
+
+            collector_set_threshold(1);
+            let survivor = node(&drops);
+            collector_set_threshold(0);
+            assert_eq!(drops.load(Ordering::SeqCst), 3);
+            assert!(collector_report().is_empty());
+            drop(survivor);
+        }
+    }
+}
@@ -1,11 +1,13 @@
 use crate::cell::{HxDynRef, HxRef};
 use crate::{dynamic, exception, io};
 use mio::{Events, Interest, Poll, Token};
-use socket2::{Domain, Protocol, Socket, Type};
+use socket2::{Domain, Protocol, SockRef, Socket, TcpKeepalive, Type};
 use std::io::{Read, Write};
 use std::net::{
-    IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4, TcpListener, TcpStream, ToSocketAddrs, UdpSocket,
+    IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpListener, TcpStream, ToSocketAddrs, UdpSocket,
 };
+#[cfg(unix)]
+use std::os::unix::net::{UnixDatagram, UnixListener, UnixStream};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -23,30 +25,51 @@ use rustls::{ClientConfig, ClientConnection, RootCertStore, ServerConfig, Server
 /// - The Haxe stdlib expects `sys.net.Socket.select` and `waitForRead` to exist for basic polling.
 ///
 /// What
-/// - Hostname resolution (`resolve`, `toString`, reverse lookup, local hostname).
+/// - Hostname resolution (`resolve`, `toString`, reverse lookup, local hostname) for IPv4 and
+///   IPv6.
 /// - TCP socket operations: bind/listen/accept/connect, stream read/write, timeouts, non-blocking,
 ///   and best-effort `select`.
-/// - UDP socket operations: bind/connect, send/recv, broadcast.
+/// - UDP socket operations: bind/connect, send/recv, broadcast, multicast group membership
+///   (IPv4 and IPv6) with multicast TTL/loopback options, and the full sender address of the last
+///   received datagram.
+/// - Socket options (Rust-target extension): `SO_REUSEADDR`, `SO_REUSEPORT`, keepalive with
+///   idle/interval, `SO_LINGER`, send/receive buffer sizes and IP TTL.
+/// - Unix domain sockets (unix only): a TCP handle becomes a Unix stream socket on
+///   `connect_unix` / `bind_unix`, a UDP handle a Unix datagram socket. Both keep timeouts,
+///   blocking mode, `select` and event-loop watches.
 ///
 /// How
 /// - We represent sockets as a single handle type (`SocketHandle`) that can be TCP or UDP.
+/// - Addresses are full `IpAddr`s. Haxe's `Host.ip:Int` only holds IPv4, so Haxe code passes the
+///   canonical address text and `ip_to_ipv4_int` derives the integer (0 for IPv6).
+/// - Binding an IPv6 address is dual-stack (`IPV6_V6ONLY` off), so `::` also accepts IPv4 peers;
+///   reported addresses are canonicalized, so those peers show up as plain IPv4.
 /// - IO errors map into typed `haxe.io.Error` values (runtime `hxrt::io::Error`):
 ///   - `WouldBlock` -> `Error::Blocked`
 ///   - `TimedOut` -> `Error::Custom("Timeout")`
 ///   - Other failures -> `Error::Custom("<os error>")`
 /// - `select` uses `mio` so it works on Unix and Windows.
+/// - Haxe sockets only get an OS socket on bind/connect, so option setters are recorded in
+///   `SocketOptions` and replayed onto the OS socket when it is created (before `bind`, so reuse
+///   flags take effect). Setters on an open socket also apply immediately; getters read the OS
+///   value once a socket exists.
 
 #[derive(Debug, Default)]
 pub struct SocketHandle {
     kind: SocketKind,
     timeout: Option<Duration>,
     blocking: bool,
+    options: SocketOptions,
 }
 
 #[derive(Debug)]
 enum SocketKind {
     Tcp(TcpState),
     Udp(UdpState),
+    #[cfg(unix)]
+    Unix(UnixState),
+    #[cfg(unix)]
+    UnixDatagram(UnixDatagramState),
 }
 
 impl Default for SocketKind {
@@ -136,8 +159,52 @@ impl ResolvesServerCert for TlsServerResolver {
 struct UdpState {
     socket: Option<UdpSocket>,
     broadcast: bool,
+    last_sender: Option<SocketAddr>,
 }
 
+/// Unix stream socket: bound-but-not-listening, listening, or connected (like `TcpState`).
+#[cfg(unix)]
+#[derive(Debug, Default)]
+struct UnixState {
+    listener_socket: Option<Socket>,
+    listener: Option<UnixListener>,
+    stream: Option<UnixStream>,
+}
+
+#[cfg(unix)]
+#[derive(Debug)]
+struct UnixDatagramState {
+    socket: UnixDatagram,
+}
+
+/// Options set through the Rust-target `sys.net.Socket` extension; `None` keeps the OS default.
+#[derive(Debug, Default, Clone, Copy)]
+struct SocketOptions {
+    reuse_address: Option<bool>,
+    reuse_port: Option<bool>,
+    keep_alive: Option<KeepAlive>,
+    linger: Option<Option<Duration>>,
+    send_buffer_size: Option<usize>,
+    recv_buffer_size: Option<usize>,
+    ttl: Option<u32>,
+    multicast_ttl: Option<u32>,
+    multicast_loop: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct KeepAlive {
+    enabled: bool,
+    idle: Option<Duration>,
+    interval: Option<Duration>,
+}
+
+const NET_OPTION_ERROR: &str = "HXRT-NET-OPTION";
+
+#[cfg(not(unix))]
+const NET_UNIX_UNSUPPORTED_ERROR: &str = "HXRT-NET-UNIX-UNSUPPORTED";
+#[cfg(unix)]
+const UNIX_NO_IP_ERROR: &str = "Unix sockets have no IP address";
+
 fn apply_tcp_opts(s: &TcpStream, blocking: bool, timeout: Option<Duration>, fast_send: bool) {
     let _ = s.set_nonblocking(!blocking);
     let _ = s.set_nodelay(fast_send);
@@ -152,6 +219,146 @@ fn apply_udp_opts(s: &UdpSocket, blocking: bool, timeout: Option<Duration>, broa
     let _ = s.set_broadcast(broadcast);
 }
 
+fn throw_option_err(detail: String) -> ! {
+    throw_msg(&format!("{NET_OPTION_ERROR}: {detail}"))
+}
+
+/// Positive, finite seconds as a `Duration`; anything else means "OS default" (`None`).
+fn option_seconds(seconds: f64) -> Option<Duration> {
+    if seconds.is_finite() && seconds > 0.0 {
+        Some(Duration::from_secs_f64(seconds))
+    } else {
+        None
+    }
+}
+
+fn option_buffer_size(what: &str, bytes: i32) -> usize {
+    if bytes <= 0 {
+        throw_option_err(format!("{what} buffer size must be positive (got {bytes})"));
+    }
+    bytes as usize
+}
+
+fn apply_keep_alive(sock: &SockRef<'_>, keep_alive: KeepAlive) -> std::io::Result<()> {
+    if !keep_alive.enabled {
+        return sock.set_keepalive(false);
+    }
+    let mut params = TcpKeepalive::new();
+    if let Some(idle) = keep_alive.idle {
+        params = params.with_time(idle);
+    }
+    // The probe interval is not configurable everywhere; other platforms keep the OS interval.
+    #[cfg(any(
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "windows"
+    ))]
+    if let Some(interval) = keep_alive.interval {
+        params = params.with_interval(interval);
+    }
+    sock.set_tcp_keepalive(&params)
+}
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+))]
+fn set_reuse_port_on(sock: &SockRef<'_>, enabled: bool) -> std::io::Result<()> {
+    sock.set_reuse_port(enabled)
+}
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+))]
+fn reuse_port_of(sock: &SockRef<'_>) -> std::io::Result<bool> {
+    sock.reuse_port()
+}
+
+#[cfg(not(all(
+    unix,
+    not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+)))]
+fn set_reuse_port_on(_sock: &SockRef<'_>, enabled: bool) -> std::io::Result<()> {
+    if enabled {
+        throw_option_err(String::from(
+            "SO_REUSEPORT is not available on this platform",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(not(all(
+    unix,
+    not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+)))]
+fn reuse_port_of(_sock: &SockRef<'_>) -> std::io::Result<bool> {
+    Ok(false)
+}
+
+/// IPv6 sockets take the hop limit; dual-stack sockets also get the IPv4 TTL for mapped peers.
+fn apply_ttl(sock: &SockRef<'_>, ttl: u32) -> std::io::Result<()> {
+    if sock.local_addr()?.is_ipv6() {
+        sock.set_unicast_hops_v6(ttl)?;
+        let _ = sock.set_ttl(ttl);
+        Ok(())
+    } else {
+        sock.set_ttl(ttl)
+    }
+}
+
+/// IPv6 sockets take the multicast hop limit, IPv4 sockets the multicast TTL.
+fn apply_multicast_ttl(sock: &SockRef<'_>, ttl: u32) -> std::io::Result<()> {
+    if sock.local_addr()?.is_ipv6() {
+        sock.set_multicast_hops_v6(ttl)
+    } else {
+        sock.set_multicast_ttl_v4(ttl)
+    }
+}
+
+fn apply_multicast_loop(sock: &SockRef<'_>, enabled: bool) -> std::io::Result<()> {
+    if sock.local_addr()?.is_ipv6() {
+        sock.set_multicast_loop_v6(enabled)
+    } else {
+        sock.set_multicast_loop_v4(enabled)
+    }
+}
+
+/// Best-effort replay of recorded options onto a freshly created OS socket.
+fn apply_socket_options(sock: &SockRef<'_>, options: &SocketOptions) {
+    if let Some(reuse) = options.reuse_address {
+        let _ = sock.set_reuse_address(reuse);
+    }
+    if let Some(reuse) = options.reuse_port {
+        let _ = set_reuse_port_on(sock, reuse);
+    }
+    if let Some(keep_alive) = options.keep_alive {
+        let _ = apply_keep_alive(sock, keep_alive);
+    }
+    if let Some(linger) = options.linger {
+        let _ = sock.set_linger(linger);
+    }
+    if let Some(size) = options.send_buffer_size {
+        let _ = sock.set_send_buffer_size(size);
+    }
+    if let Some(size) = options.recv_buffer_size {
+        let _ = sock.set_recv_buffer_size(size);
+    }
+    if let Some(ttl) = options.ttl {
+        let _ = apply_ttl(sock, ttl);
+    }
+    if let Some(ttl) = options.multicast_ttl {
+        let _ = apply_multicast_ttl(sock, ttl);
+    }
+    if let Some(enabled) = options.multicast_loop {
+        let _ = apply_multicast_loop(sock, enabled);
+    }
+}
+
 fn throw_io_err(err: std::io::Error) -> ! {
     use std::io::ErrorKind;
     match err.kind() {
@@ -170,6 +377,36 @@ fn throw_msg(msg: &str) -> ! {
     exception::throw(dynamic::from(String::from(msg)))
 }
 
+#[cfg(not(unix))]
+fn throw_unix_unsupported() -> ! {
+    throw_msg(&format!(
+        "{}: Unix domain sockets are not available on this platform",
+        NET_UNIX_UNSUPPORTED_ERROR
+    ))
+}
+
+#[cfg(unix)]
+fn apply_unix_opts(s: &UnixStream, blocking: bool, timeout: Option<Duration>) {
+    let _ = s.set_nonblocking(!blocking);
+    let _ = s.set_read_timeout(timeout);
+    let _ = s.set_write_timeout(timeout);
+}
+
+#[cfg(unix)]
+fn apply_unix_datagram_opts(s: &UnixDatagram, blocking: bool, timeout: Option<Duration>) {
+    let _ = s.set_nonblocking(!blocking);
+    let _ = s.set_read_timeout(timeout);
+    let _ = s.set_write_timeout(timeout);
+}
+
+/// Text form of a Unix socket address: its path, or `""` for unnamed/abstract sockets.
+#[cfg(unix)]
+fn unix_addr_path(addr: std::os::unix::net::SocketAddr) -> String {
+    addr.as_pathname()
+        .map(|path| path.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
 #[derive(Debug)]
 struct NoServerCertVerifier;
 
@@ -278,38 +515,94 @@ fn int_to_ipv4(ip: i32) -> Ipv4Addr {
     Ipv4Addr::new(a, b, c, d)
 }
 
-pub fn host_resolve(name: &str) -> i32 {
-    if let Ok(ip) = name.parse::<Ipv4Addr>() {
-        return ipv4_to_int(ip);
+/// Parse an IP literal: dotted IPv4, or IPv6 with or without `[...]` brackets.
+fn parse_ip_literal(text: &str) -> Option<IpAddr> {
+    let unbracketed = text
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .unwrap_or(text);
+    unbracketed.parse::<IpAddr>().ok()
+}
+
+/// Parse the address text produced by `host_to_string` (or any IP literal).
+pub fn ip_parse(text: &str) -> IpAddr {
+    match parse_ip_literal(text) {
+        Some(ip) => ip,
+        None => throw_msg(&format!("Invalid IP address: {text}")),
+    }
+}
+
+/// The `Host.ip:Int` value of `ip`: IPv4 in network order, 0 for IPv6.
+pub fn ip_to_ipv4_int(ip: IpAddr) -> i32 {
+    match ip.to_canonical() {
+        IpAddr::V4(v4) => ipv4_to_int(v4),
+        IpAddr::V6(_) => 0,
+    }
+}
+
+/// The IPv4 address stored in a Haxe `ip:Int` (network order).
+pub fn ip_from_ipv4_int(ip: i32) -> IpAddr {
+    IpAddr::V4(int_to_ipv4(ip))
+}
+
+pub fn host_resolve(name: &str) -> IpAddr {
+    if let Some(ip) = parse_ip_literal(name) {
+        return ip;
     }
 
-    // Best-effort DNS: prefer IPv4 like Haxe's `Host.ip:Int` model.
-    let addrs = match (name, 0).to_socket_addrs() {
-        Ok(a) => a,
+    // Prefer IPv4 so `Host.ip:Int` stays usable for dual-stack names; IPv6-only names still
+    // resolve.
+    let addrs: Vec<SocketAddr> = match (name, 0).to_socket_addrs() {
+        Ok(a) => a.collect(),
         Err(e) => throw_io_err(e),
     };
-    for addr in addrs {
-        match addr.ip() {
-            IpAddr::V4(v4) => return ipv4_to_int(v4),
-            IpAddr::V6(_) => {}
-        }
+    match addrs
+        .iter()
+        .find(|addr| addr.is_ipv4())
+        .or_else(|| addrs.first())
+    {
+        Some(addr) => addr.ip(),
+        None => throw_msg("Host resolution failed (no address found)"),
     }
-
-    throw_msg("Host resolution failed (no IPv4 address found)")
 }
 
-pub fn host_to_string(ip: i32) -> String {
-    int_to_ipv4(ip).to_string()
+pub fn host_to_string(ip: IpAddr) -> String {
+    ip.to_string()
 }
 
-pub fn host_reverse(ip: i32) -> String {
-    let addr = IpAddr::V4(int_to_ipv4(ip));
-    match dns_lookup::lookup_addr(&addr) {
+pub fn host_reverse(ip: IpAddr) -> String {
+    match dns_lookup::lookup_addr(&ip) {
         Ok(name) => name,
         Err(_) => host_to_string(ip),
     }
 }
 
+/// A local/peer address as reported to Haxe: IPv4-mapped IPv6 (a v4 peer on a dual-stack socket)
+/// becomes plain IPv4.
+fn reported(addr: SocketAddr) -> (IpAddr, i32) {
+    (addr.ip().to_canonical(), addr.port() as i32)
+}
+
+/// Bound socket for `addr`; IPv6 sockets also accept IPv4 (dual-stack) where the OS allows it.
+fn bound_socket(addr: SocketAddr, ty: Type, protocol: Protocol, options: &SocketOptions) -> Socket {
+    let socket = match Socket::new(Domain::for_address(addr), ty, Some(protocol)) {
+        Ok(s) => s,
+        Err(e) => throw_io_err(e),
+    };
+    if addr.is_ipv6() {
+        let _ = socket.set_only_v6(false);
+    }
+    if ty == Type::STREAM {
+        let _ = socket.set_reuse_address(true);
+    }
+    // Before `bind`, so `SO_REUSEADDR` / `SO_REUSEPORT` choices take effect.
+    apply_socket_options(&SockRef::from(&socket), options);
+    if let Err(e) = socket.bind(&addr.into()) {
+        throw_io_err(e)
+    }
+    socket
+}
+
 pub fn host_local() -> String {
     match hostname::get() {
         Ok(name) => name.to_string_lossy().to_string(),
@@ -322,6 +615,7 @@ pub fn socket_new_tcp() -> HxRef<SocketHandle> {
         kind: SocketKind::Tcp(TcpState::default()),
         timeout: None,
         blocking: true,
+        options: SocketOptions::default(),
     })
 }
 
@@ -330,6 +624,7 @@ pub fn socket_new_udp() -> HxRef<SocketHandle> {
         kind: SocketKind::Udp(UdpState::default()),
         timeout: None,
         blocking: true,
+        options: SocketOptions::default(),
     })
 }
 
@@ -337,14 +632,14 @@ impl SocketHandle {
     fn tcp_mut(&mut self) -> &mut TcpState {
         match &mut self.kind {
             SocketKind::Tcp(s) => s,
-            SocketKind::Udp(_) => throw_msg("Socket is not TCP"),
+            _ => throw_msg("Socket is not TCP"),
         }
     }
 
     fn udp_mut(&mut self) -> &mut UdpState {
         match &mut self.kind {
             SocketKind::Udp(s) => s,
-            SocketKind::Tcp(_) => throw_msg("Socket is not UDP"),
+            _ => throw_msg("Socket is not UDP"),
         }
     }
 
@@ -352,6 +647,7 @@ impl SocketHandle {
         self.kind = SocketKind::Tcp(TcpState::default());
         self.timeout = None;
         self.blocking = true;
+        self.options = SocketOptions::default();
     }
 
     pub fn set_timeout(&mut self, seconds: f64) {
@@ -381,6 +677,18 @@ impl SocketHandle {
                     let _ = s.set_write_timeout(self.timeout);
                 }
             }
+            #[cfg(unix)]
+            SocketKind::Unix(u) => {
+                if let Some(s) = u.stream.as_ref() {
+                    let _ = s.set_read_timeout(self.timeout);
+                    let _ = s.set_write_timeout(self.timeout);
+                }
+            }
+            #[cfg(unix)]
+            SocketKind::UnixDatagram(u) => {
+                let _ = u.socket.set_read_timeout(self.timeout);
+                let _ = u.socket.set_write_timeout(self.timeout);
+            }
         }
     }
 
@@ -406,10 +714,28 @@ impl SocketHandle {
                     let _ = s.set_nonblocking(!blocking);
                 }
             }
+            #[cfg(unix)]
+            SocketKind::Unix(u) => {
+                if let Some(l) = u.listener.as_ref() {
+                    let _ = l.set_nonblocking(!blocking);
+                }
+                if let Some(s) = u.stream.as_ref() {
+                    let _ = s.set_nonblocking(!blocking);
+                }
+            }
+            #[cfg(unix)]
+            SocketKind::UnixDatagram(u) => {
+                let _ = u.socket.set_nonblocking(!blocking);
+            }
         }
     }
 
     pub fn set_fast_send(&mut self, fast: bool) {
+        #[cfg(unix)]
+        if matches!(self.kind, SocketKind::Unix(_) | SocketKind::UnixDatagram(_)) {
+            // Unix sockets have no Nagle buffering to disable.
+            return;
+        }
         let t = self.tcp_mut();
         t.fast_send = fast;
         if let Some(s) = t.stream.as_ref() {
@@ -417,19 +743,174 @@ impl SocketHandle {
         }
     }
 
-    pub fn connect(&mut self, ip: i32, port: i32) {
+    /// The OS socket options act on, if the handle has one yet.
+    fn live_socket(&self) -> Option<SockRef<'_>> {
+        match &self.kind {
+            SocketKind::Tcp(t) => t
+                .stream
+                .as_ref()
+                .map(SockRef::from)
+                .or_else(|| t.listener.as_ref().map(SockRef::from))
+                .or_else(|| t.listener_socket.as_ref().map(SockRef::from)),
+            SocketKind::Udp(u) => u.socket.as_ref().map(SockRef::from),
+            #[cfg(unix)]
+            SocketKind::Unix(u) => u
+                .stream
+                .as_ref()
+                .map(SockRef::from)
+                .or_else(|| u.listener.as_ref().map(SockRef::from))
+                .or_else(|| u.listener_socket.as_ref().map(SockRef::from)),
+            #[cfg(unix)]
+            SocketKind::UnixDatagram(u) => Some(SockRef::from(&u.socket)),
+        }
+    }
+
+    fn set_live_option(&self, apply: impl FnOnce(&SockRef<'_>) -> std::io::Result<()>) {
+        if let Some(sock) = self.live_socket() {
+            if let Err(e) = apply(&sock) {
+                throw_io_err(e)
+            }
+        }
+    }
+
+    fn read_live_option<T>(
+        &self,
+        read: impl FnOnce(&SockRef<'_>) -> std::io::Result<T>,
+        pending: T,
+    ) -> T {
+        match self.live_socket() {
+            Some(sock) => read(&sock).unwrap_or_else(|e| throw_io_err(e)),
+            None => pending,
+        }
+    }
+
+    /// `SO_REUSEADDR`. Listening TCP sockets enable it by default so servers restart quickly.
+    pub fn set_reuse_address(&mut self, enabled: bool) {
+        self.options.reuse_address = Some(enabled);
+        self.set_live_option(|s| s.set_reuse_address(enabled));
+    }
+
+    pub fn reuse_address(&self) -> bool {
+        let pending = self.options.reuse_address.unwrap_or(false);
+        self.read_live_option(|s| s.reuse_address(), pending)
+    }
+
+    /// `SO_REUSEPORT` (Unix only): several sockets may bind the same port to share load. Enabling
+    /// it elsewhere throws `HXRT-NET-OPTION` once the handle has a socket.
+    pub fn set_reuse_port(&mut self, enabled: bool) {
+        self.options.reuse_port = Some(enabled);
+        self.set_live_option(|s| set_reuse_port_on(s, enabled));
+    }
+
+    pub fn reuse_port(&self) -> bool {
+        let pending = self.options.reuse_port.unwrap_or(false);
+        self.read_live_option(reuse_port_of, pending)
+    }
+
+    /// TCP keepalive. `idle_seconds` / `interval_seconds` <= 0 keep the OS defaults; the interval
+    /// is ignored on platforms that cannot configure it.
+    pub fn set_keep_alive(&mut self, enabled: bool, idle_seconds: f64, interval_seconds: f64) {
+        let keep_alive = KeepAlive {
+            enabled,
+            idle: option_seconds(idle_seconds),
+            interval: option_seconds(interval_seconds),
+        };
+        self.options.keep_alive = Some(keep_alive);
+        self.set_live_option(|s| apply_keep_alive(s, keep_alive));
+    }
+
+    pub fn keep_alive(&self) -> bool {
+        let pending = self.options.keep_alive.is_some_and(|k| k.enabled);
+        self.read_live_option(|s| s.keepalive(), pending)
+    }
+
+    /// `SO_LINGER`: `seconds < 0` disables lingering; `0` resets the connection on close.
+    pub fn set_linger(&mut self, seconds: f64) {
+        if seconds.is_nan() {
+            throw_option_err(String::from("linger must be a number"));
+        }
+        let linger = if seconds < 0.0 {
+            None
+        } else {
+            Some(Duration::from_secs_f64(seconds.min(u32::MAX as f64)))
+        };
+        self.options.linger = Some(linger);
+        self.set_live_option(|s| s.set_linger(linger));
+    }
+
+    /// Linger time in seconds, or `-1` when lingering is off.
+    pub fn linger(&self) -> f64 {
+        let pending = self.options.linger.flatten();
+        self.read_live_option(|s| s.linger(), pending)
+            .map_or(-1.0, |d| d.as_secs_f64())
+    }
+
+    pub fn set_send_buffer_size(&mut self, bytes: i32) {
+        let size = option_buffer_size("send", bytes);
+        self.options.send_buffer_size = Some(size);
+        self.set_live_option(|s| s.set_send_buffer_size(size));
+    }
+
+    /// The size the OS reports, which may differ from the request (Linux doubles it).
+    pub fn send_buffer_size(&self) -> i32 {
+        let pending = self.options.send_buffer_size.unwrap_or(0);
+        let size = self.read_live_option(|s| s.send_buffer_size(), pending);
+        i32::try_from(size).unwrap_or(i32::MAX)
+    }
+
+    pub fn set_recv_buffer_size(&mut self, bytes: i32) {
+        let size = option_buffer_size("receive", bytes);
+        self.options.recv_buffer_size = Some(size);
+        self.set_live_option(|s| s.set_recv_buffer_size(size));
+    }
+
+    pub fn recv_buffer_size(&self) -> i32 {
+        let pending = self.options.recv_buffer_size.unwrap_or(0);
+        let size = self.read_live_option(|s| s.recv_buffer_size(), pending);
+        i32::try_from(size).unwrap_or(i32::MAX)
+    }
+
+    /// IP time-to-live (IPv6 hop limit) for outgoing unicast packets, `1..=255`.
+    pub fn set_ttl(&mut self, ttl: i32) {
+        if !(1..=255).contains(&ttl) {
+            throw_option_err(format!("TTL must be between 1 and 255 (got {ttl})"));
+        }
+        let ttl = ttl as u32;
+        self.options.ttl = Some(ttl);
+        self.set_live_option(|s| apply_ttl(s, ttl));
+    }
+
+    pub fn ttl(&self) -> i32 {
+        let pending = self.options.ttl.unwrap_or(0);
+        let ttl = self.read_live_option(
+            |s| {
+                if s.local_addr()?.is_ipv6() {
+                    s.unicast_hops_v6()
+                } else {
+                    s.ttl()
+                }
+            },
+            pending,
+        );
+        ttl as i32
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", name = "net.connect", skip_all, fields(host = %ip, port)))]
+    pub fn connect(&mut self, ip: IpAddr, port: i32) {
         let blocking = self.blocking;
         let timeout = self.timeout;
+        let options = self.options;
         match &mut self.kind {
             SocketKind::Tcp(t) => {
                 let fast_send = t.fast_send;
-                let addr = SocketAddr::V4(SocketAddrV4::new(int_to_ipv4(ip), port as u16));
+                let addr = SocketAddr::new(ip, port as u16);
                 let stream = match TcpStream::connect(addr) {
                     Ok(s) => s,
                     Err(e) => throw_io_err(e),
                 };
 
                 apply_tcp_opts(&stream, blocking, timeout, fast_send);
+                apply_socket_options(&SockRef::from(&stream), &options);
                 let read = match stream.try_clone() {
                     Ok(s) => s,
                     Err(e) => throw_io_err(e),
@@ -447,26 +928,43 @@ impl SocketHandle {
             }
             SocketKind::Udp(u) => {
                 if u.socket.is_none() {
-                    let sock = match UdpSocket::bind("0.0.0.0:0") {
+                    let any: IpAddr = if ip.is_ipv6() {
+                        Ipv6Addr::UNSPECIFIED.into()
+                    } else {
+                        Ipv4Addr::UNSPECIFIED.into()
+                    };
+                    let sock = match UdpSocket::bind(SocketAddr::new(any, 0)) {
                         Ok(s) => s,
                         Err(e) => throw_io_err(e),
                     };
                     apply_udp_opts(&sock, blocking, timeout, u.broadcast);
+                    apply_socket_options(&SockRef::from(&sock), &options);
                     u.socket = Some(sock);
                 }
-                let addr = SocketAddr::V4(SocketAddrV4::new(int_to_ipv4(ip), port as u16));
+                let addr = SocketAddr::new(ip, port as u16);
                 if let Some(sock) = u.socket.as_ref() {
                     if let Err(e) = sock.connect(addr) {
                         throw_io_err(e)
                     }
                 }
             }
+            #[cfg(unix)]
+            SocketKind::Unix(_) | SocketKind::UnixDatagram(_) => throw_msg(UNIX_NO_IP_ERROR),
         }
     }
 
     /// Upgrade an already-connected TCP socket into a TLS client connection.
     ///
     /// This is used by `sys.ssl.Socket.handshake()` for HTTPS support.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            name = "net.tls_handshake",
+            skip_all,
+            fields(role = "client")
+        )
+    )]
     pub fn tls_handshake_client(
         &mut self,
         hostname: impl Into<crate::string::HxString>,
@@ -547,10 +1045,19 @@ impl SocketHandle {
                     crate::ssl::cert_new()
                 }
             }
-            SocketKind::Udp(_) => crate::ssl::cert_new(),
+            _ => crate::ssl::cert_new(),
         }
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            name = "net.tls_handshake",
+            skip_all,
+            fields(role = "server")
+        )
+    )]
     pub fn tls_handshake_server(
         &mut self,
         cert: HxRef<crate::ssl::Certificate>,
@@ -593,6 +1100,7 @@ impl SocketHandle {
         }));
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", name = "net.tls_handshake", skip_all, fields(role = "server", sni_entries = sni_entries.len())))]
     pub fn tls_handshake_server_sni(
         &mut self,
         default_cert: HxRef<crate::ssl::Certificate>,
@@ -651,38 +1159,240 @@ impl SocketHandle {
         }));
     }
 
-    pub fn bind(&mut self, ip: i32, port: i32) {
+    pub fn bind(&mut self, ip: IpAddr, port: i32) {
         let blocking = self.blocking;
         let timeout = self.timeout;
+        let options = self.options;
+        let addr = SocketAddr::new(ip, port as u16);
         match &mut self.kind {
             SocketKind::Tcp(t) => {
-                let addr = SocketAddr::V4(SocketAddrV4::new(int_to_ipv4(ip), port as u16));
-                let domain = Domain::IPV4;
-                let socket = match Socket::new(domain, Type::STREAM, Some(Protocol::TCP)) {
+                t.listener_socket = Some(bound_socket(addr, Type::STREAM, Protocol::TCP, &options));
+            }
+            SocketKind::Udp(u) => {
+                let sock: UdpSocket =
+                    bound_socket(addr, Type::DGRAM, Protocol::UDP, &options).into();
+                apply_udp_opts(&sock, blocking, timeout, u.broadcast);
+                u.socket = Some(sock);
+            }
+            #[cfg(unix)]
+            SocketKind::Unix(_) | SocketKind::UnixDatagram(_) => throw_msg(UNIX_NO_IP_ERROR),
+        }
+    }
+
+    /// Connect to the Unix domain socket at `path` (`sys.net.Socket.connectUnix`).
+    ///
+    /// A TCP handle becomes a Unix stream socket and a UDP handle a Unix datagram socket; the
+    /// handle must not have been connected or bound before.
+    #[cfg(unix)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", name = "net.connect", skip_all, fields(path = %path)))]
+    pub fn connect_unix(&mut self, path: &str) {
+        let blocking = self.blocking;
+        let timeout = self.timeout;
+        let options = self.options;
+        self.ensure_fresh();
+        self.kind = match &self.kind {
+            SocketKind::Tcp(_) => {
+                let stream = match UnixStream::connect(path) {
+                    Ok(s) => s,
+                    Err(e) => throw_io_err(e),
+                };
+                apply_unix_opts(&stream, blocking, timeout);
+                apply_socket_options(&SockRef::from(&stream), &options);
+                SocketKind::Unix(UnixState {
+                    stream: Some(stream),
+                    ..UnixState::default()
+                })
+            }
+            _ => {
+                let socket = match UnixDatagram::unbound() {
                     Ok(s) => s,
                     Err(e) => throw_io_err(e),
                 };
+                if let Err(e) = socket.connect(path) {
+                    throw_io_err(e)
+                }
+                apply_unix_datagram_opts(&socket, blocking, timeout);
+                apply_socket_options(&SockRef::from(&socket), &options);
+                SocketKind::UnixDatagram(UnixDatagramState { socket })
+            }
+        };
+    }
 
-                let _ = socket.set_reuse_address(true);
-                if let Err(e) = socket.bind(&addr.into()) {
+    /// Bind to a new Unix domain socket file at `path` (`sys.net.Socket.bindUnix`).
+    ///
+    /// The file is created by the OS and is not removed on `close`; callers that reuse a path
+    /// delete the stale file first, as with any Unix socket server.
+    #[cfg(unix)]
+    pub fn bind_unix(&mut self, path: &str) {
+        let blocking = self.blocking;
+        let timeout = self.timeout;
+        let options = self.options;
+        self.ensure_fresh();
+        self.kind = match &self.kind {
+            SocketKind::Tcp(_) => {
+                let addr = match socket2::SockAddr::unix(path) {
+                    Ok(a) => a,
+                    Err(e) => throw_io_err(e),
+                };
+                let sock = match Socket::new(Domain::UNIX, Type::STREAM, None) {
+                    Ok(s) => s,
+                    Err(e) => throw_io_err(e),
+                };
+                apply_socket_options(&SockRef::from(&sock), &options);
+                if let Err(e) = sock.bind(&addr) {
                     throw_io_err(e)
                 }
-                t.listener_socket = Some(socket);
+                SocketKind::Unix(UnixState {
+                    listener_socket: Some(sock),
+                    ..UnixState::default()
+                })
             }
-            SocketKind::Udp(u) => {
-                let addr = SocketAddr::V4(SocketAddrV4::new(int_to_ipv4(ip), port as u16));
-                let sock = match UdpSocket::bind(addr) {
+            _ => {
+                let socket = match UnixDatagram::bind(path) {
                     Ok(s) => s,
                     Err(e) => throw_io_err(e),
                 };
-                apply_udp_opts(&sock, blocking, timeout, u.broadcast);
-                u.socket = Some(sock);
+                apply_unix_datagram_opts(&socket, blocking, timeout);
+                apply_socket_options(&SockRef::from(&socket), &options);
+                SocketKind::UnixDatagram(UnixDatagramState { socket })
+            }
+        };
+    }
+
+    #[cfg(not(unix))]
+    pub fn connect_unix(&mut self, _path: &str) {
+        throw_unix_unsupported()
+    }
+
+    #[cfg(not(unix))]
+    pub fn bind_unix(&mut self, _path: &str) {
+        throw_unix_unsupported()
+    }
+
+    #[cfg(not(unix))]
+    pub fn unix_local_path(&self) -> String {
+        throw_unix_unsupported()
+    }
+
+    #[cfg(not(unix))]
+    pub fn unix_peer_path(&self) -> String {
+        throw_unix_unsupported()
+    }
+
+    #[cfg(not(unix))]
+    pub fn unix_send_to(&mut self, _buf: &[u8], _path: &str) -> i32 {
+        throw_unix_unsupported()
+    }
+
+    /// Throw unless the handle is a fresh TCP/UDP socket that `connect_unix`/`bind_unix` may
+    /// turn into a Unix socket.
+    #[cfg(unix)]
+    fn ensure_fresh(&self) {
+        let fresh = match &self.kind {
+            SocketKind::Tcp(t) => {
+                t.stream.is_none()
+                    && t.listener.is_none()
+                    && t.listener_socket.is_none()
+                    && t.tls.is_none()
+            }
+            SocketKind::Udp(u) => u.socket.is_none(),
+            SocketKind::Unix(_) | SocketKind::UnixDatagram(_) => false,
+        };
+        if !fresh {
+            throw_msg("Socket is already open");
+        }
+    }
+
+    /// Path of the local end of a Unix socket, or `""` when it is unnamed.
+    #[cfg(unix)]
+    pub fn unix_local_path(&self) -> String {
+        let addr = match &self.kind {
+            SocketKind::Unix(u) => {
+                if let Some(s) = u.stream.as_ref() {
+                    s.local_addr()
+                } else if let Some(l) = u.listener.as_ref() {
+                    l.local_addr()
+                } else if let Some(sock) = u.listener_socket.as_ref() {
+                    return sock
+                        .local_addr()
+                        .ok()
+                        .and_then(|a| a.as_pathname().map(|p| p.to_string_lossy().into_owned()))
+                        .unwrap_or_default();
+                } else {
+                    throw_msg("Socket is not open")
+                }
             }
+            SocketKind::UnixDatagram(u) => u.socket.local_addr(),
+            _ => throw_msg("Socket is not a Unix socket"),
+        };
+        match addr {
+            Ok(a) => unix_addr_path(a),
+            Err(e) => throw_io_err(e),
+        }
+    }
+
+    /// Path of the remote end of a connected Unix socket, or `""` when it is unnamed.
+    #[cfg(unix)]
+    pub fn unix_peer_path(&self) -> String {
+        let addr = match &self.kind {
+            SocketKind::Unix(u) => match u.stream.as_ref() {
+                Some(s) => s.peer_addr(),
+                None => throw_msg("Socket is not connected"),
+            },
+            SocketKind::UnixDatagram(u) => u.socket.peer_addr(),
+            _ => throw_msg("Socket is not a Unix socket"),
+        };
+        match addr {
+            Ok(a) => unix_addr_path(a),
+            Err(e) => throw_io_err(e),
+        }
+    }
+
+    /// Send one datagram to the Unix datagram socket bound at `path`.
+    ///
+    /// A fresh UDP handle is switched to an unbound Unix datagram socket first, so a client that
+    /// never expects replies can send without binding a path of its own.
+    #[cfg(unix)]
+    pub fn unix_send_to(&mut self, buf: &[u8], path: &str) -> i32 {
+        if let SocketKind::Udp(u) = &self.kind {
+            if u.socket.is_some() {
+                throw_msg("Socket is not a Unix socket");
+            }
+            let socket = match UnixDatagram::unbound() {
+                Ok(s) => s,
+                Err(e) => throw_io_err(e),
+            };
+            apply_unix_datagram_opts(&socket, self.blocking, self.timeout);
+            self.kind = SocketKind::UnixDatagram(UnixDatagramState { socket });
+        }
+        let SocketKind::UnixDatagram(u) = &self.kind else {
+            throw_msg("Socket is not a Unix datagram socket")
+        };
+        match u.socket.send_to(buf, path) {
+            Ok(n) => n as i32,
+            Err(e) => throw_io_err(e),
         }
     }
 
     pub fn listen(&mut self, backlog: i32) {
         let blocking = self.blocking;
+        #[cfg(unix)]
+        if let SocketKind::Unix(u) = &mut self.kind {
+            if u.listener.is_some() {
+                return;
+            }
+            let sock = match u.listener_socket.take() {
+                Some(s) => s,
+                None => throw_msg("Socket is not bound"),
+            };
+            if let Err(e) = sock.listen(backlog.max(0)) {
+                throw_io_err(e)
+            }
+            let listener = UnixListener::from(std::os::fd::OwnedFd::from(sock));
+            let _ = listener.set_nonblocking(!blocking);
+            u.listener = Some(listener);
+            return;
+        }
         let t = self.tcp_mut();
         if t.listener.is_some() {
             return;
@@ -700,12 +1410,37 @@ impl SocketHandle {
         t.listener = Some(listener);
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", name = "net.accept", skip_all)
+    )]
     pub fn accept(&mut self) -> HxRef<SocketHandle> {
         let blocking = self.blocking;
         let timeout = self.timeout;
-        let t = self.tcp_mut();
-        let fast_send = t.fast_send;
-        let listener = match t.listener.as_ref() {
+        let options = self.options;
+        #[cfg(unix)]
+        if let SocketKind::Unix(u) = &self.kind {
+            let listener = match u.listener.as_ref() {
+                Some(l) => l,
+                None => throw_msg("Socket is not listening"),
+            };
+            let (stream, _) = match listener.accept() {
+                Ok(v) => v,
+                Err(e) => throw_io_err(e),
+            };
+            apply_unix_opts(&stream, blocking, timeout);
+            apply_socket_options(&SockRef::from(&stream), &options);
+            return HxRef::new(SocketHandle {
+                kind: SocketKind::Unix(UnixState {
+                    stream: Some(stream),
+                    ..UnixState::default()
+                }),
+                timeout,
+                blocking,
+                options,
+            });
+        }
+        let listener = match self.tcp_mut().listener.as_ref() {
             Some(l) => l,
             None => throw_msg("Socket is not listening"),
         };
@@ -713,16 +1448,37 @@ impl SocketHandle {
             Ok(v) => v,
             Err(e) => throw_io_err(e),
         };
+        self.accepted_tcp(stream)
+    }
 
+    /// `accept` on a non-blocking TCP listener that reports "nothing pending" (or any accept
+    /// failure) as `None` instead of throwing, for runtime accept loops that poll.
+    #[cfg(feature = "http_server")]
+    pub(crate) fn try_accept_tcp(&mut self) -> Option<HxRef<SocketHandle>> {
+        let SocketKind::Tcp(t) = &self.kind else {
+            return None;
+        };
+        let (stream, _) = t.listener.as_ref()?.accept().ok()?;
+        Some(self.accepted_tcp(stream))
+    }
+
+    /// Wrap a stream accepted by this listener, inheriting its timeout, blocking mode and options.
+    fn accepted_tcp(&mut self, stream: TcpStream) -> HxRef<SocketHandle> {
+        let blocking = self.blocking;
+        let timeout = self.timeout;
+        let options = self.options;
+        let fast_send = self.tcp_mut().fast_send;
         let mut out = SocketHandle {
             kind: SocketKind::Tcp(TcpState::default()),
             timeout,
             blocking,
+            options,
         };
         if let SocketKind::Tcp(ts) = &mut out.kind {
             ts.fast_send = fast_send;
         }
         apply_tcp_opts(&stream, blocking, timeout, fast_send);
+        apply_socket_options(&SockRef::from(&stream), &options);
         let read = match stream.try_clone() {
             Ok(s) => s,
             Err(e) => throw_io_err(e),
@@ -743,11 +1499,6 @@ impl SocketHandle {
     }
 
     pub fn shutdown(&mut self, read: bool, write: bool) {
-        let t = self.tcp_mut();
-        let stream = match t.stream.as_ref() {
-            Some(s) => s,
-            None => throw_msg("Socket is not connected"),
-        };
         use std::net::Shutdown;
         let how = match (read, write) {
             (true, true) => Shutdown::Both,
@@ -755,12 +1506,51 @@ impl SocketHandle {
             (false, true) => Shutdown::Write,
             (false, false) => return,
         };
+        #[cfg(unix)]
+        if let SocketKind::Unix(u) = &self.kind {
+            let stream = match u.stream.as_ref() {
+                Some(s) => s,
+                None => throw_msg("Socket is not connected"),
+            };
+            if let Err(e) = stream.shutdown(how) {
+                throw_io_err(e)
+            }
+            return;
+        }
+        let t = self.tcp_mut();
+        let stream = match t.stream.as_ref() {
+            Some(s) => s,
+            None => throw_msg("Socket is not connected"),
+        };
         if let Err(e) = stream.shutdown(how) {
             throw_io_err(e)
         }
     }
 
-    pub fn peer(&self) -> (i32, i32) {
+    /// `true` if this connected TCP stream can carry another request: the peer has not closed it
+    /// and sent nothing unsolicited (used by `hxrt::http_pool` before reusing a connection).
+    ///
+    /// Peeks the OS socket without blocking, so for TLS streams a pending record such as
+    /// `close_notify` also counts as "not idle".
+    pub(crate) fn is_idle_connection(&self) -> bool {
+        let SocketKind::Tcp(t) = &self.kind else {
+            return false;
+        };
+        let Some(stream) = t.stream.as_ref() else {
+            return false;
+        };
+        if stream.set_nonblocking(true).is_err() {
+            return false;
+        }
+        let mut probe = [0u8; 1];
+        let idle = matches!(
+            stream.peek(&mut probe),
+            Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock
+        );
+        stream.set_nonblocking(!self.blocking).is_ok() && idle
+    }
+
+    pub fn peer(&self) -> (IpAddr, i32) {
         match &self.kind {
             SocketKind::Tcp(t) => {
                 let stream = match t.stream.as_ref() {
@@ -771,10 +1561,7 @@ impl SocketHandle {
                     Ok(a) => a,
                     Err(e) => throw_io_err(e),
                 };
-                match addr {
-                    SocketAddr::V4(v4) => (ipv4_to_int(*v4.ip()), v4.port() as i32),
-                    SocketAddr::V6(_) => (0, 0),
-                }
+                reported(addr)
             }
             SocketKind::Udp(u) => {
                 let sock = match u.socket.as_ref() {
@@ -785,15 +1572,14 @@ impl SocketHandle {
                     Ok(a) => a,
                     Err(e) => throw_io_err(e),
                 };
-                match addr {
-                    SocketAddr::V4(v4) => (ipv4_to_int(*v4.ip()), v4.port() as i32),
-                    SocketAddr::V6(_) => (0, 0),
-                }
+                reported(addr)
             }
+            #[cfg(unix)]
+            SocketKind::Unix(_) | SocketKind::UnixDatagram(_) => throw_msg(UNIX_NO_IP_ERROR),
         }
     }
 
-    pub fn host(&self) -> (i32, i32) {
+    pub fn host(&self) -> (IpAddr, i32) {
         match &self.kind {
             SocketKind::Tcp(t) => {
                 let addr = if let Some(stream) = t.stream.as_ref() {
@@ -818,10 +1604,7 @@ impl SocketHandle {
                 } else {
                     throw_msg("Socket is not bound")
                 };
-                match addr {
-                    SocketAddr::V4(v4) => (ipv4_to_int(*v4.ip()), v4.port() as i32),
-                    SocketAddr::V6(_) => (0, 0),
-                }
+                reported(addr)
             }
             SocketKind::Udp(u) => {
                 let sock = match u.socket.as_ref() {
@@ -832,15 +1615,36 @@ impl SocketHandle {
                     Ok(a) => a,
                     Err(e) => throw_io_err(e),
                 };
-                match addr {
-                    SocketAddr::V4(v4) => (ipv4_to_int(*v4.ip()), v4.port() as i32),
-                    SocketAddr::V6(_) => (0, 0),
-                }
+                reported(addr)
             }
+            #[cfg(unix)]
+            SocketKind::Unix(_) | SocketKind::UnixDatagram(_) => throw_msg(UNIX_NO_IP_ERROR),
         }
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", name = "net.read", skip_all, fields(capacity = buf.len()), ret))]
     pub fn read_stream(&mut self, buf: &mut [u8]) -> i32 {
+        #[cfg(unix)]
+        match &mut self.kind {
+            SocketKind::Unix(u) => {
+                let Some(stream) = u.stream.as_mut() else {
+                    throw_msg("Socket is not connected")
+                };
+                return match stream.read(buf) {
+                    Ok(0) => -1,
+                    Ok(n) => n as i32,
+                    Err(e) => throw_io_err(e),
+                };
+            }
+            SocketKind::UnixDatagram(u) => {
+                return match u.socket.recv(buf) {
+                    Ok(0) => -1,
+                    Ok(n) => n as i32,
+                    Err(e) => throw_io_err(e),
+                };
+            }
+            _ => {}
+        }
         let t = self.tcp_mut();
         if let Some(tls) = t.tls.as_mut() {
             let stream = match t.stream.as_mut() {
@@ -878,7 +1682,27 @@ impl SocketHandle {
         }
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", name = "net.write", skip_all, fields(len = buf.len()), ret))]
     pub fn write_stream(&mut self, buf: &[u8]) -> i32 {
+        #[cfg(unix)]
+        match &mut self.kind {
+            SocketKind::Unix(u) => {
+                let Some(stream) = u.stream.as_mut() else {
+                    throw_msg("Socket is not connected")
+                };
+                return match stream.write(buf) {
+                    Ok(n) => n as i32,
+                    Err(e) => throw_io_err(e),
+                };
+            }
+            SocketKind::UnixDatagram(u) => {
+                return match u.socket.send(buf) {
+                    Ok(n) => n as i32,
+                    Err(e) => throw_io_err(e),
+                };
+            }
+            _ => {}
+        }
         let t = self.tcp_mut();
         if let Some(tls) = t.tls.as_mut() {
             let stream = match t.stream.as_mut() {
@@ -929,68 +1753,244 @@ impl SocketHandle {
         }
     }
 
-    pub fn udp_send_to(&mut self, buf: &[u8], ip: i32, port: i32) -> i32 {
+    pub fn udp_send_to(&mut self, buf: &[u8], ip: IpAddr, port: i32) -> i32 {
         let u = self.udp_mut();
         let sock = match u.socket.as_ref() {
             Some(s) => s,
             None => throw_msg("UDP socket is not open"),
         };
-        let addr = SocketAddr::V4(SocketAddrV4::new(int_to_ipv4(ip), port as u16));
-        match sock.send_to(buf, addr) {
+        match sock.send_to(buf, SocketAddr::new(ip, port as u16)) {
             Ok(n) => n as i32,
             Err(e) => throw_io_err(e),
         }
     }
 
-    pub fn udp_read_from(&mut self, buf: &mut [u8]) -> (i32, i32, i32) {
+    /// Receive one datagram: `(length, sender ip, sender port)`, or length `-1` for an empty one.
+    pub fn udp_read_from(&mut self, buf: &mut [u8]) -> (i32, IpAddr, i32) {
         let u = self.udp_mut();
         let sock = match u.socket.as_ref() {
             Some(s) => s,
             None => throw_msg("UDP socket is not open"),
         };
         match sock.recv_from(buf) {
-            Ok((0, _)) => (-1, 0, 0),
-            Ok((n, addr)) => match addr {
-                SocketAddr::V4(v4) => (n as i32, ipv4_to_int(*v4.ip()), v4.port() as i32),
-                SocketAddr::V6(_) => (n as i32, 0, 0),
-            },
+            Ok((0, _)) => (-1, Ipv4Addr::UNSPECIFIED.into(), 0),
+            Ok((n, addr)) => {
+                u.last_sender = Some(addr);
+                let (ip, port) = reported(addr);
+                (n as i32, ip, port)
+            }
             Err(e) => throw_io_err(e),
         }
     }
+
+    /// Sender of the last datagram `udp_read_from` returned, including IPv6 senders that Haxe's
+    /// IPv4-only `Address.host` cannot hold. Throws before the first datagram.
+    pub fn udp_last_sender(&self) -> (IpAddr, i32) {
+        match &self.kind {
+            SocketKind::Udp(UdpState {
+                last_sender: Some(addr),
+                ..
+            }) => reported(*addr),
+            SocketKind::Udp(_) => throw_msg("UDP socket has not received a datagram"),
+            _ => throw_msg("Socket is not UDP"),
+        }
+    }
+
+    /// Join a multicast group on a bound UDP socket. IPv4 groups join on the local `interface`
+    /// address (unspecified = OS default); IPv6 groups on `interface_index` (0 = OS default).
+    pub fn udp_join_multicast(&mut self, group: IpAddr, interface: IpAddr, interface_index: i32) {
+        self.udp_multicast_membership(group, interface, interface_index, true);
+    }
+
+    pub fn udp_leave_multicast(&mut self, group: IpAddr, interface: IpAddr, interface_index: i32) {
+        self.udp_multicast_membership(group, interface, interface_index, false);
+    }
+
+    fn udp_multicast_membership(
+        &mut self,
+        group: IpAddr,
+        interface: IpAddr,
+        interface_index: i32,
+        join: bool,
+    ) {
+        if !group.is_multicast() {
+            throw_option_err(format!("{group} is not a multicast group address"));
+        }
+        let interface_index = u32::try_from(interface_index).unwrap_or_else(|_| {
+            throw_option_err(format!(
+                "multicast interface index must not be negative (got {interface_index})"
+            ))
+        });
+        let sock = match self.udp_mut().socket.as_ref() {
+            Some(s) => s,
+            None => throw_msg("UDP socket is not open"),
+        };
+        let result = match (group, interface) {
+            (IpAddr::V4(group), IpAddr::V4(interface)) if join => {
+                sock.join_multicast_v4(&group, &interface)
+            }
+            (IpAddr::V4(group), IpAddr::V4(interface)) => {
+                sock.leave_multicast_v4(&group, &interface)
+            }
+            (IpAddr::V4(_), IpAddr::V6(_)) => throw_option_err(format!(
+                "IPv4 multicast group {group} needs an IPv4 interface address (got {interface})"
+            )),
+            (IpAddr::V6(group), _) if join => sock.join_multicast_v6(&group, interface_index),
+            (IpAddr::V6(group), _) => sock.leave_multicast_v6(&group, interface_index),
+        };
+        if let Err(e) = result {
+            throw_io_err(e)
+        }
+    }
+
+    /// Multicast TTL (IPv6: multicast hop limit), `0..=255`. The OS default of 1 keeps datagrams
+    /// on the local subnet.
+    pub fn udp_set_multicast_ttl(&mut self, ttl: i32) {
+        self.udp_mut();
+        if !(0..=255).contains(&ttl) {
+            throw_option_err(format!(
+                "multicast TTL must be between 0 and 255 (got {ttl})"
+            ));
+        }
+        let ttl = ttl as u32;
+        self.options.multicast_ttl = Some(ttl);
+        self.set_live_option(|s| apply_multicast_ttl(s, ttl));
+    }
+
+    pub fn udp_multicast_ttl(&self) -> i32 {
+        let pending = self.options.multicast_ttl.unwrap_or(1);
+        let ttl = self.read_live_option(
+            |s| {
+                if s.local_addr()?.is_ipv6() {
+                    s.multicast_hops_v6()
+                } else {
+                    s.multicast_ttl_v4()
+                }
+            },
+            pending,
+        );
+        ttl as i32
+    }
+
+    /// Whether members on this host (including this socket) receive the datagrams it sends to a
+    /// group. On by default.
+    pub fn udp_set_multicast_loop(&mut self, enabled: bool) {
+        self.udp_mut();
+        self.options.multicast_loop = Some(enabled);
+        self.set_live_option(|s| apply_multicast_loop(s, enabled));
+    }
+
+    pub fn udp_multicast_loop(&self) -> bool {
+        let pending = self.options.multicast_loop.unwrap_or(true);
+        self.read_live_option(
+            |s| {
+                if s.local_addr()?.is_ipv6() {
+                    s.multicast_loop_v6()
+                } else {
+                    s.multicast_loop_v4()
+                }
+            },
+            pending,
+        )
+    }
 }
 
 enum AnySource {
     TcpStream(mio::net::TcpStream),
     TcpListener(mio::net::TcpListener),
     Udp(mio::net::UdpSocket),
+    #[cfg(unix)]
+    UnixStream(mio::net::UnixStream),
+    #[cfg(unix)]
+    UnixListener(mio::net::UnixListener),
+    #[cfg(unix)]
+    UnixDatagram(mio::net::UnixDatagram),
 }
 
-impl AnySource {
+impl mio::event::Source for AnySource {
     fn register(
         &mut self,
-        poll: &mut Poll,
+        registry: &mio::Registry,
         token: Token,
         interest: Interest,
     ) -> std::io::Result<()> {
         match self {
-            AnySource::TcpStream(s) => poll.registry().register(s, token, interest),
-            AnySource::TcpListener(s) => poll.registry().register(s, token, interest),
-            AnySource::Udp(s) => poll.registry().register(s, token, interest),
+            AnySource::TcpStream(s) => s.register(registry, token, interest),
+            AnySource::TcpListener(s) => s.register(registry, token, interest),
+            AnySource::Udp(s) => s.register(registry, token, interest),
+            #[cfg(unix)]
+            AnySource::UnixStream(s) => s.register(registry, token, interest),
+            #[cfg(unix)]
+            AnySource::UnixListener(s) => s.register(registry, token, interest),
+            #[cfg(unix)]
+            AnySource::UnixDatagram(s) => s.register(registry, token, interest),
         }
     }
+
+    fn reregister(
+        &mut self,
+        registry: &mio::Registry,
+        token: Token,
+        interest: Interest,
+    ) -> std::io::Result<()> {
+        match self {
+            AnySource::TcpStream(s) => s.reregister(registry, token, interest),
+            AnySource::TcpListener(s) => s.reregister(registry, token, interest),
+            AnySource::Udp(s) => s.reregister(registry, token, interest),
+            #[cfg(unix)]
+            AnySource::UnixStream(s) => s.reregister(registry, token, interest),
+            #[cfg(unix)]
+            AnySource::UnixListener(s) => s.reregister(registry, token, interest),
+            #[cfg(unix)]
+            AnySource::UnixDatagram(s) => s.reregister(registry, token, interest),
+        }
+    }
+
+    fn deregister(&mut self, registry: &mio::Registry) -> std::io::Result<()> {
+        match self {
+            AnySource::TcpStream(s) => s.deregister(registry),
+            AnySource::TcpListener(s) => s.deregister(registry),
+            AnySource::Udp(s) => s.deregister(registry),
+            #[cfg(unix)]
+            AnySource::UnixStream(s) => s.deregister(registry),
+            #[cfg(unix)]
+            AnySource::UnixListener(s) => s.deregister(registry),
+            #[cfg(unix)]
+            AnySource::UnixDatagram(s) => s.deregister(registry),
+        }
+    }
+}
+
+/// Non-blocking `mio` source for `h`'s OS handle, for runtime code that polls a socket itself
+/// (the embedded HTTP server's accept loop). The non-blocking flag is shared with `h`.
+#[cfg(feature = "http_server")]
+pub(crate) fn socket_nonblocking_source(
+    h: &HxRef<SocketHandle>,
+) -> Option<crate::thread::IoSource> {
+    socket_source_clone(h, true).map(|source| Box::new(source) as crate::thread::IoSource)
 }
 
-fn socket_source_clone(h: &HxRef<SocketHandle>) -> Option<AnySource> {
+/// Clone the socket's OS handle into a `mio` source.
+///
+/// `select` switches the clone to non-blocking mode. Event-loop watches pass `false`: they never
+/// read through the clone, and the non-blocking flag is shared with the Haxe socket's own handle,
+/// so setting it would silently turn a blocking Haxe socket non-blocking for as long as it is
+/// watched.
+fn socket_source_clone(h: &HxRef<SocketHandle>, nonblocking: bool) -> Option<AnySource> {
     let hb = h.borrow();
     match &hb.kind {
         SocketKind::Tcp(t) => {
             if let Some(s) = t.stream.as_ref() {
                 let c = s.try_clone().ok()?;
-                c.set_nonblocking(true).ok()?;
+                if nonblocking {
+                    c.set_nonblocking(true).ok()?;
+                }
                 Some(AnySource::TcpStream(mio::net::TcpStream::from_std(c)))
             } else if let Some(l) = t.listener.as_ref() {
                 let c = l.try_clone().ok()?;
-                c.set_nonblocking(true).ok()?;
+                if nonblocking {
+                    c.set_nonblocking(true).ok()?;
+                }
                 Some(AnySource::TcpListener(mio::net::TcpListener::from_std(c)))
             } else {
                 None
@@ -999,12 +1999,73 @@ fn socket_source_clone(h: &HxRef<SocketHandle>) -> Option<AnySource> {
         SocketKind::Udp(u) => {
             let s = u.socket.as_ref()?;
             let c = s.try_clone().ok()?;
-            c.set_nonblocking(true).ok()?;
+            if nonblocking {
+                c.set_nonblocking(true).ok()?;
+            }
             Some(AnySource::Udp(mio::net::UdpSocket::from_std(c)))
         }
+        #[cfg(unix)]
+        SocketKind::Unix(u) => {
+            if let Some(s) = u.stream.as_ref() {
+                let c = s.try_clone().ok()?;
+                if nonblocking {
+                    c.set_nonblocking(true).ok()?;
+                }
+                Some(AnySource::UnixStream(mio::net::UnixStream::from_std(c)))
+            } else if let Some(l) = u.listener.as_ref() {
+                let c = l.try_clone().ok()?;
+                if nonblocking {
+                    c.set_nonblocking(true).ok()?;
+                }
+                Some(AnySource::UnixListener(mio::net::UnixListener::from_std(c)))
+            } else {
+                None
+            }
+        }
+        #[cfg(unix)]
+        SocketKind::UnixDatagram(u) => {
+            let c = u.socket.try_clone().ok()?;
+            if nonblocking {
+                c.set_nonblocking(true).ok()?;
+            }
+            Some(AnySource::UnixDatagram(mio::net::UnixDatagram::from_std(c)))
+        }
     }
 }
 
+/// Register `socket` with the `EventLoop` of `thread_id` (`sys.net.Socket.watch`).
+///
+/// `callback` runs on that thread whenever the socket is readable and/or writable, and keeps
+/// running while the condition holds (the watch is re-armed after each call). Returns an
+/// `EventHandler` id for `EventLoop.cancel`.
+///
+/// Readiness is reported for the OS socket: for TLS sockets, already-decrypted data buffered inside
+/// the TLS session does not trigger the watch.
+pub fn socket_watch(
+    thread_id: i32,
+    socket: &HxRef<SocketHandle>,
+    readable: bool,
+    writable: bool,
+    callback: HxDynRef<dyn Fn() + Send + Sync>,
+) -> i32 {
+    let interest = match (readable, writable) {
+        (true, true) => Interest::READABLE | Interest::WRITABLE,
+        (true, false) => Interest::READABLE,
+        (false, true) => Interest::WRITABLE,
+        (false, false) => throw_msg(&format!(
+            "{}: a watch needs readable or writable interest",
+            crate::thread::EVENT_LOOP_IO_ERROR
+        )),
+    };
+    let Some(source) = socket_source_clone(socket, false) else {
+        throw_msg(&format!(
+            "{}: socket is not connected, listening, or bound",
+            crate::thread::EVENT_LOOP_IO_ERROR
+        ));
+    };
+    crate::thread::event_loop_watch(thread_id, Box::new(source), interest, callback)
+}
+
 pub fn socket_select(
     read: Vec<HxRef<SocketHandle>>,
     write: Vec<HxRef<SocketHandle>>,
@@ -1030,11 +2091,11 @@ pub fn socket_select(
     let mut register_group =
         |group: Group, interest: Interest, sockets: &Vec<HxRef<SocketHandle>>| {
             for (idx, s) in sockets.iter().enumerate() {
-                let Some(mut src) = socket_source_clone(s) else {
+                let Some(mut src) = socket_source_clone(s, true) else {
                     continue;
                 };
                 let token = Token(token_map.len());
-                if let Err(e) = src.register(&mut poll, token, interest) {
+                if let Err(e) = poll.registry().register(&mut src, token, interest) {
                     throw_io_err(e)
                 }
                 token_map.push((group, idx));
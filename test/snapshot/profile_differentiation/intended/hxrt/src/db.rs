@@ -30,8 +30,48 @@ pub struct QueryResult {
     current: Option<Array<Dynamic>>,
 }
 
+/// Scope of one `sys.db` request, returned by `query_span`.
+///
+/// With the `tracing` feature this keeps a `db.query` span (driver, statement, row count) entered
+/// until it is dropped; otherwise it is empty.
+pub struct QuerySpan {
+    #[cfg(feature = "tracing")]
+    _span: tracing::span::EnteredSpan,
+}
+
+/// Longest statement prefix recorded on a `db.query` span.
+#[cfg(feature = "tracing")]
+const QUERY_STATEMENT_LIMIT: usize = 512;
+
+/// Open the span for one request; drivers hold the result for the duration of the query.
+pub fn query_span(driver: &'static str, sql: &str) -> QuerySpan {
+    #[cfg(feature = "tracing")]
+    {
+        let statement = match sql.char_indices().nth(QUERY_STATEMENT_LIMIT) {
+            Some((end, _)) => &sql[..end],
+            None => sql,
+        };
+        QuerySpan {
+            _span: tracing::debug_span!(
+                "db.query",
+                driver,
+                statement,
+                rows = tracing::field::Empty
+            )
+            .entered(),
+        }
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = (driver, sql);
+        QuerySpan {}
+    }
+}
+
 #[inline]
 pub fn query_result_new(names: Array<String>, rows: Array<Array<Dynamic>>) -> HxRef<QueryResult> {
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("rows", rows.len());
     HxRef::new(QueryResult {
         names,
         rows,
@@ -0,0 +1,120 @@
+//! `hxrt::compression`
+//!
+//! Content decoding behind `sys.Http`.
+//!
+//! Why
+//! - Servers compress responses whenever the client advertises support; without native decoding
+//!   every `sys.Http` caller had to unpack `gzip`/`deflate` bodies by hand.
+//!
+//! What
+//! - `decode_content` undoes a `Content-Encoding` header value (`gzip`, `x-gzip`, `deflate`, `br`,
+//!   `identity`, or a comma-separated list of them) on a complete body.
+//! - `decode_content_limited` does the same with an explicit cap on the decoded size;
+//!   `decode_content` uses `DEFAULT_MAX_DECODED_BYTES`.
+//! - `accept_encoding` is the matching `Accept-Encoding` request value.
+//!
+//! How
+//! - Codings are undone in reverse order of application, as RFC 9110 lists them.
+//! - `deflate` is zlib-wrapped per the RFC, but some servers send raw deflate; a body without a
+//!   valid zlib header is decoded as raw deflate.
+//! - Every decoding step reads at most one byte past the cap, so a small compressed body cannot
+//!   expand into unbounded memory (a "decompression bomb").
+//! - Unknown codings, corrupt bodies and bodies past the cap throw a catchable String beginning
+//!   with `HXRT-HTTP-DECODE`.
+
+use crate::bytes::Bytes;
+use crate::cell::HxRef;
+use crate::{dynamic, exception};
+use flate2::read::{DeflateDecoder, MultiGzDecoder, ZlibDecoder};
+use std::io::Read;
+
+const HTTP_DECODE_ERROR: &str = "HXRT-HTTP-DECODE";
+
+/// Decoded-size cap used by `decode_content` (64 MiB).
+pub const DEFAULT_MAX_DECODED_BYTES: i32 = 64 * 1024 * 1024;
+
+fn throw_msg(msg: String) -> ! {
+    exception::throw(dynamic::from(msg))
+}
+
+/// `Accept-Encoding` value for every coding `decode_content` understands.
+pub fn accept_encoding() -> String {
+    String::from("gzip, deflate, br")
+}
+
+/// Whether `decode_content` can undo every coding listed in `encoding`.
+pub fn is_supported_encoding<E: AsRef<str>>(encoding: E) -> bool {
+    codings(encoding.as_ref()).all(|coding| {
+        matches!(
+            coding.as_str(),
+            "identity" | "gzip" | "x-gzip" | "deflate" | "br"
+        )
+    })
+}
+
+/// Decode `body` according to the `Content-Encoding` header value `encoding`.
+pub fn decode_content<E: AsRef<str>>(encoding: E, body: &HxRef<Bytes>) -> HxRef<Bytes> {
+    decode_content_limited(encoding, body, DEFAULT_MAX_DECODED_BYTES)
+}
+
+/// `decode_content` that throws once any decoding step produces more than `max_bytes` bytes.
+pub fn decode_content_limited<E: AsRef<str>>(
+    encoding: E,
+    body: &HxRef<Bytes>,
+    max_bytes: i32,
+) -> HxRef<Bytes> {
+    let encoding = encoding.as_ref();
+    let Ok(limit) = usize::try_from(max_bytes) else {
+        throw_msg(format!(
+            "{HTTP_DECODE_ERROR}: invalid decoded size limit {max_bytes}"
+        ))
+    };
+    let mut data = body.borrow().as_slice().to_vec();
+    let applied: Vec<String> = codings(encoding).collect();
+    for coding in applied.iter().rev() {
+        data = match coding.as_str() {
+            "identity" => data,
+            "gzip" | "x-gzip" => read_all(MultiGzDecoder::new(&data[..]), coding, limit),
+            "deflate" if has_zlib_header(&data) => {
+                read_all(ZlibDecoder::new(&data[..]), coding, limit)
+            }
+            "deflate" => read_all(DeflateDecoder::new(&data[..]), coding, limit),
+            "br" => read_all(
+                brotli_decompressor::Decompressor::new(&data[..], 4096),
+                coding,
+                limit,
+            ),
+            _ => throw_msg(format!(
+                "{HTTP_DECODE_ERROR}: unsupported content encoding '{coding}'"
+            )),
+        };
+    }
+    HxRef::new(Bytes::from_vec(data))
+}
+
+fn codings(encoding: &str) -> impl Iterator<Item = String> + '_ {
+    encoding
+        .split(',')
+        .map(|coding| coding.trim().to_ascii_lowercase())
+        .filter(|coding| !coding.is_empty())
+}
+
+// RFC 1950: CM = 8 (deflate) and the header checksum is a multiple of 31.
+fn has_zlib_header(data: &[u8]) -> bool {
+    data.len() >= 2
+        && data[0] & 0x0f == 8
+        && (u16::from(data[0]) << 8 | u16::from(data[1])) % 31 == 0
+}
+
+fn read_all(decoder: impl Read, coding: &str, limit: usize) -> Vec<u8> {
+    let mut out = Vec::new();
+    if let Err(err) = decoder.take(limit as u64 + 1).read_to_end(&mut out) {
+        throw_msg(format!("{HTTP_DECODE_ERROR}: invalid {coding} body: {err}"));
+    }
+    if out.len() > limit {
+        throw_msg(format!(
+            "{HTTP_DECODE_ERROR}: decoded {coding} body exceeds {limit} bytes"
+        ));
+    }
+    out
+}
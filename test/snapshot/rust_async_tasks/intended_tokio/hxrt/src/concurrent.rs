@@ -6,26 +6,35 @@
 //!   dropping into raw injection from application code.
 //!
 //! What
-//! - `ChannelHandle<T>`: typed MPSC channel helper (`send`/`recv`/`try_recv`).
-//! - `TaskHandle<T>`: spawn + join one-shot thread tasks.
+//! - `ChannelHandle<T>`: typed MPSC channel helper (`send`/`recv`/`try_recv`), optionally bounded,
+//!   with timeouts, sender-side `close`, and multi-channel readiness `select`.
+//! - `TaskHandle<T>`: spawn + join one-shot tasks on the shared `hxrt::thread` work-stealing pool.
 //! - `MutexHandle<T>` / `RwLockHandle<T>`: closure-scoped lock helpers.
+//! - `parallel_*`: data-parallel `map`/`forEach`/`filter`/`reduce`/`sort` over `Array<T>`, run on
+//!   the same pool with source-ordered results and Haxe exceptions rethrown in the caller.
 //!
 //! How
 //! - Runtime values are wrapped in `HxRef<...>` so they follow nullable/shared semantics expected
 //!   by generated Haxe code.
 //! - Callback-based lock helpers avoid leaking Rust guard lifetimes into Haxe code.
 
+use crate::array::{Array, MutSliceCallback, SliceCallback};
 use crate::cell::{HxDynRef, HxRc, HxRef};
+use crate::dynamic::Dynamic;
 use crate::{dynamic, exception};
-use parking_lot::{Mutex as ParkMutex, RwLock as ParkRwLock};
+use parking_lot::{Condvar, Mutex as ParkMutex, RwLock as ParkRwLock};
 use std::cell::RefCell;
-use std::sync::mpsc;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 fn throw_msg(msg: &str) -> ! {
     exception::throw(dynamic::from(String::from(msg)))
 }
 
+const CHANNEL_CLOSED_ERROR: &str = "HXRT-CHANNEL-CLOSED: channel is closed";
+
 const LOCK_REENTRANCY_ERROR: &str =
     "HXRT-LOCK-REENTRANCY: same-handle lock access from a rust.concurrent callback is not allowed";
 
@@ -105,16 +114,101 @@ impl Drop for LockCallbackScope {
 }
 
 /// Typed multi-producer, single-consumer channel handle.
+///
+/// Why
+/// - `std::sync::mpsc` cannot be closed by a sender that shares the handle with its receiver, has
+///   no receive-side wait over several channels, and splits bounded/unbounded into two types.
+///
+/// What
+/// - One queue with an optional capacity, a `closed` flag, and the select waiters to wake.
+/// - Closing rejects new sends; receivers drain buffered values before observing the close.
+///
+/// How
+/// - Operations clone the shared state out of the `HxRef` borrow before blocking, so no `HxCell`
+///   guard is held while a thread waits on a condition variable.
 #[derive(Debug)]
 pub struct ChannelHandle<T> {
-    sender: mpsc::Sender<T>,
-    receiver: ParkMutex<mpsc::Receiver<T>>,
+    shared: Arc<ChannelShared<T>>,
 }
 
-/// One-shot task join handle.
 #[derive(Debug)]
+struct ChannelShared<T> {
+    state: ParkMutex<ChannelState<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+}
+
+#[derive(Debug)]
+struct ChannelState<T> {
+    queue: VecDeque<T>,
+    capacity: Option<usize>,
+    closed: bool,
+    select_waiters: Vec<Arc<SelectSignal>>,
+}
+
+impl<T> ChannelState<T> {
+    fn is_full(&self) -> bool {
+        self.capacity
+            .is_some_and(|capacity| self.queue.len() >= capacity)
+    }
+
+    fn is_ready(&self) -> bool {
+        self.closed || !self.queue.is_empty()
+    }
+
+    fn wake_selectors(&self) {
+        for waiter in &self.select_waiters {
+            waiter.fire();
+        }
+    }
+}
+
+/// Wake-up token shared between one blocked `channel_select` call and the channels it watches.
+#[derive(Debug, Default)]
+struct SelectSignal {
+    fired: ParkMutex<bool>,
+    cv: Condvar,
+}
+
+impl SelectSignal {
+    fn fire(&self) {
+        *self.fired.lock() = true;
+        self.cv.notify_all();
+    }
+}
+
+/// One-shot task join handle.
+///
+/// Tasks run on the process-wide elastic pool (`hxrt::thread::task_pool_submit`) instead of one
+/// OS thread each. The job publishes its outcome into `slot`; `task_join` waits for it once.
 pub struct TaskHandle<T> {
-    join: ParkMutex<Option<std::thread::JoinHandle<T>>>,
+    slot: Arc<TaskSlot<T>>,
+}
+
+struct TaskSlot<T> {
+    state: ParkMutex<TaskState<T>>,
+    done: Condvar,
+}
+
+enum TaskState<T> {
+    Running,
+    Finished(T),
+    Threw(Dynamic),
+    Panicked,
+    Joined,
+}
+
+impl<T> std::fmt::Debug for TaskHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = match *self.slot.state.lock() {
+            TaskState::Running => "running",
+            TaskState::Finished(_) => "finished",
+            TaskState::Threw(_) => "threw",
+            TaskState::Panicked => "panicked",
+            TaskState::Joined => "joined",
+        };
+        f.debug_struct("TaskHandle").field("state", &state).finish()
+    }
 }
 
 /// Shared mutex-backed value.
@@ -129,47 +223,262 @@ pub struct RwLockHandle<T> {
     value: ParkRwLock<T>,
 }
 
-pub fn channel_new<T>() -> HxRef<ChannelHandle<T>> {
-    let (sender, receiver) = mpsc::channel::<T>();
+fn channel_with_capacity<T>(capacity: Option<usize>) -> HxRef<ChannelHandle<T>> {
     HxRef::new(ChannelHandle {
-        sender,
-        receiver: ParkMutex::new(receiver),
+        shared: Arc::new(ChannelShared {
+            state: ParkMutex::new(ChannelState {
+                queue: VecDeque::new(),
+                capacity,
+                closed: false,
+                select_waiters: Vec::new(),
+            }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+        }),
     })
 }
 
+fn channel_shared<T>(channel: &HxRef<ChannelHandle<T>>) -> Arc<ChannelShared<T>> {
+    channel.borrow().shared.clone()
+}
+
+/// Create an unbounded channel; `send` never blocks.
+pub fn channel_new<T>() -> HxRef<ChannelHandle<T>> {
+    channel_with_capacity(None)
+}
+
+/// Create a bounded channel; `send` blocks while `capacity` values are buffered.
+///
+/// A capacity below `1` is treated as `1`.
+pub fn channel_bounded<T>(capacity: i32) -> HxRef<ChannelHandle<T>> {
+    channel_with_capacity(Some(capacity.max(1) as usize))
+}
+
+/// Enqueue `value`, waiting for space until `deadline` (forever when `None`).
+///
+/// Returns the value back when the deadline passes first; throws when the channel is closed.
+fn channel_send_until<T>(
+    channel: &HxRef<ChannelHandle<T>>,
+    value: T,
+    deadline: Option<Instant>,
+) -> Result<(), T> {
+    let shared = channel_shared(channel);
+    let mut state = shared.state.lock();
+    loop {
+        if state.closed {
+            drop(state);
+            throw_msg(CHANNEL_CLOSED_ERROR);
+        }
+        if !state.is_full() {
+            break;
+        }
+        match deadline {
+            Some(deadline) => {
+                if shared.not_full.wait_until(&mut state, deadline).timed_out() && state.is_full() {
+                    return Err(value);
+                }
+            }
+            None => shared.not_full.wait(&mut state),
+        }
+    }
+    state.queue.push_back(value);
+    state.wake_selectors();
+    shared.not_empty.notify_one();
+    Ok(())
+}
+
+/// Dequeue one value, waiting until `deadline` (forever when `None`).
+///
+/// Returns `None` when the deadline passes first; throws once the channel is closed and drained.
+fn channel_recv_until<T>(
+    channel: &HxRef<ChannelHandle<T>>,
+    deadline: Option<Instant>,
+) -> Option<T> {
+    let shared = channel_shared(channel);
+    let mut state = shared.state.lock();
+    loop {
+        if let Some(value) = state.queue.pop_front() {
+            shared.not_full.notify_one();
+            return Some(value);
+        }
+        if state.closed {
+            drop(state);
+            throw_msg(CHANNEL_CLOSED_ERROR);
+        }
+        match deadline {
+            Some(deadline) => {
+                if shared
+                    .not_empty
+                    .wait_until(&mut state, deadline)
+                    .timed_out()
+                    && !state.is_ready()
+                {
+                    return None;
+                }
+            }
+            None => shared.not_empty.wait(&mut state),
+        }
+    }
+}
+
+/// Outcome of `channel_offer`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ChannelOffer {
+    Sent,
+    /// A bounded channel had no space; the value was dropped.
+    Full,
+    /// The channel is closed; the value was dropped.
+    Closed,
+}
+
+/// Non-blocking, non-throwing send for runtime producers (for example signal delivery) that run
+/// outside any Haxe `try` and must not unwind on a closed channel.
+pub(crate) fn channel_offer<T>(channel: &HxRef<ChannelHandle<T>>, value: T) -> ChannelOffer {
+    let shared = channel_shared(channel);
+    let mut state = shared.state.lock();
+    if state.closed {
+        return ChannelOffer::Closed;
+    }
+    if state.is_full() {
+        return ChannelOffer::Full;
+    }
+    state.queue.push_back(value);
+    state.wake_selectors();
+    shared.not_empty.notify_one();
+    ChannelOffer::Sent
+}
+
 pub fn channel_send<T>(channel: &HxRef<ChannelHandle<T>>, value: T) {
-    let sender = {
-        let channel_borrow = channel.borrow();
-        channel_borrow.sender.clone()
-    };
-    if sender.send(value).is_err() {
-        throw_msg("Channel is disconnected");
+    let _ = channel_send_until(channel, value, None);
+}
+
+/// Send without blocking; returns `false` (dropping `value`) when a bounded channel is full.
+pub fn channel_try_send<T>(channel: &HxRef<ChannelHandle<T>>, value: T) -> bool {
+    match channel_offer(channel, value) {
+        ChannelOffer::Sent => true,
+        ChannelOffer::Full => false,
+        ChannelOffer::Closed => throw_msg(CHANNEL_CLOSED_ERROR),
     }
 }
 
+/// Send, waiting at most `timeout` for space; returns `false` (dropping `value`) on timeout.
+pub fn channel_send_timeout<T>(
+    channel: &HxRef<ChannelHandle<T>>,
+    value: T,
+    timeout: Duration,
+) -> bool {
+    channel_send_until(channel, value, Some(Instant::now() + timeout)).is_ok()
+}
+
 pub fn channel_recv<T>(channel: &HxRef<ChannelHandle<T>>) -> T {
-    let recv_result = {
-        let channel_borrow = channel.borrow();
-        let receiver = channel_borrow.receiver.lock();
-        receiver.recv()
-    };
-    match recv_result {
-        Ok(value) => value,
-        Err(_) => throw_msg("Channel is disconnected"),
+    match channel_recv_until(channel, None) {
+        Some(value) => value,
+        None => throw_msg(CHANNEL_CLOSED_ERROR),
     }
 }
 
 pub fn channel_try_recv<T>(channel: &HxRef<ChannelHandle<T>>) -> Option<T> {
-    let recv_result = {
-        let channel_borrow = channel.borrow();
-        let receiver = channel_borrow.receiver.lock();
-        receiver.try_recv()
+    channel_recv_until(channel, Some(Instant::now()))
+}
+
+/// Receive, waiting at most `timeout`; returns `None` on timeout.
+pub fn channel_recv_timeout<T>(channel: &HxRef<ChannelHandle<T>>, timeout: Duration) -> Option<T> {
+    channel_recv_until(channel, Some(Instant::now() + timeout))
+}
+
+/// Close the channel: later sends throw, receivers drain buffered values and then throw.
+///
+/// Closing is idempotent and wakes every blocked sender, receiver and `select` call.
+pub fn channel_close<T>(channel: &HxRef<ChannelHandle<T>>) {
+    let shared = channel_shared(channel);
+    let mut state = shared.state.lock();
+    state.closed = true;
+    state.wake_selectors();
+    shared.not_empty.notify_all();
+    shared.not_full.notify_all();
+}
+
+pub fn channel_is_closed<T>(channel: &HxRef<ChannelHandle<T>>) -> bool {
+    channel_shared(channel).state.lock().closed
+}
+
+/// Number of values currently buffered.
+pub fn channel_len<T>(channel: &HxRef<ChannelHandle<T>>) -> i32 {
+    channel_shared(channel).state.lock().queue.len() as i32
+}
+
+/// Wait until one of `channels` has a buffered value or is closed, or until `deadline`.
+///
+/// Why
+/// - Consumers that own several channels otherwise poll `try_recv` in a sleep loop.
+///
+/// What
+/// - Returns the lowest ready index, or `-1` when the deadline passes first.
+/// - Readiness is not consumption: the single consumer follows up with `try_recv`, which yields the
+///   value or throws `HXRT-CHANNEL-CLOSED` for a closed, drained channel.
+///
+/// How
+/// - The signal is registered on every channel before readiness is checked, so a send racing with
+///   registration still wakes this call. Registrations are removed before returning.
+fn channel_select_until<T>(
+    channels: &Array<HxRef<ChannelHandle<T>>>,
+    deadline: Option<Instant>,
+) -> i32 {
+    let shared: Vec<Arc<ChannelShared<T>>> =
+        channels.iter().map(|ch| channel_shared(&ch)).collect();
+    if shared.is_empty() {
+        return -1;
+    }
+    let signal = Arc::new(SelectSignal::default());
+    for channel in &shared {
+        channel.state.lock().select_waiters.push(signal.clone());
+    }
+    let ready = loop {
+        *signal.fired.lock() = false;
+        if let Some(index) = shared
+            .iter()
+            .position(|channel| channel.state.lock().is_ready())
+        {
+            break index as i32;
+        }
+        let mut fired = signal.fired.lock();
+        if *fired {
+            continue;
+        }
+        match deadline {
+            Some(deadline) => {
+                if signal.cv.wait_until(&mut fired, deadline).timed_out() && !*fired {
+                    drop(fired);
+                    break shared
+                        .iter()
+                        .position(|channel| channel.state.lock().is_ready())
+                        .map_or(-1, |index| index as i32);
+                }
+            }
+            None => signal.cv.wait(&mut fired),
+        }
     };
-    match recv_result {
-        Ok(value) => Some(value),
-        Err(mpsc::TryRecvError::Empty) => None,
-        Err(mpsc::TryRecvError::Disconnected) => throw_msg("Channel is disconnected"),
+    for channel in &shared {
+        channel
+            .state
+            .lock()
+            .select_waiters
+            .retain(|waiter| !Arc::ptr_eq(waiter, &signal));
     }
+    ready
+}
+
+/// Block until one of `channels` is ready (see `channel_select_until`).
+pub fn channel_select<T>(channels: &Array<HxRef<ChannelHandle<T>>>) -> i32 {
+    channel_select_until(channels, None)
+}
+
+/// Like `channel_select`, but returns `-1` when nothing becomes ready within `timeout`.
+pub fn channel_select_timeout<T>(
+    channels: &Array<HxRef<ChannelHandle<T>>>,
+    timeout: Duration,
+) -> i32 {
+    channel_select_until(channels, Some(Instant::now() + timeout))
 }
 
 pub fn task_spawn<T>(job: HxDynRef<dyn Fn() -> T + Send + Sync>) -> HxRef<TaskHandle<T>>
@@ -181,25 +490,49 @@ where
         None => throw_msg("Null Access"),
     };
 
-    let join = std::thread::spawn(move || job());
-    HxRef::new(TaskHandle {
-        join: ParkMutex::new(Some(join)),
-    })
+    let slot = Arc::new(TaskSlot {
+        state: ParkMutex::new(TaskState::Running),
+        done: Condvar::new(),
+    });
+    let publish = slot.clone();
+    crate::thread::task_pool_submit(Box::new(move || {
+        let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            exception::catch_unwind(|| job())
+        }));
+        *publish.state.lock() = match outcome {
+            Ok(Ok(value)) => TaskState::Finished(value),
+            Ok(Err(error)) => TaskState::Threw(error),
+            Err(_) => TaskState::Panicked,
+        };
+        publish.done.notify_all();
+    }));
+    HxRef::new(TaskHandle { slot })
 }
 
+/// Wait for a task's result.
+///
+/// A Haxe exception thrown by the job is rethrown here unchanged; a Rust panic surfaces as
+/// `"Task panicked"`. Each task can be joined once.
 pub fn task_join<T>(task: &HxRef<TaskHandle<T>>) -> T {
-    let join = {
-        let task_borrow = task.borrow();
-        let mut slot = task_borrow.join.lock();
-        match slot.take() {
-            Some(join) => join,
-            None => throw_msg("Task already joined"),
+    let slot = task.borrow().slot.clone();
+    let mut state = slot.state.lock();
+    while matches!(*state, TaskState::Running) {
+        slot.done.wait(&mut state);
+    }
+    match std::mem::replace(&mut *state, TaskState::Joined) {
+        TaskState::Finished(value) => value,
+        TaskState::Threw(error) => {
+            drop(state);
+            exception::throw(error)
+        }
+        TaskState::Panicked => {
+            drop(state);
+            throw_msg("Task panicked")
+        }
+        TaskState::Running | TaskState::Joined => {
+            drop(state);
+            throw_msg("Task already joined")
         }
-    };
-
-    match join.join() {
-        Ok(value) => value,
-        Err(_) => throw_msg("Task panicked"),
     }
 }
 
@@ -372,6 +705,297 @@ where
     callback(&mut *guard)
 }
 
+// ---- Parallel array operations ---------------------------------------------
+
+/// Upper bound on the number of chunks one parallel call splits an array into.
+///
+/// The chunk layout depends only on the array length (never on the host's core count), so chunk
+/// boundaries, reduction grouping, and merge order are identical on every machine.
+const PARALLEL_MAX_CHUNKS: usize = 64;
+
+fn parallel_chunk_len(len: usize) -> usize {
+    len.div_ceil(PARALLEL_MAX_CHUNKS).max(1)
+}
+
+enum ChunkOutcome<R> {
+    Done(R),
+    Threw(Dynamic),
+    Panicked(Box<dyn std::any::Any + Send>),
+    Skipped,
+}
+
+type ChunkWork<I, R> = dyn Fn(I, &dyn Fn() -> bool) -> R + Send + Sync;
+
+/// One parallel call: owned chunk inputs claimed by index, per-index outcomes, and a latch.
+struct ParallelBatch<I, R> {
+    inputs: Vec<ParkMutex<Option<I>>>,
+    outcomes: Vec<ParkMutex<Option<ChunkOutcome<R>>>>,
+    work: Box<ChunkWork<I, R>>,
+    next: AtomicUsize,
+    // Lowest chunk index that threw or panicked; later chunks stop early, earlier ones never do.
+    first_failure: AtomicUsize,
+    pending: ParkMutex<usize>,
+    done: Condvar,
+}
+
+impl<I, R> ParallelBatch<I, R> {
+    /// Claim and run chunks until none are left. Called by the caller and by every helper job.
+    fn drain(&self) {
+        loop {
+            let index = self.next.fetch_add(1, Ordering::SeqCst);
+            let Some(input) = self.inputs.get(index) else {
+                return;
+            };
+            let input = input.lock().take().expect("parallel chunk claimed twice");
+            let stop = || self.first_failure.load(Ordering::SeqCst) < index;
+            let outcome = if stop() {
+                ChunkOutcome::Skipped
+            } else {
+                match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    exception::catch_unwind(|| (self.work)(input, &stop))
+                })) {
+                    Ok(Ok(value)) => ChunkOutcome::Done(value),
+                    Ok(Err(error)) => ChunkOutcome::Threw(error),
+                    Err(payload) => ChunkOutcome::Panicked(payload),
+                }
+            };
+            if !matches!(outcome, ChunkOutcome::Done(_)) {
+                self.first_failure.fetch_min(index, Ordering::SeqCst);
+            }
+            *self.outcomes[index].lock() = Some(outcome);
+            let mut pending = self.pending.lock();
+            *pending -= 1;
+            if *pending == 0 {
+                self.done.notify_all();
+            }
+        }
+    }
+}
+
+/// Run `work` over every input on the shared task pool and return the results in input order.
+///
+/// The calling thread claims chunks alongside the pool helpers, so a call always makes progress
+/// even when every pool worker is busy (including nested parallel calls from inside a worker).
+/// The failure with the lowest input index wins: its Haxe exception is rethrown unchanged, or its
+/// Rust panic is resumed on the calling thread.
+fn parallel_run<I, R, F>(inputs: Vec<I>, work: F) -> Vec<R>
+where
+    I: Send + 'static,
+    R: Send + 'static,
+    F: Fn(I, &dyn Fn() -> bool) -> R + Send + Sync + 'static,
+{
+    let count = inputs.len();
+    if count == 0 {
+        return Vec::new();
+    }
+    let batch = Arc::new(ParallelBatch {
+        inputs: inputs
+            .into_iter()
+            .map(|i| ParkMutex::new(Some(i)))
+            .collect(),
+        outcomes: (0..count).map(|_| ParkMutex::new(None)).collect(),
+        work: Box::new(work),
+        next: AtomicUsize::new(0),
+        first_failure: AtomicUsize::new(usize::MAX),
+        pending: ParkMutex::new(count),
+        done: Condvar::new(),
+    });
+
+    let parallelism = std::thread::available_parallelism().map_or(1, |n| n.get());
+    for _ in 1..parallelism.min(count) {
+        let helper = batch.clone();
+        crate::thread::task_pool_submit(Box::new(move || helper.drain()));
+    }
+    batch.drain();
+
+    let mut pending = batch.pending.lock();
+    while *pending > 0 {
+        batch.done.wait(&mut pending);
+    }
+    drop(pending);
+
+    let mut results = Vec::with_capacity(count);
+    for outcome in &batch.outcomes {
+        match outcome.lock().take() {
+            Some(ChunkOutcome::Done(value)) => results.push(value),
+            Some(ChunkOutcome::Threw(error)) => exception::throw(error),
+            Some(ChunkOutcome::Panicked(payload)) => std::panic::resume_unwind(payload),
+            Some(ChunkOutcome::Skipped) | None => {
+                unreachable!("parallel chunk skipped without an earlier failure")
+            }
+        }
+    }
+    results
+}
+
+/// Split `array` into owned chunks through the `with_slice` borrow path.
+///
+/// Elements are cloned once here (the Haxe callbacks take them by value) and the array borrow is
+/// released before any callback runs, so callbacks may freely read or mutate the source array.
+fn parallel_chunks<T>(array: &Array<T>) -> Vec<Vec<T>>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let split: SliceCallback<T, Vec<Vec<T>>> = HxDynRef::new(HxRc::new(|slice: &[T]| {
+        let chunk_len = parallel_chunk_len(slice.len());
+        slice.chunks(chunk_len).map(<[T]>::to_vec).collect()
+    }));
+    crate::array::with_slice(array, split)
+}
+
+fn parallel_callback<F: ?Sized>(callback: HxDynRef<F>) -> HxRc<F> {
+    match callback.as_arc_opt() {
+        Some(rc) => rc.clone(),
+        None => throw_msg("Null Access"),
+    }
+}
+
+/// Apply `f` to every element in parallel; results keep the source order.
+pub fn parallel_map<T, U>(array: &Array<T>, f: HxDynRef<dyn Fn(T) -> U + Send + Sync>) -> Array<U>
+where
+    T: Clone + Send + Sync + 'static,
+    U: Send + 'static,
+{
+    let f = parallel_callback(f);
+    let mapped = parallel_run(parallel_chunks(array), move |chunk: Vec<T>, stop| {
+        let mut out = Vec::with_capacity(chunk.len());
+        for value in chunk {
+            if stop() {
+                break;
+            }
+            out.push(f(value));
+        }
+        out
+    });
+    Array::from_vec(mapped.into_iter().flatten().collect())
+}
+
+/// Call `f` for every element in parallel. Calls may run in any order and on any thread.
+pub fn parallel_for_each<T>(array: &Array<T>, f: HxDynRef<dyn Fn(T) + Send + Sync>)
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let f = parallel_callback(f);
+    parallel_run(parallel_chunks(array), move |chunk: Vec<T>, stop| {
+        for value in chunk {
+            if stop() {
+                break;
+            }
+            f(value);
+        }
+    });
+}
+
+/// Keep the elements for which `f` returns `true`, in source order.
+pub fn parallel_filter<T>(
+    array: &Array<T>,
+    f: HxDynRef<dyn Fn(T) -> bool + Send + Sync>,
+) -> Array<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let f = parallel_callback(f);
+    let kept = parallel_run(parallel_chunks(array), move |chunk: Vec<T>, stop| {
+        let mut out = Vec::new();
+        for value in chunk {
+            if stop() {
+                break;
+            }
+            if f(value.clone()) {
+                out.push(value);
+            }
+        }
+        out
+    });
+    Array::from_vec(kept.into_iter().flatten().collect())
+}
+
+/// Combine all elements with `f`, starting from `initial`.
+///
+/// `f` must be associative: each chunk is folded left to right on its own, then the chunk results
+/// are folded onto `initial` in source order. The grouping is fixed by the array length, so the
+/// result is reproducible run to run. An empty array returns `initial`.
+pub fn parallel_reduce<T>(
+    array: &Array<T>,
+    f: HxDynRef<dyn Fn(T, T) -> T + Send + Sync>,
+    initial: T,
+) -> T
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let f = parallel_callback(f);
+    let fold = f.clone();
+    let partials = parallel_run(parallel_chunks(array), move |chunk: Vec<T>, stop| {
+        let mut values = chunk.into_iter();
+        let mut acc = values.next()?;
+        for value in values {
+            if stop() {
+                break;
+            }
+            acc = fold(acc, value);
+        }
+        Some(acc)
+    });
+    partials
+        .into_iter()
+        .flatten()
+        .fold(initial, |acc, part| f(acc, part))
+}
+
+/// Sort `array` in place with `compare` (negative / zero / positive, like `Array.sort`).
+///
+/// Chunks are sorted in parallel and then merged pairwise, also in parallel. Both phases are
+/// stable, so equal elements keep their original relative order. The array stays mutably borrowed
+/// for the whole call, exactly like the sequential `Array.sort`.
+pub fn parallel_sort<T>(array: &Array<T>, compare: HxDynRef<dyn Fn(T, T) -> i32 + Send + Sync>)
+where
+    T: Clone + Send + Sync + 'static,
+{
+    let compare = parallel_callback(compare);
+    let sort: MutSliceCallback<T, ()> = HxDynRef::new(HxRc::new(move |slice: &mut [T]| {
+        let chunk_len = parallel_chunk_len(slice.len());
+        let chunks: Vec<Vec<T>> = slice.chunks(chunk_len).map(<[T]>::to_vec).collect();
+
+        let by = compare.clone();
+        let mut runs = parallel_run(chunks, move |mut chunk: Vec<T>, _| {
+            chunk.sort_by(|a, b| by(a.clone(), b.clone()).cmp(&0));
+            chunk
+        });
+        while runs.len() > 1 {
+            let mut pairs = Vec::with_capacity(runs.len().div_ceil(2));
+            let mut drain = runs.into_iter();
+            while let Some(left) = drain.next() {
+                pairs.push((left, drain.next().unwrap_or_default()));
+            }
+            let by = compare.clone();
+            runs = parallel_run(pairs, move |(left, right): (Vec<T>, Vec<T>), _| {
+                merge_sorted(left, right, &*by)
+            });
+        }
+        for (slot, value) in slice.iter_mut().zip(runs.into_iter().flatten()) {
+            *slot = value;
+        }
+    }));
+    crate::array::with_mut_slice(array, sort);
+}
+
+/// Stable merge: on ties the element from `left` (earlier in the source) goes first.
+fn merge_sorted<T: Clone>(left: Vec<T>, right: Vec<T>, compare: &dyn Fn(T, T) -> i32) -> Vec<T> {
+    let mut out = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+        if compare(l.clone(), r.clone()) <= 0 {
+            out.extend(left.next());
+        } else {
+            out.extend(right.next());
+        }
+    }
+    out.extend(left);
+    out.extend(right);
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -384,11 +1008,183 @@ mod tests {
         assert_eq!(channel_try_recv(&channel), None);
     }
 
+    #[test]
+    fn bounded_channel_applies_backpressure_and_timeouts() {
+        let channel = channel_bounded::<i32>(1);
+        assert!(channel_try_send(&channel, 1));
+        assert!(!channel_try_send(&channel, 2));
+        assert!(!channel_send_timeout(&channel, 3, Duration::from_millis(5)));
+        assert_eq!(channel_len(&channel), 1);
+
+        let producer = channel.clone();
+        let sender = std::thread::spawn(move || channel_send(&producer, 4));
+        assert_eq!(channel_recv(&channel), 1);
+        sender.join().unwrap();
+        assert_eq!(
+            channel_recv_timeout(&channel, Duration::from_millis(50)),
+            Some(4)
+        );
+        assert_eq!(
+            channel_recv_timeout(&channel, Duration::from_millis(5)),
+            None
+        );
+    }
+
+    #[test]
+    fn closed_channel_drains_then_throws() {
+        let channel = channel_new::<i32>();
+        channel_send(&channel, 1);
+        channel_close(&channel);
+        assert!(channel_is_closed(&channel));
+
+        let send = exception::catch_unwind(|| channel_send(&channel, 2));
+        assert_eq!(
+            send.unwrap_err()
+                .downcast_ref::<String>()
+                .map(String::as_str),
+            Some(CHANNEL_CLOSED_ERROR)
+        );
+        assert_eq!(channel_recv(&channel), 1);
+        assert!(exception::catch_unwind(|| channel_recv(&channel)).is_err());
+    }
+
+    #[test]
+    fn close_wakes_blocked_receiver() {
+        let channel = channel_new::<i32>();
+        let closer = channel.clone();
+        let handle = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(10));
+            channel_close(&closer);
+        });
+        assert!(exception::catch_unwind(|| channel_recv(&channel)).is_err());
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn select_reports_ready_channel() {
+        let first = channel_new::<i32>();
+        let second = channel_bounded::<i32>(4);
+        let channels = Array::from_vec(vec![first.clone(), second.clone()]);
+        assert_eq!(
+            channel_select_timeout(&channels, Duration::from_millis(5)),
+            -1
+        );
+
+        let producer = second.clone();
+        let handle = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(10));
+            channel_send(&producer, 9);
+        });
+        assert_eq!(channel_select(&channels), 1);
+        assert_eq!(channel_try_recv(&second), Some(9));
+        handle.join().unwrap();
+
+        channel_close(&first);
+        assert_eq!(
+            channel_select_timeout(&channels, Duration::from_millis(50)),
+            0
+        );
+    }
+
     #[test]
     fn task_spawn_join() {
         let job_rc: HxRc<dyn Fn() -> i32 + Send + Sync> = HxRc::new(|| 42);
         let task = task_spawn(HxDynRef::new(job_rc));
         assert_eq!(task_join(&task), 42);
+        assert!(exception::catch_unwind(|| task_join(&task)).is_err());
+    }
+
+    #[test]
+    fn task_join_rethrows_haxe_exception_and_nested_tasks_complete() {
+        let failing: HxRc<dyn Fn() -> i32 + Send + Sync> =
+            HxRc::new(|| exception::throw(dynamic::from(String::from("task boom"))));
+        let task = task_spawn(HxDynRef::new(failing));
+        let error = exception::catch_unwind(|| task_join(&task)).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<String>().map(String::as_str),
+            Some("task boom")
+        );
+
+        let outer: HxRc<dyn Fn() -> i32 + Send + Sync> = HxRc::new(|| {
+            let inner: HxRc<dyn Fn() -> i32 + Send + Sync> = HxRc::new(|| 20);
+            let tasks: Vec<_> = (0..4)
+                .map(|_| task_spawn(HxDynRef::new(inner.clone())))
+                .collect();
+            tasks.iter().map(task_join).sum()
+        });
+        assert_eq!(task_join(&task_spawn(HxDynRef::new(outer))), 80);
+    }
+
+    fn array_of(values: &[i32]) -> Array<i32> {
+        Array::from_vec(values.to_vec())
+    }
+
+    #[test]
+    fn parallel_map_and_filter_keep_source_order() {
+        let input = array_of(&(0..1000).collect::<Vec<_>>());
+        let double: HxRc<dyn Fn(i32) -> i32 + Send + Sync> = HxRc::new(|v| v * 2);
+        let third: HxRc<dyn Fn(i32) -> bool + Send + Sync> = HxRc::new(|v| v % 3 == 0);
+
+        let mapped = parallel_map(&input, HxDynRef::new(double.clone()));
+        assert_eq!(
+            mapped.to_vec(),
+            (0..1000).map(|v| v * 2).collect::<Vec<_>>()
+        );
+        let kept = parallel_filter(&input, HxDynRef::new(third));
+        assert_eq!(
+            kept.to_vec(),
+            (0..1000).filter(|v| v % 3 == 0).collect::<Vec<_>>()
+        );
+
+        let seen = Arc::new(AtomicUsize::new(0));
+        let count = seen.clone();
+        let visit: HxRc<dyn Fn(i32) + Send + Sync> = HxRc::new(move |_| {
+            count.fetch_add(1, Ordering::SeqCst);
+        });
+        parallel_for_each(&input, HxDynRef::new(visit));
+        assert_eq!(seen.load(Ordering::SeqCst), 1000);
+        assert!(parallel_map(&array_of(&[]), HxDynRef::new(double)).is_empty());
+    }
+
+    #[test]
+    fn parallel_reduce_and_stable_sort() {
+        let input = array_of(&(1..=500).collect::<Vec<_>>());
+        let add: HxRc<dyn Fn(i32, i32) -> i32 + Send + Sync> = HxRc::new(|a, b| a + b);
+        assert_eq!(
+            parallel_reduce(&input, HxDynRef::new(add.clone()), 10),
+            125_260
+        );
+        assert_eq!(parallel_reduce(&array_of(&[]), HxDynRef::new(add), 7), 7);
+
+        // Sort by value / 10 only, so stability is observable through the low digit.
+        let values: Vec<i32> = (0..777).map(|i| (i * 7919) % 1000).collect();
+        let array = array_of(&values);
+        let by_tens: HxRc<dyn Fn(i32, i32) -> i32 + Send + Sync> =
+            HxRc::new(|a, b| a / 10 - b / 10);
+        parallel_sort(&array, HxDynRef::new(by_tens));
+        let mut expected = values;
+        expected.sort_by_key(|v| v / 10);
+        assert_eq!(array.to_vec(), expected);
+    }
+
+    #[test]
+    fn parallel_rethrows_earliest_haxe_exception() {
+        let input = array_of(&(0..640).collect::<Vec<_>>());
+        let failing: HxRc<dyn Fn(i32) -> i32 + Send + Sync> = HxRc::new(|v| {
+            if v % 100 == 99 {
+                exception::throw(dynamic::from(format!("boom {v}")));
+            }
+            v
+        });
+        for _ in 0..10 {
+            let error =
+                exception::catch_unwind(|| parallel_map(&input, HxDynRef::new(failing.clone())))
+                    .unwrap_err();
+            assert_eq!(
+                error.downcast_ref::<String>().map(String::as_str),
+                Some("boom 99")
+            );
+        }
     }
 
     #[test]
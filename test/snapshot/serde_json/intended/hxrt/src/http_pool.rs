@@ -0,0 +1,223 @@
+//! `hxrt::http_pool`
+//!
+//! Keep-alive connection pool behind `sys.Http`.
+//!
+//! Why
+//! - `sys.Http` opened a new socket, plus a full TLS handshake for HTTPS, for every request; for
+//!   service-to-service calls that setup dominated request latency.
+//!
+//! What
+//! - Idle connected sockets kept per key (`"https://host:443"`), with a cap on connections per key
+//!   (checked out plus idle, default 8) and an idle timeout (default 30 s; `0` disables pooling).
+//! - `http_pool_acquire` reserves a connection slot, waiting while the key is at its cap, and moves
+//!   a live idle connection into the caller's socket when one exists. `http_pool_release` returns
+//!   the slot and, when the response left the connection reusable, keeps it idle.
+//!
+//! How
+//! - Connections move between the pool and `sys.net.Socket` handles by swapping the
+//!   `SocketHandle` state, so a TLS session travels with its connection and the Haxe socket object
+//!   stays the same.
+//! - Idle connections are peeked before reuse (`SocketHandle::is_idle_connection`); a connection
+//!   the server closed, or one with unsolicited bytes, is dropped. Expired connections are closed
+//!   lazily on the next pool access for their key, or by `http_pool_clear`.
+
+use crate::cell::HxRef;
+use crate::net::SocketHandle;
+use crate::{dynamic, exception};
+use parking_lot::{Condvar, Mutex as ParkMutex};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+const HTTP_POOL_ERROR: &str = "HXRT-HTTP-POOL";
+
+fn throw_msg(msg: String) -> ! {
+    exception::throw(dynamic::from(msg))
+}
+
+#[derive(Debug)]
+struct IdleConnection {
+    socket: SocketHandle,
+    since: Instant,
+}
+
+#[derive(Debug, Default)]
+struct HostEntry {
+    // Slots handed out by `http_pool_acquire` and not yet released.
+    checked_out: usize,
+    // Oldest first; reuse takes the most recent.
+    idle: Vec<IdleConnection>,
+}
+
+#[derive(Debug)]
+struct PoolState {
+    max_connections: usize,
+    idle_timeout: Duration,
+    hosts: HashMap<String, HostEntry>,
+}
+
+impl PoolState {
+    fn evict_expired(&mut self, key: &str) {
+        let idle_timeout = self.idle_timeout;
+        if let Some(entry) = self.hosts.get_mut(key) {
+            entry
+                .idle
+                .retain(|idle| idle.since.elapsed() < idle_timeout);
+        }
+    }
+
+    fn forget_if_unused(&mut self, key: &str) {
+        if self
+            .hosts
+            .get(key)
+            .is_some_and(|entry| entry.checked_out == 0 && entry.idle.is_empty())
+        {
+            self.hosts.remove(key);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Pool {
+    state: ParkMutex<PoolState>,
+    // Signalled whenever a slot is released.
+    released: Condvar,
+}
+
+fn pool() -> &'static Pool {
+    static POOL: OnceLock<Pool> = OnceLock::new();
+    POOL.get_or_init(|| Pool {
+        state: ParkMutex::new(PoolState {
+            max_connections: 8,
+            idle_timeout: Duration::from_secs(30),
+            hosts: HashMap::new(),
+        }),
+        released: Condvar::new(),
+    })
+}
+
+/// Reserve a connection slot for `key` and, if a live idle connection exists, move it into
+/// `socket` (replacing its unconnected state). Returns `true` when a pooled connection was moved
+/// in; `false` means the caller connects `socket` itself.
+///
+/// Waits while `key` is at its connection cap; after `timeout_seconds` (`<= 0` waits forever) it
+/// throws a catchable String beginning with `HXRT-HTTP-POOL`. Every acquire must be paired with
+/// one `http_pool_release`, also when the request fails.
+pub fn http_pool_acquire<K: AsRef<str>>(
+    key: K,
+    socket: &HxRef<SocketHandle>,
+    timeout_seconds: f64,
+) -> bool {
+    let key = key.as_ref();
+    let deadline = (timeout_seconds > 0.0)
+        .then(|| Duration::try_from_secs_f64(timeout_seconds).ok())
+        .flatten()
+        .map(|timeout| Instant::now() + timeout);
+    let pool = pool();
+    let mut state = pool.state.lock();
+    loop {
+        state.evict_expired(key);
+        let max_connections = state.max_connections;
+        let entry = state.hosts.entry(key.to_owned()).or_default();
+        while let Some(idle) = entry.idle.pop() {
+            if idle.socket.is_idle_connection() {
+                entry.checked_out += 1;
+                *socket.borrow_mut() = idle.socket;
+                return true;
+            }
+        }
+        if entry.checked_out < max_connections {
+            entry.checked_out += 1;
+            return false;
+        }
+        match deadline {
+            Some(deadline) => {
+                if pool.released.wait_until(&mut state, deadline).timed_out() {
+                    throw_msg(format!(
+                        "{HTTP_POOL_ERROR}: timed out waiting for a free connection to {key}"
+                    ));
+                }
+            }
+            None => pool.released.wait(&mut state),
+        }
+    }
+}
+
+/// Return the slot taken by `http_pool_acquire`. With `reusable`, the connection in `socket` is
+/// moved into the pool (leaving `socket` unconnected); otherwise the caller closes it.
+pub fn http_pool_release<K: AsRef<str>>(key: K, socket: &HxRef<SocketHandle>, reusable: bool) {
+    let key = key.as_ref();
+    let pool = pool();
+    let mut state = pool.state.lock();
+    let keep = reusable && !state.idle_timeout.is_zero();
+    let max_connections = state.max_connections;
+    if let Some(entry) = state.hosts.get_mut(key) {
+        entry.checked_out = entry.checked_out.saturating_sub(1);
+        if keep && socket.borrow().is_idle_connection() {
+            entry.idle.push(IdleConnection {
+                socket: std::mem::take(&mut *socket.borrow_mut()),
+                since: Instant::now(),
+            });
+        }
+        // A lowered cap trims the oldest idle connections.
+        let excess = (entry.checked_out + entry.idle.len()).saturating_sub(max_connections);
+        entry.idle.drain(..excess.min(entry.idle.len()));
+    }
+    state.forget_if_unused(key);
+    drop(state);
+    pool.released.notify_all();
+}
+
+/// Most connections per key, checked out plus idle (default 8).
+pub fn http_pool_set_max_connections(connections: i32) {
+    if connections < 1 {
+        throw_msg(format!(
+            "{HTTP_POOL_ERROR}: max connections must be at least 1 (got {connections})"
+        ));
+    }
+    pool().state.lock().max_connections = connections as usize;
+    pool().released.notify_all();
+}
+
+pub fn http_pool_max_connections() -> i32 {
+    pool().state.lock().max_connections as i32
+}
+
+/// How long an idle connection stays reusable (default 30 s); `0` disables pooling and closes the
+/// idle connections.
+pub fn http_pool_set_idle_timeout(seconds: f64) {
+    let timeout = match Duration::try_from_secs_f64(seconds) {
+        Ok(timeout) => timeout,
+        Err(_) => throw_msg(format!(
+            "{HTTP_POOL_ERROR}: idle timeout must be a non-negative number of seconds (got {seconds})"
+        )),
+    };
+    pool().state.lock().idle_timeout = timeout;
+    if timeout.is_zero() {
+        http_pool_clear();
+    }
+}
+
+pub fn http_pool_idle_timeout() -> f64 {
+    pool().state.lock().idle_timeout.as_secs_f64()
+}
+
+/// Close every idle connection. Checked-out connections are unaffected.
+pub fn http_pool_clear() {
+    let mut state = pool().state.lock();
+    for entry in state.hosts.values_mut() {
+        entry.idle.clear();
+    }
+    state.hosts.retain(|_, entry| entry.checked_out > 0);
+}
+
+/// Idle connections currently pooled for `key` (expired ones excluded).
+pub fn http_pool_idle_count<K: AsRef<str>>(key: K) -> i32 {
+    let key = key.as_ref();
+    let mut state = pool().state.lock();
+    state.evict_expired(key);
+    state
+        .hosts
+        .get(key)
+        .map_or(0, |entry| entry.idle.len() as i32)
+}
@@ -59,6 +59,18 @@ impl<T> PartialEq for Array<T> {
 
 impl<T> Eq for Array<T> {}
 
+// Cycle collection: an array is traced through its shared `Vec` storage.
+#[cfg(feature = "cycle_collector")]
+impl<T: crate::cell::HxTraceEdges + Send + Sync + 'static> crate::cell::HxTraceEdges for Array<T> {
+    fn trace_edges(&self, tracer: &mut crate::cell::HxTracer) {
+        self.inner.trace_edges(tracer);
+    }
+
+    fn clear_edges(&mut self) {
+        *self = Self::null();
+    }
+}
+
 pub type SliceCallback<T, R> = HxDynRef<dyn Fn(&[T]) -> R + Send + Sync>;
 pub type MutSliceCallback<T, R> = HxDynRef<dyn Fn(&mut [T]) -> R + Send + Sync>;
 
@@ -19,14 +19,18 @@
 //!   `haxe.io.Error.Custom` values. Neither route uses a Rust panic as an application error.
 //! - `stdin_read_bytes` and std-stream byte writes operate directly on `hxrt::bytes::Bytes`
 //!   via typed runtime buffers.
+//! - Exit handlers (`add_exit_handler`) run in LIFO order on `Sys.exit`, on normal `main` return,
+//!   and when an uncaught exception ends `main`, followed by a stdout/stderr flush.
 
 use crate::array::Array;
 use crate::bytes::{self, Bytes};
-use crate::cell::HxRef;
+use crate::cell::{HxDynRef, HxRc, HxRef};
 use crate::string::HxString;
 use crate::{dynamic, exception, io as hx_io};
+use parking_lot::Mutex as ParkMutex;
 use std::io::{Read, Write};
 use std::process::Command;
+use std::sync::atomic::{AtomicI32, Ordering};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Converts a normal Rust failure into the Haxe-visible error family owned by the calling API.
@@ -278,10 +282,114 @@ where
     status.code().unwrap_or(1)
 }
 
+/// `Sys.exit`: run exit handlers and flush the standard streams, then end the process.
 pub fn exit(code: i32) -> ! {
+    run_exit_handlers();
     std::process::exit(code);
 }
 
+// ---- Exit handlers ----------------------------------------------------------
+
+const EXIT_HANDLER_ERROR: &str = "HXRT-EXIT-HANDLER";
+
+type ExitHandler = HxRc<dyn Fn() + Send + Sync>;
+
+static EXIT_HANDLERS: ParkMutex<Vec<(i32, ExitHandler)>> = parking_lot::const_mutex(Vec::new());
+static NEXT_EXIT_HANDLER_ID: AtomicI32 = AtomicI32::new(1);
+
+/// Held by every unit test that registers or drains exit handlers (here and in
+/// `async_::run_entry`), so parallel tests cannot run each other's handlers.
+#[cfg(test)]
+pub(crate) static EXIT_HANDLERS_TEST_LOCK: ParkMutex<()> = parking_lot::const_mutex(());
+
+/// Register `handler` to run once when the program ends; returns an id for `remove_exit_handler`.
+///
+/// Why
+/// - `std::process::exit` skips destructors and buffered output, so open files, database
+///   connections, and pending event-loop work would otherwise be abandoned on `Sys.exit`.
+///
+/// What
+/// - Handlers run in reverse registration order (LIFO) from `Sys.exit`, from normal `main` return,
+///   and while an uncaught exception unwinds out of `main`.
+///
+/// How
+/// - Generated `main` holds a `MainExitGuard` when the program uses exit handlers; async `main`
+///   runs them from `hxrt::async_::run_main`.
+pub fn add_exit_handler(handler: HxDynRef<dyn Fn() + Send + Sync>) -> i32 {
+    let handler: ExitHandler = match handler.as_arc_opt() {
+        Some(rc) => rc.clone(),
+        None => exception::throw(dynamic::from(HxString::from("Null Access"))),
+    };
+    let id = NEXT_EXIT_HANDLER_ID.fetch_add(1, Ordering::Relaxed);
+    EXIT_HANDLERS.lock().push((id, handler));
+    id
+}
+
+/// Unregister a handler; returns `false` when it already ran or was removed.
+pub fn remove_exit_handler(id: i32) -> bool {
+    let mut handlers = EXIT_HANDLERS.lock();
+    match handlers
+        .iter()
+        .position(|(handler_id, _)| *handler_id == id)
+    {
+        Some(index) => {
+            handlers.remove(index);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Run every registered exit handler once, newest first, then flush stdout and stderr.
+///
+/// Each handler is removed before it runs, so a handler that calls `Sys.exit` (or registers another
+/// handler) continues the same sequence instead of repeating it. A throwing handler is reported as
+/// `[HXRT-EXIT-HANDLER] <payload>` on stderr and the remaining handlers still run: this can execute
+/// during unwinding, where no Haxe frame is left to catch it.
+pub fn run_exit_handlers() {
+    loop {
+        let next = EXIT_HANDLERS.lock().pop();
+        let Some((_, handler)) = next else {
+            break;
+        };
+        let outcome = exception::preserving_in_flight(|| {
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                exception::catch_unwind(|| handler())
+            }))
+        });
+        let failure = match outcome {
+            Ok(Ok(())) => None,
+            Ok(Err(error)) => Some(error.to_haxe_string()),
+            Err(_) => Some(String::from("exit handler panicked")),
+        };
+        if let Some(failure) = failure {
+            let _ = writeln!(std::io::stderr().lock(), "[{EXIT_HANDLER_ERROR}] {failure}");
+        }
+    }
+    #[cfg(feature = "cycle_collector")]
+    crate::cell::collector_report_at_exit();
+    #[cfg(feature = "stats")]
+    crate::stats::dump_at_exit();
+    let _ = std::io::stdout().flush();
+    let _ = std::io::stderr().flush();
+}
+
+/// Runs exit handlers when generated `main` returns or unwinds with an uncaught exception.
+pub struct MainExitGuard {
+    _private: (),
+}
+
+/// First statement of a generated `main` that uses exit handlers.
+pub fn main_exit_guard() -> MainExitGuard {
+    MainExitGuard { _private: () }
+}
+
+impl Drop for MainExitGuard {
+    fn drop(&mut self) {
+        run_exit_handlers();
+    }
+}
+
 pub fn time() -> f64 {
     PortableFailure::System("Sys.time")
         .resolve(SystemTime::now().duration_since(UNIX_EPOCH))
@@ -423,6 +531,7 @@ pub fn stderr_flush() {
 mod tests {
     use super::*;
     use std::io;
+    use std::sync::Arc;
 
     struct FailingIo;
 
@@ -494,4 +603,46 @@ mod tests {
         };
         assert!(payload.downcast_ref::<HxString>().is_some());
     }
+
+    fn exit_handler(f: impl Fn() + Send + Sync + 'static) -> HxDynRef<dyn Fn() + Send + Sync> {
+        let handler: ExitHandler = HxRc::new(f);
+        HxDynRef::new(handler)
+    }
+
+    #[test]
+    fn exit_handlers_run_lifo_once_including_during_unwind() {
+        let _lock = EXIT_HANDLERS_TEST_LOCK.lock();
+        let order = Arc::new(ParkMutex::new(Vec::new()));
+        let record = |label: &'static str| {
+            let order = order.clone();
+            exit_handler(move || order.lock().push(label))
+        };
+
+        add_exit_handler(record("first"));
+        let removed = add_exit_handler(record("removed"));
+        add_exit_handler(exit_handler(|| {
+            exception::throw(dynamic::from(HxString::from("boom")))
+        }));
+        add_exit_handler(record("last"));
+        assert!(remove_exit_handler(removed));
+        assert!(!remove_exit_handler(removed));
+
+        run_exit_handlers();
+        assert_eq!(*order.lock(), vec!["last", "first"]);
+        run_exit_handlers();
+        assert_eq!(order.lock().len(), 2);
+
+        // An uncaught throw leaving `main` still runs handlers from the guard, and a handler that
+        // throws during that unwind is contained.
+        add_exit_handler(record("unwound"));
+        add_exit_handler(exit_handler(|| {
+            exception::throw(dynamic::from(HxString::from("again")))
+        }));
+        let caught = exception::catch_unwind(|| {
+            let _guard = main_exit_guard();
+            exception::throw(dynamic::from(HxString::from("uncaught")))
+        });
+        assert!(caught.is_err());
+        assert_eq!(*order.lock(), vec!["last", "first", "unwound"]);
+    }
 }
@@ -57,6 +57,7 @@ pub fn close_handle(handle: &HxRef<MysqlConnectionHandle>) {
 }
 
 pub fn request(handle: &HxRef<MysqlConnectionHandle>, sql: &str) -> HxRef<QueryResult> {
+    let _query = hxrt::db::query_span("mysql", sql);
     let binding = handle.borrow();
     let mut conn_guard = binding.conn.lock().unwrap_or_else(|e| e.into_inner());
     let conn = conn_guard
@@ -42,6 +42,8 @@ fn ensure_panic_hook_installed() {
 /// - Rust panic payloads must be `Send`, but Haxe thrown values are not necessarily `Send`.
 /// - We keep the payload in thread-local storage and panic with a small `u64` id instead.
 pub fn throw(value: Dynamic) -> ! {
+    #[cfg(feature = "stats")]
+    crate::stats::record_throw();
     let id = NEXT_ID.with(|c| {
         let id = c.get();
         c.set(id.wrapping_add(1));
@@ -103,6 +105,29 @@ where
     }
 }
 
+/// Run `f` without disturbing a Haxe throw that is currently unwinding.
+///
+/// Haxe code executed from a destructor during unwinding (for example exit handlers run while an
+/// uncaught exception leaves `main`) may throw and catch its own exceptions; the in-flight payload
+/// must still be found by whichever frame eventually catches the original throw.
+pub(crate) fn preserving_in_flight<R>(f: impl FnOnce() -> R) -> R {
+    struct Restore {
+        active: u64,
+        payload: Option<Dynamic>,
+    }
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            ACTIVE_ID.with(|c| c.set(self.active));
+            PAYLOAD.with(|p| *p.borrow_mut() = self.payload.take());
+        }
+    }
+    let _restore = Restore {
+        active: ACTIVE_ID.with(|c| c.replace(0)),
+        payload: PAYLOAD.with(|p| p.borrow_mut().take()),
+    };
+    f()
+}
+
 /// Re-throw a previously caught payload.
 ///
 /// Useful when a `try/catch` does not match the caught value's type; we rethrow so
@@ -1,6 +1,6 @@
 use crate::array::Array;
 use crate::bytes::{self, Bytes};
-use crate::cell::HxRef;
+use crate::cell::{HxDynRef, HxRef};
 use crate::{dynamic, exception, io};
 use std::io::{Read, Write};
 use std::process::{Child, ChildStderr, ChildStdin, ChildStdout, Command, ExitStatus, Stdio};
@@ -126,10 +126,104 @@ impl Process {
     }
 }
 
+/// Owned duplicate of a child pipe descriptor, registrable with an event loop.
+#[cfg(unix)]
+struct PipeSource(std::os::fd::OwnedFd);
+
+#[cfg(unix)]
+impl mio::event::Source for PipeSource {
+    fn register(
+        &mut self,
+        registry: &mio::Registry,
+        token: mio::Token,
+        interest: mio::Interest,
+    ) -> std::io::Result<()> {
+        use std::os::fd::AsRawFd;
+        mio::unix::SourceFd(&self.0.as_raw_fd()).register(registry, token, interest)
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &mio::Registry,
+        token: mio::Token,
+        interest: mio::Interest,
+    ) -> std::io::Result<()> {
+        use std::os::fd::AsRawFd;
+        mio::unix::SourceFd(&self.0.as_raw_fd()).reregister(registry, token, interest)
+    }
+
+    fn deregister(&mut self, registry: &mio::Registry) -> std::io::Result<()> {
+        use std::os::fd::AsRawFd;
+        mio::unix::SourceFd(&self.0.as_raw_fd()).deregister(registry)
+    }
+}
+
+/// Register the child's stdout (or, with `stderr`, its stderr) pipe with the `EventLoop` of
+/// `thread_id` (`sys.io.Process.watchStdout` / `watchStderr`).
+///
+/// `callback` runs on that thread while the pipe has data or has reached end of file; a pipe at end
+/// of file stays ready, so the callback should cancel the watch once a read reports EOF. Returns an
+/// `EventHandler` id for `EventLoop.cancel`.
+///
+/// Unix only: other platforms throw `HXRT-EVENTLOOP-IO` (their pipes cannot join a readiness poll).
+pub fn process_watch_output(
+    thread_id: i32,
+    process: &HxRef<Process>,
+    stderr: bool,
+    callback: HxDynRef<dyn Fn() + Send + Sync>,
+) -> i32 {
+    #[cfg(unix)]
+    {
+        use std::os::fd::AsFd;
+        let pipe = {
+            let process = process.borrow();
+            let fd = if stderr {
+                process
+                    .stderr
+                    .as_ref()
+                    .map(|pipe| pipe.as_fd().try_clone_to_owned())
+            } else {
+                process
+                    .stdout
+                    .as_ref()
+                    .map(|pipe| pipe.as_fd().try_clone_to_owned())
+            };
+            match fd {
+                Some(Ok(fd)) => fd,
+                Some(Err(error)) => {
+                    throw_msg(&format!("{}: {error}", crate::thread::EVENT_LOOP_IO_ERROR))
+                }
+                None if stderr => throw_msg("Process stderr is closed"),
+                None => throw_msg("Process stdout is closed"),
+            }
+        };
+        crate::thread::event_loop_watch(
+            thread_id,
+            Box::new(PipeSource(pipe)),
+            mio::Interest::READABLE,
+            callback,
+        )
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = (thread_id, process, stderr, callback);
+        throw_msg(&format!(
+            "{}: process pipes can only be watched on Unix",
+            crate::thread::EVENT_LOOP_IO_ERROR
+        ))
+    }
+}
+
 fn exit_code(status: ExitStatus) -> i32 {
     status.code().unwrap_or(1)
 }
 
+#[cfg_attr(feature = "tracing", tracing::instrument(
+    level = "debug",
+    name = "process.spawn",
+    skip(args),
+    fields(args = args.as_ref().map_or(0, Vec::len))
+))]
 pub fn spawn(cmd: &str, args: Option<Vec<String>>, detached: bool) -> HxRef<Process> {
     if detached {
         // Best-effort: still allow pid + exitCode, but no stdin/out/err.
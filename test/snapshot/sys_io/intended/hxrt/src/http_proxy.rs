@@ -0,0 +1,171 @@
+//! `hxrt::http_proxy`
+//!
+//! Outbound proxy support behind `sys.Http`.
+//!
+//! Why
+//! - CI and customer networks only reach the outside through an HTTP proxy; `sys.Http` has to honour
+//!   the conventional proxy environment variables and tunnel HTTPS through `CONNECT`.
+//!
+//! What
+//! - `http_proxy_from_env` picks the proxy URL for a request from `https_proxy`/`HTTPS_PROXY` or
+//!   `http_proxy`/`HTTP_PROXY`, unless `no_proxy`/`NO_PROXY` exempts the target.
+//! - `http_proxy_bypassed` is the `NO_PROXY` match on its own.
+//! - `http_proxy_connect` opens a `CONNECT` tunnel on a socket already connected to the proxy; the
+//!   caller then runs the TLS handshake over it (`SocketHandle::tls_handshake_client`).
+//!
+//! How
+//! - Lower-case variables win over upper-case ones, as in curl; empty values count as unset.
+//! - When `REQUEST_METHOD` is set the process is running as a CGI script, where a request's
+//!   `Proxy:` header arrives as `HTTP_PROXY`; that variable is ignored then ("httpoxy",
+//!   CVE-2016-5385), and only the lower-case `http_proxy` configures plain-HTTP proxying.
+//! - `NO_PROXY` is a comma-separated list of `*`, host names (matching the host and its
+//!   subdomains, with or without a leading `.`) and IP literals, each optionally with `:port`.
+//! - The `CONNECT` response head is read byte by byte so no TLS bytes are consumed; a non-`2xx`
+//!   answer throws a catchable String beginning with `HXRT-HTTP-PROXY`.
+
+use crate::cell::HxRef;
+use crate::net::SocketHandle;
+use crate::{dynamic, exception};
+
+const HTTP_PROXY_ERROR: &str = "HXRT-HTTP-PROXY";
+const MAX_CONNECT_HEAD_BYTES: usize = 64 * 1024;
+
+fn throw_msg(msg: String) -> ! {
+    exception::throw(dynamic::from(msg))
+}
+
+fn env_value(names: &[&str]) -> Option<String> {
+    names
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .map(|value| value.trim().to_owned())
+        .find(|value| !value.is_empty())
+}
+
+/// Proxy URL from the environment for a request to `host:port`, or `None` when no proxy applies.
+pub fn http_proxy_from_env<H: AsRef<str>>(secure: bool, host: H, port: i32) -> Option<String> {
+    let names: &[&str] = if secure {
+        &["https_proxy", "HTTPS_PROXY"]
+    } else if std::env::var_os("REQUEST_METHOD").is_some() {
+        &["http_proxy"]
+    } else {
+        &["http_proxy", "HTTP_PROXY"]
+    };
+    let proxy = env_value(names)?;
+    match env_value(&["no_proxy", "NO_PROXY"]) {
+        Some(no_proxy) if http_proxy_bypassed(&no_proxy, host.as_ref(), port) => None,
+        _ => Some(proxy),
+    }
+}
+
+/// Whether the `NO_PROXY` list `no_proxy` exempts `host:port` from proxying.
+pub fn http_proxy_bypassed<N: AsRef<str>, H: AsRef<str>>(no_proxy: N, host: H, port: i32) -> bool {
+    let host = host
+        .as_ref()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .trim_end_matches('.')
+        .to_ascii_lowercase();
+    no_proxy
+        .as_ref()
+        .split(',')
+        .map(|entry| entry.trim().to_ascii_lowercase())
+        .filter(|entry| !entry.is_empty())
+        .any(|entry| {
+            if entry == "*" {
+                return true;
+            }
+            let (pattern, entry_port) = split_port(&entry);
+            if entry_port.is_some_and(|entry_port| entry_port != port) {
+                return false;
+            }
+            let pattern = pattern
+                .trim_start_matches("*.")
+                .trim_start_matches('.')
+                .trim_start_matches('[')
+                .trim_end_matches(']');
+            !pattern.is_empty()
+                && (host == pattern
+                    || host
+                        .strip_suffix(pattern)
+                        .is_some_and(|prefix| prefix.ends_with('.')))
+        })
+}
+
+// `host:port`, `[v6]:port` and bare IPv6 literals (several colons, no port).
+fn split_port(entry: &str) -> (&str, Option<i32>) {
+    let colon = match entry.rfind(':') {
+        Some(colon) if entry.starts_with('[') || entry.matches(':').count() == 1 => colon,
+        _ => return (entry, None),
+    };
+    if entry.starts_with('[') && !entry[..colon].ends_with(']') {
+        return (entry, None);
+    }
+    match entry[colon + 1..].parse() {
+        Ok(port) => (&entry[..colon], Some(port)),
+        Err(_) => (entry, None),
+    }
+}
+
+/// Send `CONNECT host:port` over `socket` (connected to the proxy) and read the proxy's answer.
+///
+/// `authorization` is sent as the `Proxy-Authorization` header value. Returns once the tunnel is
+/// open; throws `HXRT-HTTP-PROXY` when the proxy refuses it or closes the connection.
+pub fn http_proxy_connect<H: AsRef<str>>(
+    socket: &HxRef<SocketHandle>,
+    host: H,
+    port: i32,
+    authorization: Option<String>,
+) {
+    let host = host.as_ref();
+    let authority = if host.contains(':') {
+        format!("[{host}]:{port}")
+    } else {
+        format!("{host}:{port}")
+    };
+    let mut request = format!("CONNECT {authority} HTTP/1.1\r\nHost: {authority}\r\n");
+    if let Some(authorization) = authorization {
+        request.push_str(&format!("Proxy-Authorization: {authorization}\r\n"));
+    }
+    request.push_str("\r\n");
+
+    let mut pending = request.as_bytes();
+    while !pending.is_empty() {
+        let written = socket.borrow_mut().write_stream(pending);
+        if written <= 0 {
+            throw_msg(format!(
+                "{HTTP_PROXY_ERROR}: proxy closed the connection during CONNECT to {authority}"
+            ));
+        }
+        pending = &pending[written as usize..];
+    }
+
+    let mut head = Vec::new();
+    let mut byte = [0u8; 1];
+    while !head.ends_with(b"\r\n\r\n") {
+        if socket.borrow_mut().read_stream(&mut byte) != 1 {
+            throw_msg(format!(
+                "{HTTP_PROXY_ERROR}: proxy closed the connection during CONNECT to {authority}"
+            ));
+        }
+        if head.len() == MAX_CONNECT_HEAD_BYTES {
+            throw_msg(format!(
+                "{HTTP_PROXY_ERROR}: oversized CONNECT response for {authority}"
+            ));
+        }
+        head.push(byte[0]);
+    }
+
+    let head = String::from_utf8_lossy(&head);
+    let status_line = head.lines().next().unwrap_or_default();
+    let status = status_line
+        .split(' ')
+        .nth(1)
+        .and_then(|code| code.parse::<i32>().ok());
+    match status {
+        Some(200..=299) => {}
+        _ => throw_msg(format!(
+            "{HTTP_PROXY_ERROR}: CONNECT to {authority} failed: {status_line}"
+        )),
+    }
+}